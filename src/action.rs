@@ -4,6 +4,7 @@ use std::fmt;
 use std::str::FromStr;
 
 use crate::core::WindowId;
+use crate::theme::Flavor;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IpcMessage {
@@ -16,6 +17,47 @@ pub enum IpcMessage {
 pub enum Query {
     Workspaces,
     MinimizedWindows,
+    Tree,
+    Keymaps,
+    Mode,
+    ConfigStatus,
+    /// A picture of a workspace's layout, the focused one when `workspace`
+    /// is `None`.
+    Render {
+        format: RenderFormat,
+        workspace: Option<String>,
+    },
+}
+
+/// Picture format of `Query::Render`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RenderFormat {
+    #[default]
+    Ascii,
+    Svg,
+}
+
+impl fmt::Display for RenderFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderFormat::Ascii => write!(f, "ascii"),
+            RenderFormat::Svg => write!(f, "svg"),
+        }
+    }
+}
+
+impl FromStr for RenderFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ascii" => Ok(RenderFormat::Ascii),
+            "svg" => Ok(RenderFormat::Svg),
+            _ => Err(anyhow!(
+                "Unknown render format: {s} (expected ascii or svg)"
+            )),
+        }
+    }
 }
 
 /// Wire DTO for `Query::MinimizedWindows`. `bundle_id` is populated on
@@ -40,18 +82,34 @@ pub enum Action {
     Move(MoveTarget),
     Toggle(ToggleTarget),
     Master(MasterTarget),
+    Float(FloatTarget),
+    Scroll(ScrollTarget),
     /// Restore a specific minimized window. Not bindable in keymaps and lacks
     /// `FromStr` because `WindowId`s are not stable across daemon restarts, so a
     /// bound id would have no meaning after a reload.
     UnminimizeWindow(WindowId),
+    /// Focus a specific window. Emitted by the keymap when a `focus hint` label
+    /// is typed; not bindable for the same reason as `UnminimizeWindow`.
+    FocusWindow(WindowId),
     Exec {
         command: String,
     },
+    /// Show every workspace scaled down side by side, to jump to one by label
+    /// or click, or to drag a window onto another.
+    Overview,
     Exit,
     Close,
     Mode {
         name: String,
     },
+    Mark {
+        name: String,
+    },
+    /// `theme set <flavor>`. Overrides the configured theme, including the
+    /// light/dark pair, until the next config reload.
+    Theme {
+        flavor: Flavor,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -85,11 +143,17 @@ impl fmt::Display for Action {
             Action::Move(t) => write!(f, "move {t}"),
             Action::Toggle(t) => write!(f, "toggle {t}"),
             Action::Master(t) => write!(f, "master {t}"),
+            Action::Float(t) => write!(f, "float {t}"),
+            Action::Scroll(t) => write!(f, "scroll {t}"),
             Action::UnminimizeWindow(id) => write!(f, "unminimize window {id}"),
+            Action::FocusWindow(id) => write!(f, "focus window {id}"),
             Action::Exec { command } => write!(f, "exec {command}"),
+            Action::Overview => write!(f, "overview"),
             Action::Exit => write!(f, "exit"),
             Action::Close => write!(f, "close"),
             Action::Mode { name } => write!(f, "mode {name}"),
+            Action::Mark { name } => write!(f, "mark {name}"),
+            Action::Theme { flavor } => write!(f, "theme set {flavor}"),
         }
     }
 }
@@ -154,9 +218,22 @@ pub enum FocusTarget {
     Left,
     Right,
     Parent,
-    Tab { direction: TabDirection },
-    Workspace { name: String },
-    Monitor { target: MonitorTarget },
+    Tab {
+        direction: TabDirection,
+    },
+    Workspace {
+        name: String,
+    },
+    Monitor {
+        target: MonitorTarget,
+    },
+    Mark {
+        name: String,
+    },
+    /// Label every visible window and focus the one whose label is typed next.
+    Hint,
+    /// The window that most recently asked for attention.
+    Urgent,
 }
 
 impl fmt::Display for FocusTarget {
@@ -170,6 +247,9 @@ impl fmt::Display for FocusTarget {
             FocusTarget::Tab { direction } => write!(f, "tab {direction}"),
             FocusTarget::Workspace { name } => write!(f, "workspace {name}"),
             FocusTarget::Monitor { target } => write!(f, "monitor {target}"),
+            FocusTarget::Mark { name } => write!(f, "mark {name}"),
+            FocusTarget::Hint => write!(f, "hint"),
+            FocusTarget::Urgent => write!(f, "urgent"),
         }
     }
 }
@@ -182,6 +262,7 @@ pub enum MoveTarget {
     Right,
     Workspace { name: String },
     Monitor { target: MonitorTarget },
+    Mark { name: String },
 }
 
 impl fmt::Display for MoveTarget {
@@ -193,6 +274,7 @@ impl fmt::Display for MoveTarget {
             MoveTarget::Right => write!(f, "right"),
             MoveTarget::Workspace { name } => write!(f, "workspace {name}"),
             MoveTarget::Monitor { target } => write!(f, "monitor {target}"),
+            MoveTarget::Mark { name } => write!(f, "mark {name}"),
         }
     }
 }
@@ -216,6 +298,7 @@ pub enum ToggleTarget {
     Layout,
     Float,
     Fullscreen,
+    Sticky,
 }
 
 impl fmt::Display for ToggleTarget {
@@ -226,6 +309,7 @@ impl fmt::Display for ToggleTarget {
             ToggleTarget::Layout => write!(f, "layout"),
             ToggleTarget::Float => write!(f, "float"),
             ToggleTarget::Fullscreen => write!(f, "fullscreen"),
+            ToggleTarget::Sticky => write!(f, "sticky"),
         }
     }
 }
@@ -249,6 +333,201 @@ impl fmt::Display for MasterTarget {
     }
 }
 
+/// Keyboard geometry for the focused float. Amounts are logical pixels, or a
+/// percentage of the monitor's work area along the axis being changed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum FloatTarget {
+    Move {
+        direction: FloatDirection,
+        amount: FloatAmount,
+    },
+    Resize {
+        change: FloatResize,
+        amount: FloatAmount,
+    },
+    Center,
+    Snap {
+        preset: SnapPreset,
+    },
+}
+
+impl fmt::Display for FloatTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FloatTarget::Move { direction, amount } => write!(f, "move {direction} {amount}"),
+            FloatTarget::Resize { change, amount } => write!(f, "resize {change} {amount}"),
+            FloatTarget::Center => write!(f, "center"),
+            FloatTarget::Snap { preset } => write!(f, "snap {preset}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum FloatDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl fmt::Display for FloatDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FloatDirection::Up => write!(f, "up"),
+            FloatDirection::Down => write!(f, "down"),
+            FloatDirection::Left => write!(f, "left"),
+            FloatDirection::Right => write!(f, "right"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum FloatResize {
+    Grow,
+    Shrink,
+}
+
+impl fmt::Display for FloatResize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FloatResize::Grow => write!(f, "grow"),
+            FloatResize::Shrink => write!(f, "shrink"),
+        }
+    }
+}
+
+/// `50` is logical pixels, `10%` a share of the work area.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FloatAmount {
+    Pixels(u32),
+    Percent(f32),
+}
+
+impl fmt::Display for FloatAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FloatAmount::Pixels(px) => write!(f, "{px}"),
+            FloatAmount::Percent(pct) => write!(f, "{pct}%"),
+        }
+    }
+}
+
+/// Pans the focused workspace's viewport without moving focus. `Pan` without an
+/// amount steps by a tenth of the work area; `Center` brings the focused window
+/// to the middle.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ScrollTarget {
+    Pan {
+        direction: ScrollDirection,
+        amount: Option<FloatAmount>,
+    },
+    Center,
+}
+
+impl fmt::Display for ScrollTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScrollTarget::Pan {
+                direction,
+                amount: Some(amount),
+            } => write!(f, "{direction} {amount}"),
+            ScrollTarget::Pan {
+                direction,
+                amount: None,
+            } => write!(f, "{direction}"),
+            ScrollTarget::Center => write!(f, "center"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScrollDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl fmt::Display for ScrollDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScrollDirection::Up => write!(f, "up"),
+            ScrollDirection::Down => write!(f, "down"),
+            ScrollDirection::Left => write!(f, "left"),
+            ScrollDirection::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// A rect in the work area, expressed as fractions of it so it is the same on
+/// every monitor. `Center` is the centered 60% box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SnapPreset {
+    LeftHalf,
+    RightHalf,
+    TopHalf,
+    BottomHalf,
+    LeftThird,
+    CenterThird,
+    RightThird,
+    LeftTwoThirds,
+    RightTwoThirds,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Center,
+}
+
+impl SnapPreset {
+    const ALL: [(SnapPreset, &'static str); 14] = [
+        (SnapPreset::LeftHalf, "left-half"),
+        (SnapPreset::RightHalf, "right-half"),
+        (SnapPreset::TopHalf, "top-half"),
+        (SnapPreset::BottomHalf, "bottom-half"),
+        (SnapPreset::LeftThird, "left-third"),
+        (SnapPreset::CenterThird, "center-third"),
+        (SnapPreset::RightThird, "right-third"),
+        (SnapPreset::LeftTwoThirds, "left-two-thirds"),
+        (SnapPreset::RightTwoThirds, "right-two-thirds"),
+        (SnapPreset::TopLeft, "top-left"),
+        (SnapPreset::TopRight, "top-right"),
+        (SnapPreset::BottomLeft, "bottom-left"),
+        (SnapPreset::BottomRight, "bottom-right"),
+        (SnapPreset::Center, "center"),
+    ];
+
+    /// `(x, y, width, height)` as fractions of the work area.
+    pub(crate) fn fractions(self) -> (f32, f32, f32, f32) {
+        match self {
+            SnapPreset::LeftHalf => (0.0, 0.0, 0.5, 1.0),
+            SnapPreset::RightHalf => (0.5, 0.0, 0.5, 1.0),
+            SnapPreset::TopHalf => (0.0, 0.0, 1.0, 0.5),
+            SnapPreset::BottomHalf => (0.0, 0.5, 1.0, 0.5),
+            SnapPreset::LeftThird => (0.0, 0.0, 1.0 / 3.0, 1.0),
+            SnapPreset::CenterThird => (1.0 / 3.0, 0.0, 1.0 / 3.0, 1.0),
+            SnapPreset::RightThird => (2.0 / 3.0, 0.0, 1.0 / 3.0, 1.0),
+            SnapPreset::LeftTwoThirds => (0.0, 0.0, 2.0 / 3.0, 1.0),
+            SnapPreset::RightTwoThirds => (1.0 / 3.0, 0.0, 2.0 / 3.0, 1.0),
+            SnapPreset::TopLeft => (0.0, 0.0, 0.5, 0.5),
+            SnapPreset::TopRight => (0.5, 0.0, 0.5, 0.5),
+            SnapPreset::BottomLeft => (0.0, 0.5, 0.5, 0.5),
+            SnapPreset::BottomRight => (0.5, 0.5, 0.5, 0.5),
+            SnapPreset::Center => (0.2, 0.2, 0.6, 0.6),
+        }
+    }
+}
+
+impl fmt::Display for SnapPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (_, name) = SnapPreset::ALL
+            .iter()
+            .find(|(preset, _)| preset == self)
+            .expect("every preset is listed in ALL");
+        write!(f, "{name}")
+    }
+}
+
 impl FromStr for Action {
     type Err = anyhow::Error;
 
@@ -278,6 +557,8 @@ impl FromStr for Action {
             ["focus", "left"] => Ok(Action::Focus(FocusTarget::Left)),
             ["focus", "right"] => Ok(Action::Focus(FocusTarget::Right)),
             ["focus", "parent"] => Ok(Action::Focus(FocusTarget::Parent)),
+            ["focus", "hint"] => Ok(Action::Focus(FocusTarget::Hint)),
+            ["focus", "urgent"] => Ok(Action::Focus(FocusTarget::Urgent)),
             ["focus", "workspace", n] => Ok(Action::Focus(FocusTarget::Workspace {
                 name: n.to_string(),
             })),
@@ -290,6 +571,9 @@ impl FromStr for Action {
             ["focus", "monitor", target] => Ok(Action::Focus(FocusTarget::Monitor {
                 target: parse_monitor_target(target)?,
             })),
+            ["focus", "mark", n] => Ok(Action::Focus(FocusTarget::Mark {
+                name: n.to_string(),
+            })),
             ["move", "up"] => Ok(Action::Move(MoveTarget::Up)),
             ["move", "down"] => Ok(Action::Move(MoveTarget::Down)),
             ["move", "left"] => Ok(Action::Move(MoveTarget::Left)),
@@ -300,15 +584,51 @@ impl FromStr for Action {
             ["move", "monitor", target] => Ok(Action::Move(MoveTarget::Monitor {
                 target: parse_monitor_target(target)?,
             })),
+            ["move", "mark", n] => Ok(Action::Move(MoveTarget::Mark {
+                name: n.to_string(),
+            })),
             ["toggle", "spawn"] => Ok(Action::Toggle(ToggleTarget::Spawn)),
             ["toggle", "direction"] => Ok(Action::Toggle(ToggleTarget::Direction)),
             ["toggle", "layout"] => Ok(Action::Toggle(ToggleTarget::Layout)),
             ["toggle", "float"] => Ok(Action::Toggle(ToggleTarget::Float)),
             ["toggle", "fullscreen"] => Ok(Action::Toggle(ToggleTarget::Fullscreen)),
+            ["toggle", "sticky"] => Ok(Action::Toggle(ToggleTarget::Sticky)),
             ["master", "grow"] => Ok(Action::Master(MasterTarget::Grow)),
             ["master", "shrink"] => Ok(Action::Master(MasterTarget::Shrink)),
             ["master", "more"] => Ok(Action::Master(MasterTarget::More)),
             ["master", "fewer"] => Ok(Action::Master(MasterTarget::Fewer)),
+            ["float", "move", direction, amount] => Ok(Action::Float(FloatTarget::Move {
+                direction: parse_float_direction(direction)?,
+                amount: parse_float_amount(amount)?,
+            })),
+            ["float", "resize", "grow", amount] => Ok(Action::Float(FloatTarget::Resize {
+                change: FloatResize::Grow,
+                amount: parse_float_amount(amount)?,
+            })),
+            ["float", "resize", "shrink", amount] => Ok(Action::Float(FloatTarget::Resize {
+                change: FloatResize::Shrink,
+                amount: parse_float_amount(amount)?,
+            })),
+            ["float", "center"] => Ok(Action::Float(FloatTarget::Center)),
+            ["float", "snap", preset] => Ok(Action::Float(FloatTarget::Snap {
+                preset: parse_snap_preset(preset)?,
+            })),
+            ["scroll", "center"] => Ok(Action::Scroll(ScrollTarget::Center)),
+            ["scroll", direction] => Ok(Action::Scroll(ScrollTarget::Pan {
+                direction: parse_scroll_direction(direction)?,
+                amount: None,
+            })),
+            ["scroll", direction, amount] => Ok(Action::Scroll(ScrollTarget::Pan {
+                direction: parse_scroll_direction(direction)?,
+                amount: Some(parse_float_amount(amount)?),
+            })),
+            ["mark", n] => Ok(Action::Mark {
+                name: n.to_string(),
+            }),
+            ["theme", "set", flavor] => Ok(Action::Theme {
+                flavor: flavor.parse()?,
+            }),
+            ["overview"] => Ok(Action::Overview),
             ["exit"] => Ok(Action::Exit),
             ["close"] => Ok(Action::Close),
             _ => Err(anyhow!("Unknown action: {}", s)),
@@ -331,6 +651,58 @@ pub(crate) fn parse_monitor_target(s: &str) -> Result<MonitorTarget> {
     }
 }
 
+fn parse_float_direction(s: &str) -> Result<FloatDirection> {
+    match s {
+        "up" => Ok(FloatDirection::Up),
+        "down" => Ok(FloatDirection::Down),
+        "left" => Ok(FloatDirection::Left),
+        "right" => Ok(FloatDirection::Right),
+        _ => Err(anyhow!("Unknown float direction: {s}")),
+    }
+}
+
+fn parse_scroll_direction(s: &str) -> Result<ScrollDirection> {
+    match s {
+        "up" => Ok(ScrollDirection::Up),
+        "down" => Ok(ScrollDirection::Down),
+        "left" => Ok(ScrollDirection::Left),
+        "right" => Ok(ScrollDirection::Right),
+        _ => Err(anyhow!("Unknown scroll direction: {s}")),
+    }
+}
+
+/// Also the clap value parser for `dome float move/resize` and `dome scroll`.
+pub(crate) fn parse_float_amount(s: &str) -> Result<FloatAmount> {
+    let amount = match s.strip_suffix('%') {
+        Some(pct) => {
+            let pct: f32 = pct
+                .parse()
+                .map_err(|_| anyhow!("Invalid percentage: {s}"))?;
+            if !(pct.is_finite() && pct > 0.0 && pct <= 100.0) {
+                return Err(anyhow!("Percentage must be in (0, 100]: {s}"));
+            }
+            FloatAmount::Percent(pct)
+        }
+        None => {
+            let px: u32 = s.parse().map_err(|_| anyhow!("Invalid amount: {s}"))?;
+            if i32::try_from(px).is_err() {
+                return Err(anyhow!("Amount must be at most {}: {s}", i32::MAX));
+            }
+            FloatAmount::Pixels(px)
+        }
+    };
+    Ok(amount)
+}
+
+/// Also the clap value parser for `dome float snap`.
+pub(crate) fn parse_snap_preset(s: &str) -> Result<SnapPreset> {
+    SnapPreset::ALL
+        .iter()
+        .find(|(_, name)| *name == s)
+        .map(|&(preset, _)| preset)
+        .ok_or_else(|| anyhow!("Unknown snap preset: {s}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -351,12 +723,19 @@ mod tests {
                 },
                 r#"{"Exec":{"command":"open -a Terminal"}}"#,
             ),
+            (Action::Overview, r#""Overview""#),
             (Action::Exit, r#""Exit""#),
             (Action::Close, r#""Close""#),
             (
                 Action::UnminimizeWindow(serde_json::from_value(serde_json::json!(7)).unwrap()),
                 r#"{"UnminimizeWindow":7}"#,
             ),
+            (
+                Action::FocusWindow(serde_json::from_value(serde_json::json!(7)).unwrap()),
+                r#"{"FocusWindow":7}"#,
+            ),
+            (Action::Focus(FocusTarget::Hint), r#"{"Focus":"Hint"}"#),
+            (Action::Focus(FocusTarget::Urgent), r#"{"Focus":"Urgent"}"#),
             (
                 Action::Mode {
                     name: "resize".into(),
@@ -369,6 +748,48 @@ mod tests {
                 }),
                 r#"{"Focus":{"Tab":{"direction":"Next"}}}"#,
             ),
+            (
+                Action::Mark {
+                    name: "editor".into(),
+                },
+                r#"{"Mark":{"name":"editor"}}"#,
+            ),
+            (
+                Action::Theme {
+                    flavor: Flavor::Latte,
+                },
+                r#"{"Theme":{"flavor":"latte"}}"#,
+            ),
+            (
+                Action::Float(FloatTarget::Move {
+                    direction: FloatDirection::Left,
+                    amount: FloatAmount::Pixels(50),
+                }),
+                r#"{"Float":{"Move":{"direction":"Left","amount":{"Pixels":50}}}}"#,
+            ),
+            (
+                Action::Float(FloatTarget::Snap {
+                    preset: SnapPreset::LeftHalf,
+                }),
+                r#"{"Float":{"Snap":{"preset":"LeftHalf"}}}"#,
+            ),
+            (
+                Action::Scroll(ScrollTarget::Pan {
+                    direction: ScrollDirection::Right,
+                    amount: None,
+                }),
+                r#"{"Scroll":{"Pan":{"direction":"Right","amount":null}}}"#,
+            ),
+            (
+                Action::Scroll(ScrollTarget::Center),
+                r#"{"Scroll":"Center"}"#,
+            ),
+            (
+                Action::Focus(FocusTarget::Mark {
+                    name: "editor".into(),
+                }),
+                r#"{"Focus":{"Mark":{"name":"editor"}}}"#,
+            ),
         ];
         for (action, expected) in &cases {
             let json = serde_json::to_string(action).unwrap();
@@ -398,6 +819,20 @@ mod tests {
                 IpcMessage::Query(Query::MinimizedWindows),
                 r#"{"Query":"MinimizedWindows"}"#,
             ),
+            (IpcMessage::Query(Query::Tree), r#"{"Query":"Tree"}"#),
+            (IpcMessage::Query(Query::Keymaps), r#"{"Query":"Keymaps"}"#),
+            (IpcMessage::Query(Query::Mode), r#"{"Query":"Mode"}"#),
+            (
+                IpcMessage::Query(Query::ConfigStatus),
+                r#"{"Query":"ConfigStatus"}"#,
+            ),
+            (
+                IpcMessage::Query(Query::Render {
+                    format: RenderFormat::Svg,
+                    workspace: Some("web".into()),
+                }),
+                r#"{"Query":{"Render":{"format":"Svg","workspace":"web"}}}"#,
+            ),
         ];
         for (msg, expected) in &cases {
             let json = serde_json::to_string(msg).unwrap();
@@ -428,24 +863,47 @@ mod tests {
             "focus workspace 3",
             "focus monitor left",
             "focus monitor foo",
+            "focus mark editor",
+            "focus hint",
+            "focus urgent",
             "move up",
             "move down",
             "move left",
             "move right",
             "move workspace 3",
             "move monitor left",
+            "move mark editor",
             "toggle spawn",
             "toggle direction",
             "toggle layout",
             "toggle float",
             "toggle fullscreen",
+            "toggle sticky",
             "master grow",
             "master shrink",
             "master more",
             "master fewer",
+            "overview",
             "exit",
             "close",
             "mode resize",
+            "float move left 50",
+            "float move down 12.5%",
+            "float resize grow 10%",
+            "float resize shrink 40",
+            "float center",
+            "float snap left-half",
+            "float snap right-two-thirds",
+            "float snap bottom-right",
+            "float snap center",
+            "scroll left",
+            "scroll right 200",
+            "scroll up 25%",
+            "scroll down",
+            "scroll center",
+            "mark editor",
+            "theme set latte",
+            "theme set mocha",
             "exec open -a Terminal",
         ];
         for input in cases {
@@ -459,6 +917,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn float_amount_rejects_bad_input() {
+        for input in [
+            "float move left",
+            "float move left -5",
+            "float move left 0%",
+            "float move left 150%",
+            "float move left 2147483648",
+            "float move sideways 10",
+            "float resize grow ten",
+            "float snap left-quarter",
+            "scroll sideways",
+            "scroll down 0%",
+            "scroll left far",
+            "scroll center 10",
+        ] {
+            assert!(
+                Action::from_str(input).is_err(),
+                "{input:?} should not parse"
+            );
+        }
+    }
+
     #[test]
     fn unminimize_window_display_uses_space() {
         let id: WindowId = serde_json::from_value(serde_json::json!(7)).unwrap();
@@ -1,10 +1,12 @@
 use clap::{Parser, Subcommand};
 
 use crate::action::{
-    Action, FocusTarget, MasterTarget, MonitorTarget, MoveTarget, Query, TabDirection,
-    ToggleTarget, parse_monitor_target,
+    Action, FloatAmount, FloatDirection, FloatResize, FloatTarget, FocusTarget, MasterTarget,
+    MonitorTarget, MoveTarget, Query, RenderFormat, ScrollDirection, ScrollTarget, SnapPreset,
+    TabDirection, ToggleTarget, parse_float_amount, parse_monitor_target, parse_snap_preset,
 };
 use crate::core::WindowId;
+use crate::theme::Flavor;
 
 #[derive(Parser)]
 #[command(name = "dome", about = "A cross-platform tiling window manager")]
@@ -37,14 +39,30 @@ enum CliCommand {
         #[command(subcommand)]
         target: CliMaster,
     },
+    Float {
+        #[command(subcommand)]
+        target: CliFloat,
+    },
+    Scroll {
+        #[command(subcommand)]
+        target: CliScroll,
+    },
     Exec {
         command: String,
     },
+    Overview,
     Exit,
     Close,
     Mode {
         name: String,
     },
+    Mark {
+        name: String,
+    },
+    Theme {
+        #[command(subcommand)]
+        target: CliTheme,
+    },
     Export,
     Query {
         #[command(subcommand)]
@@ -54,6 +72,13 @@ enum CliCommand {
     UnminimizeWindow {
         id: u64,
     },
+    #[command(name = "check-config")]
+    CheckConfig {
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        layout: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -74,6 +99,11 @@ enum CliFocus {
         #[arg(value_parser = parse_monitor_target)]
         target: MonitorTarget,
     },
+    Mark {
+        name: String,
+    },
+    Hint,
+    Urgent,
 }
 
 #[derive(Subcommand)]
@@ -89,6 +119,9 @@ enum CliMove {
         #[arg(value_parser = parse_monitor_target)]
         target: MonitorTarget,
     },
+    Mark {
+        name: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -98,6 +131,7 @@ enum CliToggle {
     Layout,
     Float,
     Fullscreen,
+    Sticky,
 }
 
 #[derive(Subcommand)]
@@ -108,17 +142,107 @@ enum CliMaster {
     Fewer,
 }
 
+#[derive(Subcommand)]
+enum CliFloat {
+    Move {
+        #[command(subcommand)]
+        direction: CliFloatMove,
+    },
+    Resize {
+        #[command(subcommand)]
+        change: CliFloatResize,
+    },
+    Center,
+    Snap {
+        #[arg(value_parser = parse_snap_preset)]
+        preset: SnapPreset,
+    },
+}
+
+#[derive(Subcommand)]
+enum CliFloatMove {
+    Up {
+        #[arg(value_parser = parse_float_amount)]
+        amount: FloatAmount,
+    },
+    Down {
+        #[arg(value_parser = parse_float_amount)]
+        amount: FloatAmount,
+    },
+    Left {
+        #[arg(value_parser = parse_float_amount)]
+        amount: FloatAmount,
+    },
+    Right {
+        #[arg(value_parser = parse_float_amount)]
+        amount: FloatAmount,
+    },
+}
+
+#[derive(Subcommand)]
+enum CliFloatResize {
+    Grow {
+        #[arg(value_parser = parse_float_amount)]
+        amount: FloatAmount,
+    },
+    Shrink {
+        #[arg(value_parser = parse_float_amount)]
+        amount: FloatAmount,
+    },
+}
+
+#[derive(Subcommand)]
+enum CliScroll {
+    Up {
+        #[arg(value_parser = parse_float_amount)]
+        amount: Option<FloatAmount>,
+    },
+    Down {
+        #[arg(value_parser = parse_float_amount)]
+        amount: Option<FloatAmount>,
+    },
+    Left {
+        #[arg(value_parser = parse_float_amount)]
+        amount: Option<FloatAmount>,
+    },
+    Right {
+        #[arg(value_parser = parse_float_amount)]
+        amount: Option<FloatAmount>,
+    },
+    Center,
+}
+
 #[derive(Subcommand)]
 enum CliTab {
     Next,
     Prev,
 }
 
+#[derive(Subcommand)]
+enum CliTheme {
+    Set {
+        #[arg(value_parser = str::parse::<Flavor>)]
+        flavor: Flavor,
+    },
+}
+
 #[derive(Subcommand)]
 enum CliQuery {
     Workspaces,
     #[command(name = "minimized")]
     MinimizedWindows,
+    Tree,
+    Keymaps,
+    Mode,
+    #[command(name = "config-status")]
+    ConfigStatus,
+    /// Draw the focused workspace, or the named one, as text or SVG.
+    Render {
+        #[arg(long, default_value = "ascii", value_parser = str::parse::<RenderFormat>)]
+        format: RenderFormat,
+        #[arg(long)]
+        workspace: Option<String>,
+    },
 }
 
 #[derive(Debug)]
@@ -130,6 +254,10 @@ enum Dispatch {
     Action(Action),
     Query(Query),
     Export,
+    CheckConfig {
+        config: Option<String>,
+        layout: Option<String>,
+    },
 }
 
 impl From<CliFocus> for FocusTarget {
@@ -145,6 +273,9 @@ impl From<CliFocus> for FocusTarget {
             },
             CliFocus::Workspace { name } => FocusTarget::Workspace { name },
             CliFocus::Monitor { target } => FocusTarget::Monitor { target },
+            CliFocus::Mark { name } => FocusTarget::Mark { name },
+            CliFocus::Hint => FocusTarget::Hint,
+            CliFocus::Urgent => FocusTarget::Urgent,
         }
     }
 }
@@ -158,6 +289,7 @@ impl From<CliMove> for MoveTarget {
             CliMove::Right => MoveTarget::Right,
             CliMove::Workspace { name } => MoveTarget::Workspace { name },
             CliMove::Monitor { target } => MoveTarget::Monitor { target },
+            CliMove::Mark { name } => MoveTarget::Mark { name },
         }
     }
 }
@@ -173,6 +305,44 @@ impl From<CliMaster> for MasterTarget {
     }
 }
 
+impl From<CliFloat> for FloatTarget {
+    fn from(cf: CliFloat) -> Self {
+        match cf {
+            CliFloat::Move { direction } => {
+                let (direction, amount) = match direction {
+                    CliFloatMove::Up { amount } => (FloatDirection::Up, amount),
+                    CliFloatMove::Down { amount } => (FloatDirection::Down, amount),
+                    CliFloatMove::Left { amount } => (FloatDirection::Left, amount),
+                    CliFloatMove::Right { amount } => (FloatDirection::Right, amount),
+                };
+                FloatTarget::Move { direction, amount }
+            }
+            CliFloat::Resize { change } => {
+                let (change, amount) = match change {
+                    CliFloatResize::Grow { amount } => (FloatResize::Grow, amount),
+                    CliFloatResize::Shrink { amount } => (FloatResize::Shrink, amount),
+                };
+                FloatTarget::Resize { change, amount }
+            }
+            CliFloat::Center => FloatTarget::Center,
+            CliFloat::Snap { preset } => FloatTarget::Snap { preset },
+        }
+    }
+}
+
+impl From<CliScroll> for ScrollTarget {
+    fn from(cs: CliScroll) -> Self {
+        let (direction, amount) = match cs {
+            CliScroll::Up { amount } => (ScrollDirection::Up, amount),
+            CliScroll::Down { amount } => (ScrollDirection::Down, amount),
+            CliScroll::Left { amount } => (ScrollDirection::Left, amount),
+            CliScroll::Right { amount } => (ScrollDirection::Right, amount),
+            CliScroll::Center => return ScrollTarget::Center,
+        };
+        ScrollTarget::Pan { direction, amount }
+    }
+}
+
 impl From<CliTab> for TabDirection {
     fn from(ct: CliTab) -> Self {
         match ct {
@@ -187,6 +357,11 @@ impl From<CliQuery> for Query {
         match cq {
             CliQuery::Workspaces => Query::Workspaces,
             CliQuery::MinimizedWindows => Query::MinimizedWindows,
+            CliQuery::Tree => Query::Tree,
+            CliQuery::Keymaps => Query::Keymaps,
+            CliQuery::Mode => Query::Mode,
+            CliQuery::ConfigStatus => Query::ConfigStatus,
+            CliQuery::Render { format, workspace } => Query::Render { format, workspace },
         }
     }
 }
@@ -198,6 +373,7 @@ fn cli_toggle_to_action(t: CliToggle) -> Action {
         CliToggle::Layout => Action::Toggle(ToggleTarget::Layout),
         CliToggle::Float => Action::Toggle(ToggleTarget::Float),
         CliToggle::Fullscreen => Action::Toggle(ToggleTarget::Fullscreen),
+        CliToggle::Sticky => Action::Toggle(ToggleTarget::Sticky),
     }
 }
 
@@ -209,10 +385,17 @@ impl From<CliCommand> for Dispatch {
             CliCommand::Move { target } => Dispatch::Action(Action::Move(target.into())),
             CliCommand::Toggle { target } => Dispatch::Action(cli_toggle_to_action(target)),
             CliCommand::Master { target } => Dispatch::Action(Action::Master(target.into())),
+            CliCommand::Float { target } => Dispatch::Action(Action::Float(target.into())),
+            CliCommand::Scroll { target } => Dispatch::Action(Action::Scroll(target.into())),
             CliCommand::Exec { command } => Dispatch::Action(Action::Exec { command }),
+            CliCommand::Overview => Dispatch::Action(Action::Overview),
             CliCommand::Exit => Dispatch::Action(Action::Exit),
             CliCommand::Close => Dispatch::Action(Action::Close),
             CliCommand::Mode { name } => Dispatch::Action(Action::Mode { name }),
+            CliCommand::Mark { name } => Dispatch::Action(Action::Mark { name }),
+            CliCommand::Theme {
+                target: CliTheme::Set { flavor },
+            } => Dispatch::Action(Action::Theme { flavor }),
             CliCommand::Export => Dispatch::Export,
             CliCommand::Query { query } => Dispatch::Query(query.into()),
             CliCommand::UnminimizeWindow { id } => {
@@ -223,6 +406,7 @@ impl From<CliCommand> for Dispatch {
                     .expect("WindowId round-trips from a bare integer");
                 Dispatch::Action(Action::UnminimizeWindow(window_id))
             }
+            CliCommand::CheckConfig { config, layout } => Dispatch::CheckConfig { config, layout },
         }
     }
 }
@@ -244,11 +428,19 @@ pub fn run() -> anyhow::Result<()> {
         }
         Dispatch::Query(query) => {
             let response = crate::DomeClient.send_query(&query)?;
-            println!("{response}");
+            // The picture travels as a JSON string to fit the one-line reply.
+            // Errors stay JSON objects and print as-is.
+            match (&query, serde_json::from_str::<String>(&response)) {
+                (Query::Render { .. }, Ok(picture)) => print!("{picture}"),
+                _ => println!("{response}"),
+            }
         }
         Dispatch::Export => {
             crate::DomeClient.send_export_layout()?;
         }
+        Dispatch::CheckConfig { config, layout } => {
+            crate::diagnostics::check_config(config, layout)?;
+        }
     }
     Ok(())
 }
@@ -287,6 +479,9 @@ mod tests {
         assert_action(&["dome", "focus", "workspace", "3"], "focus workspace 3");
         assert_action(&["dome", "focus", "monitor", "left"], "focus monitor left");
         assert_action(&["dome", "focus", "monitor", "foo"], "focus monitor foo");
+        assert_action(&["dome", "focus", "mark", "editor"], "focus mark editor");
+        assert_action(&["dome", "focus", "hint"], "focus hint");
+        assert_action(&["dome", "focus", "urgent"], "focus urgent");
     }
 
     #[test]
@@ -297,6 +492,7 @@ mod tests {
         assert_action(&["dome", "move", "right"], "move right");
         assert_action(&["dome", "move", "workspace", "3"], "move workspace 3");
         assert_action(&["dome", "move", "monitor", "left"], "move monitor left");
+        assert_action(&["dome", "move", "mark", "editor"], "move mark editor");
     }
 
     #[test]
@@ -306,6 +502,7 @@ mod tests {
         assert_action(&["dome", "toggle", "layout"], "toggle layout");
         assert_action(&["dome", "toggle", "float"], "toggle float");
         assert_action(&["dome", "toggle", "fullscreen"], "toggle fullscreen");
+        assert_action(&["dome", "toggle", "sticky"], "toggle sticky");
     }
 
     #[test]
@@ -316,6 +513,38 @@ mod tests {
         assert_action(&["dome", "master", "fewer"], "master fewer");
     }
 
+    #[test]
+    fn cli_float_subcommands() {
+        assert_action(
+            &["dome", "float", "move", "left", "50"],
+            "float move left 50",
+        );
+        assert_action(&["dome", "float", "move", "up", "5%"], "float move up 5%");
+        assert_action(
+            &["dome", "float", "resize", "grow", "10%"],
+            "float resize grow 10%",
+        );
+        assert_action(
+            &["dome", "float", "resize", "shrink", "40"],
+            "float resize shrink 40",
+        );
+        assert_action(&["dome", "float", "center"], "float center");
+        assert_action(
+            &["dome", "float", "snap", "top-left"],
+            "float snap top-left",
+        );
+        assert!(Cli::try_parse_from(["dome", "float", "snap", "nowhere"]).is_err());
+    }
+
+    #[test]
+    fn cli_scroll_subcommands() {
+        assert_action(&["dome", "scroll", "left"], "scroll left");
+        assert_action(&["dome", "scroll", "down", "200"], "scroll down 200");
+        assert_action(&["dome", "scroll", "right", "25%"], "scroll right 25%");
+        assert_action(&["dome", "scroll", "center"], "scroll center");
+        assert!(Cli::try_parse_from(["dome", "scroll", "up", "0%"]).is_err());
+    }
+
     #[test]
     fn cli_exec_passthrough() {
         assert_action(
@@ -329,6 +558,22 @@ mod tests {
         assert_action(&["dome", "mode", "resize"], "mode resize");
     }
 
+    #[test]
+    fn cli_mark() {
+        assert_action(&["dome", "mark", "editor"], "mark editor");
+    }
+
+    #[test]
+    fn cli_theme_set() {
+        assert_action(&["dome", "theme", "set", "latte"], "theme set latte");
+        assert!(Cli::try_parse_from(["dome", "theme", "set", "dracula"]).is_err());
+    }
+
+    #[test]
+    fn cli_overview() {
+        assert_action(&["dome", "overview"], "overview");
+    }
+
     #[test]
     fn cli_exit() {
         assert_action(&["dome", "exit"], "exit");
@@ -357,6 +602,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_query_tree() {
+        let d = dispatch_from_argv(&["dome", "query", "tree"]);
+        match d {
+            Dispatch::Query(Query::Tree) => {}
+            other => panic!("expected Query(Tree), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn cli_query_keymaps() {
+        let d = dispatch_from_argv(&["dome", "query", "keymaps"]);
+        match d {
+            Dispatch::Query(Query::Keymaps) => {}
+            other => panic!("expected Query(Keymaps), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn cli_query_mode() {
+        let d = dispatch_from_argv(&["dome", "query", "mode"]);
+        match d {
+            Dispatch::Query(Query::Mode) => {}
+            other => panic!("expected Query(Mode), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn cli_query_config_status() {
+        let d = dispatch_from_argv(&["dome", "query", "config-status"]);
+        match d {
+            Dispatch::Query(Query::ConfigStatus) => {}
+            other => panic!("expected Query(ConfigStatus), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn cli_query_render() {
+        match dispatch_from_argv(&["dome", "query", "render"]) {
+            Dispatch::Query(Query::Render {
+                format: RenderFormat::Ascii,
+                workspace: None,
+            }) => {}
+            other => panic!("expected Query(Render), got {other:?}"),
+        }
+        let d = dispatch_from_argv(&[
+            "dome",
+            "query",
+            "render",
+            "--format",
+            "svg",
+            "--workspace",
+            "web",
+        ]);
+        match d {
+            Dispatch::Query(Query::Render {
+                format: RenderFormat::Svg,
+                workspace: Some(name),
+            }) if name == "web" => {}
+            other => panic!("expected Query(Render svg web), got {other:?}"),
+        }
+        assert!(Cli::try_parse_from(["dome", "query", "render", "--format", "png"]).is_err());
+    }
+
     #[test]
     fn cli_unminimize_window() {
         let expected: WindowId = serde_json::from_value(serde_json::json!(7)).unwrap();
@@ -416,4 +725,18 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn cli_check_config_with_paths() {
+        let d = dispatch_from_argv(&["dome", "check-config", "-c", "/tmp/c", "-l", "/tmp/l"]);
+        match d {
+            Dispatch::CheckConfig {
+                config: Some(ref c),
+                layout: Some(ref l),
+            } if c == "/tmp/c" && l == "/tmp/l" => {}
+            other => panic!(
+                "expected CheckConfig {{ Some(\"/tmp/c\"), Some(\"/tmp/l\") }}, got {other:?}"
+            ),
+        }
+    }
 }
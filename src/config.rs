@@ -2,16 +2,20 @@ use anyhow::{Result, anyhow};
 use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::action::{
     Action, Actions, FocusTarget, MonitorTarget, MoveTarget, TabDirection, ToggleTarget,
 };
 use crate::core::{Length, Logical, Pixels, Unit};
+use crate::diagnostics::{ConfigFile, LoadReport, reload_reported};
 use crate::font::{FontConfig, MAX_FONT_SIZE, MIN_FONT_SIZE, default_text_size};
-use crate::theme::{Flavor, Theme};
+use crate::hook::Hook;
+use crate::theme::{Flavor, HexColor, LightDark, Theme, ThemeColor, ThemeConfig};
 
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -55,10 +59,132 @@ impl FromStr for Keymap {
     }
 }
 
+impl std::fmt::Display for Keymap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (flag, name) in [
+            (Modifiers::META, "meta"),
+            (Modifiers::CTRL, "ctrl"),
+            (Modifiers::ALT, "alt"),
+            (Modifiers::SHIFT, "shift"),
+        ] {
+            if self.modifiers.contains(flag) {
+                write!(f, "{name}+")?;
+            }
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// One or more keypresses bound together, written space-separated in config
+/// (`"meta+w v"`). A single-key binding is a sequence of length one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct KeySequence(pub(crate) Vec<Keymap>);
+
+impl KeySequence {
+    /// Whether `self` is strictly longer than `prefix` and starts with it.
+    pub(crate) fn extends(&self, prefix: &[Keymap]) -> bool {
+        self.0.len() > prefix.len() && self.0.starts_with(prefix)
+    }
+}
+
+impl From<Keymap> for KeySequence {
+    fn from(keymap: Keymap) -> Self {
+        Self(vec![keymap])
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let keys = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<Keymap>>>()?;
+        if keys.is_empty() {
+            return Err(anyhow!("Empty keymap"));
+        }
+        Ok(Self(keys))
+    }
+}
+
+impl std::fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, keymap) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{keymap}")?;
+        }
+        Ok(())
+    }
+}
+
+const DEFAULT_SEQUENCE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Built-in mode that forwards every key to the focused app except its
+/// escape binding. `[keymaps.mode.passthrough]` replaces it to pick another
+/// escape key.
+pub(crate) const PASSTHROUGH_MODE: &str = "passthrough";
+
 #[derive(Debug, Clone)]
 pub(crate) struct ModalKeymaps {
-    pub(crate) default: HashMap<Keymap, Actions>,
-    pub(crate) modes: HashMap<String, HashMap<Keymap, Actions>>,
+    pub(crate) default: HashMap<KeySequence, Actions>,
+    pub(crate) modes: HashMap<String, HashMap<KeySequence, Actions>>,
+    /// Options from each mode's table. A mode without an entry uses the
+    /// defaults.
+    pub(crate) mode_options: HashMap<String, ModeOptions>,
+    /// `[[keymaps.app]]` blocks in config order.
+    pub(crate) apps: Vec<AppKeymaps>,
+    /// How long a partially typed sequence waits for its next key.
+    pub(crate) sequence_timeout: Duration,
+}
+
+impl ModalKeymaps {
+    /// Whether any table binds a wheel pseudo-key. Without one the platforms
+    /// leave wheel events alone entirely.
+    pub(crate) fn binds_wheel(&self) -> bool {
+        let is_wheel = |seq: &KeySequence| seq.0.iter().any(|k| k.key.starts_with("wheel"));
+        self.default
+            .keys()
+            .chain(self.modes.values().flat_map(|m| m.keys()))
+            .chain(self.apps.iter().flat_map(|a| a.bindings.keys()))
+            .any(is_wheel)
+    }
+}
+
+/// Behavior of a named mode, read from the option keys of its
+/// `[keymaps.mode.<name>]` table.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct ModeOptions {
+    /// Return to the default mode after this long without a keypress.
+    pub(crate) timeout: Option<Duration>,
+    /// Return to the default mode once a binding fires.
+    pub(crate) oneshot: bool,
+    /// Swallow keys the mode does not bind instead of passing them on.
+    pub(crate) swallow_unbound: bool,
+}
+
+/// Bindings layered over the default table while a window matching `window`
+/// is focused. Later blocks win over earlier ones.
+#[derive(Debug, Clone)]
+pub(crate) struct AppKeymaps {
+    pub(crate) window: WindowMatcher,
+    /// Drops every default binding, leaving only the block's own.
+    pub(crate) passthrough: bool,
+    pub(crate) bindings: HashMap<KeySequence, Actions>,
+    pub(crate) unbound: Vec<KeySequence>,
+}
+
+fn default_passthrough_mode() -> HashMap<KeySequence, Actions> {
+    let escape = Keymap {
+        key: "escape".into(),
+        modifiers: Modifiers::META | Modifiers::SHIFT,
+    };
+    let actions = Actions::new(vec![Action::Mode {
+        name: "default".to_string(),
+    }]);
+    HashMap::from([(escape.into(), actions)])
 }
 
 fn default_keymaps() -> ModalKeymaps {
@@ -223,8 +349,11 @@ fn default_keymaps() -> ModalKeymaps {
         );
     }
     ModalKeymaps {
-        default: keymaps,
-        modes: HashMap::new(),
+        default: keymaps.into_iter().map(|(k, a)| (k.into(), a)).collect(),
+        modes: HashMap::from([(PASSTHROUGH_MODE.to_string(), default_passthrough_mode())]),
+        mode_options: HashMap::new(),
+        apps: Vec::new(),
+        sequence_timeout: DEFAULT_SEQUENCE_TIMEOUT,
     }
 }
 
@@ -401,6 +530,41 @@ impl Drop for Walker<'_> {
     }
 }
 
+// `theme` is either a bare flavor name or a `[theme]` table.
+fn walk_theme(w: &mut Walker) -> ThemeConfig {
+    if let Some(toml::Value::String(_)) = w.table.get("theme") {
+        return w.field("theme", Flavor::default()).into();
+    }
+    w.nested_or("theme", ThemeConfig::default())
+}
+
+impl WalkRecover for ThemeConfig {
+    fn walk(w: &mut Walker) -> Self {
+        let flavor = w.field("flavor", Flavor::default());
+        let light = w.field::<Option<Flavor>>("light", None);
+        let dark = w.field::<Option<Flavor>>("dark", None);
+        let light_dark = (light.is_some() || dark.is_some()).then(|| {
+            let stock = LightDark::default();
+            LightDark {
+                light: light.unwrap_or(stock.light),
+                dark: dark.unwrap_or(stock.dark),
+            }
+        });
+        let overrides = ThemeColor::ALL
+            .into_iter()
+            .filter_map(|slot| {
+                w.field::<Option<HexColor>>(slot.key(), None)
+                    .map(|c| (slot, c.0))
+            })
+            .collect();
+        ThemeConfig {
+            flavor,
+            light_dark,
+            overrides,
+        }
+    }
+}
+
 struct RawConfig;
 
 impl RawConfig {
@@ -409,7 +573,12 @@ impl RawConfig {
         Config {
             keymaps: walk_keymaps(&mut w),
             border_size: w.field("border_size", default_border_size()),
-            theme: w.field("theme", Flavor::default()),
+            border_radius: w.field("border_radius", default_border_radius()),
+            focused_border_size: w.field("focused_border_size", None),
+            unfocused_border_size: w.field("unfocused_border_size", None),
+            border: w.rule_vec::<BorderRule>("border"),
+            tab_title: w.rule_vec::<TitleRewrite>("tab_title"),
+            theme: walk_theme(&mut w),
             font: w.nested_or("font", FontConfig::default()),
             ignore: {
                 let mut ignore = w.rule_vec::<WindowMatcher>("ignore");
@@ -419,6 +588,10 @@ impl RawConfig {
             log_level: w.field("log_level", LogLevel::default()),
             start_at_login: w.field("start_at_login", false),
             strategy: w.field("strategy", default_strategy()),
+            float_placement: w.field("float_placement", FloatPlacement::default()),
+            scroll_indicator: w.field("scroll_indicator", ScrollIndicator::default()),
+            scroll_focus: w.field("scroll_focus", ScrollFocus::default()),
+            animation: w.nested_or("animation", AnimationConfig::default()),
             partition_tree: w.nested::<PartitionTreeConfig>("partition_tree"),
             master: w.nested::<MasterConfig>("master"),
             size_constraints: SizeConstraints {
@@ -429,6 +602,10 @@ impl RawConfig {
             },
             float: w.rule_vec::<WindowMatcher>("float"),
             fullscreen: w.rule_vec::<WindowMatcher>("fullscreen"),
+            sticky: w.rule_vec::<WindowMatcher>("sticky"),
+            hooks: w.rule_vec::<Hook>("hook"),
+            includes: Vec::new(),
+            key_origins: KeyOrigins::default(),
         }
     }
 }
@@ -486,9 +663,25 @@ impl WalkRecover for PartitionTreeConfig {
             );
             default_tab_bar_height()
         };
+        let tab_sidebar_width = w.field("tab_sidebar_width", default_tab_sidebar_width());
+        // Same zero-sized surface as above, on the other axis.
+        let tab_sidebar_width = if tab_sidebar_width > Pixels::ZERO {
+            tab_sidebar_width
+        } else {
+            tracing::warn!(
+                field = %field_path(&w.prefix, "tab_sidebar_width"),
+                value = tab_sidebar_width.value(),
+                "Out of range, using default",
+            );
+            default_tab_sidebar_width()
+        };
         PartitionTreeConfig {
             tab_bar_height,
+            tab_bar_position: w.field("tab_bar_position", TabBarPosition::default()),
+            tab_sidebar_width,
             automatic_tiling: w.field("automatic_tiling", default_automatic_tiling()),
+            tab_title_format: w.field("tab_title_format", default_tab_title_format()),
+            tab_icons: w.field("tab_icons", default_tab_icons()),
         }
     }
 }
@@ -524,6 +717,29 @@ impl WalkRecover for MasterConfig {
     }
 }
 
+impl WalkRecover for AnimationConfig {
+    fn walk(w: &mut Walker) -> Self {
+        let mut millis = |name: &str, default: u32| {
+            let value = w.field(name, default);
+            if value <= MAX_ANIMATION_DURATION_MS {
+                value
+            } else {
+                tracing::warn!(
+                    field = %field_path(&w.prefix, name),
+                    value,
+                    "Out of range, using default",
+                );
+                default
+            }
+        };
+        AnimationConfig {
+            duration_ms: millis("duration_ms", 0),
+            focus_flash_ms: millis("focus_flash_ms", default_focus_flash_ms()),
+            easing: w.field("easing", Easing::default()),
+        }
+    }
+}
+
 impl WalkRecover for FontConfig {
     fn walk(w: &mut Walker) -> Self {
         let text_size = w.field("text_size", default_text_size());
@@ -557,11 +773,45 @@ fn walk_keymaps(w: &mut Walker) -> ModalKeymaps {
         return default_keymaps();
     };
 
+    let (inherit_defaults, sequence_timeout) = {
+        let mut options = split_options(
+            &mut keymaps_table,
+            &["inherit_defaults", "sequence_timeout_ms"],
+        );
+        let mut kw = Walker::new(&mut options, "keymaps");
+        let inherit_defaults = kw.field("inherit_defaults", false);
+        let sequence_timeout = match kw.field("sequence_timeout_ms", None::<u64>) {
+            Some(0) => {
+                tracing::warn!(
+                    field = "keymaps.sequence_timeout_ms",
+                    "Timeout must be positive, using default",
+                );
+                DEFAULT_SEQUENCE_TIMEOUT
+            }
+            Some(ms) => Duration::from_millis(ms),
+            None => DEFAULT_SEQUENCE_TIMEOUT,
+        };
+        (inherit_defaults, sequence_timeout)
+    };
     let mode_table = keymaps_table.remove("mode");
+    let app_table = keymaps_table.remove("app");
 
-    let default = walk_bindings_table(keymaps_table, "keymaps");
+    let mut default = if inherit_defaults {
+        default_keymaps().default
+    } else {
+        HashMap::new()
+    };
+    let (bindings, unbound) = walk_bindings_table(keymaps_table, "keymaps", &default);
+    for keymap in &unbound {
+        default.remove(keymap);
+    }
+    default.extend(bindings);
+    let apps = app_table
+        .map(|value| walk_app_keymaps(value, &default))
+        .unwrap_or_default();
 
     let mut modes = HashMap::new();
+    let mut mode_options = HashMap::new();
     if let Some(toml::Value::Table(mode_map)) = mode_table {
         for (mode_name, mode_val) in mode_map {
             if mode_name == "default" {
@@ -575,7 +825,7 @@ fn walk_keymaps(w: &mut Walker) -> ModalKeymaps {
                 tracing::warn!(field = "keymaps.mode.", "Empty mode name, dropping",);
                 continue;
             }
-            let toml::Value::Table(bindings) = mode_val else {
+            let toml::Value::Table(mut bindings) = mode_val else {
                 tracing::warn!(
                     field = %format!("keymaps.mode.{mode_name}"),
                     "Expected table for mode, dropping",
@@ -583,21 +833,126 @@ fn walk_keymaps(w: &mut Walker) -> ModalKeymaps {
                 continue;
             };
             let prefix = format!("keymaps.mode.{mode_name}");
-            let mode_bindings = walk_bindings_table(bindings, &prefix);
+            let options = walk_mode_options(&mut bindings, &prefix);
+            // Modes have no defaults to inherit, so an unbind entry only keeps
+            // the key out of the mode's table.
+            let (mode_bindings, _) = walk_bindings_table(bindings, &prefix, &HashMap::new());
+            mode_options.insert(mode_name.clone(), options);
             modes.insert(mode_name, mode_bindings);
         }
     } else if let Some(_non_table) = mode_table {
         tracing::warn!(field = "keymaps.mode", "Expected table, ignoring",);
     }
+    modes
+        .entry(PASSTHROUGH_MODE.to_string())
+        .or_insert_with(default_passthrough_mode);
+
+    ModalKeymaps {
+        default,
+        modes,
+        mode_options,
+        apps,
+        sequence_timeout,
+    }
+}
+
+/// Moves `keys` out of a table that mixes options with bindings, so a Walker
+/// over the result does not report every binding as an unknown field.
+fn split_options(table: &mut toml::Table, keys: &[&str]) -> toml::Table {
+    keys.iter()
+        .filter_map(|key| table.remove_entry(*key))
+        .collect()
+}
+
+/// Pops the option keys out of a mode table so only bindings remain.
+fn walk_mode_options(table: &mut toml::Table, prefix: &str) -> ModeOptions {
+    let defaults = ModeOptions::default();
+    let mut options = split_options(table, &["timeout_ms", "oneshot", "swallow_unbound"]);
+    let mut w = Walker::new(&mut options, prefix);
+    let timeout = match w.field("timeout_ms", None::<u64>) {
+        Some(0) => {
+            tracing::warn!(
+                field = %field_path(prefix, "timeout_ms"),
+                "Timeout must be positive, ignoring",
+            );
+            None
+        }
+        ms => ms.map(Duration::from_millis),
+    };
+    ModeOptions {
+        timeout,
+        oneshot: w.field("oneshot", defaults.oneshot),
+        swallow_unbound: w.field("swallow_unbound", defaults.swallow_unbound),
+    }
+}
 
-    ModalKeymaps { default, modes }
+fn walk_app_keymaps(
+    value: toml::Value,
+    default: &HashMap<KeySequence, Actions>,
+) -> Vec<AppKeymaps> {
+    let toml::Value::Array(arr) = value else {
+        tracing::warn!(
+            field = "keymaps.app",
+            error = %format!("expected array, got {}", value.type_str()),
+            "Invalid value, ignoring",
+        );
+        return Vec::new();
+    };
+    let mut apps = Vec::new();
+    for (i, elem) in arr.into_iter().enumerate() {
+        let prefix = format!("keymaps.app[{i}]");
+        let toml::Value::Table(mut table) = elem else {
+            tracing::warn!(field = %prefix, "Expected table element, dropping");
+            continue;
+        };
+        let window = match table.remove("match").map(|v| v.try_into::<WindowMatcher>()) {
+            Some(Ok(m)) if m != WindowMatcher::default() => m,
+            Some(Err(e)) => {
+                tracing::warn!(
+                    field = %field_path(&prefix, "match"),
+                    error = %e,
+                    "Invalid window matcher, dropping",
+                );
+                continue;
+            }
+            _ => {
+                tracing::warn!(field = %prefix, "Missing window matcher, dropping");
+                continue;
+            }
+        };
+        let mut options = split_options(&mut table, &["passthrough"]);
+        let passthrough = Walker::new(&mut options, prefix.as_str()).field("passthrough", false);
+        let inherited = if passthrough {
+            &HashMap::new()
+        } else {
+            default
+        };
+        let (bindings, unbound) = walk_bindings_table(table, &prefix, inherited);
+        apps.push(AppKeymaps {
+            window,
+            passthrough,
+            bindings,
+            unbound,
+        });
+    }
+    apps
 }
 
-fn walk_bindings_table(table: toml::Table, prefix: &str) -> HashMap<Keymap, Actions> {
+/// Walks a table of `"key" = ["action", ...]` bindings. Keys bound to `[]` or
+/// `"unbind"` are returned separately so the caller can remove them from an
+/// inherited table. `inherited` is what the table is layered over, and is
+/// only read to warn about sequences a shorter binding shadows.
+fn walk_bindings_table(
+    table: toml::Table,
+    prefix: &str,
+    inherited: &HashMap<KeySequence, Actions>,
+) -> (HashMap<KeySequence, Actions>, Vec<KeySequence>) {
     let mut result = HashMap::new();
+    let mut unbound = Vec::new();
+    let mut sequences = Vec::new();
     for (key_str, value) in table {
         let field = field_path(prefix, &key_str);
-        let keymap = match key_str.parse::<Keymap>() {
+        let keymap = match key_str.parse::<KeySequence>() {
             Ok(k) => k,
             Err(e) => {
                 tracing::warn!(
@@ -608,6 +963,10 @@ fn walk_bindings_table(table: toml::Table, prefix: &str) -> HashMap<Keymap, Acti
                 continue;
             }
         };
+        if value.as_str() == Some("unbind") || value.as_array().is_some_and(|a| a.is_empty()) {
+            unbound.push(keymap);
+            continue;
+        }
         let action_strs: Vec<String> = match value.try_into() {
             Ok(v) => v,
             Err(e) => {
@@ -621,6 +980,9 @@ fn walk_bindings_table(table: toml::Table, prefix: &str) -> HashMap<Keymap, Acti
         };
         match parse_actions(&action_strs) {
             Ok(actions) => {
+                if keymap.0.len() > 1 {
+                    sequences.push((keymap.clone(), field));
+                }
                 result.insert(keymap, actions);
             }
             Err(e) => {
@@ -632,7 +994,23 @@ fn walk_bindings_table(table: toml::Table, prefix: &str) -> HashMap<Keymap, Acti
             }
         }
     }
-    result
+    // A key bound on its own resolves before any sequence it starts.
+    let is_bound = |seq: &KeySequence| {
+        result.contains_key(seq) || (inherited.contains_key(seq) && !unbound.contains(seq))
+    };
+    for (sequence, field) in sequences {
+        let shadowed_by = (1..sequence.0.len())
+            .map(|len| KeySequence(sequence.0[..len].to_vec()))
+            .find(|prefix| is_bound(prefix));
+        if let Some(shadowed_by) = shadowed_by {
+            tracing::warn!(
+                field = %field,
+                shadowed_by = %shadowed_by,
+                "Sequence never fires, a shorter binding starts it",
+            );
+        }
+    }
+    (result, unbound)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -758,13 +1136,136 @@ pub(crate) enum Strategy {
     Master,
 }
 
+/// Where a window goes when it becomes a float, through a float rule or
+/// `toggle float`. Whatever the policy, the result is clamped fully into the
+/// work area of the float's monitor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum FloatPlacement {
+    /// Where the window already was.
+    #[default]
+    Keep,
+    Center,
+    /// Centered over the focused window when it is from the same app, else
+    /// over the app's topmost window on the workspace.
+    Parent,
+    /// Down and right of the topmost float.
+    Cascade,
+    /// Centered under the pointer.
+    Cursor,
+}
+
+/// How far a workspace whose windows overflow the screen scrolls when focus
+/// lands on a window that is not fully in view.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ScrollFocus {
+    /// Just far enough to bring the window to the nearest screen edge.
+    #[default]
+    Minimal,
+    /// Until the window is centered, as far as the content allows.
+    Center,
+}
+
+/// What the tiling overlay paints over a workspace whose windows' minimum
+/// sizes overflow the screen and scroll.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ScrollIndicator {
+    #[default]
+    Off,
+    /// Bars on the edges with content past them, and an arrow toward each
+    /// window scrolled fully out of view.
+    Edges,
+    /// A scaled diagram of all the content in the corner, with the part on
+    /// screen outlined.
+    Minimap,
+}
+
+/// `[animation]`: how tiling windows move to a new layout and how long the
+/// border of a newly focused window flashes. Read fresh on every frame, so a
+/// reload applies to transitions already in flight.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub(crate) struct AnimationConfig {
+    /// Length of one transition. `0` places windows instantly.
+    #[serde(default)]
+    pub(crate) duration_ms: u32,
+    /// How long the focused border flashes after focus moves. `0` turns the
+    /// flash off.
+    #[serde(default = "default_focus_flash_ms")]
+    pub(crate) focus_flash_ms: u32,
+    #[serde(default)]
+    pub(crate) easing: Easing,
+}
+
+impl Default for AnimationConfig {
+    fn default() -> Self {
+        Self {
+            duration_ms: 0,
+            focus_flash_ms: default_focus_flash_ms(),
+            easing: Easing::default(),
+        }
+    }
+}
+
+fn default_focus_flash_ms() -> u32 {
+    200
+}
+
+/// Longest `animation.duration_ms` or `animation.focus_flash_ms` accepted, so
+/// a typo can't leave windows crawling or flashing for minutes.
+const MAX_ANIMATION_DURATION_MS: u32 = 2000;
+
+/// Progress curve of an animated transition.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Easing {
+    Linear,
+    EaseIn,
+    /// Fast start, gentle landing.
+    #[default]
+    EaseOut,
+    EaseInOut,
+}
+
 /// All fields are read fresh by the strategy on every layout pass.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub(crate) struct PartitionTreeConfig {
     #[serde(default = "default_tab_bar_height")]
     pub(crate) tab_bar_height: Pixels<Logical>,
+    #[serde(default)]
+    pub(crate) tab_bar_position: TabBarPosition,
+    /// Width of the tab list when `tab_bar_position` is `left` or `right`.
+    #[serde(default = "default_tab_sidebar_width")]
+    pub(crate) tab_sidebar_width: Pixels<Logical>,
     #[serde(default = "default_automatic_tiling")]
     pub(crate) automatic_tiling: bool,
+    /// Tab label template. `{app}`, `{title}` and `{index}` (1-based) are
+    /// substituted, anything else is kept as written.
+    #[serde(default = "default_tab_title_format")]
+    pub(crate) tab_title_format: String,
+    /// Draw the app icon before each tab label where the platform has one.
+    #[serde(default = "default_tab_icons")]
+    pub(crate) tab_icons: bool,
+}
+
+/// Side of a tabbed container that holds its tabs. `top` and `bottom` lay tabs
+/// out in a bar `tab_bar_height` tall; `left` and `right` stack them in a list
+/// `tab_sidebar_width` wide.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TabBarPosition {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl TabBarPosition {
+    pub(crate) fn is_vertical(self) -> bool {
+        matches!(self, TabBarPosition::Left | TabBarPosition::Right)
+    }
 }
 
 /// Global `master_ratio` and `master_count` seed new workspaces on their first
@@ -780,17 +1281,17 @@ pub(crate) struct MasterConfig {
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub(crate) struct WindowMatcher {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) app: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) bundle_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) title: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) process: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) class: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) aumid: Option<String>,
 }
 
@@ -799,6 +1300,76 @@ impl WalkRule for WindowMatcher {
         &["app", "bundle_id", "title", "process", "class", "aumid"];
 }
 
+/// `[[border]]`: the border size for windows the matcher picks, in place of
+/// `border_size`. `size = 0` drops the border entirely.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(crate) struct BorderRule {
+    #[serde(flatten)]
+    pub(crate) matcher: WindowMatcher,
+    pub(crate) size: Pixels<Logical>,
+}
+
+impl WalkRule for BorderRule {
+    const KNOWN: &'static [&'static str] = &[
+        "app",
+        "bundle_id",
+        "title",
+        "process",
+        "class",
+        "aumid",
+        "size",
+    ];
+}
+
+/// `[[tab_title]]`: rewrites the title of windows the matcher picks before it
+/// fills `{title}` in `tab_title_format`. Every matching rule applies, in order.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(crate) struct TitleRewrite {
+    #[serde(flatten)]
+    pub(crate) matcher: WindowMatcher,
+    pub(crate) pattern: TitlePattern,
+    /// May refer to capture groups as `$1` or `${name}`.
+    #[serde(default)]
+    pub(crate) replace: String,
+}
+
+impl WalkRule for TitleRewrite {
+    const KNOWN: &'static [&'static str] = &[
+        "app",
+        "bundle_id",
+        "title",
+        "process",
+        "class",
+        "aumid",
+        "pattern",
+        "replace",
+    ];
+}
+
+/// A regex compiled once at load, so a bad pattern drops its rule with a
+/// warning instead of failing on every repaint.
+#[derive(Debug, Clone)]
+pub(crate) struct TitlePattern(pub(crate) regex::Regex);
+
+impl PartialEq for TitlePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl<'de> Deserialize<'de> for TitlePattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        regex::Regex::new(&s)
+            .map(TitlePattern)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl WalkRule for Hook {
+    const KNOWN: &'static [&'static str] = &["event", "match", "name", "actions"];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub(crate) enum SplitMode {
@@ -916,6 +1487,12 @@ pub(crate) fn default_strategy() -> Strategy {
 fn default_automatic_tiling() -> bool {
     true
 }
+fn default_tab_title_format() -> String {
+    "{title}".to_string()
+}
+fn default_tab_icons() -> bool {
+    true
+}
 fn default_master_ratio() -> f32 {
     0.5
 }
@@ -925,7 +1502,11 @@ fn default_master_count() -> usize {
 pub(crate) fn default_partition_tree_config() -> PartitionTreeConfig {
     PartitionTreeConfig {
         tab_bar_height: default_tab_bar_height(),
+        tab_bar_position: TabBarPosition::default(),
+        tab_sidebar_width: default_tab_sidebar_width(),
         automatic_tiling: default_automatic_tiling(),
+        tab_title_format: default_tab_title_format(),
+        tab_icons: default_tab_icons(),
     }
 }
 pub(crate) fn default_master_config() -> MasterConfig {
@@ -1050,8 +1631,19 @@ pub(crate) struct Config {
     pub(crate) keymaps: ModalKeymaps,
     #[serde(default = "default_border_size")]
     pub(crate) border_size: Pixels<Logical>,
+    #[serde(default = "default_border_radius")]
+    pub(crate) border_radius: Pixels<Logical>,
+    /// Painted inside the reserved space, see `reserved_border_size`.
     #[serde(default)]
-    pub(crate) theme: Flavor,
+    pub(crate) focused_border_size: Option<Pixels<Logical>>,
+    #[serde(default)]
+    pub(crate) unfocused_border_size: Option<Pixels<Logical>>,
+    #[serde(default)]
+    pub(crate) border: Vec<BorderRule>,
+    #[serde(default)]
+    pub(crate) tab_title: Vec<TitleRewrite>,
+    #[serde(default)]
+    pub(crate) theme: ThemeConfig,
     #[serde(default)]
     pub(crate) font: FontConfig,
     #[serde(default)]
@@ -1062,6 +1654,14 @@ pub(crate) struct Config {
     pub(crate) start_at_login: bool,
     #[serde(default = "default_strategy")]
     pub(crate) strategy: Strategy,
+    #[serde(default)]
+    pub(crate) float_placement: FloatPlacement,
+    #[serde(default)]
+    pub(crate) scroll_indicator: ScrollIndicator,
+    #[serde(default)]
+    pub(crate) scroll_focus: ScrollFocus,
+    #[serde(default)]
+    pub(crate) animation: AnimationConfig,
     #[serde(default = "default_partition_tree_config")]
     pub(crate) partition_tree: PartitionTreeConfig,
     #[serde(default = "default_master_config")]
@@ -1072,6 +1672,17 @@ pub(crate) struct Config {
     pub(crate) float: Vec<WindowMatcher>,
     #[serde(default)]
     pub(crate) fullscreen: Vec<WindowMatcher>,
+    #[serde(default)]
+    pub(crate) sticky: Vec<WindowMatcher>,
+    #[serde(default, rename = "hook")]
+    pub(crate) hooks: Vec<Hook>,
+    /// Files pulled in through `include`, so the watcher can reload on their
+    /// changes too.
+    #[serde(skip)]
+    pub(crate) includes: Vec<PathBuf>,
+    /// Which file each key was merged from, so warnings point into it.
+    #[serde(skip)]
+    pub(crate) key_origins: KeyOrigins,
 }
 
 #[derive(Debug, Deserialize, Default, Clone, Copy)]
@@ -1101,34 +1712,64 @@ pub(crate) fn default_border_size() -> Pixels<Logical> {
     Pixels::new(4)
 }
 
+fn default_border_radius() -> Pixels<Logical> {
+    Pixels::new(12)
+}
+
 fn default_tab_bar_height() -> Pixels<Logical> {
     Pixels::new(24)
 }
 
+fn default_tab_sidebar_width() -> Pixels<Logical> {
+    Pixels::new(200)
+}
+
 impl Default for Config {
     fn default() -> Self {
         Config {
             keymaps: default_keymaps(),
             border_size: default_border_size(),
+            border_radius: default_border_radius(),
+            focused_border_size: None,
+            unfocused_border_size: None,
+            border: Vec::new(),
+            tab_title: Vec::new(),
             // Mocha is the darkest flavour and matches Dome's pre-theme default palette.
-            theme: Flavor::default(),
+            theme: ThemeConfig::default(),
             font: FontConfig::default(),
             ignore: default_ignore(),
             log_level: LogLevel::default(),
             start_at_login: false,
             strategy: default_strategy(),
+            float_placement: FloatPlacement::default(),
+            scroll_indicator: ScrollIndicator::default(),
+            scroll_focus: ScrollFocus::default(),
+            animation: AnimationConfig::default(),
             partition_tree: default_partition_tree_config(),
             master: default_master_config(),
             size_constraints: SizeConstraints::default(),
             float: Vec::new(),
             fullscreen: Vec::new(),
+            sticky: Vec::new(),
+            hooks: Vec::new(),
+            includes: Vec::new(),
+            key_origins: KeyOrigins::default(),
         }
     }
 }
 
 impl Config {
     pub(crate) fn theme(&self) -> Theme {
-        Theme::from_flavor(self.theme)
+        self.theme.resolve()
+    }
+
+    /// Space reserved around each window: `border_size`, widened to fit a
+    /// larger `focused_border_size` or `unfocused_border_size`.
+    pub(crate) fn reserved_border_size(&self) -> Pixels<Logical> {
+        self.focused_border_size
+            .into_iter()
+            .chain(self.unfocused_border_size)
+            .fold(self.border_size, Pixels::max)
     }
 
     #[cfg(target_os = "windows")]
@@ -1210,35 +1851,363 @@ impl Config {
     pub(crate) fn load(path: &str) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
         let table: toml::Table = toml::from_str(&content)?;
-        let config = RawConfig::into_config(table);
+        let path = Path::new(path);
+        let mut chain = vec![path.canonicalize()?];
+        let (table, includes, key_origins) = resolve_includes(path, table, &mut chain);
+        let mut config = RawConfig::into_config(table);
+        config.includes = includes;
+        config.key_origins = key_origins;
         config.validate_layout()?;
         Ok(config)
     }
 }
 
-fn load_toml<T: WalkRecover>(path: &str) -> anyhow::Result<T> {
-    let content = std::fs::read_to_string(path)?;
-    let mut table: toml::Table = toml::from_str(&content)?;
-    let mut w = Walker::new(&mut table, "");
-    Ok(T::walk(&mut w))
+/// Merges the files listed in `include`, then `table` itself, then its
+/// `[platform.<os>]` table, each overriding the last. Returns the merged
+/// table, every file that went into it or is named but missing, and where
+/// each key came from.
+/// `chain` holds the files currently being resolved, so an include cycle is
+/// skipped instead of recursing.
+fn resolve_includes(
+    path: &Path,
+    mut table: toml::Table,
+    chain: &mut Vec<PathBuf>,
+) -> (toml::Table, Vec<PathBuf>, KeyOrigins) {
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+    let entries = match table.remove("include") {
+        Some(toml::Value::Array(entries)) => entries,
+        Some(other) => {
+            tracing::warn!(
+                field = "include",
+                file = %path.display(),
+                error = %format!("expected array, got {}", other.type_str()),
+                "Invalid value, ignoring",
+            );
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let mut merged = toml::Table::new();
+    let mut origins = KeyOrigins::default();
+    let mut files = Vec::new();
+    for (i, entry) in entries.into_iter().enumerate() {
+        let field = format!("include[{i}]");
+        let Some(spec) = entry.as_str() else {
+            tracing::warn!(field = %field, file = %path.display(), "Expected string, ignoring");
+            continue;
+        };
+        let expanded = expand_include_path(spec, base_dir);
+        let include = match expanded.canonicalize() {
+            Ok(include) => include,
+            Err(e) => {
+                tracing::warn!(
+                    field = %field,
+                    file = %path.display(),
+                    error = %e,
+                    "Include not found, skipping",
+                );
+                // Still listed, so the watcher reloads once the file appears.
+                files.push(expanded);
+                continue;
+            }
+        };
+        if chain.contains(&include) {
+            tracing::warn!(
+                field = %field,
+                file = %path.display(),
+                "Include cycle, skipping",
+            );
+            continue;
+        }
+        let inner = match std::fs::read_to_string(&include)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(toml::from_str::<toml::Table>(&content)?))
+        {
+            Ok(inner) => inner,
+            Err(e) => {
+                tracing::warn!(
+                    field = %field,
+                    file = %path.display(),
+                    error = %format!("{e:#}"),
+                    "Invalid include, skipping",
+                );
+                continue;
+            }
+        };
+        chain.push(include.clone());
+        let (inner, inner_files, inner_origins) = resolve_includes(&include, inner, chain);
+        chain.pop();
+        files.push(include);
+        files.extend(inner_files);
+        merge_tables(&mut merged, &mut origins, inner, &inner_origins);
+    }
+    let platform = table.remove("platform");
+    let own = KeyOrigins::file(path);
+    merge_tables(&mut merged, &mut origins, table, &own);
+    if let Some(platform) = platform {
+        apply_platform(&mut merged, &mut origins, platform, path, CURRENT_PLATFORM);
+    }
+    (merged, files, origins)
 }
 
-pub(crate) fn load_or_default<T: Default>(
-    path: &str,
-    load: impl Fn(&str) -> anyhow::Result<T>,
-) -> T {
-    match load(path) {
-        Ok(v) => v,
-        Err(e)
-            if e.downcast_ref::<std::io::Error>()
-                .is_some_and(|io| io.kind() == std::io::ErrorKind::NotFound) =>
-        {
-            tracing::info!(%path, "File not found, using defaults");
-            T::default()
+#[cfg(target_os = "macos")]
+const CURRENT_PLATFORM: &str = "macos";
+#[cfg(target_os = "windows")]
+const CURRENT_PLATFORM: &str = "windows";
+
+const PLATFORMS: &[&str] = &["macos", "windows"];
+
+fn apply_platform(
+    table: &mut toml::Table,
+    origins: &mut KeyOrigins,
+    platform: toml::Value,
+    path: &Path,
+    current: &str,
+) {
+    let toml::Value::Table(platforms) = platform else {
+        tracing::warn!(field = "platform", file = %path.display(), "Expected table, ignoring");
+        return;
+    };
+    let from = KeyOrigins::file(path).child("platform");
+    for (name, overrides) in platforms {
+        if !PLATFORMS.contains(&name.as_str()) {
+            tracing::warn!(
+                field = %format!("platform.{name}"),
+                file = %path.display(),
+                "Unknown platform, ignoring",
+            );
+            continue;
         }
-        Err(e) => {
-            tracing::warn!(%path, error = %format!("{e:#}"), "Failed to load, using defaults");
-            T::default()
+        if name != current {
+            continue;
+        }
+        match overrides {
+            toml::Value::Table(overrides) => {
+                merge_tables(table, origins, overrides, &from.child(&name));
+            }
+            _ => tracing::warn!(
+                field = %format!("platform.{name}"),
+                file = %path.display(),
+                "Expected table, ignoring",
+            ),
+        }
+    }
+}
+
+/// Deep-merges `overlay` into `base`. Tables merge key by key and arrays of
+/// tables (rule lists like `[[float]]`) append. Any other value replaces the
+/// one in `base`. `origins` follows along, taking each overlaid key from
+/// `from`.
+fn merge_tables(
+    base: &mut toml::Table,
+    origins: &mut KeyOrigins,
+    overlay: toml::Table,
+    from: &KeyOrigins,
+) {
+    let children = origins.expand_table(base);
+    for (key, value) in overlay {
+        let from = from.child(&key);
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => {
+                merge_tables(base, children.entry(key).or_default(), overlay, &from);
+            }
+            (Some(toml::Value::Array(base)), toml::Value::Array(overlay))
+                if base.iter().chain(&overlay).all(toml::Value::is_table) =>
+            {
+                let elements = children.entry(key).or_default().expand_array(base.len());
+                elements.extend((0..overlay.len()).map(|i| from.element(i)));
+                base.extend(overlay);
+            }
+            (_, value) => {
+                children.insert(key.clone(), from);
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Where the keys of a merged config came from: for each key, the file and
+/// the key's path within that file. A `[[float]]` entry may be `float[3]`
+/// after merging but `float[0]` in its include, and a `[platform.macos]` key
+/// sits under `platform.macos` in its file.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum KeyOrigins {
+    /// Everything below came from `file`, where it sits at `path`.
+    File {
+        file: Arc<Path>,
+        path: String,
+    },
+    Table(HashMap<String, KeyOrigins>),
+    Array(Vec<KeyOrigins>),
+}
+
+impl Default for KeyOrigins {
+    fn default() -> Self {
+        Self::Table(HashMap::new())
+    }
+}
+
+impl KeyOrigins {
+    fn file(file: &Path) -> Self {
+        Self::File {
+            file: Arc::from(file),
+            path: String::new(),
+        }
+    }
+
+    fn child(&self, key: &str) -> Self {
+        match self {
+            Self::File { file, path } => Self::File {
+                file: file.clone(),
+                path: join_key_path(path, key),
+            },
+            Self::Table(children) => children.get(key).cloned().unwrap_or_default(),
+            Self::Array(_) => Self::default(),
+        }
+    }
+
+    fn element(&self, index: usize) -> Self {
+        match self {
+            Self::File { file, path } => Self::File {
+                file: file.clone(),
+                path: format!("{path}[{index}]"),
+            },
+            Self::Array(elements) => elements.get(index).cloned().unwrap_or_default(),
+            Self::Table(_) => Self::default(),
+        }
+    }
+
+    /// Per-key origins for the keys of `table`, splitting a whole-file origin.
+    fn expand_table(&mut self, table: &toml::Table) -> &mut HashMap<String, KeyOrigins> {
+        if !matches!(self, Self::Table(_)) {
+            let children = table.keys().map(|k| (k.clone(), self.child(k))).collect();
+            *self = Self::Table(children);
+        }
+        let Self::Table(children) = self else {
+            unreachable!()
+        };
+        children
+    }
+
+    /// Per-element origins for an array of `len` tables.
+    fn expand_array(&mut self, len: usize) -> &mut Vec<KeyOrigins> {
+        if !matches!(self, Self::Array(_)) {
+            let elements = (0..len).map(|i| self.element(i)).collect();
+            *self = Self::Array(elements);
+        }
+        let Self::Array(elements) = self else {
+            unreachable!()
+        };
+        elements
+    }
+
+    /// File that the walker key path `field` (e.g. `float[1].size`) came
+    /// from, and the same key's path in that file. `None` when nothing was
+    /// recorded, as for a config built without `Config::load`.
+    pub(crate) fn locate(&self, field: &str) -> Option<(&Path, String)> {
+        match self {
+            Self::File { file, path } => Some((file, join_key_path(path, field))),
+            Self::Array(elements) => {
+                let (index, rest) = field.strip_prefix('[')?.split_once(']')?;
+                let element = elements.get(index.parse::<usize>().ok()?)?;
+                element.locate(rest.strip_prefix('.').unwrap_or(rest))
+            }
+            // Keys may contain dots, so take the longest that matches.
+            Self::Table(children) => {
+                let (key, child) = children
+                    .iter()
+                    .filter(|(key, _)| {
+                        field
+                            .strip_prefix(key.as_str())
+                            .is_some_and(|tail| tail.is_empty() || tail.starts_with(['.', '[']))
+                    })
+                    .max_by_key(|(key, _)| key.len())?;
+                let rest = &field[key.len()..];
+                child.locate(rest.strip_prefix('.').unwrap_or(rest))
+            }
+        }
+    }
+}
+
+fn join_key_path(prefix: &str, rest: &str) -> String {
+    match (prefix.is_empty(), rest.is_empty() || rest.starts_with('[')) {
+        (true, _) => rest.to_string(),
+        (false, true) => format!("{prefix}{rest}"),
+        (false, false) => format!("{prefix}.{rest}"),
+    }
+}
+
+/// Expands `~`, `$(hostname)` and `${VAR}` in an include path, and resolves
+/// it against the including file's directory.
+fn expand_include_path(spec: &str, base_dir: &Path) -> PathBuf {
+    let mut expanded = spec.replace("$(hostname)", &hostname());
+    while let Some(start) = expanded.find("${") {
+        let Some(len) = expanded[start..].find('}') else {
+            break;
+        };
+        let value = std::env::var(&expanded[start + 2..start + len]).unwrap_or_default();
+        expanded.replace_range(start..=start + len, &value);
+    }
+    let path = match expanded
+        .strip_prefix("~/")
+        .or_else(|| expanded.strip_prefix("~\\"))
+    {
+        Some(rest) => home_dir().join(rest),
+        None => PathBuf::from(&expanded),
+    };
+    base_dir.join(path)
+}
+
+#[cfg(target_os = "windows")]
+fn home_dir() -> PathBuf {
+    PathBuf::from(std::env::var("USERPROFILE").unwrap_or_default())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn home_dir() -> PathBuf {
+    PathBuf::from(std::env::var("HOME").unwrap_or_default())
+}
+
+#[cfg(target_os = "macos")]
+fn hostname() -> String {
+    let mut buf = [0u8; 256];
+    // gethostname NUL-terminates within the buffer on success.
+    let rc = unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len()) };
+    if rc != 0 {
+        return String::new();
+    }
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
+#[cfg(target_os = "windows")]
+fn hostname() -> String {
+    std::env::var("COMPUTERNAME").unwrap_or_default()
+}
+
+fn load_toml<T: WalkRecover>(path: &str) -> anyhow::Result<T> {
+    let content = std::fs::read_to_string(path)?;
+    let mut table: toml::Table = toml::from_str(&content)?;
+    let mut w = Walker::new(&mut table, "");
+    Ok(T::walk(&mut w))
+}
+
+pub(crate) fn load_or_default<T: Default>(
+    path: &str,
+    load: impl Fn(&str) -> anyhow::Result<T>,
+) -> T {
+    match load(path) {
+        Ok(v) => v,
+        Err(e)
+            if e.downcast_ref::<std::io::Error>()
+                .is_some_and(|io| io.kind() == std::io::ErrorKind::NotFound) =>
+        {
+            tracing::info!(%path, "File not found, using defaults");
+            T::default()
+        }
+        Err(e) => {
+            tracing::warn!(%path, error = %format!("{e:#}"), "Failed to load, using defaults");
+            T::default()
         }
     }
 }
@@ -1250,34 +2219,124 @@ pub(crate) fn layout_default_path(config_path: &Path) -> PathBuf {
         .join("layout.toml")
 }
 
-pub(crate) fn start_config_watcher<T: Send + 'static>(
+/// Files besides the watched one whose changes should trigger a reload.
+pub(crate) trait IncludedFiles {
+    fn included_files(&self) -> &[PathBuf] {
+        &[]
+    }
+
+    /// Where each key came from, for locating warnings. `None` when every
+    /// key is from the loaded file itself.
+    fn key_origins(&self) -> Option<&KeyOrigins> {
+        None
+    }
+}
+
+impl IncludedFiles for Config {
+    fn included_files(&self) -> &[PathBuf] {
+        &self.includes
+    }
+
+    fn key_origins(&self) -> Option<&KeyOrigins> {
+        Some(&self.key_origins)
+    }
+}
+
+impl IncludedFiles for LayoutConfig {}
+
+/// Watches `path` and the files it includes, and reloads on change.
+/// `on_reload` gets the new value, or `None` when the reload failed and the
+/// previous one should stay, along with the report for `dome query
+/// config-status`. Events are handled on a dedicated thread, which owns the
+/// watcher so it can follow the includes as reloads add and drop them.
+pub(crate) fn start_config_watcher<T: IncludedFiles + Send + 'static>(
     path: &str,
+    file: ConfigFile,
+    includes: &[PathBuf],
     load_fn: impl Fn(&str) -> anyhow::Result<T> + Send + 'static,
-    on_change: impl Fn(T) + Send + 'static,
-) -> anyhow::Result<RecommendedWatcher> {
-    let path_buf = Path::new(path).canonicalize()?;
-    let watch_dir = path_buf
-        .parent()
-        .ok_or_else(|| anyhow::anyhow!("no parent dir"))?
-        .to_owned();
-    let target = path_buf.clone();
-    let mut watcher = notify::recommended_watcher(move |res: Result<notify::Event, _>| {
-        if let Ok(event) = res
-            && matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_))
-            && event.paths.iter().any(|p| p == &target)
-        {
-            match load_fn(target.to_str().unwrap()) {
-                Ok(v) => {
+    on_reload: impl Fn(Option<T>, LoadReport) + Send + 'static,
+) -> anyhow::Result<()> {
+    let target = Path::new(path).canonicalize()?;
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    let mut watched = WatchedFiles::default();
+    watched.add(&mut watcher, &target)?;
+    for include in includes {
+        watched.add_or_warn(&mut watcher, include);
+    }
+    std::thread::Builder::new()
+        .name("config-watcher".to_string())
+        .spawn(move || {
+            for res in rx {
+                let Ok(event): notify::Result<notify::Event> = res else {
+                    continue;
+                };
+                if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_))
+                    || !event.paths.iter().any(|p| watched.files.contains(p))
+                {
+                    continue;
+                }
+                let (value, report) = reload_reported(file, target.to_str().unwrap(), &load_fn);
+                if let Some(value) = &value {
                     tracing::info!(path = %target.display(), "File reloaded");
-                    on_change(v);
+                    watched.sync(&mut watcher, &target, value.included_files());
                 }
-                Err(e) => tracing::warn!(path = %target.display(), error = %e, "Failed to reload"),
+                on_reload(value, report);
             }
-        }
-    })?;
-    watcher.watch(&watch_dir, RecursiveMode::NonRecursive)?;
+        })?;
     tracing::info!(%path, "File watcher started");
-    Ok(watcher)
+    Ok(())
+}
+
+#[derive(Default)]
+struct WatchedFiles {
+    files: HashSet<PathBuf>,
+    dirs: HashSet<PathBuf>,
+}
+
+impl WatchedFiles {
+    // Editors often save by replacing the file, so the parent directory is
+    // watched rather than the file itself.
+    fn add(&mut self, watcher: &mut RecommendedWatcher, file: &Path) -> anyhow::Result<()> {
+        let dir = file
+            .parent()
+            .ok_or_else(|| anyhow::anyhow!("no parent dir"))?;
+        if !self.dirs.contains(dir) {
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+            self.dirs.insert(dir.to_owned());
+        }
+        self.files.insert(file.to_owned());
+        Ok(())
+    }
+
+    fn add_or_warn(&mut self, watcher: &mut RecommendedWatcher, file: &Path) {
+        if let Err(e) = self.add(watcher, file) {
+            tracing::warn!(path = %file.display(), error = %format!("{e:#}"), "Failed to watch include");
+        }
+    }
+
+    /// Watch exactly `target` and `includes` after a reload: includes it no
+    /// longer names stop triggering reloads, and directories left without a
+    /// watched file are unwatched.
+    fn sync(&mut self, watcher: &mut RecommendedWatcher, target: &Path, includes: &[PathBuf]) {
+        let wanted: HashSet<&Path> = std::iter::once(target)
+            .chain(includes.iter().map(PathBuf::as_path))
+            .collect();
+        self.files.retain(|file| wanted.contains(file.as_path()));
+        let files = &self.files;
+        self.dirs.retain(|dir| {
+            let in_use = files
+                .iter()
+                .any(|file| file.parent() == Some(dir.as_path()));
+            if !in_use && let Err(e) = watcher.unwatch(dir) {
+                tracing::debug!(path = %dir.display(), error = %e, "Failed to unwatch directory");
+            }
+            in_use
+        });
+        for include in includes {
+            self.add_or_warn(watcher, include);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1439,7 +2498,7 @@ mod tests {
     #[test]
     fn theme_deserializes() {
         let config: Config = toml::from_str(r#"theme = "latte""#).unwrap();
-        assert_eq!(config.theme, Flavor::Latte);
+        assert_eq!(config.theme.flavor, Flavor::Latte);
     }
 
     #[test]
@@ -1458,7 +2517,7 @@ mod tests {
     fn config_theme_method_returns_correct_theme() {
         use crate::theme::Theme;
         let config = Config {
-            theme: Flavor::Latte,
+            theme: Flavor::Latte.into(),
             ..Config::default()
         };
         assert_eq!(
@@ -1467,6 +2526,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn theme_table_overrides_flavor_colors() {
+        let table: toml::Table = toml::from_str(
+            r##"
+            [theme]
+            flavor = "latte"
+            marked_border = "#ff0000"
+            float_border = "#00ff0080"
+            focused_border = "blue"
+            "##,
+        )
+        .unwrap();
+        let config = RawConfig::into_config(table);
+        let theme = config.theme();
+        let latte = Theme::from_flavor(Flavor::Latte);
+        assert_eq!(config.theme.flavor, Flavor::Latte);
+        assert_eq!(theme.marked_border, egui::Color32::from_rgb(255, 0, 0));
+        assert_eq!(
+            theme.float_border,
+            egui::Color32::from_rgba_unmultiplied(0, 255, 0, 128)
+        );
+        // An invalid color keeps the flavor's own.
+        assert_eq!(theme.focused_border, latte.focused_border);
+        assert_eq!(theme.unfocused_border, latte.unfocused_border);
+    }
+
+    #[test]
+    fn theme_light_dark_pair_loads() {
+        let table: toml::Table =
+            toml::from_str(r#"theme = { light = "frappe", dark = "macchiato" }"#).unwrap();
+        let config = RawConfig::into_config(table);
+        assert_eq!(
+            config.theme.light_dark,
+            Some(LightDark {
+                light: Flavor::Frappe,
+                dark: Flavor::Macchiato,
+            })
+        );
+
+        // One side alone keeps the stock pairing for the other.
+        let table: toml::Table = toml::from_str(
+            r#"
+            [theme]
+            dark = "frappe"
+            "#,
+        )
+        .unwrap();
+        let config = RawConfig::into_config(table);
+        assert_eq!(
+            config.theme.light_dark,
+            Some(LightDark {
+                light: Flavor::Latte,
+                dark: Flavor::Frappe,
+            })
+        );
+        assert_eq!(
+            RawConfig::into_config(toml::Table::new()).theme.light_dark,
+            None
+        );
+    }
+
     #[test]
     fn removed_color_field_rejected() {
         let nanos = std::time::SystemTime::now()
@@ -1477,20 +2597,75 @@ mod tests {
         std::fs::write(&path, "focused_color = \"#ff0000\"\ntheme = \"latte\"\n").unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
-        assert_eq!(config.theme, Flavor::Latte);
+        assert_eq!(config.theme.flavor, Flavor::Latte);
     }
 
     #[test]
-    fn removed_border_radius_rejected() {
+    fn border_radius_and_state_sizes_load() {
         let nanos = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_nanos();
         let path = std::env::temp_dir().join(format!("dome_config_border_radius_{nanos}.toml"));
-        std::fs::write(&path, "border_radius = 4\nborder_size = 5.0\n").unwrap();
+        std::fs::write(
+            &path,
+            "border_radius = 4\nborder_size = 5.0\nunfocused_border_size = 1\n",
+        )
+        .unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
         assert_eq!(config.border_size.value(), 5);
+        assert_eq!(config.border_radius.value(), 4);
+        assert_eq!(config.focused_border_size, None);
+        assert_eq!(config.unfocused_border_size, Some(Pixels::new(1)));
+        assert_eq!(config.reserved_border_size(), Pixels::new(5));
+    }
+
+    #[test]
+    fn reserved_border_size_fits_the_widest_state() {
+        let config = Config {
+            border_size: Pixels::new(4),
+            focused_border_size: Some(Pixels::new(6)),
+            unfocused_border_size: Some(Pixels::new(1)),
+            ..Config::default()
+        };
+        assert_eq!(config.reserved_border_size(), Pixels::new(6));
+        assert_eq!(
+            crate::core::GlobalLayoutConfig::from(&config).border_size,
+            Pixels::new(6)
+        );
+    }
+
+    #[test]
+    fn border_rules_load_with_matcher_and_size() {
+        let table: toml::Table =
+            toml::from_str("[[border]]\napp = \"mpv\"\nsize = 0\n\n[[border]]\napp = \"vlc\"\n")
+                .unwrap();
+        let config = RawConfig::into_config(table);
+        // The second rule has no size and is dropped with a warning.
+        assert_eq!(config.border.len(), 1);
+        assert_eq!(config.border[0].matcher.app.as_deref(), Some("mpv"));
+        assert_eq!(config.border[0].size, Pixels::ZERO);
+    }
+
+    #[test]
+    fn tab_title_rules_drop_invalid_patterns() {
+        let table: toml::Table = toml::from_str(
+            "[[tab_title]]\nprocess = \"firefox\"\npattern = \" - Mozilla Firefox$\"\n\n\
+             [[tab_title]]\npattern = \"(\"\nreplace = \"x\"\n",
+        )
+        .unwrap();
+        let config = RawConfig::into_config(table);
+        assert_eq!(config.tab_title.len(), 1);
+        assert_eq!(
+            config.tab_title[0].matcher.process.as_deref(),
+            Some("firefox")
+        );
+        assert_eq!(
+            config.tab_title[0].pattern.0.as_str(),
+            " - Mozilla Firefox$"
+        );
+        assert_eq!(config.tab_title[0].replace, "");
     }
 
     #[test]
@@ -1554,6 +2729,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tab_bar_position_loads_with_sidebar_width() {
+        let table: toml::Table = toml::from_str(
+            "[partition_tree]\ntab_bar_position = \"left\"\ntab_sidebar_width = 0\n",
+        )
+        .unwrap();
+        let config = RawConfig::into_config(table);
+        assert_eq!(config.partition_tree.tab_bar_position, TabBarPosition::Left);
+        assert_eq!(
+            config.partition_tree.tab_sidebar_width,
+            default_tab_sidebar_width()
+        );
+    }
+
     #[test]
     fn negative_border_size_falls_back_to_default() {
         let nanos = std::time::SystemTime::now()
@@ -1644,7 +2833,7 @@ mod tests {
     }
 
     #[test]
-    fn modal_keymaps_empty_modes() {
+    fn modal_keymaps_only_builtin_modes() {
         let nanos = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -1653,8 +2842,9 @@ mod tests {
         std::fs::write(&path, "[keymaps]\n\"meta+h\" = [\"focus left\"]\n").unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
-        assert!(config.keymaps.modes.is_empty());
-        let keymap = "meta+h".parse::<Keymap>().unwrap();
+        let modes: Vec<&String> = config.keymaps.modes.keys().collect();
+        assert_eq!(modes, [PASSTHROUGH_MODE]);
+        let keymap = "meta+h".parse::<KeySequence>().unwrap();
         assert!(config.keymaps.default.contains_key(&keymap));
     }
 
@@ -1695,19 +2885,338 @@ mod tests {
         .unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
-        let meta_h = "meta+h".parse::<Keymap>().unwrap();
+        let meta_h = "meta+h".parse::<KeySequence>().unwrap();
         assert!(config.keymaps.default.contains_key(&meta_h));
         let resize = config
             .keymaps
             .modes
             .get("resize")
             .expect("resize mode missing");
-        let h = "h".parse::<Keymap>().unwrap();
+        let h = "h".parse::<KeySequence>().unwrap();
         assert!(resize.contains_key(&h));
-        let esc = "escape".parse::<Keymap>().unwrap();
+        let esc = "escape".parse::<KeySequence>().unwrap();
         assert!(resize.contains_key(&esc));
     }
 
+    #[test]
+    fn keymaps_inherit_defaults_merges_and_unbinds() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_keymaps_inherit_{nanos}.toml"));
+        std::fs::write(
+            &path,
+            concat!(
+                "[keymaps]\n",
+                "inherit_defaults = true\n",
+                "\"meta+h\" = []\n",
+                "\"meta+j\" = \"unbind\"\n",
+                "\"meta+e\" = [\"exec kitty\"]\n",
+                "\"meta+y\" = [\"focus left\"]\n",
+            ),
+        )
+        .unwrap();
+        let _cleanup = CleanupFile(path.clone());
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        let defaults = default_keymaps().default;
+        let key = |s: &str| s.parse::<KeySequence>().unwrap();
+        assert!(!config.keymaps.default.contains_key(&key("meta+h")));
+        assert!(!config.keymaps.default.contains_key(&key("meta+j")));
+        assert_eq!(
+            config.keymaps.default[&key("meta+e")].to_string(),
+            "[exec kitty]"
+        );
+        assert!(config.keymaps.default.contains_key(&key("meta+y")));
+        assert!(config.keymaps.default.contains_key(&key("meta+k")));
+        assert_eq!(config.keymaps.default.len(), defaults.len() - 1);
+    }
+
+    #[test]
+    fn keymaps_without_inherit_defaults_replace_table() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_keymaps_replace_{nanos}.toml"));
+        std::fs::write(
+            &path,
+            concat!(
+                "[keymaps]\n",
+                "inherit_defaults = \"yes\"\n",
+                "\"meta+h\" = \"unbind\"\n",
+                "\"meta+y\" = [\"focus left\"]\n",
+            ),
+        )
+        .unwrap();
+        let _cleanup = CleanupFile(path.clone());
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.keymaps.default.len(), 1);
+        assert!(
+            config
+                .keymaps
+                .default
+                .contains_key(&"meta+y".parse::<KeySequence>().unwrap())
+        );
+    }
+
+    #[test]
+    fn keymaps_load_sequences_and_timeout() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_keymaps_seq_{nanos}.toml"));
+        std::fs::write(
+            &path,
+            concat!(
+                "[keymaps]\n",
+                "sequence_timeout_ms = 500\n",
+                "\"meta+w  v\" = [\"toggle direction\"]\n",
+            ),
+        )
+        .unwrap();
+        let _cleanup = CleanupFile(path.clone());
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.keymaps.sequence_timeout, Duration::from_millis(500));
+        let seq: KeySequence = "meta+w v".parse().unwrap();
+        assert_eq!(seq.to_string(), "meta+w v");
+        assert!(config.keymaps.default.contains_key(&seq));
+
+        let table: toml::Table = toml::from_str("[keymaps]\nsequence_timeout_ms = 0\n").unwrap();
+        let zero = RawConfig::into_config(table);
+        assert_eq!(zero.keymaps.sequence_timeout, DEFAULT_SEQUENCE_TIMEOUT);
+    }
+
+    struct CleanupDir(std::path::PathBuf);
+    impl Drop for CleanupDir {
+        fn drop(&mut self) {
+            std::fs::remove_dir_all(&self.0).ok();
+        }
+    }
+
+    fn temp_config_dir(name: &str) -> CleanupDir {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("dome_config_{name}_{nanos}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        CleanupDir(dir)
+    }
+
+    #[test]
+    fn config_load_merges_includes_under_local_values() {
+        let dir = temp_config_dir("include");
+        std::fs::write(
+            dir.0.join("common.toml"),
+            concat!(
+                "border_size = 2\n",
+                "theme = \"latte\"\n",
+                "include = [\"nested.toml\"]\n",
+                "[keymaps]\n",
+                "\"meta+h\" = [\"focus left\"]\n",
+                "[[float]]\n",
+                "app = \"Finder\"\n",
+            ),
+        )
+        .unwrap();
+        std::fs::write(dir.0.join("nested.toml"), "log_level = \"debug\"\n").unwrap();
+        let main = dir.0.join("config.toml");
+        std::fs::write(
+            &main,
+            concat!(
+                "include = [\"common.toml\", \"missing.toml\"]\n",
+                "border_size = 6\n",
+                "[keymaps]\n",
+                "\"meta+l\" = [\"focus right\"]\n",
+                "[[float]]\n",
+                "app = \"Calculator\"\n",
+            ),
+        )
+        .unwrap();
+
+        let config = Config::load(main.to_str().unwrap()).unwrap();
+        assert_eq!(config.border_size, Pixels::new(6));
+        assert_eq!(config.theme.flavor, Flavor::Latte);
+        assert!(matches!(config.log_level, LogLevel::Debug));
+        assert_eq!(config.keymaps.default.len(), 2);
+        assert_eq!(config.float.len(), 2);
+        let names: Vec<_> = config
+            .includes
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(names, ["common.toml", "nested.toml", "missing.toml"]);
+    }
+
+    #[test]
+    fn missing_include_is_listed_and_loads_once_created() {
+        let dir = temp_config_dir("include_missing");
+        let main = dir.0.join("config.toml");
+        std::fs::write(&main, "include = [\"host.toml\"]\n").unwrap();
+        let config = Config::load(main.to_str().unwrap()).unwrap();
+        assert_eq!(config.includes, [dir.0.join("host.toml")]);
+        assert_eq!(config.border_size, default_border_size());
+
+        std::fs::write(dir.0.join("host.toml"), "border_size = 7\n").unwrap();
+        let config = Config::load(main.to_str().unwrap()).unwrap();
+        assert_eq!(config.border_size, Pixels::new(7));
+    }
+
+    #[test]
+    fn config_load_skips_include_cycles() {
+        let dir = temp_config_dir("include_cycle");
+        let main = dir.0.join("config.toml");
+        std::fs::write(&main, "include = [\"other.toml\"]\nborder_size = 3\n").unwrap();
+        std::fs::write(dir.0.join("other.toml"), "include = [\"config.toml\"]\n").unwrap();
+        let config = Config::load(main.to_str().unwrap()).unwrap();
+        assert_eq!(config.border_size, Pixels::new(3));
+        assert_eq!(config.includes.len(), 1);
+    }
+
+    #[test]
+    fn platform_table_overrides_only_current_platform() {
+        let mut table: toml::Table = toml::from_str(concat!(
+            "border_size = 2\n",
+            "[platform.macos]\n",
+            "border_size = 4\n",
+            "[platform.windows]\n",
+            "border_size = 8\n",
+        ))
+        .unwrap();
+        let platform = table.remove("platform").unwrap();
+        let mut origins = KeyOrigins::default();
+        let path = Path::new("config.toml");
+        apply_platform(&mut table, &mut origins, platform, path, "windows");
+        assert_eq!(table["border_size"].as_integer(), Some(8));
+    }
+
+    #[test]
+    fn expand_include_path_substitutes_variables() {
+        let base = Path::new("/etc/dome");
+        let host = hostname();
+        assert_eq!(
+            expand_include_path("hosts/$(hostname).toml", base),
+            base.join(format!("hosts/{host}.toml"))
+        );
+        assert_eq!(
+            expand_include_path("/abs/common.toml", base),
+            PathBuf::from("/abs/common.toml")
+        );
+        assert_eq!(
+            expand_include_path("~/dome.toml", base),
+            home_dir().join("dome.toml")
+        );
+    }
+
+    #[test]
+    fn keymaps_load_mode_options() {
+        let table: toml::Table = toml::from_str(concat!(
+            "[keymaps.mode.resize]\n",
+            "timeout_ms = 2000\n",
+            "oneshot = true\n",
+            "swallow_unbound = true\n",
+            "h = [\"focus left\"]\n",
+            "\n",
+            "[keymaps.mode.launch]\n",
+            "timeout_ms = 0\n",
+            "\n",
+            "[keymaps.mode.passthrough]\n",
+            "\"meta+escape\" = [\"mode default\"]\n",
+        ))
+        .unwrap();
+        let config = RawConfig::into_config(table);
+        let options = &config.keymaps.mode_options;
+        assert_eq!(
+            options["resize"],
+            ModeOptions {
+                timeout: Some(Duration::from_secs(2)),
+                oneshot: true,
+                swallow_unbound: true,
+            }
+        );
+        // Option keys are not bindings
+        assert_eq!(config.keymaps.modes["resize"].len(), 1);
+        assert_eq!(
+            options["launch"],
+            ModeOptions {
+                timeout: None,
+                oneshot: false,
+                swallow_unbound: false,
+            }
+        );
+        assert!(!options[PASSTHROUGH_MODE].swallow_unbound);
+    }
+
+    #[test]
+    fn keymaps_bind_wheel_only_when_configured() {
+        assert!(!default_keymaps().binds_wheel());
+        let table: toml::Table = toml::from_str(concat!(
+            "[keymaps.mode.scroll]\n",
+            "\"alt+wheeldown\" = [\"scroll down\"]\n",
+        ))
+        .unwrap();
+        assert!(RawConfig::into_config(table).keymaps.binds_wheel());
+    }
+
+    #[test]
+    fn keymaps_load_app_blocks() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_keymaps_app_{nanos}.toml"));
+        std::fs::write(
+            &path,
+            concat!(
+                "[keymaps]\n",
+                "\"meta+h\" = [\"focus left\"]\n",
+                "\n",
+                "[[keymaps.app]]\n",
+                "match = { app = \"Zed\" }\n",
+                "\"meta+h\" = \"unbind\"\n",
+                "\"meta+j\" = [\"focus down\"]\n",
+                "\n",
+                "[[keymaps.app]]\n",
+                "\"meta+k\" = [\"focus up\"]\n",
+                "\n",
+                "[[keymaps.app]]\n",
+                "match = { process = \"game.exe\" }\n",
+                "passthrough = true\n",
+                "\n",
+                "[keymaps.mode.passthrough]\n",
+                "\"ctrl+escape\" = [\"mode default\"]\n",
+            ),
+        )
+        .unwrap();
+        let _cleanup = CleanupFile(path.clone());
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        let apps = &config.keymaps.apps;
+        assert_eq!(apps.len(), 2, "block without match is dropped");
+        assert_eq!(apps[0].window.app.as_deref(), Some("Zed"));
+        assert!(!apps[0].passthrough);
+        assert_eq!(apps[0].unbound, ["meta+h".parse::<KeySequence>().unwrap()]);
+        assert!(
+            apps[0]
+                .bindings
+                .contains_key(&"meta+j".parse::<KeySequence>().unwrap())
+        );
+        assert!(apps[1].passthrough && apps[1].bindings.is_empty());
+
+        let passthrough = &config.keymaps.modes[PASSTHROUGH_MODE];
+        assert_eq!(passthrough.len(), 1);
+        assert!(passthrough.contains_key(&"ctrl+escape".parse::<KeySequence>().unwrap()));
+    }
+
+    #[test]
+    fn keymap_display_is_canonical() {
+        let key: Keymap = "shift+cmd+alt+ctrl+h".parse().unwrap();
+        assert_eq!(key.to_string(), "meta+ctrl+alt+shift+h");
+        let bare: Keymap = "escape".parse().unwrap();
+        assert_eq!(bare.to_string(), "escape");
+    }
+
     #[test]
     fn modal_keymaps_drops_default_mode_name() {
         let nanos = std::time::SystemTime::now()
@@ -1728,7 +3237,7 @@ mod tests {
         .unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
-        let meta_h = "meta+h".parse::<Keymap>().unwrap();
+        let meta_h = "meta+h".parse::<KeySequence>().unwrap();
         assert!(config.keymaps.default.contains_key(&meta_h));
         assert!(!config.keymaps.modes.contains_key("default"));
     }
@@ -1754,7 +3263,7 @@ mod tests {
         .unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
-        let meta_h = "meta+h".parse::<Keymap>().unwrap();
+        let meta_h = "meta+h".parse::<KeySequence>().unwrap();
         assert!(config.keymaps.default.contains_key(&meta_h));
         assert!(!config.keymaps.modes.contains_key(""));
     }
@@ -1829,6 +3338,94 @@ mod tests {
         assert!(toml::from_str::<Config>("strategy = \"floating\"").is_err());
     }
 
+    #[test]
+    fn float_placement_loads_and_recovers() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_float_placement_{nanos}.toml"));
+        let _cleanup = CleanupFile(path.clone());
+        std::fs::write(&path, "float_placement = \"cascade\"\n").unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.float_placement, FloatPlacement::Cascade);
+
+        std::fs::write(&path, "float_placement = \"random\"\nborder_size = 5.0\n").unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.float_placement, FloatPlacement::Keep);
+        assert_eq!(config.border_size.value(), 5);
+    }
+
+    #[test]
+    fn scroll_indicator_loads_and_recovers() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_scroll_indicator_{nanos}.toml"));
+        let _cleanup = CleanupFile(path.clone());
+        std::fs::write(&path, "scroll_indicator = \"minimap\"\n").unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.scroll_indicator, ScrollIndicator::Minimap);
+
+        std::fs::write(&path, "scroll_indicator = \"arrows\"\nborder_size = 5.0\n").unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.scroll_indicator, ScrollIndicator::Off);
+        assert_eq!(config.border_size.value(), 5);
+    }
+
+    #[test]
+    fn scroll_focus_loads_and_recovers() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_scroll_focus_{nanos}.toml"));
+        let _cleanup = CleanupFile(path.clone());
+        std::fs::write(&path, "scroll_focus = \"center\"\n").unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.scroll_focus, ScrollFocus::Center);
+
+        std::fs::write(&path, "scroll_focus = \"middle\"\nborder_size = 5.0\n").unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.scroll_focus, ScrollFocus::Minimal);
+        assert_eq!(config.border_size.value(), 5);
+    }
+
+    #[test]
+    fn animation_loads_and_recovers() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_animation_{nanos}.toml"));
+        let _cleanup = CleanupFile(path.clone());
+        assert_eq!(Config::default().animation.duration_ms, 0, "off by default");
+
+        std::fs::write(
+            &path,
+            "[animation]\nduration_ms = 150\nfocus_flash_ms = 0\neasing = \"linear\"\n",
+        )
+        .unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(
+            config.animation,
+            AnimationConfig {
+                duration_ms: 150,
+                focus_flash_ms: 0,
+                easing: Easing::Linear,
+            }
+        );
+
+        std::fs::write(
+            &path,
+            "[animation]\nduration_ms = 60000\nfocus_flash_ms = 60000\neasing = \"bounce\"\n",
+        )
+        .unwrap();
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.animation, AnimationConfig::default());
+    }
+
     #[test]
     fn layout_rejects_unknown_subfield_master() {
         let nanos = std::time::SystemTime::now()
@@ -1866,7 +3463,7 @@ mod tests {
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
         assert_eq!(config.border_size, default_border_size());
-        assert_eq!(config.theme, Flavor::Latte);
+        assert_eq!(config.theme.flavor, Flavor::Latte);
     }
 
     #[test]
@@ -1928,7 +3525,7 @@ mod tests {
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
         assert_eq!(config.border_size, default_border_size());
-        assert_eq!(config.theme, Flavor::default());
+        assert_eq!(config.theme.flavor, Flavor::default());
     }
 
     #[test]
@@ -2006,7 +3603,7 @@ mod tests {
         .unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
-        let good = "meta+a".parse::<Keymap>().unwrap();
+        let good = "meta+a".parse::<KeySequence>().unwrap();
         assert!(config.keymaps.default.contains_key(&good));
         assert_eq!(config.keymaps.default.len(), 1);
     }
@@ -2029,12 +3626,44 @@ mod tests {
         .unwrap();
         let _cleanup = CleanupFile(path.clone());
         let config = load_or_default(path.to_str().unwrap(), Config::load);
-        let b = "meta+b".parse::<Keymap>().unwrap();
+        let b = "meta+b".parse::<KeySequence>().unwrap();
         assert!(config.keymaps.default.contains_key(&b));
-        let a = "meta+a".parse::<Keymap>().unwrap();
+        let a = "meta+a".parse::<KeySequence>().unwrap();
         assert!(!config.keymaps.default.contains_key(&a));
     }
 
+    #[test]
+    fn load_drops_single_bad_hook() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_config_bad_hook_{nanos}.toml"));
+        std::fs::write(
+            &path,
+            concat!(
+                "[[hook]]\n",
+                "event = \"window_created\"\n",
+                "match = { app = \"Mail\" }\n",
+                "actions = [\"move workspace mail\"]\n",
+                "[[hook]]\n",
+                "event = \"window_exploded\"\n",
+                "actions = [\"exec x\"]\n",
+                "[[hook]]\n",
+                "event = \"mode_entered\"\n",
+                "actions = [\"fly to mars\"]\n",
+            ),
+        )
+        .unwrap();
+        let _cleanup = CleanupFile(path.clone());
+        let config = load_or_default(path.to_str().unwrap(), Config::load);
+        assert_eq!(config.hooks.len(), 1);
+        assert_eq!(
+            config.hooks[0].window.as_ref().unwrap().app.as_deref(),
+            Some("Mail")
+        );
+    }
+
     // The exact count catches a dropped or duplicated [[ignore]] entry in the
     // bundled data file.
     #[test]
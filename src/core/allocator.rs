@@ -46,6 +46,10 @@ impl<T: std::fmt::Debug + Node> Allocator<T> {
             .unwrap_or_else(|| panic!("Node {id:?} not found or was deleted"))
     }
 
+    pub(super) fn contains(&self, id: T::Id) -> bool {
+        self.storage.contains_key(&id)
+    }
+
     pub(super) fn all_active(&self) -> Vec<(T::Id, T)> {
         let mut entries: Vec<_> = self
             .storage
@@ -0,0 +1,248 @@
+//! Animated layout transitions: tiling windows glide from their last shown
+//! rect to the new one instead of jumping, and a newly focused window's border
+//! flashes briefly.
+//!
+//! Core never reads the clock, so shells pass `now` to
+//! `get_animated_placements` and come back at `next_animation_frame` for the
+//! next intermediate frame until it returns `None`. Only tiling windows on a
+//! normal layout animate. A window shown fullscreen, hidden, or reported by
+//! the shell as fighting its placement jumps straight to its target.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crate::config::{AnimationConfig, Easing};
+use crate::core::{
+    Hub, WindowId,
+    hub::{MonitorLayout, TilingWindowPlacement, VisiblePlacements, WindowEmphasis},
+    node::{MonitorId, PixelRect},
+};
+
+/// Time between intermediate frames, about 60 per second.
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+#[derive(Debug, Default)]
+pub(super) struct Animator {
+    /// Last target of every tiling window on screen, with the transition
+    /// toward it when one is in flight.
+    tracks: HashMap<WindowId, Track>,
+    /// Windows that fought a placement. Cleared in `delete_window`.
+    exempt: HashSet<WindowId>,
+    /// Windows shown part way through their transition as of the last frame.
+    in_flight: HashSet<WindowId>,
+    /// Focused window as of the last frame, to notice focus moving.
+    focused: Option<WindowId>,
+    /// End of the focus flash, while one is showing.
+    flash_until: Option<Instant>,
+    next_frame: Option<Instant>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Track {
+    from: PixelRect,
+    to: PixelRect,
+    start: Instant,
+}
+
+impl Track {
+    fn settled(rect: PixelRect, now: Instant) -> Self {
+        Self {
+            from: rect,
+            to: rect,
+            start: now,
+        }
+    }
+
+    /// How far along the transition is, in `[0, 1]`.
+    fn progress(&self, duration: Duration, now: Instant) -> f32 {
+        if self.from == self.to || duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(self.start);
+        (elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.0)
+    }
+
+    fn rect_at(&self, config: AnimationConfig, now: Instant) -> PixelRect {
+        let t = self.progress(duration(config), now);
+        lerp_rect(self.from, self.to, ease(config.easing, t))
+    }
+}
+
+fn duration(config: AnimationConfig) -> Duration {
+    Duration::from_millis(config.duration_ms.into())
+}
+
+/// Maps linear progress to eased progress, both in `[0, 1]`.
+fn ease(easing: Easing, t: f32) -> f32 {
+    match easing {
+        Easing::Linear => t,
+        Easing::EaseIn => t * t * t,
+        Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+        Easing::EaseInOut => {
+            if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+            }
+        }
+    }
+}
+
+fn lerp_rect(from: PixelRect, to: PixelRect, t: f32) -> PixelRect {
+    let lerp = |a: i32, b: i32| a + ((b - a) as f32 * t).round() as i32;
+    let x = lerp(from.x().value(), to.x().value());
+    let y = lerp(from.y().value(), to.y().value());
+    let right = lerp(from.right().value(), to.right().value());
+    let bottom = lerp(from.bottom().value(), to.bottom().value());
+    PixelRect::new(x, y, right - x, bottom - y)
+}
+
+impl Animator {
+    pub(super) fn forget(&mut self, window_id: WindowId) {
+        self.exempt.remove(&window_id);
+        self.tracks.remove(&window_id);
+        self.in_flight.remove(&window_id);
+    }
+
+    fn step(
+        &mut self,
+        placements: &mut VisiblePlacements,
+        config: AnimationConfig,
+        work_area: impl Fn(MonitorId) -> PixelRect,
+        now: Instant,
+    ) {
+        let enabled = config.duration_ms > 0;
+        let mut shown = HashSet::new();
+        self.in_flight.clear();
+        for mp in &mut placements.monitors {
+            let MonitorLayout::Normal { tiling_windows, .. } = &mut mp.layout else {
+                continue;
+            };
+            let work_area = work_area(mp.monitor_id);
+            for wp in tiling_windows {
+                shown.insert(wp.id);
+                let target = wp.border_box;
+                let track = match self.tracks.get(&wp.id) {
+                    Some(track) if enabled && !self.exempt.contains(&wp.id) => {
+                        if track.to == target {
+                            *track
+                        } else {
+                            Track {
+                                from: track.rect_at(config, now),
+                                to: target,
+                                start: now,
+                            }
+                        }
+                    }
+                    _ => Track::settled(target, now),
+                };
+                self.tracks.insert(wp.id, track);
+                if track.progress(duration(config), now) < 1.0 {
+                    self.in_flight.insert(wp.id);
+                    retarget(wp, track.rect_at(config, now), work_area);
+                }
+            }
+        }
+        // A window that left the screen comes back without a transition.
+        self.tracks.retain(|id, _| shown.contains(id));
+        let flash_until = self.flash(placements, config, now);
+        let in_flight = !self.in_flight.is_empty();
+        self.next_frame = match (in_flight.then(|| now + FRAME_INTERVAL), flash_until) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    /// Flash the focused window's border when focus moved since the last
+    /// frame. Returns when the flash ends.
+    fn flash(
+        &mut self,
+        placements: &mut VisiblePlacements,
+        config: AnimationConfig,
+        now: Instant,
+    ) -> Option<Instant> {
+        if placements.focused_window != self.focused {
+            self.focused = placements.focused_window;
+            self.flash_until = (self.focused.is_some() && config.focus_flash_ms > 0)
+                .then(|| now + Duration::from_millis(config.focus_flash_ms.into()));
+        }
+        self.flash_until = self.flash_until.filter(|&until| now < until);
+        let focused = self.focused?;
+        let until = self.flash_until?;
+        for mp in &mut placements.monitors {
+            let MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                ..
+            } = &mut mp.layout
+            else {
+                continue;
+            };
+            let emphasis = tiling_windows
+                .iter_mut()
+                .filter(|wp| wp.id == focused)
+                .map(|wp| &mut wp.emphasis)
+                .chain(
+                    float_windows
+                        .iter_mut()
+                        .filter(|wp| wp.id == focused)
+                        .map(|wp| &mut wp.emphasis),
+                );
+            for e in emphasis {
+                *e = WindowEmphasis::Flash;
+            }
+        }
+        Some(until)
+    }
+}
+
+/// Move `wp` to `border_box`, keeping its border and re-clipping the visible
+/// boxes. A frame that would put the window fully off screen keeps the target.
+fn retarget(wp: &mut TilingWindowPlacement, border_box: PixelRect, work_area: PixelRect) {
+    let Some(visible_border_box) = border_box.clip(work_area) else {
+        return;
+    };
+    let content_box = border_box.inset_by(wp.border);
+    wp.border_box = border_box;
+    wp.visible_border_box = visible_border_box;
+    wp.content_box = content_box;
+    wp.visible_content_box = content_box.clip(work_area).unwrap_or(PixelRect::ZERO);
+}
+
+impl Hub {
+    /// `get_visible_placements` with tiling windows part way through their
+    /// transition to the current layout, as of `now`.
+    pub(crate) fn get_animated_placements(&mut self, now: Instant) -> VisiblePlacements {
+        let mut placements = self.get_visible_placements();
+        let access = &self.access;
+        self.animator.step(
+            &mut placements,
+            access.layout.animation,
+            |monitor_id| access.monitors.get(monitor_id).work_area,
+            now,
+        );
+        placements
+    }
+
+    /// When the shell should call `get_animated_placements` again, or `None`
+    /// when every window has reached its target.
+    pub(crate) fn next_animation_frame(&self) -> Option<Instant> {
+        self.animator.next_frame
+    }
+
+    /// Whether the window was last placed at an intermediate frame. Shells
+    /// ignore drift until it settles, since the next frame moves it anyway.
+    pub(crate) fn is_animating(&self, window_id: WindowId) -> bool {
+        self.animator.in_flight.contains(&window_id)
+    }
+
+    /// Stop animating a window that keeps fighting its settled placement
+    /// after the shell ran out of retries. It jumps to its targets from now
+    /// on, so the shell's corrections aren't mistaken for frames and vice
+    /// versa.
+    pub(crate) fn stop_animating(&mut self, window_id: WindowId) {
+        if self.animator.exempt.insert(window_id) {
+            tracing::debug!(%window_id, "Window fights placement, no longer animated");
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::node::{WindowId, WindowMetadata};
+
 /// Serializable workspace metadata for IPC queries. External tools (status bars,
 /// scripts) consume this as JSON over IPC -- the JSON field names are the
 /// stability contract, not this Rust type.
@@ -9,4 +11,79 @@ pub(crate) struct WorkspaceInfo {
     pub is_focused: bool,
     pub is_visible: bool,
     pub window_count: usize,
+    /// Some window on the workspace asks for attention.
+    pub urgent: bool,
+}
+
+/// Serializable snapshot for `dome query tree`: every monitor with its
+/// workspaces and their windows, plus minimized windows, which belong to no
+/// workspace. Like `WorkspaceInfo`, the JSON shape is the contract.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TreeInfo {
+    pub monitors: Vec<TreeMonitor>,
+    pub minimized: Vec<TreeWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TreeMonitor {
+    pub name: String,
+    pub is_focused: bool,
+    pub workspaces: Vec<TreeWorkspace>,
+}
+
+/// `windows` lists tiling windows first, then floats bottom to top, then
+/// fullscreen windows bottom to top.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TreeWorkspace {
+    pub name: String,
+    pub is_focused: bool,
+    pub is_visible: bool,
+    pub windows: Vec<TreeWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TreeWindow {
+    pub id: WindowId,
+    pub title: String,
+    pub app_name: Option<String>,
+    pub mode: TreeWindowMode,
+    pub is_focused: bool,
+    pub is_sticky: bool,
+    pub marks: Vec<String>,
+}
+
+/// For a minimized window, the mode it returns to when restored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TreeWindowMode {
+    Tiling,
+    Float,
+    Fullscreen,
+}
+
+/// A change `[[hook]]` entries can react to. Recorded by `Hub` as it happens
+/// and drained by the platform after each layout pass, so window events carry
+/// a metadata snapshot taken while the window still existed.
+#[derive(Debug)]
+pub(crate) enum LifecycleEvent {
+    WindowCreated {
+        id: WindowId,
+        workspace: String,
+        metadata: Box<dyn WindowMetadata>,
+    },
+    /// `workspace` is `None` for a window destroyed while minimized.
+    WindowDestroyed {
+        id: WindowId,
+        workspace: Option<String>,
+        metadata: Box<dyn WindowMetadata>,
+    },
+    WorkspaceFocused {
+        name: String,
+    },
+    MonitorConnected {
+        name: String,
+    },
+    MonitorDisconnected {
+        name: String,
+    },
 }
@@ -1,11 +1,126 @@
+use crate::action::{FloatAmount, FloatDirection, FloatResize, SnapPreset};
+use crate::config::FloatPlacement;
 use crate::core::{
     Hub, WindowId,
     hub::RestrictedAction,
     matcher::FloatFullscreenMatcherId,
-    node::{DisplayMode, MonitorId, PixelRect, WorkspaceId},
+    node::{Dimension, DisplayMode, Length, Logical, MonitorId, PixelRect, Pixels, WorkspaceId},
 };
 
+/// How far `FloatPlacement::Cascade` steps each new float from the last.
+const CASCADE_STEP: Pixels<Logical> = Pixels::new(32);
+
 impl Hub {
+    /// Record the pointer position for `FloatPlacement::Cursor`. Core never asks
+    /// the OS, so shells report it right before anything that may create a
+    /// float. `None` when the shell could not read it.
+    pub(crate) fn set_cursor_position(&mut self, position: Option<(Pixels, Pixels)>) {
+        self.cursor = position;
+    }
+
+    /// Where a window that is becoming a float on `ws` goes, per the
+    /// `float_placement` policy. `rect` is where it was, and sets the size.
+    /// Every policy falls back to centering on the work area when it has
+    /// nothing to go on, and the result always lies fully inside it.
+    pub(super) fn place_new_float(
+        &self,
+        ws: WorkspaceId,
+        app_name: Option<&str>,
+        rect: PixelRect,
+    ) -> PixelRect {
+        let monitor = self
+            .access
+            .monitors
+            .get(self.access.workspaces.get(ws).monitor);
+        let area = monitor.work_area;
+        let placed = match self.access.layout.float_placement {
+            FloatPlacement::Keep => rect,
+            FloatPlacement::Center => center_in(rect, area),
+            FloatPlacement::Parent => {
+                center_in(rect, self.float_anchor(ws, app_name).unwrap_or(area))
+            }
+            FloatPlacement::Cascade => {
+                let top = self.access.workspaces.get(ws).float_windows.last();
+                match top.map(|&id| self.access.windows.get(id).mode) {
+                    Some(DisplayMode::Float { border_box, .. }) => {
+                        let step =
+                            Pixels::round(Length::from_pixels(CASCADE_STEP).to_unit(monitor.scale));
+                        let x = border_box.x() + step;
+                        let y = border_box.y() + step;
+                        // Start over from the corner rather than pile up
+                        // against the far edge once the cascade runs out of room.
+                        if x + rect.width() > area.right() || y + rect.height() > area.bottom() {
+                            PixelRect::from_pixels(area.x(), area.y(), rect.width(), rect.height())
+                        } else {
+                            PixelRect::from_pixels(x, y, rect.width(), rect.height())
+                        }
+                    }
+                    _ => center_in(rect, area),
+                }
+            }
+            FloatPlacement::Cursor => match self.cursor {
+                // A pointer on another monitor says nothing about this one.
+                Some((x, y))
+                    if x >= area.x() && x < area.right() && y >= area.y() && y < area.bottom() =>
+                {
+                    PixelRect::from_pixels(
+                        x - rect.width() / 2,
+                        y - rect.height() / 2,
+                        rect.width(),
+                        rect.height(),
+                    )
+                }
+                _ => center_in(rect, area),
+            },
+        };
+        let placed = keep_inside(placed, area);
+        tracing::debug!(?rect, ?placed, "Placed new float");
+        placed
+    }
+
+    /// Border box of the window a new float of `app_name` belongs with for
+    /// `FloatPlacement::Parent`: the focused window on `ws` if it is from the
+    /// same app, else the app's topmost float, else its first tiling window.
+    /// An unknown app can only match the focused window.
+    fn float_anchor(&self, ws: WorkspaceId, app_name: Option<&str>) -> Option<PixelRect> {
+        let same_app = |id: WindowId| {
+            app_name.is_some()
+                && self.access.windows.get(id).metadata.app_name().as_deref() == app_name
+        };
+        let workspace = self.access.workspaces.get(ws);
+        let anchor = self
+            .focused_window(ws)
+            .filter(|&id| app_name.is_none() || same_app(id))
+            .or_else(|| {
+                let mut floats = workspace.float_windows.iter().rev().copied();
+                floats.find(|&id| same_app(id))
+            });
+        match anchor.map(|id| (id, self.access.windows.get(id).mode)) {
+            Some((_, DisplayMode::Float { border_box, .. })) => Some(border_box),
+            // Centering over a fullscreen window is centering on the monitor.
+            Some((_, DisplayMode::Fullscreen { .. })) => None,
+            Some((anchor, DisplayMode::Tiling)) => self.tiling_border_box(ws, |id| id == anchor),
+            None => self.tiling_border_box(ws, same_app),
+        }
+    }
+
+    /// Border box of the first tiling window on `ws` that `pred` accepts. Lays
+    /// out the whole workspace, so only for one-off lookups.
+    fn tiling_border_box(
+        &self,
+        ws: WorkspaceId,
+        pred: impl Fn(WindowId) -> bool,
+    ) -> Option<PixelRect> {
+        let highlighted = ws == self.current_workspace();
+        self.strategies
+            .for_workspace(ws)
+            .collect_tiling_placements(&self.access, ws, highlighted)
+            .windows
+            .into_iter()
+            .find(|p| pred(p.id))
+            .map(|p| p.border_box)
+    }
+
     /// Move the given float to the end of float_windows (making it topmost)
     /// and mark float as focused.
     pub(super) fn focus_float(&mut self, ws: WorkspaceId, window_id: WindowId) {
@@ -77,7 +192,7 @@ impl Hub {
         content_box: PixelRect,
         monitor_id: MonitorId,
     ) {
-        let border = self.access.border(monitor_id);
+        let border = self.access.window_border(window_id, monitor_id);
         let border_box = content_box.outset_by(border);
         let old_ws = {
             let window = self.access.windows.get_mut(window_id);
@@ -110,6 +225,70 @@ impl Hub {
         }
     }
 
+    /// Move the sticky floats on `from` to `to`, which has just replaced it as
+    /// its monitor's active workspace. They keep their rects and their stacking
+    /// order among themselves, and land on top of `to`'s floats unless `to`
+    /// already has a focused float, which they then slide under so arriving
+    /// does not steal focus.
+    pub(super) fn carry_sticky_floats(&mut self, from: WorkspaceId, to: WorkspaceId) {
+        let from_ws = self.access.workspaces.get(from);
+        let sticky: Vec<WindowId> = from_ws
+            .float_windows
+            .iter()
+            .copied()
+            .filter(|&id| self.access.windows.get(id).sticky)
+            .collect();
+        if sticky.is_empty() {
+            return;
+        }
+        let sticky_focused = from_ws.is_float_focused
+            && from_ws
+                .float_windows
+                .last()
+                .is_some_and(|id| sticky.contains(id));
+
+        let from_ws = self.access.workspaces.get_mut(from);
+        from_ws.float_windows.retain(|id| !sticky.contains(id));
+        if from_ws.float_windows.is_empty() {
+            from_ws.is_float_focused = false;
+        }
+        for &id in &sticky {
+            let window = self.access.windows.get_mut(id);
+            window.set_workspace(Some(to));
+            // Cross-workspace hop, so drop occupy like every other one does.
+            if let DisplayMode::Float { occupy, .. } = &mut window.mode {
+                *occupy = None;
+            }
+        }
+
+        let to_ws = self.access.workspaces.get_mut(to);
+        let at = if to_ws.is_float_focused && !sticky_focused {
+            to_ws.float_windows.len().saturating_sub(1)
+        } else {
+            to_ws.float_windows.len()
+        };
+        to_ws.float_windows.splice(at..at, sticky);
+        if sticky_focused && to_ws.fullscreen_windows.is_empty() {
+            to_ws.is_float_focused = true;
+        }
+        tracing::debug!(%from, %to, "Carried sticky floats");
+    }
+
+    /// Toggle whether the focused float follows its monitor across workspaces.
+    /// Does nothing unless a float is focused.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn toggle_sticky(&mut self) {
+        let Some(window_id) = self.focused_window(self.current_workspace()) else {
+            return;
+        };
+        let window = self.access.windows.get_mut(window_id);
+        if !window.is_float() {
+            return;
+        }
+        window.sticky = !window.sticky;
+        tracing::debug!(%window_id, sticky = window.sticky, "Toggled sticky");
+    }
+
     /// Toggle the focused window between tiling and floating mode.
     /// Does nothing if no window is focused or a container is focused.
     #[tracing::instrument(skip(self))]
@@ -126,7 +305,9 @@ impl Hub {
             DisplayMode::Fullscreen { .. } => (),
             DisplayMode::Float { .. } => {
                 self.detach_float_from_workspace(window_id);
-                self.access.windows.get_mut(window_id).mode = DisplayMode::Tiling;
+                let window = self.access.windows.get_mut(window_id);
+                window.mode = DisplayMode::Tiling;
+                window.sticky = false;
                 self.strategies.for_workspace_mut(current_ws).attach_window(
                     &mut self.access,
                     window_id,
@@ -141,9 +322,154 @@ impl Hub {
                     .strategies
                     .for_workspace_mut(current_ws)
                     .detach_window(&mut self.access, window_id);
+                let app_name = self.access.windows.get(window_id).metadata.app_name();
+                let border_box = self.place_new_float(current_ws, app_name.as_deref(), border_box);
                 self.attach_float_to_workspace(current_ws, window_id, border_box, None);
                 tracing::debug!(%window_id, "Window is now floating");
             }
         }
     }
+
+    /// The focused float and its monitor's work area, or `None` when the
+    /// focused window is not a float. Shared entry check for the keyboard
+    /// geometry actions below.
+    fn focused_float_geometry(&self) -> Option<(WindowId, PixelRect, PixelRect, f32)> {
+        // BlockAll is the only restriction a float can carry, and it blocks
+        // every in-place rearrangement.
+        if self.is_restricted(RestrictedAction::TilingNavigation) {
+            return None;
+        }
+        let ws_id = self.current_workspace();
+        let window_id = self.focused_window(ws_id)?;
+        let DisplayMode::Float { border_box, .. } = self.access.windows.get(window_id).mode else {
+            return None;
+        };
+        let monitor = self
+            .access
+            .monitors
+            .get(self.access.workspaces.get(ws_id).monitor);
+        Some((window_id, border_box, monitor.work_area, monitor.scale))
+    }
+
+    /// Replace the float's border box in place. The window stays on its
+    /// workspace, so `occupy` is kept, as for a same-monitor drag settle.
+    fn set_float_border_box(&mut self, window_id: WindowId, border_box: PixelRect) {
+        let window = self.access.windows.get_mut(window_id);
+        if let DisplayMode::Float { occupy, .. } = window.mode {
+            window.mode = DisplayMode::Float { border_box, occupy };
+        }
+        tracing::debug!(%window_id, ?border_box, "Float rect set");
+    }
+
+    /// Move the focused float by `amount`, stopping at the work area's edge.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn move_float(&mut self, direction: FloatDirection, amount: FloatAmount) {
+        let Some((window_id, rect, area, scale)) = self.focused_float_geometry() else {
+            return;
+        };
+        let (dx, dy) = match direction {
+            FloatDirection::Left => (
+                Pixels::ZERO - resolve_amount(amount, area.width(), scale),
+                Pixels::ZERO,
+            ),
+            FloatDirection::Right => (resolve_amount(amount, area.width(), scale), Pixels::ZERO),
+            FloatDirection::Up => (
+                Pixels::ZERO,
+                Pixels::ZERO - resolve_amount(amount, area.height(), scale),
+            ),
+            FloatDirection::Down => (Pixels::ZERO, resolve_amount(amount, area.height(), scale)),
+        };
+        let moved =
+            PixelRect::from_pixels(rect.x() + dx, rect.y() + dy, rect.width(), rect.height());
+        self.set_float_border_box(window_id, keep_inside(moved, area));
+    }
+
+    /// Grow or shrink the focused float on both axes around its center. Growth
+    /// stops at the work area's size, shrinking at one pixel of content.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn resize_float(&mut self, change: FloatResize, amount: FloatAmount) {
+        let Some((window_id, rect, area, scale)) = self.focused_float_geometry() else {
+            return;
+        };
+        let mut dw = resolve_amount(amount, area.width(), scale);
+        let mut dh = resolve_amount(amount, area.height(), scale);
+        if let FloatResize::Shrink = change {
+            dw = Pixels::ZERO - dw;
+            dh = Pixels::ZERO - dh;
+        }
+        let monitor = self.access.workspaces.get(self.current_workspace()).monitor;
+        let min = self.access.window_border(window_id, monitor) * 2 + Pixels::new(1);
+        let width = (rect.width() + dw).max(min).min(area.width());
+        let height = (rect.height() + dh).max(min).min(area.height());
+        let resized = PixelRect::from_pixels(
+            rect.x() - (width - rect.width()) / 2,
+            rect.y() - (height - rect.height()) / 2,
+            width,
+            height,
+        );
+        self.set_float_border_box(window_id, keep_inside(resized, area));
+    }
+
+    /// Center the focused float in the work area without resizing it.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn center_float(&mut self) {
+        let Some((window_id, rect, area, _)) = self.focused_float_geometry() else {
+            return;
+        };
+        self.set_float_border_box(window_id, keep_inside(center_in(rect, area), area));
+    }
+
+    /// Fit the focused float to a fixed share of the work area.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn snap_float(&mut self, preset: SnapPreset) {
+        let Some((window_id, _, area, _)) = self.focused_float_geometry() else {
+            return;
+        };
+        let (fx, fy, fw, fh) = preset.fractions();
+        let area_dim = area.to_dimension();
+        // Through `Dimension` so adjacent presets (left-third, center-third)
+        // share an edge rather than each rounding its own extent.
+        let snapped = PixelRect::from_dimension(Dimension::new(
+            area_dim.x + area_dim.width * fx,
+            area_dim.y + area_dim.height * fy,
+            area_dim.width * fw,
+            area_dim.height * fh,
+        ));
+        self.set_float_border_box(window_id, snapped);
+    }
+}
+
+/// `amount` in the monitor's unit. Pixels are logical like every other config
+/// length; percentages are of `axis`, the work area's extent along the
+/// direction being changed. Never more than `axis`.
+pub(super) fn resolve_amount(amount: FloatAmount, axis: Pixels, scale: f32) -> Pixels {
+    let resolved = match amount {
+        FloatAmount::Pixels(px) => {
+            // Actions from IPC skip `parse_float_amount`'s bound.
+            let px = i32::try_from(px).unwrap_or(i32::MAX);
+            Pixels::round(Length::from_pixels(Pixels::<Logical>::new(px)).to_unit(scale))
+        }
+        FloatAmount::Percent(pct) => Pixels::round(Length::from_pixels(axis) * (pct / 100.0)),
+    };
+    // Capped like 100%, so adding it to a coordinate cannot overflow.
+    resolved.min(axis)
+}
+
+/// `rect`'s size, centered on `anchor`.
+fn center_in(rect: PixelRect, anchor: PixelRect) -> PixelRect {
+    PixelRect::from_pixels(
+        anchor.x() + (anchor.width() - rect.width()) / 2,
+        anchor.y() + (anchor.height() - rect.height()) / 2,
+        rect.width(),
+        rect.height(),
+    )
+}
+
+/// Shift `rect` so it lies inside `area`, shrinking it first if it is larger.
+fn keep_inside(rect: PixelRect, area: PixelRect) -> PixelRect {
+    let width = rect.width().min(area.width());
+    let height = rect.height().min(area.height());
+    let x = rect.x().min(area.right() - width).max(area.x());
+    let y = rect.y().min(area.bottom() - height).max(area.y());
+    PixelRect::from_pixels(x, y, width, height)
 }
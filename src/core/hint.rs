@@ -0,0 +1,156 @@
+//! Window hints: short labels painted over every visible window so one can be
+//! focused by typing its label, easymotion-style.
+//!
+//! The hub only assigns labels and reports them through `MonitorPlacements`.
+//! Matching typed keys happens in the keymap via `HintSession`, which lives for
+//! the temporary `hint` mode and outlives nothing else.
+
+use crate::core::{
+    Hub, PixelRect, WindowId,
+    hub::{MonitorLayout, RestrictedAction},
+};
+
+/// Key mode active while hint labels are shown.
+pub(crate) const HINT_MODE: &str = "hint";
+
+/// Home-row keys, most reachable first.
+pub(super) const HINT_ALPHABET: &[char] = &['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l'];
+
+/// A label as placed on screen, for the overlays.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct HintPlacement {
+    pub(crate) label: String,
+    pub(crate) visible_border_box: PixelRect,
+}
+
+/// Outcome of one key typed during a hint session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum HintPress<T = WindowId> {
+    /// The typed keys prefix more than one label.
+    Pending,
+    Chosen(T),
+    /// No label starts with the typed keys.
+    Miss,
+}
+
+impl<T> HintPress<T> {
+    pub(crate) fn map<U>(self, f: impl FnOnce(T) -> U) -> HintPress<U> {
+        match self {
+            HintPress::Pending => HintPress::Pending,
+            HintPress::Chosen(target) => HintPress::Chosen(f(target)),
+            HintPress::Miss => HintPress::Miss,
+        }
+    }
+}
+
+/// Labels to targets, windows for `focus hint` and workspace names for
+/// `overview`.
+#[derive(Clone, Debug)]
+pub(crate) struct HintSession<T = WindowId> {
+    targets: Vec<(String, T)>,
+    typed: String,
+}
+
+impl<T: Clone> HintSession<T> {
+    pub(crate) fn new(targets: Vec<(String, T)>) -> Self {
+        Self {
+            targets,
+            typed: String::new(),
+        }
+    }
+
+    pub(crate) fn press(&mut self, key: char) -> HintPress<T> {
+        self.typed.push(key);
+        let mut matching = self
+            .targets
+            .iter()
+            .filter(|(label, _)| label.starts_with(&self.typed));
+        match (matching.next(), matching.next()) {
+            (Some((label, target)), None) if *label == self.typed => {
+                HintPress::Chosen(target.clone())
+            }
+            (None, _) => HintPress::Miss,
+            _ => HintPress::Pending,
+        }
+    }
+}
+
+/// `count` labels over `alphabet`, none a prefix of another, as short as
+/// possible for the earliest targets. While there are too few, the earliest
+/// unexpanded label is traded for its one-key extensions.
+pub(crate) fn hint_labels(count: usize, alphabet: &[char]) -> Vec<String> {
+    if count == 0 || alphabet.is_empty() {
+        return Vec::new();
+    }
+    let mut labels: Vec<String> = alphabet.iter().map(char::to_string).collect();
+    let mut offset = 0;
+    while labels.len() - offset < count {
+        let prefix = labels[offset].clone();
+        offset += 1;
+        labels.extend(alphabet.iter().map(|c| format!("{prefix}{c}")));
+    }
+    labels.drain(..offset);
+    labels.truncate(count);
+    labels
+}
+
+impl Hub {
+    /// Label every tiling and float window on visible workspaces, monitor by
+    /// monitor and top-left first. Returns `None` when there is nothing to
+    /// label or focus is locked, in which case no hints are shown.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn show_hints(&mut self) -> Option<HintSession> {
+        if self.is_restricted(RestrictedAction::TilingNavigation) {
+            return None;
+        }
+        self.hints.clear();
+        let mut windows = Vec::new();
+        for monitor in self.get_visible_placements().monitors {
+            let MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                ..
+            } = monitor.layout
+            else {
+                continue;
+            };
+            let mut boxes: Vec<_> = tiling_windows
+                .iter()
+                .map(|p| (p.id, p.visible_border_box))
+                .chain(float_windows.iter().map(|p| (p.id, p.visible_border_box)))
+                .filter(|(_, b)| !b.is_empty())
+                .collect();
+            boxes.sort_by_key(|(_, b)| (b.y(), b.x()));
+            windows.extend(boxes.into_iter().map(|(id, _)| id));
+        }
+        if windows.is_empty() {
+            return None;
+        }
+        let labels = hint_labels(windows.len(), HINT_ALPHABET);
+        self.hints = labels.into_iter().zip(windows).collect();
+        Some(HintSession::new(self.hints.clone()))
+    }
+
+    /// Returns whether any hints were showing.
+    pub(crate) fn hide_hints(&mut self) -> bool {
+        let shown = !self.hints.is_empty();
+        self.hints.clear();
+        shown
+    }
+
+    /// Focus a window by id, switching workspace and monitor as needed. Ids
+    /// held outside the hub may be stale, so a closed window is ignored, as is
+    /// a minimized one.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn focus_window(&mut self, window_id: WindowId) {
+        if self.is_restricted(RestrictedAction::TilingNavigation) {
+            return;
+        }
+        if !self.access.windows.contains(window_id)
+            || self.access.windows.get(window_id).is_minimized()
+        {
+            return;
+        }
+        self.set_focus(window_id);
+    }
+}
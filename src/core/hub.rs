@@ -1,24 +1,47 @@
-use crate::action::MonitorTarget;
+use std::collections::BTreeMap;
+
+use crate::action::{FloatAmount, MonitorTarget, ScrollDirection};
 use crate::config::{
-    Config, LayoutWorkspaceConfig, MasterConfig, PartitionTreeConfig, SizeConstraints, Strategy,
+    AnimationConfig, BorderRule, Config, FloatPlacement, LayoutWorkspaceConfig, MasterConfig,
+    PartitionTreeConfig, ScrollFocus, SizeConstraints, Strategy, TabBarPosition, TitleRewrite,
     WindowMatcher, WindowMode, default_border_size, default_master_config,
     default_partition_tree_config, default_strategy,
 };
 
 use super::allocator::{Allocator, NodeId};
+use super::animation::Animator;
+use super::dto::LifecycleEvent;
+use super::float::resolve_amount;
+use super::hint::HintPlacement;
 use super::matcher::{FloatFullscreenMatcherId, MatcherHit};
 use super::node::{
-    Container, ContainerId, DisplayMode, Length, LimitObservation, LimitUpdate, Logical, Monitor,
-    MonitorId, PixelRect, Pixels, Unit, Window, WindowId, WindowMetadata, WindowRestrictions,
-    Workspace, WorkspaceId,
+    Container, ContainerId, Direction, DisplayMode, Length, LimitObservation, LimitUpdate, Logical,
+    Monitor, MonitorId, PixelRect, Pixels, Unit, Window, WindowId, WindowMetadata,
+    WindowRestrictions, Workspace, WorkspaceId,
 };
+use super::overview::OverviewWorkspace;
 use super::partition_tree::Child;
 use super::strategy::{StrategySet, TilingAction, WorkspaceExport};
 
+/// How far `scroll <direction>` pans when no amount is given.
+const SCROLL_STEP: FloatAmount = FloatAmount::Percent(10.0);
+
+/// Focus as it was before `Hub::focus_for_hook`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FocusRestore {
+    workspace: WorkspaceId,
+    window: Option<WindowId>,
+    /// Current workspace once the hook's window took focus.
+    hook_workspace: WorkspaceId,
+}
+
 pub(crate) struct VisiblePlacements {
     pub(crate) focused_window: Option<WindowId>,
     pub(crate) focused_monitor: MonitorId,
     pub(crate) monitors: Vec<MonitorPlacements>,
+    /// Diagram of every workspace, empty unless the overview is showing. The
+    /// shell draws it on `focused_monitor`.
+    pub(crate) overview: Vec<OverviewWorkspace>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -28,17 +51,15 @@ pub(crate) struct TilingWindowPlacement {
     pub(crate) visible_border_box: PixelRect,
     pub(crate) content_box: PixelRect,
     /// `content_box` trimmed to the monitor. Zero-area when nothing remains.
-    #[cfg_attr(
-        target_os = "windows",
-        expect(
-            dead_code,
-            reason = "macOS trims tiling placements to the work area, Windows places them unclipped"
-        )
-    )]
+    /// Only macOS places it, Windows places tiling windows unclipped.
     pub(crate) visible_content_box: PixelRect,
     /// Highlighting does not require keyboard focus.
     pub(crate) is_highlighted: bool,
+    pub(crate) emphasis: WindowEmphasis,
     pub(crate) spawn_indicator: Option<SpawnIndicator>,
+    /// Space between `border_box` and `content_box`. Differs from the monitor's
+    /// `border_thickness` when a `[[border]]` rule matched the window.
+    pub(crate) border: Pixels<Unit>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -48,6 +69,24 @@ pub(crate) struct FloatWindowPlacement {
     pub(crate) visible_border_box: PixelRect,
     pub(crate) content_box: PixelRect,
     pub(crate) is_highlighted: bool,
+    pub(crate) emphasis: WindowEmphasis,
+    /// See `TilingWindowPlacement::border`.
+    pub(crate) border: Pixels<Unit>,
+}
+
+/// Border state of a window that is not highlighted. When several apply, the
+/// earlier variant wins.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum WindowEmphasis {
+    /// Focused window of a visible workspace on a monitor without focus.
+    InactiveFocus,
+    Urgent,
+    Marked,
+    /// Newly focused window, for `animation.focus_flash_ms`. Set by the
+    /// animator, never by `get_visible_placements`.
+    Flash,
+    #[default]
+    None,
 }
 
 #[derive(Clone, Debug)]
@@ -55,20 +94,40 @@ pub(crate) struct ContainerPlacement {
     pub(crate) id: ContainerId,
     pub(crate) border_box: PixelRect,
     pub(crate) visible_border_box: PixelRect,
-    /// Top band of `border_box` reserved for the tab strip, zero-height when the container
-    /// is not tabbed.
+    /// Band of `border_box` reserved for the tabs on the `tab_bar_position` side,
+    /// zero-height when the container is not tabbed.
     pub(crate) tab_bar_band: PixelRect,
+    pub(crate) tab_bar_position: TabBarPosition,
     pub(crate) is_highlighted: bool,
     pub(crate) spawn_indicator: Option<SpawnIndicator>,
     pub(crate) is_tabbed: bool,
     pub(crate) active_tab_index: usize,
     pub(crate) titles: Vec<String>,
+    /// Window behind each tab, `None` for a nested container. Lets the shell
+    /// look up an icon per tab.
+    pub(crate) tab_windows: Vec<Option<WindowId>>,
+}
+
+impl ContainerPlacement {
+    /// Size of `tab_bar_band` across its side.
+    pub(crate) fn tab_bar_thickness(&self) -> Pixels<Unit> {
+        if self.tab_bar_position.is_vertical() {
+            self.tab_bar_band.width()
+        } else {
+            self.tab_bar_band.height()
+        }
+    }
 }
 
 pub(crate) struct MonitorPlacements {
     pub(crate) monitor_id: MonitorId,
     pub(crate) border_thickness: Pixels<Unit>,
     pub(crate) layout: MonitorLayout,
+    /// Labels of `focus hint`, empty unless hints are showing.
+    pub(crate) hints: Vec<HintPlacement>,
+    /// Regions whose tiling content overflows the work area, empty when
+    /// everything fits or the workspace is fullscreen.
+    pub(crate) scroll_extents: Vec<ScrollExtent>,
 }
 
 pub(crate) enum MonitorLayout {
@@ -80,6 +139,38 @@ pub(crate) enum MonitorLayout {
     Fullscreen(WindowId),
 }
 
+/// A region of a workspace that scrolls because its windows' minimum sizes
+/// do not fit: the whole tree in a partition tree, one pane in master.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ScrollExtent {
+    /// Where the region sits on screen.
+    pub(crate) viewport: PixelRect,
+    /// Full extent of the region's content at the current scroll position, so
+    /// it reaches past `viewport` on each side with something offscreen.
+    pub(crate) content: PixelRect,
+    /// Border boxes of every window in the region, offscreen ones included.
+    pub(crate) windows: Vec<PixelRect>,
+}
+
+impl ScrollExtent {
+    /// `None` when `content` fits inside `viewport`, as there is nothing to
+    /// scroll to.
+    pub(crate) fn overflowing(
+        viewport: PixelRect,
+        content: PixelRect,
+        windows: Vec<PixelRect>,
+    ) -> Option<Self> {
+        if content.clip(viewport) == Some(content) {
+            return None;
+        }
+        Some(Self {
+            viewport,
+            content,
+            windows,
+        })
+    }
+}
+
 /// Which border edges to highlight with the spawn indicator color.
 /// `left` is always false today but included so we don't need a struct change
 /// if a future spawn mode uses it.
@@ -114,26 +205,38 @@ pub(super) enum RestrictedAction {
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct GlobalLayoutConfig {
     pub(crate) strategy: Strategy,
+    pub(crate) float_placement: FloatPlacement,
+    pub(crate) scroll_focus: ScrollFocus,
+    pub(crate) animation: AnimationConfig,
     pub(crate) border_size: Pixels<Logical>,
     pub(crate) partition_tree: PartitionTreeConfig,
     pub(crate) master: MasterConfig,
     pub(crate) size_constraints: SizeConstraints,
     pub(crate) float: Vec<WindowMatcher>,
     pub(crate) fullscreen: Vec<WindowMatcher>,
+    pub(crate) sticky: Vec<WindowMatcher>,
     pub(crate) ignore: Vec<WindowMatcher>,
+    pub(crate) border: Vec<BorderRule>,
+    pub(crate) tab_title: Vec<TitleRewrite>,
 }
 
 impl From<&Config> for GlobalLayoutConfig {
     fn from(c: &Config) -> Self {
         Self {
             strategy: c.strategy,
-            border_size: c.border_size,
+            float_placement: c.float_placement,
+            scroll_focus: c.scroll_focus,
+            animation: c.animation,
+            border_size: c.reserved_border_size(),
             partition_tree: c.partition_tree.clone(),
             master: c.master.clone(),
             size_constraints: c.size_constraints,
             float: c.float.clone(),
             fullscreen: c.fullscreen.clone(),
+            sticky: c.sticky.clone(),
             ignore: c.ignore.clone(),
+            border: c.border.clone(),
+            tab_title: c.tab_title.clone(),
         }
     }
 }
@@ -142,6 +245,9 @@ impl Default for GlobalLayoutConfig {
     fn default() -> Self {
         Self {
             strategy: default_strategy(),
+            float_placement: FloatPlacement::default(),
+            scroll_focus: ScrollFocus::default(),
+            animation: AnimationConfig::default(),
             border_size: default_border_size(),
             partition_tree: default_partition_tree_config(),
             master: default_master_config(),
@@ -150,7 +256,10 @@ impl Default for GlobalLayoutConfig {
             // manages every window it inserts.
             float: Vec::new(),
             fullscreen: Vec::new(),
+            sticky: Vec::new(),
             ignore: Vec::new(),
+            border: Vec::new(),
+            tab_title: Vec::new(),
         }
     }
 }
@@ -226,9 +335,21 @@ impl HubAccess {
     /// thickness ending in `.5` would otherwise round the two opposite edges
     /// apart by a pixel.
     pub(super) fn border(&self, monitor: MonitorId) -> Pixels<Unit> {
-        Pixels::round(
-            Length::from_pixels(self.layout.border_size).to_unit(self.monitors.get(monitor).scale),
-        )
+        self.border_of_size(self.layout.border_size, monitor)
+    }
+
+    /// `border` for one window, honoring a `[[border]]` rule that matched it.
+    pub(super) fn window_border(&self, window: WindowId, monitor: MonitorId) -> Pixels<Unit> {
+        let size = self
+            .windows
+            .get(window)
+            .border
+            .unwrap_or(self.layout.border_size);
+        self.border_of_size(size, monitor)
+    }
+
+    fn border_of_size(&self, size: Pixels<Logical>, monitor: MonitorId) -> Pixels<Unit> {
+        Pixels::round(Length::from_pixels(size).to_unit(self.monitors.get(monitor).scale))
     }
 }
 
@@ -240,6 +361,23 @@ pub(crate) struct Hub {
     pub(super) float_fullscreen_matchers: Allocator<WindowMatcher>,
     pub(super) global_float_matchers: Vec<FloatFullscreenMatcherId>,
     pub(super) global_fullscreen_matchers: Vec<FloatFullscreenMatcherId>,
+    pub(super) lifecycle_events: Vec<LifecycleEvent>,
+    /// Workspace last reported as focused by `take_lifecycle_events`. Focus
+    /// changes through too many paths to record at each one, so the drain
+    /// diffs against this instead.
+    pub(super) reported_workspace: Option<WorkspaceId>,
+    /// Mark name to window. See `mark.rs`.
+    pub(super) marks: BTreeMap<String, WindowId>,
+    /// Windows asking for attention, oldest first. See `urgent.rs`.
+    pub(super) urgent: Vec<WindowId>,
+    /// Label to window while `focus hint` is showing. See `hint.rs`.
+    pub(super) hints: Vec<(String, WindowId)>,
+    /// Label to workspace name while the overview is showing. See `overview.rs`.
+    pub(super) overview: Vec<(String, String)>,
+    /// Pointer position last reported by the shell, for `FloatPlacement::Cursor`.
+    pub(super) cursor: Option<(Pixels, Pixels)>,
+    /// Transitions in flight. See `animation.rs`.
+    pub(super) animator: Animator,
 }
 
 impl Hub {
@@ -267,6 +405,14 @@ impl Hub {
             float_fullscreen_matchers: Allocator::new(),
             global_float_matchers: Vec::new(),
             global_fullscreen_matchers: Vec::new(),
+            lifecycle_events: Vec::new(),
+            reported_workspace: None,
+            marks: BTreeMap::new(),
+            urgent: Vec::new(),
+            hints: Vec::new(),
+            overview: Vec::new(),
+            cursor: None,
+            animator: Animator::default(),
         };
 
         let primary_id = hub.add_monitor("primary".to_string(), primary_screen, primary_scale);
@@ -276,6 +422,20 @@ impl Hub {
         hub
     }
 
+    /// Drains the lifecycle changes recorded since the previous call, with a
+    /// trailing `WorkspaceFocused` when the focused workspace moved in between.
+    pub(crate) fn take_lifecycle_events(&mut self) -> Vec<LifecycleEvent> {
+        let mut events = std::mem::take(&mut self.lifecycle_events);
+        let current = self.current_workspace();
+        if self.reported_workspace != Some(current) {
+            self.reported_workspace = Some(current);
+            events.push(LifecycleEvent::WorkspaceFocused {
+                name: self.access.workspaces.get(current).name.clone(),
+            });
+        }
+        events
+    }
+
     pub(crate) fn current_workspace(&self) -> WorkspaceId {
         self.access
             .monitors
@@ -283,6 +443,13 @@ impl Hub {
             .active_workspace
     }
 
+    /// The focused window on the focused workspace with its metadata, for
+    /// keymap overrides that depend on the app in front.
+    pub(crate) fn focused_window_metadata(&self) -> Option<(WindowId, &dyn WindowMetadata)> {
+        let id = self.focused_window(self.current_workspace())?;
+        Some((id, &*self.access.windows.get(id).metadata))
+    }
+
     /// The top most fullscreen window will get the focus, if any, as fullscreen windows take over
     /// the whole workspaces they are in.
     /// If none is present, focus between float and tiling windows will be decided by is_float_focused
@@ -328,6 +495,37 @@ impl Hub {
         self.strategies
             .for_workspace_mut(ws_id)
             .handle_action(&mut self.access, action);
+        self.clear_focused_urgent();
+    }
+
+    /// Pan the current workspace's viewport without moving focus. `amount`
+    /// defaults to `SCROLL_STEP` of the work area along the scrolled axis.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn scroll_viewport(
+        &mut self,
+        direction: ScrollDirection,
+        amount: Option<FloatAmount>,
+    ) {
+        let monitor = self
+            .access
+            .monitors
+            .get(self.access.workspaces.get(self.current_workspace()).monitor);
+        let (axis, extent, forward) = match direction {
+            ScrollDirection::Left => (Direction::Horizontal, monitor.work_area.width(), false),
+            ScrollDirection::Right => (Direction::Horizontal, monitor.work_area.width(), true),
+            ScrollDirection::Up => (Direction::Vertical, monitor.work_area.height(), false),
+            ScrollDirection::Down => (Direction::Vertical, monitor.work_area.height(), true),
+        };
+        let amount = resolve_amount(amount.unwrap_or(SCROLL_STEP), extent, monitor.scale);
+        self.handle_tiling_action(TilingAction::Scroll {
+            direction: axis,
+            forward,
+            amount: Length::from_pixels(amount),
+        });
+    }
+
+    pub(crate) fn center_viewport(&mut self) {
+        self.handle_tiling_action(TilingAction::CenterViewport);
     }
 
     pub(crate) fn focus_tab_index(&mut self, container_id: ContainerId, index: usize) {
@@ -337,6 +535,39 @@ impl Hub {
         });
     }
 
+    pub(crate) fn move_tab(&mut self, container_id: ContainerId, from: usize, to: usize) {
+        self.handle_tiling_action(TilingAction::MoveTab {
+            container_id,
+            from,
+            to,
+        });
+    }
+
+    pub(crate) fn split_out_tab(&mut self, container_id: ContainerId, index: usize) {
+        self.handle_tiling_action(TilingAction::SplitOutTab {
+            container_id,
+            index,
+        });
+    }
+
+    /// The window a tab bar shows at `index`, for closing a tab from its bar.
+    /// `None` when the container is gone or the tab holds a nested container.
+    pub(crate) fn tab_window(&self, container_id: ContainerId, index: usize) -> Option<WindowId> {
+        if !self.access.containers.contains(container_id) {
+            return None;
+        }
+        match self
+            .access
+            .containers
+            .get(container_id)
+            .children()
+            .get(index)
+        {
+            Some(Child::Window(wid)) => Some(*wid),
+            _ => None,
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     pub(crate) fn focus_monitor(&mut self, target: &MonitorTarget) {
         if self.is_restricted(RestrictedAction::TilingNavigation) {
@@ -350,6 +581,7 @@ impl Hub {
         }
         tracing::debug!("Focusing monitor");
         self.access.focused_monitor = target_id;
+        self.clear_focused_urgent();
     }
 
     #[tracing::instrument(skip(self))]
@@ -370,7 +602,7 @@ impl Hub {
         if let Some(window_id) = self.focused_window(current_ws) {
             self.move_child_to_workspace_with_id(window_id, target_ws);
         } else {
-            self.move_focused_across_workspaces(current_ws, target_ws);
+            self.move_focused_across_workspaces(current_ws, target_ws, None);
         }
     }
 
@@ -385,6 +617,45 @@ impl Hub {
             .expect("non-minimized window has a workspace");
         self.set_workspace_focus(window_id);
         self.focus_workspace_with_id(ws);
+        self.clear_focused_urgent();
+    }
+
+    /// Focus `window_id` so hook actions, which act on the focused window, act
+    /// on it instead. Returns where focus was, for `restore_focus`, or `None`
+    /// when the window already has focus or can no longer take it.
+    pub(crate) fn focus_for_hook(&mut self, window_id: WindowId) -> Option<FocusRestore> {
+        if !self.access.windows.contains(window_id)
+            || self.access.windows.get(window_id).is_minimized()
+        {
+            return None;
+        }
+        let workspace = self.current_workspace();
+        let window = self.focused_window(workspace);
+        if window == Some(window_id) {
+            return None;
+        }
+        self.set_focus(window_id);
+        Some(FocusRestore {
+            workspace,
+            window,
+            hook_workspace: self.current_workspace(),
+        })
+    }
+
+    /// Undo `focus_for_hook`, unless the hook switched workspace itself.
+    pub(crate) fn restore_focus(&mut self, restore: FocusRestore) {
+        if self.current_workspace() != restore.hook_workspace {
+            return;
+        }
+        match restore.window {
+            Some(id)
+                if self.access.windows.contains(id)
+                    && !self.access.windows.get(id).is_minimized() =>
+            {
+                self.set_focus(id);
+            }
+            _ => self.focus_workspace_with_id(restore.workspace),
+        }
     }
 
     /// Focus `window_id` within its own workspace, without switching workspace.
@@ -442,10 +713,82 @@ impl Hub {
                 is_focused: ws_id == focused_ws,
                 is_visible: visible.contains(&ws_id),
                 window_count: self.count_workspace_windows(ws_id, &ws),
+                urgent: self
+                    .urgent
+                    .iter()
+                    .any(|&w| self.access.windows.get(w).workspace() == Some(ws_id)),
             })
             .collect()
     }
 
+    /// Snapshot of the whole hierarchy for `dome query tree`. Monitors and
+    /// workspaces are ordered by id, like `query_workspaces`.
+    pub(crate) fn query_tree(&self) -> super::TreeInfo {
+        let focused_ws = self.current_workspace();
+        let focused_window = self.focused_window(focused_ws);
+        let visible = self.visible_workspaces();
+        let windows = self.access.windows.all_active();
+        let workspaces = self.access.workspaces.all_active();
+        let describe = |id: WindowId, window: &Window| super::dto::TreeWindow {
+            id,
+            title: window
+                .metadata
+                .title()
+                .map(str::to_owned)
+                .unwrap_or_default(),
+            app_name: window.metadata.app_name(),
+            mode: match window.mode {
+                DisplayMode::Tiling => super::dto::TreeWindowMode::Tiling,
+                DisplayMode::Float { .. } => super::dto::TreeWindowMode::Float,
+                DisplayMode::Fullscreen { .. } => super::dto::TreeWindowMode::Fullscreen,
+            },
+            is_focused: focused_window == Some(id),
+            is_sticky: window.sticky,
+            marks: self.window_marks(id),
+        };
+        let monitors = self
+            .access
+            .monitors
+            .all_active()
+            .into_iter()
+            .map(|(monitor_id, monitor)| super::dto::TreeMonitor {
+                name: monitor.name,
+                is_focused: monitor_id == self.access.focused_monitor,
+                workspaces: workspaces
+                    .iter()
+                    .filter(|(_, ws)| ws.monitor == monitor_id)
+                    .map(|(ws_id, ws)| {
+                        let tiling = windows.iter().filter(|(_, w)| {
+                            w.workspace() == Some(*ws_id) && matches!(w.mode, DisplayMode::Tiling)
+                        });
+                        let stacked = ws
+                            .float_windows
+                            .iter()
+                            .chain(&ws.fullscreen_windows)
+                            .map(|&id| (id, self.access.windows.get(id)));
+                        super::dto::TreeWorkspace {
+                            name: ws.name.clone(),
+                            is_focused: *ws_id == focused_ws,
+                            is_visible: visible.contains(ws_id),
+                            windows: tiling
+                                .map(|(id, w)| describe(*id, w))
+                                .chain(stacked.map(|(id, w)| describe(id, w)))
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        super::TreeInfo {
+            monitors,
+            minimized: self
+                .minimized_windows
+                .iter()
+                .map(|&id| describe(id, self.access.windows.get(id)))
+                .collect(),
+        }
+    }
+
     fn count_workspace_windows(&self, ws_id: WorkspaceId, ws: &Workspace) -> usize {
         let tiling_count = self
             .strategies
@@ -462,7 +805,14 @@ impl Hub {
             .export_workspace(&self.access, ws_id);
 
         let ws = self.access.workspaces.get(ws_id);
-        let float_windows: Vec<WindowId> = ws.float_windows.clone();
+        // Sticky floats belong to no workspace. Exporting one under whichever
+        // workspace it sat on would pin it there on the next launch.
+        let float_windows: Vec<WindowId> = ws
+            .float_windows
+            .iter()
+            .copied()
+            .filter(|&id| !self.access.windows.get(id).sticky)
+            .collect();
         let fullscreen_windows: Vec<WindowId> = ws.fullscreen_windows.clone();
 
         let float = self.collect_display_matchers(&float_windows, |mode| match mode {
@@ -516,6 +866,8 @@ impl Hub {
             .find(|w| w.name() == workspace_name);
         self.strategies
             .register(ws_id, &self.access.layout, preferred_layout);
+        self.lifecycle_events
+            .push(LifecycleEvent::MonitorConnected { name });
         monitor_id
     }
 
@@ -544,6 +896,14 @@ impl Hub {
         if self.access.focused_monitor == monitor_id {
             self.access.focused_monitor = fallback_id;
         }
+        // The removed monitor's workspace is hidden on the fallback, so its
+        // sticky floats move to what the fallback shows.
+        let removed_active = self.access.monitors.get(monitor_id).active_workspace;
+        let fallback_active = self.access.monitors.get(fallback_id).active_workspace;
+        self.carry_sticky_floats(removed_active, fallback_active);
+        let name = self.access.monitors.get(monitor_id).name.clone();
+        self.lifecycle_events
+            .push(LifecycleEvent::MonitorDisconnected { name });
         self.access.monitors.delete(monitor_id);
     }
 
@@ -575,6 +935,10 @@ impl Hub {
 
     pub(crate) fn sync_configuration(&mut self, layout: GlobalLayoutConfig) {
         self.access.layout = layout.clone();
+        // The strategies re-lay out every workspace below, picking these up.
+        for (window_id, _) in self.access.windows.all_active() {
+            self.refresh_border(window_id);
+        }
         for (ws_id, _) in self.access.workspaces.all_active() {
             self.strategies
                 .for_workspace_mut(ws_id)
@@ -619,6 +983,13 @@ impl Hub {
             return None;
         }
         let matcher = self.resolve_matcher(&*metadata);
+        let sticky = self
+            .access
+            .layout
+            .sticky
+            .iter()
+            .any(|r| metadata.matches_window_matcher(r));
+        let border = self.border_rule(&*metadata);
         let target_ws = matcher
             .as_ref()
             .and_then(|hit| hit.ws_id)
@@ -629,6 +1000,9 @@ impl Hub {
                 Some(MatcherHit {
                     mode, matcher_id, ..
                 }) => (mode, restrictions, matcher_id),
+                // Only a float can be sticky, so a sticky rule floats the
+                // window unless another rule decided its mode.
+                None if sticky => (WindowMode::Float, restrictions, None),
                 None => (WindowMode::Tiling, restrictions, None),
             }
         } else {
@@ -639,10 +1013,9 @@ impl Hub {
 
         let window_id = match mode {
             WindowMode::Tiling => {
-                let window_id = self
-                    .access
-                    .windows
-                    .allocate(Window::tiling(target_ws, metadata));
+                let mut window = Window::tiling(target_ws, metadata);
+                window.border = border;
+                let window_id = self.access.windows.allocate(window);
                 self.strategies.for_workspace_mut(target_ws).attach_window(
                     &mut self.access,
                     window_id,
@@ -652,10 +1025,11 @@ impl Hub {
                 window_id
             }
             WindowMode::Float => {
-                let window_id = self
-                    .access
-                    .windows
-                    .allocate(Window::float(target_ws, rect, metadata));
+                let rect = self.place_new_float(target_ws, metadata.app_name().as_deref(), rect);
+                let mut window = Window::float(target_ws, rect, metadata);
+                window.sticky = sticky;
+                window.border = border;
+                let window_id = self.access.windows.allocate(window);
                 tracing::debug!(%window_id, ?rect, "Inserting float window");
                 self.attach_float_to_workspace(target_ws, window_id, rect, occupy_id);
                 self.set_focus(window_id);
@@ -673,6 +1047,12 @@ impl Hub {
             }
         };
 
+        let window = self.access.windows.get(window_id);
+        self.lifecycle_events.push(LifecycleEvent::WindowCreated {
+            id: window_id,
+            workspace: self.access.workspaces.get(target_ws).name.clone(),
+            metadata: window.metadata.clone_box(),
+        });
         Some(window_id)
     }
 
@@ -682,69 +1062,41 @@ impl Hub {
             return false;
         }
         window.metadata.set_title(title);
+        if self.refresh_border(window_id)
+            && let Some(ws_id) = self.access.windows.get(window_id).workspace()
+        {
+            self.strategies
+                .for_workspace_mut(ws_id)
+                .compute_placement(&self.access, ws_id);
+        }
         true
     }
 
+    /// Size from the first `[[border]]` rule matching `metadata`.
+    fn border_rule(&self, metadata: &dyn WindowMetadata) -> Option<Pixels<Logical>> {
+        self.access
+            .layout
+            .border
+            .iter()
+            .find(|r| metadata.matches_window_matcher(&r.matcher))
+            .map(|r| r.size)
+    }
+
+    /// Re-evaluates `[[border]]` rules for a window whose title or the rules
+    /// themselves changed. Returns whether its border did.
+    fn refresh_border(&mut self, window_id: WindowId) -> bool {
+        let border = self.border_rule(&*self.access.windows.get(window_id).metadata);
+        let window = self.access.windows.get_mut(window_id);
+        std::mem::replace(&mut window.border, border) != border
+    }
+
     pub(crate) fn get_visible_placements(&self) -> VisiblePlacements {
         let current_ws = self.current_workspace();
 
         let monitors = self
             .visible_workspaces()
             .into_iter()
-            .map(|ws_id| {
-                let ws = self.access.workspaces.get(ws_id);
-                let screen = self.access.monitors.get(ws.monitor).work_area;
-
-                if let Some(&fs_id) = ws.fullscreen_windows.last() {
-                    return MonitorPlacements {
-                        monitor_id: ws.monitor,
-                        border_thickness: self.access.border(ws.monitor),
-                        layout: MonitorLayout::Fullscreen(fs_id),
-                    };
-                }
-
-                let tiling = self
-                    .strategies
-                    .for_workspace(ws_id)
-                    .collect_tiling_placements(&self.access, ws_id, ws_id == current_ws);
-                let tiling_windows = tiling.windows;
-                let containers = tiling.containers;
-
-                let focused = if ws_id == current_ws {
-                    self.focused_window(ws_id)
-                } else {
-                    None
-                };
-
-                let border = self.access.border(ws.monitor);
-                let mut float_windows = Vec::new();
-                for &id in &ws.float_windows {
-                    let window = self.access.windows.get(id);
-                    let DisplayMode::Float { border_box, .. } = window.mode else {
-                        panic!("window {id} in float_windows but mode is not Float");
-                    };
-                    if let Some(visible_border_box) = border_box.clip(screen) {
-                        let is_highlighted = focused == Some(id);
-                        float_windows.push(FloatWindowPlacement {
-                            id,
-                            border_box,
-                            visible_border_box,
-                            content_box: border_box.inset_by(border),
-                            is_highlighted,
-                        });
-                    }
-                }
-
-                MonitorPlacements {
-                    monitor_id: ws.monitor,
-                    border_thickness: border,
-                    layout: MonitorLayout::Normal {
-                        tiling_windows,
-                        float_windows,
-                        containers,
-                    },
-                }
-            })
+            .map(|ws_id| self.workspace_placements(ws_id, current_ws))
             .collect();
 
         let focused_window = self.focused_window(current_ws);
@@ -753,6 +1105,111 @@ impl Hub {
             focused_window,
             focused_monitor: self.access.focused_monitor,
             monitors,
+            overview: self.overview_placements(),
+        }
+    }
+
+    /// Placements of `ws_id` on its monitor, as if it were shown there.
+    /// `current_ws` decides which workspace holds keyboard focus.
+    pub(super) fn workspace_placements(
+        &self,
+        ws_id: WorkspaceId,
+        current_ws: WorkspaceId,
+    ) -> MonitorPlacements {
+        let ws = self.access.workspaces.get(ws_id);
+        let screen = self.access.monitors.get(ws.monitor).work_area;
+
+        if let Some(&fs_id) = ws.fullscreen_windows.last() {
+            return MonitorPlacements {
+                monitor_id: ws.monitor,
+                border_thickness: self.access.border(ws.monitor),
+                layout: MonitorLayout::Fullscreen(fs_id),
+                hints: Vec::new(),
+                scroll_extents: Vec::new(),
+            };
+        }
+
+        let tiling = self
+            .strategies
+            .for_workspace(ws_id)
+            .collect_tiling_placements(&self.access, ws_id, ws_id == current_ws);
+        let mut tiling_windows = tiling.windows;
+        let containers = tiling.containers;
+        let scroll_extents = tiling.scroll_extents;
+
+        let (focused, inactive_focus) = if ws_id == current_ws {
+            (self.focused_window(ws_id), None)
+        } else {
+            (None, self.focused_window(ws_id))
+        };
+        for wp in &mut tiling_windows {
+            wp.emphasis = self.window_emphasis(wp.id, inactive_focus);
+        }
+
+        let mut float_windows = Vec::new();
+        for &id in &ws.float_windows {
+            let window = self.access.windows.get(id);
+            let DisplayMode::Float { border_box, .. } = window.mode else {
+                panic!("window {id} in float_windows but mode is not Float");
+            };
+            if let Some(visible_border_box) = border_box.clip(screen) {
+                let is_highlighted = focused == Some(id);
+                let border = self.access.window_border(id, ws.monitor);
+                float_windows.push(FloatWindowPlacement {
+                    id,
+                    border_box,
+                    visible_border_box,
+                    content_box: border_box.inset_by(border),
+                    is_highlighted,
+                    emphasis: self.window_emphasis(id, inactive_focus),
+                    border,
+                });
+            }
+        }
+
+        let hints = self
+            .hints
+            .iter()
+            .filter_map(|(label, id)| {
+                let visible_border_box = tiling_windows
+                    .iter()
+                    .find(|p| p.id == *id)
+                    .map(|p| p.visible_border_box)
+                    .or_else(|| {
+                        float_windows
+                            .iter()
+                            .find(|p| p.id == *id)
+                            .map(|p| p.visible_border_box)
+                    })?;
+                Some(HintPlacement {
+                    label: label.clone(),
+                    visible_border_box,
+                })
+            })
+            .collect();
+
+        MonitorPlacements {
+            monitor_id: ws.monitor,
+            border_thickness: self.access.border(ws.monitor),
+            hints,
+            scroll_extents,
+            layout: MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                containers,
+            },
+        }
+    }
+
+    fn window_emphasis(&self, id: WindowId, inactive_focus: Option<WindowId>) -> WindowEmphasis {
+        if inactive_focus == Some(id) {
+            WindowEmphasis::InactiveFocus
+        } else if self.is_urgent(id) {
+            WindowEmphasis::Urgent
+        } else if self.marks.values().any(|&w| w == id) {
+            WindowEmphasis::Marked
+        } else {
+            WindowEmphasis::None
         }
     }
 
@@ -761,6 +1218,17 @@ impl Hub {
         let window = self.access.windows.get(id);
         let is_minimized = window.is_minimized();
         let mode = window.mode;
+        self.lifecycle_events.push(LifecycleEvent::WindowDestroyed {
+            id,
+            workspace: window
+                .workspace()
+                .map(|ws_id| self.access.workspaces.get(ws_id).name.clone()),
+            metadata: window.metadata.clone_box(),
+        });
+        self.marks.retain(|_, &mut w| w != id);
+        self.urgent.retain(|&w| w != id);
+        self.hints.retain(|&(_, w)| w != id);
+        self.animator.forget(id);
 
         if is_minimized {
             self.minimized_windows.retain(|&w| w != id);
@@ -787,6 +1255,7 @@ impl Hub {
         }
 
         self.access.windows.delete(id);
+        self.clear_focused_urgent();
     }
 
     #[tracing::instrument(skip(self))]
@@ -887,7 +1356,7 @@ impl Hub {
                 self.attach_float_to_workspace(target_ws, window_id, dim, None);
             }
             DisplayMode::Tiling => {
-                self.move_focused_across_workspaces(current_ws, target_ws);
+                self.move_focused_across_workspaces(current_ws, target_ws, None);
             }
         }
 
@@ -959,7 +1428,28 @@ impl Hub {
         }
     }
 
-    pub(super) fn move_focused_across_workspaces(&mut self, from: WorkspaceId, to: WorkspaceId) {
+    /// Detach a tiling window from `ws_id`, handing focus to its floats when
+    /// no tiling window is left.
+    pub(super) fn detach_tiling_window(&mut self, ws_id: WorkspaceId, window_id: WindowId) {
+        let strategy = self.strategies.for_workspace_mut(ws_id);
+        strategy.detach_window(&mut self.access, window_id);
+        if strategy.tiling_window_count(&self.access, ws_id) == 0 {
+            let ws = self.access.workspaces.get_mut(ws_id);
+            if ws.fullscreen_windows.is_empty() {
+                ws.is_float_focused = !ws.float_windows.is_empty();
+            }
+        }
+    }
+
+    /// Moves the focused child of `from` into `to`, next to `anchor` when
+    /// given and next to `to`'s focus otherwise. `from` and `to` may be the
+    /// same workspace when there is an anchor.
+    pub(super) fn move_focused_across_workspaces(
+        &mut self,
+        from: WorkspaceId,
+        to: WorkspaceId,
+        anchor: Option<WindowId>,
+    ) {
         let strategy = self.strategies.for_workspace_mut(from);
         let child = strategy.detach_focused_child(&mut self.access, from);
         let Some(child) = child else {
@@ -971,9 +1461,11 @@ impl Hub {
                 ws.is_float_focused = !ws.float_windows.is_empty();
             }
         }
-        self.strategies
-            .for_workspace_mut(to)
-            .reattach_child(&mut self.access, child, to);
+        let strategy = self.strategies.for_workspace_mut(to);
+        if let Some(anchor) = anchor {
+            strategy.set_focus(&mut self.access, anchor);
+        }
+        strategy.reattach_child(&mut self.access, child, to);
         if let Child::Window(window_id) = child {
             self.set_workspace_focus(window_id);
         }
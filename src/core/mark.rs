@@ -0,0 +1,76 @@
+//! Marks: user-assigned names for windows, for jumping and moving by name.
+//!
+//! Marks are keyed by `WindowId`, so they follow a window through moves and
+//! minimize without any bookkeeping at those call sites. `delete_window` is the
+//! only place that has to clear them.
+
+use crate::core::{Hub, WindowId, hub::RestrictedAction, node::DisplayMode};
+
+impl Hub {
+    /// Attach `name` to the focused window. A name already on another window
+    /// moves to this one, so a mark always names exactly one window.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn mark_focused(&mut self, name: &str) {
+        let Some(window_id) = self.focused_window(self.current_workspace()) else {
+            return;
+        };
+        self.marks.insert(name.to_string(), window_id);
+    }
+
+    /// Marks on `window_id`, in name order.
+    pub(crate) fn window_marks(&self, window_id: WindowId) -> Vec<String> {
+        self.marks
+            .iter()
+            .filter(|&(_, &id)| id == window_id)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Focus the marked window, switching workspace and monitor as needed.
+    /// A minimized window keeps its marks but is skipped until restored.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn focus_mark(&mut self, name: &str) {
+        if self.is_restricted(RestrictedAction::TilingNavigation) {
+            return;
+        }
+        let Some(&window_id) = self.marks.get(name) else {
+            return;
+        };
+        if self.access.windows.get(window_id).is_minimized() {
+            return;
+        }
+        self.set_focus(window_id);
+    }
+
+    /// Move the focused window next to the marked window. Between two tiling
+    /// windows the strategy inserts beside the mark as if it were focused;
+    /// otherwise the window only moves to the mark's workspace and keeps its
+    /// mode. Focus stays on the current workspace, as with `move workspace`.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn move_focused_to_mark(&mut self, name: &str) {
+        if self.is_restricted(RestrictedAction::WorkspaceMove) {
+            return;
+        }
+        let Some(&target) = self.marks.get(name) else {
+            return;
+        };
+        let Some(target_ws) = self.access.windows.get(target).workspace() else {
+            return;
+        };
+        let current_ws = self.current_workspace();
+        let Some(window_id) = self.focused_window(current_ws) else {
+            return;
+        };
+        if window_id == target {
+            return;
+        }
+        let both_tiling = matches!(self.access.windows.get(window_id).mode, DisplayMode::Tiling)
+            && matches!(self.access.windows.get(target).mode, DisplayMode::Tiling);
+        if both_tiling {
+            self.move_focused_across_workspaces(current_ws, target_ws, Some(target));
+            tracing::debug!("Moved next to mark");
+        } else {
+            self.move_child_to_workspace_with_id(window_id, target_ws);
+        }
+    }
+}
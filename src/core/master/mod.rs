@@ -7,7 +7,7 @@ mod validate;
 
 use std::collections::HashMap;
 
-use crate::config::{LayoutWorkspaceConfig, SizeConstraints};
+use crate::config::{LayoutWorkspaceConfig, ScrollFocus, SizeConstraints};
 use crate::core::GlobalLayoutConfig;
 use crate::core::allocator::Allocator;
 use crate::core::hub::HubAccess;
@@ -32,6 +32,7 @@ pub(crate) struct MasterStrategy {
     master_count: usize,
     master_ratio: f32,
     size_constraints: SizeConstraints,
+    scroll_focus: ScrollFocus,
 }
 
 impl TilingStrategy for MasterStrategy {
@@ -308,6 +309,21 @@ impl TilingStrategy for MasterStrategy {
                 self.reconcile_master_count(hub, ws_id);
                 self.compute_placement(hub, ws_id);
             }
+            TilingAction::Scroll {
+                direction: Direction::Vertical,
+                forward,
+                amount,
+            } => self.scroll_pane(
+                hub,
+                ws_id,
+                pane,
+                if forward {
+                    amount
+                } else {
+                    Length::ZERO - amount
+                },
+            ),
+            TilingAction::CenterViewport => self.center_focused(hub, ws_id),
             _ => {}
         }
     }
@@ -399,6 +415,7 @@ impl TilingStrategy for MasterStrategy {
         self.master_ratio = layout.master.master_ratio;
         self.master_count = layout.master.master_count;
         self.size_constraints = layout.size_constraints;
+        self.scroll_focus = layout.scroll_focus;
         for ws_id in self.workspaces.keys().copied().collect::<Vec<_>>() {
             let needs_reconcile = self
                 .workspaces
@@ -422,11 +439,13 @@ impl MasterStrategy {
         master_count: usize,
         master_ratio: f32,
         size_constraints: SizeConstraints,
+        scroll_focus: ScrollFocus,
     ) -> Self {
         Self {
             master_count,
             master_ratio,
             size_constraints,
+            scroll_focus,
             workspaces: HashMap::new(),
             window_states: HashMap::new(),
             slots: Allocator::new(),
@@ -1,5 +1,5 @@
 use crate::core::{
-    Dimension, Length, PixelRect, TilingWindowPlacement, WindowId,
+    Dimension, Length, PixelRect, ScrollExtent, TilingWindowPlacement, WindowEmphasis, WindowId,
     hub::HubAccess,
     master::{MasterStrategy, WindowState},
     node::WorkspaceId,
@@ -79,12 +79,12 @@ impl MasterStrategy {
             return TilingPlacements {
                 windows: Vec::new(),
                 containers: Vec::new(),
+                scroll_extents: Vec::new(),
             };
         };
 
         let ws = hub.workspaces.get(ws_id);
         let screen = hub.monitors.get(ws.monitor).work_area;
-        let border = hub.border(ws.monitor);
 
         let mut windows = Vec::with_capacity(state.master.len() + state.secondary.len());
 
@@ -94,12 +94,16 @@ impl MasterStrategy {
             None
         };
 
+        let mut scroll_extents = Vec::new();
         let mut push_pane = |vec: &[WindowId], y_offset: Length| {
+            let mut border_boxes = Vec::with_capacity(vec.len());
             for &wid in vec.iter() {
                 let dim = self.window_states[&wid].dimension;
                 let border_box = translate(dim, Length::ZERO, y_offset, screen.x(), screen.y());
+                border_boxes.push(border_box);
                 if let Some(visible_border_box) = border_box.clip(screen) {
                     let is_highlighted = focused_id == Some(wid);
+                    let border = hub.window_border(wid, ws.monitor);
                     let content_box = border_box.inset_by(border);
                     windows.push(TilingWindowPlacement {
                         id: wid,
@@ -108,10 +112,24 @@ impl MasterStrategy {
                         content_box,
                         visible_content_box: content_box.clip(screen).unwrap_or(PixelRect::ZERO),
                         is_highlighted,
+                        emphasis: WindowEmphasis::None,
                         spawn_indicator: None,
+                        border,
                     });
                 }
             }
+            // Panes only scroll vertically, so the viewport is the pane's
+            // column of the work area.
+            let Some(content) = border_boxes.iter().copied().reduce(PixelRect::union) else {
+                return;
+            };
+            let column =
+                PixelRect::from_pixels(content.x(), screen.y(), content.width(), screen.height());
+            if let Some(viewport) = column.clip(screen)
+                && let Some(extent) = ScrollExtent::overflowing(viewport, content, border_boxes)
+            {
+                scroll_extents.push(extent);
+            }
         };
 
         push_pane(&state.master, state.master_y_offset);
@@ -120,6 +138,7 @@ impl MasterStrategy {
         TilingPlacements {
             windows,
             containers: Vec::new(),
+            scroll_extents,
         }
     }
 
@@ -170,7 +189,7 @@ impl MasterStrategy {
         }
     }
 
-    fn clamp_scroll(&mut self, hub: &HubAccess, ws_id: WorkspaceId) {
+    pub(super) fn clamp_scroll(&mut self, hub: &HubAccess, ws_id: WorkspaceId) {
         let state = self.workspaces.get(&ws_id).unwrap();
         let pane_height = Length::from_pixels(
             hub.monitors
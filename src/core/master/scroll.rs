@@ -1,12 +1,65 @@
+use crate::config::ScrollFocus;
 use crate::core::{
     Length,
     hub::HubAccess,
     master::{MasterStrategy, Pane},
     node::WorkspaceId,
+    strategy::centered_offset,
 };
 
 impl MasterStrategy {
     pub(super) fn scroll_into_view(&mut self, hub: &HubAccess, ws_id: WorkspaceId) {
+        let center = self.scroll_focus == ScrollFocus::Center;
+        self.scroll_to_focused(hub, ws_id, |offset, slot_y, slot_height, pane_height| {
+            let mut new_offset = offset;
+            if slot_y + slot_height - new_offset > pane_height {
+                new_offset = slot_y + slot_height - pane_height;
+            }
+            if slot_y - new_offset < Length::ZERO {
+                new_offset = slot_y;
+            }
+            if center && new_offset != offset {
+                centered_offset(slot_y, slot_height, pane_height)
+            } else {
+                new_offset
+            }
+        });
+    }
+
+    /// Scroll the focused window's pane so the window sits in its middle.
+    pub(super) fn center_focused(&mut self, hub: &HubAccess, ws_id: WorkspaceId) {
+        self.scroll_to_focused(hub, ws_id, |_, slot_y, slot_height, pane_height| {
+            centered_offset(slot_y, slot_height, pane_height)
+        });
+    }
+
+    /// Pan `pane` by `delta` without moving focus, clamped to its content.
+    pub(super) fn scroll_pane(
+        &mut self,
+        hub: &HubAccess,
+        ws_id: WorkspaceId,
+        pane: Pane,
+        delta: Length,
+    ) {
+        let Some(state) = self.workspaces.get_mut(&ws_id) else {
+            return;
+        };
+        match pane {
+            Pane::Master => state.master_y_offset += delta,
+            Pane::Secondary => state.stack_y_offset += delta,
+        }
+        self.clamp_scroll(hub, ws_id);
+    }
+
+    /// Sets the focused pane's offset to what `place` picks from the current
+    /// offset and the focused slot's position and height, then clamps it to
+    /// the pane's content.
+    fn scroll_to_focused(
+        &mut self,
+        hub: &HubAccess,
+        ws_id: WorkspaceId,
+        place: impl Fn(Length, Length, Length, Length) -> Length,
+    ) {
         let state = self.workspaces.get(&ws_id).unwrap();
         let Some((pane, idx)) = state.focused_position() else {
             return;
@@ -35,14 +88,8 @@ impl MasterStrategy {
         let slot_y: Length = content_start + slot_heights[..idx].iter().copied().sum::<Length>();
         let slot_height = slot_heights[idx];
 
-        let mut new_offset = offset;
-        if slot_y + slot_height - new_offset > pane_height {
-            new_offset = slot_y + slot_height - pane_height;
-        }
-        if slot_y - new_offset < Length::ZERO {
-            new_offset = slot_y;
-        }
-        new_offset = new_offset.clamp(Length::ZERO, max_offset);
+        let new_offset =
+            place(offset, slot_y, slot_height, pane_height).clamp(Length::ZERO, max_offset);
 
         let state = self.workspaces.get_mut(&ws_id).unwrap();
         match pane {
@@ -38,14 +38,7 @@ impl Hub {
 
         match prior_mode {
             DisplayMode::Tiling => {
-                let strategy = self.strategies.for_workspace_mut(prior_workspace);
-                strategy.detach_window(&mut self.access, window_id);
-                if strategy.tiling_window_count(&self.access, prior_workspace) == 0 {
-                    let ws = self.access.workspaces.get_mut(prior_workspace);
-                    if ws.fullscreen_windows.is_empty() {
-                        ws.is_float_focused = !ws.float_windows.is_empty();
-                    }
-                }
+                self.detach_tiling_window(prior_workspace, window_id);
             }
             DisplayMode::Float { .. } => {
                 self.detach_float_from_workspace(window_id);
@@ -59,6 +52,7 @@ impl Hub {
         w.set_minimized(true);
         w.set_workspace(None);
         self.minimized_windows.push(window_id);
+        self.clear_focused_urgent();
 
         tracing::info!(?prior_mode, "Window minimized");
     }
@@ -1,25 +1,33 @@
 mod allocator;
+mod animation;
 mod dto;
 mod export;
 mod float;
 mod fullscreen;
+mod hint;
 mod hub;
+mod mark;
 mod master;
 mod matcher;
 mod minimize;
 mod node;
+mod overview;
 mod partition_tree;
+mod render;
 mod strategy;
+mod tab_title;
 #[cfg(test)]
 mod tests;
+mod urgent;
 mod workspace;
 
+pub(crate) use hint::{HINT_MODE, HintPlacement, HintPress, HintSession};
 pub(crate) use hub::Hub;
 #[cfg(target_os = "macos")]
 pub(crate) use hub::MonitorPlacements;
 pub(crate) use hub::{
-    ContainerPlacement, FloatWindowPlacement, GlobalLayoutConfig, MonitorLayout, SpawnIndicator,
-    TilingWindowPlacement,
+    ContainerPlacement, FloatWindowPlacement, FocusRestore, GlobalLayoutConfig, MonitorLayout,
+    ScrollExtent, SpawnIndicator, TilingWindowPlacement, WindowEmphasis,
 };
 pub(crate) use node::Direction;
 #[cfg(target_os = "windows")]
@@ -30,9 +38,10 @@ pub(crate) use node::{
     ContainerId, Dimension, Length, LimitObservation, LimitUpdate, Logical, MonitorId, Unit,
     WindowId, WindowMetadata, WindowRestrictions,
 };
+pub(crate) use overview::{OVERVIEW_MODE, OverviewWindow, OverviewWorkspace};
 pub(crate) use strategy::TilingAction;
 
-pub(crate) use dto::WorkspaceInfo;
+pub(crate) use dto::{LifecycleEvent, TreeInfo, WorkspaceInfo};
 
 const MAX_ITERATIONS: usize = 10000;
 
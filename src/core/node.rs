@@ -165,6 +165,10 @@ pub(crate) struct Window {
     is_minimized: bool,
     pub(super) metadata: Box<dyn WindowMetadata>,
     pub(super) limits: SizeLimits,
+    /// Only takes effect while the window floats, see `carry_sticky_floats`.
+    pub(super) sticky: bool,
+    /// Border size from a matching `[[border]]` rule, in place of `border_size`.
+    pub(super) border: Option<Pixels<Logical>>,
 }
 
 impl Node for Window {
@@ -180,6 +184,8 @@ impl Clone for Window {
             restrictions: self.restrictions,
             is_minimized: self.is_minimized,
             limits: self.limits,
+            sticky: self.sticky,
+            border: self.border,
         }
     }
 }
@@ -210,6 +216,8 @@ impl Window {
             is_minimized: false,
             metadata,
             limits: SizeLimits::default(),
+            sticky: false,
+            border: None,
         }
     }
 
@@ -228,6 +236,8 @@ impl Window {
             is_minimized: false,
             metadata,
             limits: SizeLimits::default(),
+            sticky: false,
+            border: None,
         }
     }
 
@@ -243,6 +253,8 @@ impl Window {
             is_minimized: false,
             metadata,
             limits: SizeLimits::default(),
+            sticky: false,
+            border: None,
         }
     }
 
@@ -847,6 +859,15 @@ impl<U> PixelRect<U> {
         self.width.v <= 0 || self.height.v <= 0
     }
 
+    /// Smallest rectangle covering both.
+    pub(crate) fn union(self, other: Self) -> Self {
+        let x1 = self.x.min(other.x);
+        let y1 = self.y.min(other.y);
+        let x2 = self.right().max(other.right());
+        let y2 = self.bottom().max(other.bottom());
+        Self::from_pixels(x1, y1, x2 - x1, y2 - y1)
+    }
+
     /// Clamps extent at zero rather than going negative. The origin is still pushed
     /// inward, so a box narrower than `2 * border` ends up empty at an origin past
     /// its own far edge.
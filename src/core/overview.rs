@@ -0,0 +1,184 @@
+//! Workspace overview: a scaled diagram of every workspace's windows, for
+//! jumping to a workspace or dragging a window onto another one.
+//!
+//! Like hints, the hub only labels the workspaces and reports the diagram
+//! through `VisiblePlacements`. Typed labels are matched in the keymap via a
+//! `HintSession` over workspace names.
+
+use crate::core::{
+    Hub, PixelRect, WindowId,
+    hint::{HINT_ALPHABET, HintSession, hint_labels},
+    hub::RestrictedAction,
+    node::{DisplayMode, WorkspaceId},
+};
+
+/// Key mode active while the overview is shown.
+pub(crate) const OVERVIEW_MODE: &str = "overview";
+
+/// One workspace of the overview, in the coordinates of its monitor.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct OverviewWorkspace {
+    pub(crate) name: String,
+    pub(crate) label: String,
+    pub(crate) is_focused: bool,
+    /// Work area of the workspace's monitor. `windows` frames share its
+    /// coordinates, so the painter scales both together.
+    pub(crate) area: PixelRect,
+    pub(crate) windows: Vec<OverviewWindow>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct OverviewWindow {
+    pub(crate) id: WindowId,
+    pub(crate) title: String,
+    /// Visible part of the border box. Scrolled-off tiling windows are left
+    /// out rather than drawn past the workspace's edge.
+    pub(crate) frame: PixelRect,
+    pub(crate) is_float: bool,
+    pub(crate) is_fullscreen: bool,
+    /// The window its workspace would focus.
+    pub(crate) is_focused: bool,
+}
+
+impl Hub {
+    /// Label every workspace, in `query workspaces` order. Returns `None` when
+    /// focus is locked, in which case no overview is shown.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn show_overview(&mut self) -> Option<HintSession<String>> {
+        if self.is_restricted(RestrictedAction::TilingNavigation) {
+            return None;
+        }
+        let names: Vec<String> = self
+            .access
+            .workspaces
+            .all_active()
+            .into_iter()
+            .map(|(_, ws)| ws.name)
+            .collect();
+        let labels = hint_labels(names.len(), HINT_ALPHABET);
+        self.overview = labels.into_iter().zip(names).collect();
+        Some(HintSession::new(self.overview.clone()))
+    }
+
+    /// Returns whether the overview was showing.
+    pub(crate) fn hide_overview(&mut self) -> bool {
+        let shown = !self.overview.is_empty();
+        self.overview.clear();
+        shown
+    }
+
+    /// Move a window, focused or not, to the named workspace. Focus is left
+    /// alone: the window is detached and attached directly, so no focus or
+    /// workspace change is reported and nothing is flashed or un-urgented.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn move_window_to_workspace(&mut self, window_id: WindowId, name: &str) {
+        if self.is_restricted(RestrictedAction::WorkspaceMove)
+            || !self.access.windows.contains(window_id)
+            || self.access.windows.get(window_id).is_minimized()
+        {
+            return;
+        }
+        let window = self.access.windows.get(window_id);
+        let from = window
+            .workspace()
+            .expect("non-minimized window has a workspace");
+        let mode = window.mode;
+        let target = self.get_or_create_workspace(name);
+        if from == target {
+            return;
+        }
+        let current = self.current_workspace();
+        let focused = self.focused_window(current);
+        match mode {
+            DisplayMode::Tiling => {
+                self.detach_tiling_window(from, window_id);
+                self.strategies.for_workspace_mut(target).attach_window(
+                    &mut self.access,
+                    window_id,
+                    target,
+                );
+            }
+            DisplayMode::Float { .. } => {
+                let rect = self.detach_float_from_workspace(window_id);
+                self.attach_float_to_workspace(target, window_id, rect, None);
+            }
+            DisplayMode::Fullscreen { .. } => {
+                self.detach_fullscreen_from_workspace(window_id);
+                self.attach_fullscreen_to_workspace(target, window_id, None);
+                self.access.workspaces.get_mut(target).is_float_focused = false;
+            }
+        }
+        // Attaching focuses the window within its new workspace. When that is
+        // the current one, hand focus back to the window that had it.
+        if target == current
+            && let Some(focused) = focused
+        {
+            self.set_workspace_focus(focused);
+        }
+    }
+
+    /// The overview diagram, empty unless the overview is showing. Workspaces
+    /// removed since it opened drop out; ones created since have no label and
+    /// are left out too.
+    pub(super) fn overview_placements(&self) -> Vec<OverviewWorkspace> {
+        if self.overview.is_empty() {
+            return Vec::new();
+        }
+        let current = self.current_workspace();
+        self.access
+            .workspaces
+            .all_active()
+            .into_iter()
+            .filter_map(|(ws_id, ws)| {
+                let label = self
+                    .overview
+                    .iter()
+                    .find(|(_, name)| *name == ws.name)?
+                    .0
+                    .clone();
+                Some(OverviewWorkspace {
+                    windows: self.overview_windows(ws_id),
+                    area: self.access.monitors.get(ws.monitor).work_area,
+                    is_focused: ws_id == current,
+                    name: ws.name,
+                    label,
+                })
+            })
+            .collect()
+    }
+
+    fn overview_windows(&self, ws_id: WorkspaceId) -> Vec<OverviewWindow> {
+        let ws = self.access.workspaces.get(ws_id);
+        let area = self.access.monitors.get(ws.monitor).work_area;
+        let focused = self.focused_window(ws_id);
+        let window = |id: WindowId, frame: PixelRect| {
+            let w = self.access.windows.get(id);
+            OverviewWindow {
+                id,
+                title: w.title().to_string(),
+                frame,
+                is_float: w.is_float(),
+                is_fullscreen: w.is_fullscreen(),
+                is_focused: focused == Some(id),
+            }
+        };
+        if let Some(&fs_id) = ws.fullscreen_windows.last() {
+            return vec![window(fs_id, area)];
+        }
+        let tiling = self
+            .strategies
+            .for_workspace(ws_id)
+            .collect_tiling_placements(&self.access, ws_id, false)
+            .windows
+            .into_iter()
+            .filter(|p| !p.visible_border_box.is_empty())
+            .map(|p| window(p.id, p.visible_border_box));
+        let floats = ws.float_windows.iter().filter_map(|&id| {
+            let DisplayMode::Float { border_box, .. } = self.access.windows.get(id).mode else {
+                return None;
+            };
+            Some(window(id, border_box.clip(area)?))
+        });
+        tiling.chain(floats).collect()
+    }
+}
@@ -17,7 +17,8 @@ use std::collections::HashMap;
 
 use crate::config::LayoutWorkspaceConfig;
 use crate::config::SizeConstraints;
-use crate::config::SplitMode;
+use crate::config::{PartitionTreeConfig, TabBarPosition};
+use crate::config::{ScrollFocus, SplitMode};
 use crate::core::GlobalLayoutConfig;
 use crate::core::allocator::Allocator;
 use crate::core::hub::HubAccess;
@@ -39,8 +40,11 @@ pub(crate) struct PartitionTreeStrategy {
     window_slots: Allocator<PreferredWindowSlot>,
     container_slots: Allocator<PreferredContainerSlot>,
     tab_bar_height: Pixels<Logical>,
+    tab_bar_position: TabBarPosition,
+    tab_sidebar_width: Pixels<Logical>,
     automatic_tiling: bool,
     size_constraints: SizeConstraints,
+    scroll_focus: ScrollFocus,
 }
 
 impl TilingStrategy for PartitionTreeStrategy {
@@ -157,6 +161,21 @@ impl TilingStrategy for PartitionTreeStrategy {
                 container_id,
                 index,
             } => self.focus_tab_index(hub, container_id, index),
+            TilingAction::MoveTab {
+                container_id,
+                from,
+                to,
+            } => self.move_tab(hub, container_id, from, to),
+            TilingAction::SplitOutTab {
+                container_id,
+                index,
+            } => self.split_out_tab(hub, container_id, index),
+            TilingAction::Scroll {
+                direction,
+                forward,
+                amount,
+            } => self.scroll_viewport(hub, direction, forward, amount),
+            TilingAction::CenterViewport => self.center_viewport(hub),
             TilingAction::GrowMaster
             | TilingAction::ShrinkMaster
             | TilingAction::MoreMaster
@@ -309,8 +328,11 @@ impl TilingStrategy for PartitionTreeStrategy {
 
     fn apply_config(&mut self, hub: &mut HubAccess, layout: GlobalLayoutConfig) {
         self.tab_bar_height = layout.partition_tree.tab_bar_height;
+        self.tab_bar_position = layout.partition_tree.tab_bar_position;
+        self.tab_sidebar_width = layout.partition_tree.tab_sidebar_width;
         self.automatic_tiling = layout.partition_tree.automatic_tiling;
         self.size_constraints = layout.size_constraints;
+        self.scroll_focus = layout.scroll_focus;
         for ws_id in self.workspaces.keys().copied().collect::<Vec<_>>() {
             self.compute_placement(hub, ws_id);
         }
@@ -323,9 +345,9 @@ impl TilingStrategy for PartitionTreeStrategy {
 
 impl PartitionTreeStrategy {
     pub(crate) fn new(
-        tab_bar_height: Pixels<Logical>,
-        automatic_tiling: bool,
+        config: &PartitionTreeConfig,
         size_constraints: SizeConstraints,
+        scroll_focus: ScrollFocus,
     ) -> Self {
         Self {
             tiling_containers: HashMap::new(),
@@ -333,9 +355,12 @@ impl PartitionTreeStrategy {
             workspaces: HashMap::new(),
             window_slots: Allocator::new(),
             container_slots: Allocator::new(),
-            tab_bar_height,
-            automatic_tiling,
+            tab_bar_height: config.tab_bar_height,
+            tab_bar_position: config.tab_bar_position,
+            tab_sidebar_width: config.tab_sidebar_width,
+            automatic_tiling: config.automatic_tiling,
             size_constraints,
+            scroll_focus,
         }
     }
 }
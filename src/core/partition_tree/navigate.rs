@@ -1,3 +1,4 @@
+use crate::config::SplitMode;
 use crate::core::hub::HubAccess;
 use crate::core::node::{ContainerId, Direction, WorkspaceId};
 use crate::core::partition_tree::{Child, Parent, SpawnMode};
@@ -281,6 +282,85 @@ impl PartitionTreeStrategy {
         self.set_focus(hub, focus_target);
     }
 
+    /// Reorder a tab within its bar. The active tab keeps pointing at the same
+    /// child, so dragging a background tab does not switch tabs.
+    pub(super) fn move_tab(
+        &mut self,
+        hub: &mut HubAccess,
+        container_id: ContainerId,
+        from: usize,
+        to: usize,
+    ) {
+        let Some(data) = self.tiling_containers.get(&container_id) else {
+            return;
+        };
+        let len = hub.containers.get(container_id).children.len();
+        if !data.is_tabbed || from >= len || to >= len || from == to {
+            return;
+        }
+        let ws = data.workspace;
+        let active = self.active_tab(hub, container_id).unwrap();
+        let children = &mut hub.containers.get_mut(container_id).children;
+        let child = children.remove(from);
+        children.insert(to, child);
+        tracing::debug!(%container_id, from, to, "Moving tab");
+        self.set_active_tab_to_child(hub, container_id, active);
+        self.compute_placement(hub, ws);
+    }
+
+    /// Pull a tab out of its bar and place it after the tabbed container. Joins
+    /// the container's parent when that is a split; otherwise the two are paired
+    /// in a new horizontal split. The split-off child takes focus.
+    pub(super) fn split_out_tab(
+        &mut self,
+        hub: &mut HubAccess,
+        container_id: ContainerId,
+        index: usize,
+    ) {
+        let Some(data) = self.tiling_containers.get(&container_id) else {
+            return;
+        };
+        let children = hub.containers.get(container_id).children();
+        if !data.is_tabbed || index >= children.len() {
+            return;
+        }
+        let ws = data.workspace;
+        let child = children[index];
+        // The container dissolves when one tab would remain, promoting that tab
+        // into its slot.
+        let anchor = if children.len() == 2 {
+            children[1 - index]
+        } else {
+            Child::Container(container_id)
+        };
+        tracing::debug!(%container_id, index, %child, "Splitting out tab");
+        self.detach_child_from_container(hub, container_id, child);
+        match self.parent(anchor) {
+            Parent::Container(parent_id)
+                if self
+                    .tiling_containers
+                    .get(&parent_id)
+                    .unwrap()
+                    .direction()
+                    .is_some() =>
+            {
+                let pos = hub.containers.get(parent_id).position_of(anchor);
+                self.attach_child_to_container(hub, child, parent_id, Some(pos + 1));
+            }
+            _ => {
+                self.replace_anchor_with_container(
+                    hub,
+                    anchor,
+                    vec![anchor, child],
+                    SplitMode::Horizontal,
+                );
+            }
+        }
+        self.compute_placement(hub, ws);
+        let focus_target = self.focus_target_in(child);
+        self.set_focus(hub, focus_target);
+    }
+
     /// Move tiling focus from the current child to its parent container. Sets
     /// `focused_tiling` to `Child::Container`, entering container-highlight mode.
     /// No managed windows should receive keyboard focus in this mode.
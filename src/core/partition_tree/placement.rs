@@ -1,3 +1,4 @@
+use crate::config::TabBarPosition;
 use crate::core::hub::HubAccess;
 use crate::core::node::Constraints;
 use crate::core::node::{
@@ -7,7 +8,9 @@ use crate::core::partition_tree::{Child, SpawnMode};
 use crate::core::strategy::{
     TilingPlacements, clip, distribute_space, translate, window_constraints,
 };
-use crate::core::{ContainerPlacement, SpawnIndicator, TilingWindowPlacement};
+use crate::core::{
+    ContainerPlacement, ScrollExtent, SpawnIndicator, TilingWindowPlacement, WindowEmphasis,
+};
 
 use super::PartitionTreeStrategy;
 
@@ -84,6 +87,7 @@ impl PartitionTreeStrategy {
             return TilingPlacements {
                 windows: Vec::new(),
                 containers: Vec::new(),
+                scroll_extents: Vec::new(),
             };
         };
         let ws = hub.workspaces.get(ws_id);
@@ -91,7 +95,6 @@ impl PartitionTreeStrategy {
         let monitor = hub.monitors.get(ws.monitor);
         let screen = monitor.work_area;
         let scale = monitor.scale;
-        let border = hub.border(ws.monitor);
         // Fullscreen workspaces never reach here (hub returns early with
         // MonitorLayout::Fullscreen).
         let focused = if focused && !ws.is_float_focused {
@@ -101,6 +104,7 @@ impl PartitionTreeStrategy {
         };
         let mut windows = Vec::new();
         let mut containers = Vec::new();
+        let mut all_border_boxes = Vec::new();
 
         // Hand-rolled DFS kept because tabbed containers push only the active
         // tab, not all children. This visible-only traversal differs from the
@@ -117,8 +121,10 @@ impl PartitionTreeStrategy {
                         screen.x(),
                         screen.y(),
                     );
+                    all_border_boxes.push(border_box);
                     if let Some(visible_border_box) = border_box.clip(screen) {
                         let is_highlighted = focused == Some(Child::Window(id));
+                        let border = hub.window_border(id, ws.monitor);
                         let content_box = border_box.inset_by(border);
                         windows.push(TilingWindowPlacement {
                             id,
@@ -129,11 +135,13 @@ impl PartitionTreeStrategy {
                                 .clip(screen)
                                 .unwrap_or(PixelRect::ZERO),
                             is_highlighted,
+                            emphasis: WindowEmphasis::None,
                             spawn_indicator: if is_highlighted {
                                 Some(SpawnIndicator::from(self.child_spawn_mode(child)))
                             } else {
                                 None
                             },
+                            border,
                         });
                     }
                 }
@@ -142,32 +150,35 @@ impl PartitionTreeStrategy {
                     let data = self.tiling_containers.get(&id).unwrap();
                     let dim = self.child_dimension(child);
                     let border_box = translate(dim, offset_x, offset_y, screen.x(), screen.y());
+                    // Descend even when offscreen, so scroll extents see every
+                    // window.
+                    if let Some(active) = self.active_tab(hub, id) {
+                        stack.push(active);
+                    } else {
+                        for &c in container.children() {
+                            stack.push(c);
+                        }
+                    }
                     let Some(visible_border_box) = border_box.clip(screen) else {
                         continue;
                     };
-                    // Rounding the band height on its own would let round(y) + round(h) drift
-                    // a unit from the round(y + h) the content box uses. The content top comes
-                    // from the container's own dimension, not the active tab's, since a
-                    // max-constrained tab is centred within the content.
-                    let band_height = if data.is_tabbed() {
-                        let content_top =
-                            Pixels::round(dim.y + self.tab_bar_length(scale) - offset_y)
-                                + screen.y();
-                        content_top - border_box.y()
+                    let tab_bar_band = if data.is_tabbed() {
+                        self.tab_bar_band(dim, border_box, (offset_x, offset_y), screen, scale)
                     } else {
-                        Pixels::ZERO
+                        PixelRect::from_pixels(
+                            border_box.x(),
+                            border_box.y(),
+                            border_box.width(),
+                            Pixels::ZERO,
+                        )
                     };
                     let is_highlighted = focused == Some(Child::Container(id));
                     containers.push(ContainerPlacement {
                         id,
                         border_box,
                         visible_border_box,
-                        tab_bar_band: PixelRect::from_pixels(
-                            border_box.x(),
-                            border_box.y(),
-                            border_box.width(),
-                            band_height,
-                        ),
+                        tab_bar_band,
+                        tab_bar_position: self.tab_bar_position,
                         is_highlighted,
                         spawn_indicator: if is_highlighted {
                             Some(SpawnIndicator::from(self.child_spawn_mode(child)))
@@ -179,26 +190,44 @@ impl PartitionTreeStrategy {
                         titles: container
                             .children()
                             .iter()
-                            .map(|c| match c {
-                                Child::Window(wid) => hub.windows.get(*wid).title().to_owned(),
+                            .enumerate()
+                            .map(|(i, c)| match c {
+                                Child::Window(wid) => hub.tab_title(*wid, i + 1),
                                 Child::Container(_) => "Container".to_string(),
                             })
                             .collect(),
+                        tab_windows: container
+                            .children()
+                            .iter()
+                            .map(|c| match c {
+                                Child::Window(wid) => Some(*wid),
+                                Child::Container(_) => None,
+                            })
+                            .collect(),
                     });
-                    if let Some(active) = self.active_tab(hub, id) {
-                        stack.push(active);
-                    } else {
-                        for &c in container.children() {
-                            stack.push(c);
-                        }
-                    }
                 }
             }
         }
 
+        let scroll_extents = ws_state
+            .root
+            .and_then(|root| {
+                let content = translate(
+                    self.child_dimension(root),
+                    offset_x,
+                    offset_y,
+                    screen.x(),
+                    screen.y(),
+                );
+                ScrollExtent::overflowing(screen, content, all_border_boxes)
+            })
+            .into_iter()
+            .collect();
+
         TilingPlacements {
             windows,
             containers,
+            scroll_extents,
         }
     }
 
@@ -283,18 +312,20 @@ impl PartitionTreeStrategy {
             .iter()
             .map(|&c| self.get_effective_constraints(hub, c))
             .collect();
-        let tab_bar = self.tab_bar_length(scale);
-        let content = Dimension::new(dim.x, dim.y + tab_bar, dim.width, dim.height - tab_bar);
+        let content = self.tab_content(dim, scale);
 
+        // An off-screen content area collapses to zero size instead of vanishing, so
+        // place_in_visible still has an origin to clamp against.
         let outer_visible = clip(dim, viewport_rect).unwrap_or(dim);
-        let visible_content_y = outer_visible.y.max(content.y);
-        let visible_content_height =
-            (outer_visible.y + outer_visible.height - visible_content_y).max(Length::ZERO);
+        let visible_x = outer_visible.x.max(content.x);
+        let visible_y = outer_visible.y.max(content.y);
         let visible_content = Dimension::new(
-            outer_visible.x,
-            visible_content_y,
-            outer_visible.width,
-            visible_content_height,
+            visible_x,
+            visible_y,
+            ((outer_visible.x + outer_visible.width).min(content.x + content.width) - visible_x)
+                .max(Length::ZERO),
+            ((outer_visible.y + outer_visible.height).min(content.y + content.height) - visible_y)
+                .max(Length::ZERO),
         );
 
         constraints
@@ -366,7 +397,11 @@ impl PartitionTreeStrategy {
                     .iter()
                     .map(|c| c.min_height)
                     .fold(Length::ZERO, Length::max);
-                (max_w, max_h + self.tab_bar_length(scale))
+                if self.tab_bar_position.is_vertical() {
+                    (max_w + self.tab_bar_length(scale), max_h)
+                } else {
+                    (max_w, max_h + self.tab_bar_length(scale))
+                }
             }
         };
 
@@ -424,8 +459,70 @@ impl PartitionTreeStrategy {
         }
     }
 
+    /// Thickness of the tab band across its side: the bar height, or the sidebar
+    /// width for `left` and `right`.
     pub(super) fn tab_bar_length(&self, scale: f32) -> Length {
-        Length::from_pixels(self.tab_bar_height).to_unit(scale)
+        let thickness = if self.tab_bar_position.is_vertical() {
+            self.tab_sidebar_width
+        } else {
+            self.tab_bar_height
+        };
+        Length::from_pixels(thickness).to_unit(scale)
+    }
+
+    /// `dim` without the tab band, the area every tab of a tabbed container shares.
+    pub(super) fn tab_content(&self, dim: Dimension, scale: f32) -> Dimension {
+        let band = self.tab_bar_length(scale);
+        match self.tab_bar_position {
+            TabBarPosition::Top => {
+                Dimension::new(dim.x, dim.y + band, dim.width, dim.height - band)
+            }
+            TabBarPosition::Bottom => Dimension::new(dim.x, dim.y, dim.width, dim.height - band),
+            TabBarPosition::Left => {
+                Dimension::new(dim.x + band, dim.y, dim.width - band, dim.height)
+            }
+            TabBarPosition::Right => Dimension::new(dim.x, dim.y, dim.width - band, dim.height),
+        }
+    }
+
+    /// Rounding the band thickness on its own would let round(y) + round(h) drift a
+    /// unit from the round(y + h) the content box uses, so the inner edge is rounded
+    /// from the container's own dimension. Not the active tab's: a max-constrained
+    /// tab is centred within the content.
+    fn tab_bar_band(
+        &self,
+        dim: Dimension,
+        border_box: PixelRect,
+        offset: (Length, Length),
+        screen: PixelRect,
+        scale: f32,
+    ) -> PixelRect {
+        let content = self.tab_content(dim, scale);
+        let (offset_x, offset_y) = offset;
+        let (x, y, w, h) = (
+            border_box.x(),
+            border_box.y(),
+            border_box.width(),
+            border_box.height(),
+        );
+        match self.tab_bar_position {
+            TabBarPosition::Top => {
+                let inner = Pixels::round(content.y - offset_y) + screen.y();
+                PixelRect::from_pixels(x, y, w, inner - y)
+            }
+            TabBarPosition::Bottom => {
+                let inner = Pixels::round(content.y + content.height - offset_y) + screen.y();
+                PixelRect::from_pixels(x, inner, w, border_box.bottom() - inner)
+            }
+            TabBarPosition::Left => {
+                let inner = Pixels::round(content.x - offset_x) + screen.x();
+                PixelRect::from_pixels(x, y, inner - x, h)
+            }
+            TabBarPosition::Right => {
+                let inner = Pixels::round(content.x + content.width - offset_x) + screen.x();
+                PixelRect::from_pixels(inner, y, border_box.right() - inner, h)
+            }
+        }
     }
 }
 
@@ -1,7 +1,9 @@
+use crate::config::{ScrollFocus, TabBarPosition};
 use crate::core::{
     Length,
     hub::HubAccess,
-    node::{Child, WorkspaceId},
+    node::{Child, Direction, WorkspaceId},
+    strategy::centered_offset,
 };
 
 use super::PartitionTreeStrategy;
@@ -10,6 +12,65 @@ impl PartitionTreeStrategy {
     /// Adjust the workspace's viewport offset so the focused node is fully
     /// visible.
     pub(super) fn scroll_into_view(&mut self, hub: &HubAccess, workspace_id: WorkspaceId) {
+        let center = self.scroll_focus == ScrollFocus::Center;
+        self.scroll_to_focused(hub, workspace_id, |offset, origin, extent, screen| {
+            let nudged = nudge_offset_into_view(offset, origin, extent, screen);
+            if center && nudged != offset {
+                centered_offset(origin, extent, screen)
+            } else {
+                nudged
+            }
+        });
+    }
+
+    /// Center the viewport on the focused node, as far as the tree allows.
+    pub(super) fn center_viewport(&mut self, hub: &HubAccess) {
+        let workspace_id = hub.monitors.get(hub.focused_monitor).active_workspace;
+        if !self.workspaces.contains_key(&workspace_id) {
+            return;
+        }
+        self.scroll_to_focused(hub, workspace_id, |_, origin, extent, screen| {
+            centered_offset(origin, extent, screen)
+        });
+    }
+
+    /// Pan the focused workspace's viewport without moving focus. The focused
+    /// node may end up offscreen until focus next moves.
+    pub(super) fn scroll_viewport(
+        &mut self,
+        hub: &HubAccess,
+        direction: Direction,
+        forward: bool,
+        amount: Length,
+    ) {
+        let workspace_id = hub.monitors.get(hub.focused_monitor).active_workspace;
+        let Some(ws_state) = self.workspaces.get_mut(&workspace_id) else {
+            return;
+        };
+        let initial = ws_state.viewport_offset;
+        let delta = if forward {
+            amount
+        } else {
+            Length::ZERO - amount
+        };
+        match direction {
+            Direction::Horizontal => ws_state.viewport_offset.0 += delta,
+            Direction::Vertical => ws_state.viewport_offset.1 += delta,
+        }
+        self.clamp_viewport_offset(hub, workspace_id);
+        if self.workspaces[&workspace_id].viewport_offset != initial {
+            self.adjust_placement(hub, workspace_id);
+        }
+    }
+
+    /// Sets the offset on each axis to what `place` picks from the current
+    /// offset, the focused node's origin and extent, and the screen's extent.
+    fn scroll_to_focused(
+        &mut self,
+        hub: &HubAccess,
+        workspace_id: WorkspaceId,
+        place: impl Fn(Length, Length, Length, Length) -> Length,
+    ) {
         let initial = self.workspaces.get(&workspace_id).unwrap().viewport_offset;
 
         self.clamp_viewport_offset(hub, workspace_id);
@@ -26,24 +87,35 @@ impl PartitionTreeStrategy {
         if let Some(focused) = ws_state.focused_tiling {
             let focused_dim = self.child_dimension(focused);
             let scale = hub.monitors.get(monitor_id).scale;
-            let reserved_top = self.enclosing_tabbed_strip_total(focused, scale);
+            let reserved = self.enclosing_tabbed_strip_total(focused, scale);
 
-            offset_x =
-                nudge_offset_into_view(offset_x, focused_dim.x, focused_dim.width, screen_width);
-            offset_y =
-                nudge_offset_into_view(offset_y, focused_dim.y, focused_dim.height, screen_height);
-            // Keep enclosing tab strips visible at the top of the viewport.
-            // After this clamp, focused.y - offset_y >= reserved_top, so each
-            // enclosing strip sits on or below the top of the screen.
-            offset_y = offset_y.min(focused_dim.y - reserved_top);
+            offset_x = place(offset_x, focused_dim.x, focused_dim.width, screen_width);
+            offset_y = place(offset_y, focused_dim.y, focused_dim.height, screen_height);
+            // Keep enclosing tab strips inside the viewport on their side. For `top`,
+            // after this clamp focused.y - offset_y >= reserved, so each enclosing
+            // strip sits on or below the top of the screen.
+            match self.tab_bar_position {
+                TabBarPosition::Top => offset_y = offset_y.min(focused_dim.y - reserved),
+                TabBarPosition::Left => offset_x = offset_x.min(focused_dim.x - reserved),
+                TabBarPosition::Bottom => {
+                    offset_y =
+                        offset_y.max(focused_dim.y + focused_dim.height + reserved - screen_height);
+                }
+                TabBarPosition::Right => {
+                    offset_x =
+                        offset_x.max(focused_dim.x + focused_dim.width + reserved - screen_width);
+                }
+            }
 
             self.workspaces
                 .get_mut(&workspace_id)
                 .unwrap()
                 .viewport_offset = (offset_x, offset_y);
+            // A centered node near the tree's edge would scroll past it.
+            self.clamp_viewport_offset(hub, workspace_id);
         }
 
-        if (offset_x, offset_y) != initial {
+        if self.workspaces[&workspace_id].viewport_offset != initial {
             self.adjust_placement(hub, workspace_id);
         }
     }
@@ -315,18 +315,20 @@ impl PartitionTreeStrategy {
                     .monitors
                     .get(hub.workspaces.get(data.workspace).monitor)
                     .scale;
-                let expected_height = dim.height - self.tab_bar_length(scale);
+                let content = self.tab_content(dim, scale);
+                let expected_width = content.width;
+                let expected_height = content.height;
                 for (i, (child_dim, c)) in constraints.iter().enumerate() {
                     let allows_smaller_w =
-                        c.max_width.value() > 0.0 && c.max_width.value() < dim.width.value();
+                        c.max_width.value() > 0.0 && c.max_width.value() < expected_width.value();
                     let allows_smaller_h = c.max_height.value() > 0.0
                         && c.max_height.value() < expected_height.value();
                     assert!(
-                        (child_dim.width - dim.width).abs() < VALIDATION_TOLERANCE
+                        (child_dim.width - expected_width).abs() < VALIDATION_TOLERANCE
                             || allows_smaller_w,
-                        "Container {cid} tabbed child {i} width {:.2} != container width {:.2}",
+                        "Container {cid} tabbed child {i} width {:.2} != expected {:.2}",
                         child_dim.width.value(),
-                        dim.width.value()
+                        expected_width.value()
                     );
                     assert!(
                         (child_dim.height - expected_height).abs() < VALIDATION_TOLERANCE
@@ -0,0 +1,610 @@
+//! `dome query render`: a picture of one workspace's layout, as text or SVG,
+//! for bug reports and docs.
+//!
+//! Both formats draw the placements the shells apply. The text drawing
+//! helpers are shared with core's snapshot tests, which draw at one cell per
+//! pixel.
+
+use std::fmt::Write;
+
+use egui::Color32;
+
+use crate::action::RenderFormat;
+use crate::config::TabBarPosition;
+use crate::core::allocator::NodeId;
+use crate::core::{
+    Hub, WindowId,
+    hub::{
+        ContainerPlacement, FloatWindowPlacement, MonitorLayout, MonitorPlacements,
+        TilingWindowPlacement, WindowEmphasis,
+    },
+    node::PixelRect,
+};
+use crate::theme::{Flavor, Theme};
+
+/// Width of the text picture in columns. Rows follow the work area's aspect
+/// ratio, halved since a terminal cell is about twice as tall as it is wide.
+const ASCII_COLUMNS: i32 = 120;
+const ASCII_MIN_ROWS: i32 = 8;
+/// Rough width of one character at the SVG font size, to truncate labels.
+const SVG_CHAR_WIDTH: i32 = 8;
+
+impl Hub {
+    /// Draw the focused workspace, or the active workspace called
+    /// `workspace`. `None` when no workspace has that name.
+    pub(crate) fn render(&self, format: RenderFormat, workspace: Option<&str>) -> Option<String> {
+        let current = self.current_workspace();
+        let ws_id = match workspace {
+            Some(name) => {
+                self.access
+                    .workspaces
+                    .all_active()
+                    .into_iter()
+                    .find(|(_, ws)| ws.name == name)?
+                    .0
+            }
+            None => current,
+        };
+        let ws = self.access.workspaces.get(ws_id);
+        let monitor = self.access.monitors.get(ws.monitor);
+        let picture = Picture {
+            hub: self,
+            header: format!(
+                "workspace {:?} on monitor {:?}, {}x{}",
+                ws.name,
+                monitor.name,
+                monitor.work_area.width().value(),
+                monitor.work_area.height().value(),
+            ),
+            area: monitor.work_area,
+            placements: self.workspace_placements(ws_id, current),
+        };
+        Some(match format {
+            RenderFormat::Ascii => picture.ascii(),
+            RenderFormat::Svg => picture.svg(),
+        })
+    }
+}
+
+struct Picture<'a> {
+    hub: &'a Hub,
+    header: String,
+    area: PixelRect,
+    placements: MonitorPlacements,
+}
+
+impl Picture<'_> {
+    fn title(&self, id: WindowId) -> &str {
+        self.hub.access.windows.get(id).title()
+    }
+
+    fn label(&self, prefix: char, id: WindowId) -> String {
+        let title = self.title(id);
+        if title.is_empty() {
+            format!("{prefix}{}", id.get())
+        } else {
+            format!("{prefix}{} {title}", id.get())
+        }
+    }
+
+    /// The work area as a grid, one line per row, then the exact rect of
+    /// every window since the grid is too coarse to read sizes off.
+    fn ascii(&self) -> String {
+        let (width, height) = (self.area.width().value(), self.area.height().value());
+        let cols = ASCII_COLUMNS;
+        let rows = ((cols as f32 * height as f32 / width.max(1) as f32) / 2.0).round() as i32;
+        let rows = rows.max(ASCII_MIN_ROWS);
+        let mut grid = vec![vec![' '; cols as usize]; rows as usize];
+        let cells = |rect: PixelRect| {
+            let col = |v: i32| {
+                ((v - self.area.x().value()) as f32 * cols as f32 / width.max(1) as f32).round()
+                    as i32
+            };
+            let row = |v: i32| {
+                ((v - self.area.y().value()) as f32 * rows as f32 / height.max(1) as f32).round()
+                    as i32
+            };
+            let (x, y) = (col(rect.x().value()), row(rect.y().value()));
+            let (right, bottom) = (col(rect.right().value()), row(rect.bottom().value()));
+            PixelRect::new(x, y, (right - x).max(2), (bottom - y).max(2))
+        };
+        let fit = |label: String, rect: PixelRect| truncate(&label, rect.width().value() - 2);
+        let mut listing = Vec::new();
+
+        match &self.placements.layout {
+            MonitorLayout::Fullscreen(id) => {
+                let rect = PixelRect::new(0, 0, cols, rows);
+                draw_rect(
+                    &mut grid,
+                    rect,
+                    &fit(self.label('W', *id), rect),
+                    [false; 4],
+                );
+                listing.push(format!("W{} fullscreen  {}", id.get(), self.title(*id)));
+            }
+            MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                containers,
+            } => {
+                for wp in tiling_windows {
+                    let rect = cells(wp.visible_border_box);
+                    let clip = clip_edges(wp.border_box, wp.visible_border_box);
+                    draw_rect(&mut grid, rect, &fit(self.label('W', wp.id), rect), clip);
+                    listing.push(self.listing_line('W', wp.id, wp.border_box));
+                }
+                for cp in containers.iter().filter(|cp| cp.is_tabbed) {
+                    let Some(band) = cp.tab_bar_band.clip(self.area) else {
+                        continue;
+                    };
+                    let band = cells(band);
+                    if cp.tab_bar_position.is_vertical() {
+                        draw_tab_list(&mut grid, band, &cp.titles, cp.active_tab_index);
+                    } else {
+                        let y = match cp.tab_bar_position {
+                            TabBarPosition::Bottom => band.bottom().value() - 2,
+                            _ => band.y().value(),
+                        };
+                        let band = PixelRect::new(band.x().value(), y, band.width().value(), 2);
+                        // The bar is thinner than a row once scaled, so it
+                        // takes the row of the window border below it.
+                        let (x1, _, x2, y2) = corners(band);
+                        for col in x1..=x2 {
+                            put(&mut grid, col, y2, ' ');
+                        }
+                        draw_tab_bar(&mut grid, band, &cp.titles, cp.active_tab_index);
+                    }
+                }
+                let focused_float = float_windows.iter().find(|p| p.is_highlighted);
+                if focused_float.is_none() {
+                    if let Some(wp) = tiling_windows.iter().find(|p| p.is_highlighted) {
+                        let clip = clip_edges(wp.border_box, wp.visible_border_box);
+                        draw_focused_border(&mut grid, cells(wp.visible_border_box), clip);
+                    } else if let Some(cp) = containers.iter().find(|p| p.is_highlighted) {
+                        let clip = clip_edges(cp.border_box, cp.visible_border_box);
+                        draw_focused_border(&mut grid, cells(cp.visible_border_box), clip);
+                    }
+                }
+                for wp in float_windows {
+                    let rect = cells(wp.visible_border_box);
+                    let clip = clip_edges(wp.border_box, wp.visible_border_box);
+                    clear_interior(&mut grid, rect);
+                    draw_rect(&mut grid, rect, &fit(self.label('F', wp.id), rect), clip);
+                    listing.push(self.listing_line('F', wp.id, wp.border_box));
+                }
+                if let Some(wp) = focused_float {
+                    let clip = clip_edges(wp.border_box, wp.visible_border_box);
+                    draw_focused_border(&mut grid, cells(wp.visible_border_box), clip);
+                }
+            }
+        }
+
+        let mut s = self.header.clone();
+        s.push('\n');
+        for row in &grid {
+            s.extend(row.iter());
+            s.push('\n');
+        }
+        for line in listing {
+            s.push_str(&line);
+            s.push('\n');
+        }
+        s
+    }
+
+    fn listing_line(&self, prefix: char, id: WindowId, rect: PixelRect) -> String {
+        format!(
+            "{prefix}{} {},{} {}x{}  {}",
+            id.get(),
+            rect.x().value() - self.area.x().value(),
+            rect.y().value() - self.area.y().value(),
+            rect.width().value(),
+            rect.height().value(),
+            self.title(id),
+        )
+    }
+
+    /// The work area at one SVG unit per pixel, in the default theme's colors.
+    fn svg(&self) -> String {
+        let theme = Theme::from_flavor(Flavor::default());
+        let (width, height) = (self.area.width().value(), self.area.height().value());
+        let mut s = String::new();
+        let _ = writeln!(
+            s,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="14">"#
+        );
+        let _ = writeln!(s, "<title>{}</title>", escape(&self.header));
+        let _ = writeln!(
+            s,
+            r#"<rect width="{width}" height="{height}" fill="{}"/>"#,
+            hex(theme.tab_bar_bg)
+        );
+        match &self.placements.layout {
+            MonitorLayout::Fullscreen(id) => {
+                let rect = PixelRect::new(0, 0, width, height);
+                let border = self.placements.border_thickness.value();
+                self.svg_window(
+                    &mut s,
+                    &theme,
+                    rect,
+                    border,
+                    theme.fullscreen_border,
+                    'W',
+                    *id,
+                );
+            }
+            MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                containers,
+            } => {
+                for wp in tiling_windows {
+                    let color = tiling_color(&theme, wp);
+                    let rect = self.relative(wp.visible_border_box);
+                    self.svg_window(&mut s, &theme, rect, wp.border.value(), color, 'W', wp.id);
+                }
+                for cp in containers {
+                    self.svg_container(&mut s, &theme, cp);
+                }
+                for wp in float_windows {
+                    let color = float_color(&theme, wp);
+                    let rect = self.relative(wp.visible_border_box);
+                    self.svg_window(&mut s, &theme, rect, wp.border.value(), color, 'F', wp.id);
+                }
+            }
+        }
+        s.push_str("</svg>\n");
+        s
+    }
+
+    fn relative(&self, rect: PixelRect) -> PixelRect {
+        PixelRect::new(
+            rect.x().value() - self.area.x().value(),
+            rect.y().value() - self.area.y().value(),
+            rect.width().value(),
+            rect.height().value(),
+        )
+    }
+
+    #[expect(clippy::too_many_arguments, reason = "one call per window kind")]
+    fn svg_window(
+        &self,
+        s: &mut String,
+        theme: &Theme,
+        rect: PixelRect,
+        border: i32,
+        color: Color32,
+        prefix: char,
+        id: WindowId,
+    ) {
+        // Strokes straddle the path, so inset by half to keep the border
+        // inside the border box like the overlays draw it.
+        let stroke = border.max(1);
+        let half = stroke as f32 / 2.0;
+        let _ = writeln!(
+            s,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="{}" stroke-width="{stroke}"/>"#,
+            rect.x().value() as f32 + half,
+            rect.y().value() as f32 + half,
+            (rect.width().value() as f32 - stroke as f32).max(0.0),
+            (rect.height().value() as f32 - stroke as f32).max(0.0),
+            hex(theme.tab_bar_bg),
+            hex(color),
+        );
+        let label = truncate(
+            &self.label(prefix, id),
+            rect.width().value() / SVG_CHAR_WIDTH,
+        );
+        svg_text(s, rect, &label, theme.tab_text);
+    }
+
+    fn svg_container(&self, s: &mut String, theme: &Theme, cp: &ContainerPlacement) {
+        if cp.is_tabbed
+            && let Some(band) = cp.tab_bar_band.clip(self.area)
+        {
+            let band = self.relative(band);
+            let _ = writeln!(
+                s,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                band.x().value(),
+                band.y().value(),
+                band.width().value(),
+                band.height().value(),
+                hex(theme.tab_bar_bg),
+            );
+            let count = cp.titles.len() as i32;
+            let vertical = cp.tab_bar_position.is_vertical();
+            for (i, title) in cp.titles.iter().enumerate() {
+                let i = i as i32;
+                let tab = if vertical {
+                    let (y, h) = split(band.y().value(), band.height().value(), i, count);
+                    PixelRect::new(band.x().value(), y, band.width().value(), h)
+                } else {
+                    let (x, w) = split(band.x().value(), band.width().value(), i, count);
+                    PixelRect::new(x, band.y().value(), w, band.height().value())
+                };
+                if i as usize == cp.active_tab_index {
+                    let _ = writeln!(
+                        s,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                        tab.x().value(),
+                        tab.y().value(),
+                        tab.width().value(),
+                        tab.height().value(),
+                        hex(theme.active_tab_bg),
+                    );
+                }
+                let label = truncate(title, tab.width().value() / SVG_CHAR_WIDTH);
+                svg_text(s, tab, &label, theme.tab_text);
+            }
+        }
+        if cp.is_highlighted {
+            let rect = self.relative(cp.visible_border_box);
+            let stroke = self.placements.border_thickness.value().max(1);
+            let half = stroke as f32 / 2.0;
+            let _ = writeln!(
+                s,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="{}" stroke-width="{stroke}"/>"#,
+                rect.x().value() as f32 + half,
+                rect.y().value() as f32 + half,
+                (rect.width().value() as f32 - stroke as f32).max(0.0),
+                (rect.height().value() as f32 - stroke as f32).max(0.0),
+                hex(theme.focused_border),
+            );
+        }
+    }
+}
+
+fn tiling_color(theme: &Theme, wp: &TilingWindowPlacement) -> Color32 {
+    if wp.is_highlighted {
+        theme.focused_border
+    } else {
+        emphasis_color(theme, wp.emphasis)
+    }
+}
+
+fn float_color(theme: &Theme, wp: &FloatWindowPlacement) -> Color32 {
+    match wp.emphasis {
+        _ if wp.is_highlighted => theme.float_border,
+        WindowEmphasis::None => theme.unfocused_float_border(),
+        emphasis => emphasis_color(theme, emphasis),
+    }
+}
+
+fn emphasis_color(theme: &Theme, emphasis: WindowEmphasis) -> Color32 {
+    match emphasis {
+        WindowEmphasis::InactiveFocus => theme.inactive_focused_border,
+        WindowEmphasis::Urgent => theme.urgent_border,
+        WindowEmphasis::Marked => theme.marked_border,
+        WindowEmphasis::Flash => theme.focus_flash,
+        WindowEmphasis::None => theme.unfocused_border,
+    }
+}
+
+/// Start and length of part `i` of `count` equal parts of `len`, the last
+/// taking the remainder.
+fn split(start: i32, len: i32, i: i32, count: i32) -> (i32, i32) {
+    let part = len / count.max(1);
+    let offset = start + i * part;
+    let size = if i == count - 1 { len - i * part } else { part };
+    (offset, size)
+}
+
+fn svg_text(s: &mut String, rect: PixelRect, text: &str, color: Color32) {
+    if text.is_empty() {
+        return;
+    }
+    let _ = writeln!(
+        s,
+        r#"<text x="{}" y="{}" fill="{}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+        rect.x().value() as f32 + rect.width().value() as f32 / 2.0,
+        rect.y().value() as f32 + rect.height().value() as f32 / 2.0,
+        hex(color),
+        escape(text),
+    );
+}
+
+fn hex(color: Color32) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// First `max` characters of `text`, ending in `~` when cut.
+fn truncate(text: &str, max: i32) -> String {
+    let max = max.max(0) as usize;
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut out: String = text.chars().take(max.saturating_sub(1)).collect();
+    if max > 0 {
+        out.push('~');
+    }
+    out
+}
+
+/// Which edges of `border_box` were cut off to get `visible`, as
+/// `[left, right, top, bottom]`. Clipped edges are not drawn. Integral edges
+/// make these comparisons exact.
+pub(super) fn clip_edges(border_box: PixelRect, visible: PixelRect) -> [bool; 4] {
+    [
+        visible.x() > border_box.x(),
+        visible.right() < border_box.right(),
+        visible.y() > border_box.y(),
+        visible.bottom() < border_box.bottom(),
+    ]
+}
+
+fn put(grid: &mut [Vec<char>], col: isize, row: isize, ch: char) {
+    if row >= 0
+        && col >= 0
+        && let Some(cell) = grid
+            .get_mut(row as usize)
+            .and_then(|r| r.get_mut(col as usize))
+    {
+        *cell = ch;
+    }
+}
+
+/// Corners of `rect` as `(x1, y1, x2, y2)`, inclusive.
+fn corners(rect: PixelRect) -> (isize, isize, isize, isize) {
+    let x1 = rect.x().value() as isize;
+    let y1 = rect.y().value() as isize;
+    (
+        x1,
+        y1,
+        rect.right().value() as isize - 1,
+        rect.bottom().value() as isize - 1,
+    )
+}
+
+fn draw_edges(grid: &mut [Vec<char>], rect: PixelRect, clip: [bool; 4], h: char, v: char) {
+    let (x1, y1, x2, y2) = corners(rect);
+    let [clip_l, clip_r, clip_t, clip_b] = clip;
+    for col in x1..=x2 {
+        if !clip_t {
+            put(grid, col, y1, h);
+        }
+        if !clip_b {
+            put(grid, col, y2, h);
+        }
+    }
+    for row in y1..=y2 {
+        if !clip_l {
+            put(grid, x1, row, v);
+        }
+        if !clip_r {
+            put(grid, x2, row, v);
+        }
+    }
+}
+
+/// Box outline with `label` centered inside, clipped edges left open.
+pub(super) fn draw_rect(grid: &mut [Vec<char>], rect: PixelRect, label: &str, clip: [bool; 4]) {
+    draw_edges(grid, rect, clip, '-', '|');
+    let (x1, y1, x2, y2) = corners(rect);
+    let [clip_l, clip_r, clip_t, clip_b] = clip;
+    for (col, row, clipped) in [
+        (x1, y1, clip_l || clip_t),
+        (x2, y1, clip_r || clip_t),
+        (x1, y2, clip_l || clip_b),
+        (x2, y2, clip_r || clip_b),
+    ] {
+        if !clipped {
+            put(grid, col, row, '+');
+        }
+    }
+
+    let (x, y) = (rect.x().value() as f32, rect.y().value() as f32);
+    let mid_x = (x + rect.width().value() as f32 / 2.0).round() as isize;
+    let mid_y = (y + rect.height().value() as f32 / 2.0).round() as isize;
+    let start_x = mid_x - (label.len() / 2) as isize;
+    for (i, ch) in label.chars().enumerate() {
+        let col = start_x + i as isize;
+        if col > x1 && col < x2 {
+            put(grid, col, mid_y, ch);
+        }
+    }
+}
+
+/// Outline of the focused window or container, in `*`.
+pub(super) fn draw_focused_border(grid: &mut [Vec<char>], rect: PixelRect, clip: [bool; 4]) {
+    draw_edges(grid, rect, clip, '*', '*');
+}
+
+pub(super) fn clear_interior(grid: &mut [Vec<char>], rect: PixelRect) {
+    let (x1, y1, x2, y2) = corners(rect);
+    for row in (y1 + 1)..y2 {
+        for col in (x1 + 1)..x2 {
+            put(grid, col, row, ' ');
+        }
+    }
+}
+
+/// Horizontal tab bar filling `rect`, tabs split evenly and the active one in
+/// brackets.
+pub(super) fn draw_tab_bar(
+    grid: &mut [Vec<char>],
+    rect: PixelRect,
+    labels: &[String],
+    active: usize,
+) {
+    let (x1, y1, x2, y2) = corners(rect);
+    for col in x1..=x2 {
+        put(grid, col, y1, '-');
+    }
+    put(grid, x1, y1, '+');
+    put(grid, x2, y1, '+');
+    for row in (y1 + 1)..=y2 {
+        put(grid, x1, row, '|');
+        put(grid, x2, row, '|');
+    }
+
+    let tab_count = labels.len() as isize;
+    if tab_count == 0 {
+        return;
+    }
+    let label_row = y1 + 1 + (y2 - y1 - 1) / 2;
+    let tab_width = (x2 - x1 - 1) / tab_count;
+    for (i, label) in labels.iter().enumerate() {
+        let i = i as isize;
+        let tab_start = x1 + 1 + i * tab_width;
+        let tab_end = if i == tab_count - 1 {
+            x2 - 1
+        } else {
+            tab_start + tab_width - 1
+        };
+        let display = if i as usize == active {
+            format!("[{label}]")
+        } else {
+            label.clone()
+        };
+        let mid = (tab_start + tab_end) / 2;
+        let label_start = (mid - (display.len() / 2) as isize).max(0);
+        for (j, ch) in display.chars().enumerate() {
+            let col = label_start + j as isize;
+            if col <= tab_end {
+                put(grid, col, label_row, ch);
+            }
+        }
+        if i < tab_count - 1 {
+            for row in (y1 + 1)..=y2 {
+                put(grid, tab_end + 1, row, '|');
+            }
+        }
+    }
+}
+
+/// Vertical tab list filling `rect`, one tab per row, the active one in
+/// brackets.
+fn draw_tab_list(grid: &mut [Vec<char>], rect: PixelRect, labels: &[String], active: usize) {
+    draw_rect(grid, rect, "", [false; 4]);
+    let (x1, y1, x2, y2) = corners(rect);
+    let width = (x2 - x1 - 1) as i32;
+    for (i, label) in labels.iter().enumerate() {
+        let row = y1 + 1 + i as isize;
+        if row >= y2 {
+            break;
+        }
+        let display = if i == active {
+            format!("[{label}]")
+        } else {
+            label.clone()
+        };
+        for (j, ch) in truncate(&display, width).chars().enumerate() {
+            put(grid, x1 + 1 + j as isize, row, ch);
+        }
+    }
+}
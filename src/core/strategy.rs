@@ -4,7 +4,7 @@ use crate::config::{
     LayoutWorkspaceConfig, SizeConstraints, Strategy, TreeLayoutNode, WindowMatcher,
 };
 use crate::core::GlobalLayoutConfig;
-use crate::core::hub::{ContainerPlacement, HubAccess, TilingWindowPlacement};
+use crate::core::hub::{ContainerPlacement, HubAccess, ScrollExtent, TilingWindowPlacement};
 use crate::core::master::MasterStrategy;
 use crate::core::node::{
     Child, Constraints, ContainerId, Dimension, Direction, Length, PixelRect, Pixels, Unit,
@@ -33,10 +33,27 @@ pub(crate) enum TilingAction {
         container_id: ContainerId,
         index: usize,
     },
+    MoveTab {
+        container_id: ContainerId,
+        from: usize,
+        to: usize,
+    },
+    SplitOutTab {
+        container_id: ContainerId,
+        index: usize,
+    },
     GrowMaster,
     ShrinkMaster,
     MoreMaster,
     FewerMaster,
+    /// Pan the viewport by `amount` without moving focus.
+    Scroll {
+        direction: Direction,
+        forward: bool,
+        amount: Length,
+    },
+    /// Center the viewport on the focused window.
+    CenterViewport,
 }
 
 /// Tiling window and container placements collected by the strategy for a
@@ -44,6 +61,7 @@ pub(crate) enum TilingAction {
 pub(crate) struct TilingPlacements {
     pub(crate) windows: Vec<TilingWindowPlacement>,
     pub(crate) containers: Vec<ContainerPlacement>,
+    pub(crate) scroll_extents: Vec<ScrollExtent>,
 }
 
 /// Per-strategy export payload for serialization to layout.toml.
@@ -176,6 +194,12 @@ pub(super) trait ValidateStrategy {
     fn validate(&self, hub: &HubAccess);
 }
 
+/// Offset along one axis that centers a span of `extent` at `origin` on a
+/// screen of `screen_extent`. Callers clamp it to the content.
+pub(crate) fn centered_offset(origin: Length, extent: Length, screen_extent: Length) -> Length {
+    origin + (extent - screen_extent) / 2.0
+}
+
 /// Absorbs the f32 error a constraint accumulates while being distributed.
 #[cfg(test)]
 pub(super) const VALIDATION_TOLERANCE: Length = Length::new(0.01);
@@ -212,7 +236,7 @@ pub(crate) fn window_constraints(
         .maximum_height
         .resolve(screen_height, scale);
 
-    let outset = Length::from_pixels(hub.border(monitor_id) * 2);
+    let outset = Length::from_pixels(hub.window_border(wid, monitor_id) * 2);
     let limits = hub.windows.get(wid).limits();
     // Filter before the outset: a non-positive stored limit is not a limit at all, and outsetting
     // it first would turn it into a spurious `2 * border` cap that collapses the slot.
@@ -365,14 +389,15 @@ pub(super) struct StrategySet {
 impl StrategySet {
     pub(super) fn new(layout: &GlobalLayoutConfig) -> Self {
         let partition_tree = PartitionTreeStrategy::new(
-            layout.partition_tree.tab_bar_height,
-            layout.partition_tree.automatic_tiling,
+            &layout.partition_tree,
             layout.size_constraints,
+            layout.scroll_focus,
         );
         let master = MasterStrategy::new(
             layout.master.master_count,
             layout.master.master_ratio,
             layout.size_constraints,
+            layout.scroll_focus,
         );
         Self {
             partition_tree,
@@ -0,0 +1,55 @@
+//! Tab bar labels: `[[tab_title]]` rewrites and the `tab_title_format` template.
+//!
+//! Labels are built on every placement pass rather than cached, so a title
+//! change or config reload shows up on the next layout without bookkeeping.
+
+use crate::core::{WindowId, hub::HubAccess};
+
+impl HubAccess {
+    /// Label of `window` as the tab at 1-based `index`.
+    pub(super) fn tab_title(&self, window: WindowId, index: usize) -> String {
+        let window = self.windows.get(window);
+        let mut title = window.title().to_string();
+        for rule in &self.layout.tab_title {
+            if window.metadata.matches_window_matcher(&rule.matcher) {
+                title = rule
+                    .pattern
+                    .0
+                    .replace_all(&title, rule.replace.as_str())
+                    .into_owned();
+            }
+        }
+        let app = window.metadata.app_name().unwrap_or_default();
+        format_tab_title(
+            &self.layout.partition_tree.tab_title_format,
+            &app,
+            &title,
+            index,
+        )
+    }
+}
+
+/// Fills the placeholders in one pass, so a title that itself contains
+/// `{index}` is shown as written.
+pub(super) fn format_tab_title(format: &str, app: &str, title: &str, index: usize) -> String {
+    let index = index.to_string();
+    let placeholders = [("{app}", app), ("{title}", title), ("{index}", &index)];
+    let mut out = String::with_capacity(format.len() + title.len());
+    let mut rest = format;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match placeholders.iter().find(|(key, _)| rest.starts_with(key)) {
+            Some((key, value)) => {
+                out.push_str(value);
+                rest = &rest[key.len()..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,161 @@
+use std::time::{Duration, Instant};
+
+use crate::config::{AnimationConfig, Easing};
+use crate::core::hub::{MonitorLayout, VisiblePlacements, WindowEmphasis};
+use crate::core::{
+    Hub, PixelRect, TilingWindowPlacement, WindowId, WindowRestrictions,
+    tests::{LayoutConfigBuilder, default_rect, setup, setup_with_layout, titled},
+};
+
+fn animated(duration_ms: u32) -> Hub {
+    setup_with_layout(
+        LayoutConfigBuilder::new()
+            .with_animation(AnimationConfig {
+                duration_ms,
+                focus_flash_ms: 0,
+                easing: Easing::Linear,
+            })
+            .build(),
+    )
+}
+
+fn insert(hub: &mut Hub, title: &str) -> WindowId {
+    hub.insert_window(titled(title), default_rect(), WindowRestrictions::None)
+        .unwrap()
+}
+
+fn tiling(placements: &VisiblePlacements, id: WindowId) -> TilingWindowPlacement {
+    let MonitorLayout::Normal { tiling_windows, .. } = &placements.monitors[0].layout else {
+        panic!("expected a normal layout");
+    };
+    *tiling_windows.iter().find(|p| p.id == id).unwrap()
+}
+
+fn border_box(placements: &VisiblePlacements, id: WindowId) -> PixelRect {
+    tiling(placements, id).border_box
+}
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn window_glides_to_its_new_rect() {
+    let mut hub = animated(100);
+    let t0 = Instant::now();
+    let w0 = insert(&mut hub, "w0");
+    hub.get_animated_placements(t0);
+    assert_eq!(
+        hub.next_animation_frame(),
+        None,
+        "a new window appears in place"
+    );
+
+    let w1 = insert(&mut hub, "w1");
+    let frame = hub.get_animated_placements(t0);
+    assert_eq!(border_box(&frame, w0), PixelRect::new(0, 0, 150, 30));
+    assert_eq!(border_box(&frame, w1), PixelRect::new(75, 0, 75, 30));
+    assert_eq!(hub.next_animation_frame(), Some(t0 + ms(16)));
+
+    let frame = hub.get_animated_placements(t0 + ms(40));
+    assert_eq!(border_box(&frame, w0), PixelRect::new(0, 0, 120, 30));
+
+    let frame = hub.get_animated_placements(t0 + ms(100));
+    assert_eq!(border_box(&frame, w0), PixelRect::new(0, 0, 75, 30));
+    assert_eq!(hub.next_animation_frame(), None);
+}
+
+#[test]
+fn retarget_mid_flight_starts_from_the_current_frame() {
+    let mut hub = animated(100);
+    let t0 = Instant::now();
+    let w0 = insert(&mut hub, "w0");
+    hub.get_animated_placements(t0);
+    let w1 = insert(&mut hub, "w1");
+    hub.get_animated_placements(t0);
+    hub.get_animated_placements(t0 + ms(40));
+
+    hub.delete_window(w1);
+    let frame = hub.get_animated_placements(t0 + ms(40));
+    assert_eq!(border_box(&frame, w0), PixelRect::new(0, 0, 120, 30));
+    let frame = hub.get_animated_placements(t0 + ms(90));
+    assert_eq!(border_box(&frame, w0), PixelRect::new(0, 0, 135, 30));
+}
+
+#[test]
+fn window_is_animating_until_it_settles() {
+    let mut hub = animated(100);
+    let t0 = Instant::now();
+    let w0 = insert(&mut hub, "w0");
+    hub.get_animated_placements(t0);
+    assert!(!hub.is_animating(w0));
+
+    let w1 = insert(&mut hub, "w1");
+    hub.get_animated_placements(t0 + ms(40));
+    assert!(hub.is_animating(w0));
+    assert!(!hub.is_animating(w1), "a new window appears in place");
+
+    hub.get_animated_placements(t0 + ms(140));
+    assert!(!hub.is_animating(w0));
+}
+
+#[test]
+fn disabled_animation_places_windows_instantly() {
+    let mut hub = animated(0);
+    let t0 = Instant::now();
+    let w0 = insert(&mut hub, "w0");
+    hub.get_animated_placements(t0);
+    insert(&mut hub, "w1");
+    let frame = hub.get_animated_placements(t0);
+    assert_eq!(border_box(&frame, w0), PixelRect::new(0, 0, 75, 30));
+    assert_eq!(hub.next_animation_frame(), None);
+}
+
+#[test]
+fn fullscreen_and_fighting_windows_jump() {
+    let mut hub = animated(100);
+    let t0 = Instant::now();
+    let w0 = insert(&mut hub, "w0");
+    let w1 = insert(&mut hub, "w1");
+    hub.get_animated_placements(t0);
+
+    // Leaving fullscreen does not glide back from the whole screen
+    hub.set_focus(w0);
+    hub.toggle_fullscreen();
+    hub.get_animated_placements(t0);
+    hub.toggle_fullscreen();
+    let frame = hub.get_animated_placements(t0);
+    let target = hub.get_visible_placements();
+    assert_eq!(border_box(&frame, w0), border_box(&target, w0));
+    assert_eq!(hub.next_animation_frame(), None);
+
+    hub.stop_animating(w0);
+    hub.delete_window(w1);
+    let frame = hub.get_animated_placements(t0);
+    assert_eq!(border_box(&frame, w0), PixelRect::new(0, 0, 150, 30));
+    assert_eq!(hub.next_animation_frame(), None);
+}
+
+#[test]
+fn focus_change_flashes_the_new_window() {
+    // Default config: no movement, 200ms flash.
+    let mut hub = setup();
+    let t0 = Instant::now();
+    let w0 = insert(&mut hub, "w0");
+    let w1 = insert(&mut hub, "w1");
+    let frame = hub.get_animated_placements(t0);
+    assert_eq!(tiling(&frame, w1).emphasis, WindowEmphasis::Flash);
+    assert_eq!(tiling(&frame, w0).emphasis, WindowEmphasis::None);
+    assert_eq!(hub.next_animation_frame(), Some(t0 + ms(200)));
+
+    let frame = hub.get_animated_placements(t0 + ms(200));
+    assert_eq!(tiling(&frame, w1).emphasis, WindowEmphasis::None);
+    assert_eq!(hub.next_animation_frame(), None);
+
+    hub.set_focus(w0);
+    let frame = hub.get_animated_placements(t0 + ms(300));
+    assert_eq!(tiling(&frame, w0).emphasis, WindowEmphasis::Flash);
+    // Laying out again without a focus change keeps the flash's end.
+    hub.get_animated_placements(t0 + ms(350));
+    assert_eq!(hub.next_animation_frame(), Some(t0 + ms(500)));
+}
@@ -0,0 +1,131 @@
+use crate::config::BorderRule;
+use crate::core::MonitorLayout;
+use crate::core::node::{PixelRect, Pixels, WindowId, WindowRestrictions};
+use crate::core::tests::{
+    LayoutConfigBuilder, default_rect, setup_with_layout, titled, titled_matcher,
+};
+
+fn layout_borderless(title: &str) -> crate::core::GlobalLayoutConfig {
+    LayoutConfigBuilder::new()
+        .with_float(vec![titled_matcher("/^f/")])
+        .with_border(vec![BorderRule {
+            matcher: titled_matcher(title),
+            size: Pixels::ZERO,
+        }])
+        .build()
+}
+
+/// `(border, border_box, content_box)` per placed window.
+fn boxes(hub: &crate::core::Hub, id: WindowId) -> (Pixels, PixelRect, PixelRect) {
+    hub.get_visible_placements()
+        .monitors
+        .iter()
+        .find_map(|mp| match &mp.layout {
+            MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                ..
+            } => tiling_windows
+                .iter()
+                .find(|wp| wp.id == id)
+                .map(|wp| (wp.border, wp.border_box, wp.content_box))
+                .or_else(|| {
+                    float_windows
+                        .iter()
+                        .find(|wp| wp.id == id)
+                        .map(|wp| (wp.border, wp.border_box, wp.content_box))
+                }),
+            MonitorLayout::Fullscreen(_) => None,
+        })
+        .expect("window is placed")
+}
+
+#[test]
+fn border_rule_drops_border_for_matching_tiling_window() {
+    let mut hub = setup_with_layout(layout_borderless("video"));
+    let plain = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let video = hub
+        .insert_window(titled("video"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+
+    let (border, border_box, content_box) = boxes(&hub, video);
+    assert_eq!(border, Pixels::ZERO);
+    assert_eq!(border_box, content_box);
+
+    let (border, border_box, content_box) = boxes(&hub, plain);
+    assert_eq!(border, Pixels::new(1));
+    assert_eq!(border_box.inset_by(border), content_box);
+}
+
+#[test]
+fn border_rule_applies_to_float_rect_updates() {
+    let mut hub = setup_with_layout(layout_borderless("/^f/"));
+    let f0 = hub
+        .insert_window(
+            titled("f0"),
+            PixelRect::new(100, 2, 40, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    let monitor = hub.focused_monitor();
+    hub.update_float_rect(f0, PixelRect::new(90, 4, 30, 8), monitor);
+
+    let (border, border_box, content_box) = boxes(&hub, f0);
+    assert_eq!(border, Pixels::ZERO);
+    assert_eq!(border_box, PixelRect::new(90, 4, 30, 8));
+    assert_eq!(content_box, border_box);
+}
+
+#[test]
+fn zero_border_size_places_windows_edge_to_edge() {
+    let mut hub = setup_with_layout(
+        LayoutConfigBuilder::new()
+            .with_border_size(Pixels::ZERO)
+            .build(),
+    );
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let (_, left, left_content) = boxes(&hub, w0);
+    let (_, right, right_content) = boxes(&hub, w1);
+    assert_eq!(left, left_content);
+    assert_eq!(right, right_content);
+    assert_eq!(left.x() + left.width(), right.x());
+    hub.validate();
+}
+
+#[test]
+fn border_rule_follows_title_changes() {
+    let mut hub = setup_with_layout(layout_borderless("video"));
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    assert_eq!(boxes(&hub, w0).0, Pixels::new(1));
+
+    hub.set_window_title(w0, "video".to_string());
+    let (border, border_box, content_box) = boxes(&hub, w0);
+    assert_eq!(border, Pixels::ZERO);
+    assert_eq!(border_box, content_box);
+
+    hub.set_window_title(w0, "w0".to_string());
+    assert_eq!(boxes(&hub, w0).0, Pixels::new(1));
+    hub.validate();
+}
+
+#[test]
+fn border_rule_reevaluated_on_config_sync() {
+    let mut hub = setup_with_layout(LayoutConfigBuilder::new().build());
+    let video = hub
+        .insert_window(titled("video"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    assert_eq!(boxes(&hub, video).0, Pixels::new(1));
+
+    hub.sync_configuration(layout_borderless("video"));
+    assert_eq!(boxes(&hub, video).0, Pixels::ZERO);
+    hub.validate();
+}
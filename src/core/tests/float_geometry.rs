@@ -0,0 +1,163 @@
+use insta::assert_snapshot;
+
+use crate::action::{FloatAmount, FloatDirection, FloatResize, SnapPreset};
+use crate::core::Hub;
+use crate::core::node::{DisplayMode, PixelRect, WindowRestrictions};
+use crate::core::tests::{
+    LayoutConfigBuilder, default_rect, setup_with_layout, snapshot_text, titled, titled_matcher,
+};
+
+/// Hub with one float `f0` at (10, 5, 30, 10) over a tiling `w0`.
+fn setup_float() -> Hub {
+    let mut hub = setup_with_layout(
+        LayoutConfigBuilder::new()
+            .with_float(vec![titled_matcher("f0")])
+            .build(),
+    );
+    hub.insert_window(titled("w0"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(
+        titled("f0"),
+        PixelRect::new(10, 5, 30, 10),
+        WindowRestrictions::None,
+    );
+    hub
+}
+
+fn focused_border_box(hub: &Hub) -> PixelRect {
+    let id = hub.focused_window(hub.current_workspace()).unwrap();
+    let DisplayMode::Float { border_box, .. } = hub.access.windows.get(id).mode else {
+        panic!("{id} is not Float");
+    };
+    border_box
+}
+
+#[test]
+fn move_float_by_pixels_and_percent() {
+    let mut hub = setup_float();
+    hub.move_float(FloatDirection::Right, FloatAmount::Pixels(20));
+    hub.move_float(FloatDirection::Down, FloatAmount::Percent(10.0));
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=30.00, y=8.00, w=30.00, h=10.00, float, highlighted)
+      )
+    ");
+}
+
+#[test]
+fn move_float_stops_at_work_area_edge() {
+    let mut hub = setup_float();
+    hub.move_float(FloatDirection::Left, FloatAmount::Pixels(100));
+    hub.move_float(FloatDirection::Down, FloatAmount::Percent(100.0));
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=0.00, y=20.00, w=30.00, h=10.00, float, highlighted)
+      )
+    ");
+}
+
+#[test]
+fn float_amount_past_i32_is_capped_at_the_work_area() {
+    let mut hub = setup_float();
+    hub.move_float(FloatDirection::Left, FloatAmount::Pixels(u32::MAX));
+    hub.resize_float(FloatResize::Grow, FloatAmount::Pixels(u32::MAX));
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=0.00, y=0.00, w=150.00, h=30.00, float, highlighted)
+      )
+    ");
+}
+
+#[test]
+fn resize_float_keeps_center() {
+    let mut hub = setup_float();
+    hub.resize_float(FloatResize::Grow, FloatAmount::Pixels(10));
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=5.00, y=0.00, w=40.00, h=20.00, float, highlighted)
+      )
+    ");
+
+    hub.resize_float(FloatResize::Shrink, FloatAmount::Percent(100.0));
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=23.00, y=8.00, w=3.00, h=3.00, float, highlighted)
+      )
+    ");
+}
+
+#[test]
+fn resize_float_grows_at_most_to_work_area() {
+    let mut hub = setup_float();
+    hub.resize_float(FloatResize::Grow, FloatAmount::Percent(100.0));
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=0.00, y=0.00, w=150.00, h=30.00, float, highlighted)
+      )
+    ");
+}
+
+#[test]
+fn center_float_keeps_size() {
+    let mut hub = setup_float();
+    hub.center_float();
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=60.00, y=10.00, w=30.00, h=10.00, float, highlighted)
+      )
+    ");
+}
+
+#[test]
+fn snap_float_presets() {
+    let mut hub = setup_float();
+    let mut rects = Vec::new();
+    for preset in [
+        SnapPreset::LeftHalf,
+        SnapPreset::BottomHalf,
+        SnapPreset::CenterThird,
+        SnapPreset::RightTwoThirds,
+        SnapPreset::TopRight,
+        SnapPreset::Center,
+    ] {
+        hub.snap_float(preset);
+        rects.push(format!("{preset}: {:?}", focused_border_box(&hub)));
+    }
+    assert_snapshot!(rects.join("\n"), @"
+    left-half: PixelRect { x: 0, y: 0, width: 75, height: 30 }
+    bottom-half: PixelRect { x: 0, y: 15, width: 150, height: 15 }
+    center-third: PixelRect { x: 50, y: 0, width: 50, height: 30 }
+    right-two-thirds: PixelRect { x: 50, y: 0, width: 100, height: 30 }
+    top-right: PixelRect { x: 75, y: 0, width: 75, height: 15 }
+    center: PixelRect { x: 30, y: 6, width: 90, height: 18 }
+    ");
+}
+
+#[test]
+fn float_geometry_ignores_tiling_focus() {
+    let mut hub = setup_with_layout(LayoutConfigBuilder::new().build());
+    hub.insert_window(titled("w0"), default_rect(), WindowRestrictions::None);
+    hub.move_float(FloatDirection::Right, FloatAmount::Pixels(20));
+    hub.resize_float(FloatResize::Grow, FloatAmount::Pixels(20));
+    hub.center_float();
+    hub.snap_float(SnapPreset::LeftHalf);
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(0))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00, highlighted, spawn=right)
+      )
+    ");
+}
@@ -0,0 +1,139 @@
+use crate::config::FloatPlacement;
+use crate::core::Hub;
+use crate::core::node::{
+    DisplayMode, PixelRect, Pixels, WindowId, WindowMetadata, WindowRestrictions,
+};
+use crate::core::tests::{
+    LayoutConfigBuilder, TestMetadata, default_rect, setup_with_layout, titled, titled_matcher,
+};
+
+/// `fN` titles float, anything else tiles.
+fn setup_placement(placement: FloatPlacement) -> Hub {
+    setup_with_layout(
+        LayoutConfigBuilder::new()
+            .with_float(vec![titled_matcher("/^f/")])
+            .with_float_placement(placement)
+            .build(),
+    )
+}
+
+fn app_window(title: &str, app: &str) -> Box<dyn WindowMetadata> {
+    Box::new(TestMetadata {
+        title: Some(title.to_owned()),
+        process: Some(app.to_owned()),
+    })
+}
+
+fn border_box(hub: &Hub, id: WindowId) -> PixelRect {
+    let DisplayMode::Float { border_box, .. } = hub.access.windows.get(id).mode else {
+        panic!("{id} is not Float");
+    };
+    border_box
+}
+
+fn insert(hub: &mut Hub, metadata: Box<dyn WindowMetadata>, rect: PixelRect) -> WindowId {
+    hub.insert_window(metadata, rect, WindowRestrictions::None)
+        .unwrap()
+}
+
+#[test]
+fn keep_leaves_float_where_it_was() {
+    let mut hub = setup_placement(FloatPlacement::Keep);
+    let f0 = insert(&mut hub, titled("f0"), PixelRect::new(10, 5, 30, 10));
+    assert_eq!(border_box(&hub, f0), PixelRect::new(10, 5, 30, 10));
+}
+
+#[test]
+fn every_policy_keeps_float_inside_work_area() {
+    for placement in [
+        FloatPlacement::Keep,
+        FloatPlacement::Center,
+        FloatPlacement::Parent,
+        FloatPlacement::Cascade,
+        FloatPlacement::Cursor,
+    ] {
+        let mut hub = setup_placement(placement);
+        hub.set_cursor_position(Some((Pixels::new(149), Pixels::new(29))));
+        let f0 = insert(&mut hub, titled("f0"), PixelRect::new(140, -5, 200, 20));
+        let placed = border_box(&hub, f0);
+        assert_eq!(
+            placed.clip(PixelRect::new(0, 0, 150, 30)),
+            Some(placed),
+            "{placement:?}"
+        );
+    }
+}
+
+#[test]
+fn center_places_float_on_monitor_center() {
+    let mut hub = setup_placement(FloatPlacement::Center);
+    let f0 = insert(&mut hub, titled("f0"), PixelRect::new(0, 0, 30, 10));
+    assert_eq!(border_box(&hub, f0), PixelRect::new(60, 10, 30, 10));
+}
+
+#[test]
+fn toggle_float_applies_placement() {
+    let mut hub = setup_placement(FloatPlacement::Center);
+    let w0 = insert(&mut hub, titled("w0"), default_rect());
+    insert(&mut hub, titled("w1"), default_rect());
+    hub.set_focus(w0);
+    hub.toggle_float();
+    assert_eq!(border_box(&hub, w0), PixelRect::new(37, 0, 75, 30));
+}
+
+#[test]
+fn parent_centers_over_focused_window_of_same_app() {
+    let mut hub = setup_placement(FloatPlacement::Parent);
+    insert(&mut hub, app_window("w0", "editor"), default_rect());
+    insert(&mut hub, app_window("w1", "browser"), default_rect());
+    // w1 (browser) is focused, so the dialog looks for another editor window.
+    let f2 = insert(
+        &mut hub,
+        app_window("f2", "editor"),
+        PixelRect::new(0, 0, 20, 10),
+    );
+    assert_eq!(border_box(&hub, f2), PixelRect::new(27, 10, 20, 10));
+
+    // Now the editor dialog is focused and a second one centers over it.
+    let f3 = insert(
+        &mut hub,
+        app_window("f3", "editor"),
+        PixelRect::new(100, 0, 10, 4),
+    );
+    assert_eq!(border_box(&hub, f3), PixelRect::new(32, 13, 10, 4));
+}
+
+#[test]
+fn parent_without_same_app_window_centers_on_monitor() {
+    let mut hub = setup_placement(FloatPlacement::Parent);
+    insert(&mut hub, app_window("w0", "browser"), default_rect());
+    let f1 = insert(
+        &mut hub,
+        app_window("f1", "editor"),
+        PixelRect::new(0, 0, 30, 10),
+    );
+    assert_eq!(border_box(&hub, f1), PixelRect::new(60, 10, 30, 10));
+}
+
+#[test]
+fn cascade_steps_from_topmost_float_and_wraps() {
+    let mut hub = setup_placement(FloatPlacement::Cascade);
+    let f0 = insert(&mut hub, titled("f0"), PixelRect::new(0, 0, 20, 6));
+    assert_eq!(border_box(&hub, f0), PixelRect::new(65, 12, 20, 6));
+    let f1 = insert(&mut hub, titled("f1"), PixelRect::new(0, 0, 20, 6));
+    // The test monitor is too small for a 32px step, so it wraps to the corner.
+    assert_eq!(border_box(&hub, f1), PixelRect::new(0, 0, 20, 6));
+}
+
+#[test]
+fn cursor_centers_under_pointer() {
+    let mut hub = setup_placement(FloatPlacement::Cursor);
+    hub.set_cursor_position(Some((Pixels::new(40), Pixels::new(15))));
+    let f0 = insert(&mut hub, titled("f0"), PixelRect::new(0, 0, 20, 10));
+    assert_eq!(border_box(&hub, f0), PixelRect::new(30, 10, 20, 10));
+
+    // A pointer off this monitor falls back to centering.
+    hub.set_cursor_position(Some((Pixels::new(400), Pixels::new(15))));
+    let f1 = insert(&mut hub, titled("f1"), PixelRect::new(0, 0, 20, 10));
+    assert_eq!(border_box(&hub, f1), PixelRect::new(65, 10, 20, 10));
+}
@@ -688,7 +688,7 @@ fn delete_float_keeps_workspace_alive() {
 }
 
 #[test]
-fn insert_float_offscreen_does_not_scroll_viewport() {
+fn insert_float_offscreen_is_clamped_into_work_area() {
     let mut hub = setup_with_layout(layout_floating(&["w25"]));
     let _w0 = hub
         .insert_window(titled("w24"), default_rect(), WindowRestrictions::None)
@@ -705,6 +705,7 @@ fn insert_float_offscreen_does_not_scroll_viewport() {
     Hub(focused=WindowId(1))
       Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
         Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=120.00, y=5.00, w=30.00, h=20.00, float, highlighted)
       )
 
     +----------------------------------------------------------------------------------------------------------------------------------------------------+
@@ -712,26 +713,26 @@ fn insert_float_offscreen_does_not_scroll_viewport() {
     |                                                                                                                                                    |
     |                                                                                                                                                    |
     |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                         W0                                                                         |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
-    |                                                                                                                                                    |
+    |                                                                                                                       ******************************
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                         W0                                            *             F1             *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       *                            *
+    |                                                                                                                       ******************************
     |                                                                                                                                                    |
     |                                                                                                                                                    |
     |                                                                                                                                                    |
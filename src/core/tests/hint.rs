@@ -0,0 +1,68 @@
+use crate::core::hint::hint_labels;
+use crate::core::node::WindowRestrictions;
+use crate::core::tests::{default_rect, setup, titled};
+use crate::core::{HintPress, Hub};
+
+fn placed_labels(hub: &Hub) -> Vec<String> {
+    hub.get_visible_placements()
+        .monitors
+        .iter()
+        .flat_map(|mp| mp.hints.iter().map(|h| h.label.clone()))
+        .collect()
+}
+
+#[test]
+fn hint_labels_are_prefix_free() {
+    assert_eq!(hint_labels(2, &['a', 'b', 'c']), ["a", "b"]);
+    assert_eq!(hint_labels(4, &['a', 'b']), ["aa", "ab", "ba", "bb"]);
+    assert_eq!(hint_labels(3, &['a', 'b']), ["b", "aa", "ab"]);
+    assert!(hint_labels(0, &['a']).is_empty());
+}
+
+#[test]
+fn show_hints_labels_visible_windows_left_to_right() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_workspace("web");
+    hub.insert_window(titled("hidden"), default_rect(), WindowRestrictions::None);
+    hub.focus_workspace("0");
+
+    let mut session = hub.show_hints().unwrap();
+    assert_eq!(placed_labels(&hub), ["a", "s"]);
+    assert_eq!(session.press('s'), HintPress::Chosen(w1));
+
+    hub.focus_window(w0);
+    hub.hide_hints();
+    assert!(placed_labels(&hub).is_empty());
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+}
+
+#[test]
+fn show_hints_without_windows_shows_nothing() {
+    let mut hub = setup();
+    assert!(hub.show_hints().is_none());
+}
+
+#[test]
+fn closed_window_loses_its_hint_and_is_not_focused() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let mut session = hub.show_hints().unwrap();
+    hub.delete_window(w0);
+    assert_eq!(placed_labels(&hub), ["s"]);
+
+    // The keymap's session still holds the stale label.
+    assert_eq!(session.press('a'), HintPress::Chosen(w0));
+    hub.focus_window(w0);
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w1));
+}
@@ -0,0 +1,110 @@
+use crate::core::LifecycleEvent;
+use crate::core::node::{PixelRect, WindowRestrictions};
+use crate::core::tests::{default_rect, setup, titled};
+
+fn describe(events: Vec<LifecycleEvent>) -> Vec<String> {
+    events
+        .into_iter()
+        .map(|e| match e {
+            LifecycleEvent::WindowCreated {
+                id,
+                workspace,
+                metadata,
+            } => format!(
+                "created {id} {} on {workspace}",
+                metadata.title().unwrap_or("")
+            ),
+            LifecycleEvent::WindowDestroyed {
+                id,
+                workspace,
+                metadata,
+            } => format!(
+                "destroyed {id} {} on {workspace:?}",
+                metadata.title().unwrap_or("")
+            ),
+            LifecycleEvent::WorkspaceFocused { name } => format!("focused {name}"),
+            LifecycleEvent::MonitorConnected { name } => format!("connected {name}"),
+            LifecycleEvent::MonitorDisconnected { name } => format!("disconnected {name}"),
+        })
+        .collect()
+}
+
+#[test]
+fn first_drain_reports_startup_state() {
+    let mut hub = setup();
+    assert_eq!(
+        describe(hub.take_lifecycle_events()),
+        ["connected primary", "focused 0"]
+    );
+    assert!(hub.take_lifecycle_events().is_empty());
+}
+
+#[test]
+fn window_created_and_destroyed_carry_workspace_and_metadata() {
+    let mut hub = setup();
+    hub.take_lifecycle_events();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.delete_window(w0);
+    assert_eq!(
+        describe(hub.take_lifecycle_events()),
+        [
+            "created WindowId(0) w0 on 0",
+            "destroyed WindowId(0) w0 on Some(\"0\")"
+        ]
+    );
+}
+
+#[test]
+fn minimized_window_destroyed_without_workspace() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.minimize_window(w0);
+    hub.take_lifecycle_events();
+    hub.delete_window(w0);
+    assert_eq!(
+        describe(hub.take_lifecycle_events()),
+        ["destroyed WindowId(0) w0 on None"]
+    );
+}
+
+#[test]
+fn workspace_focus_reported_once_per_change() {
+    let mut hub = setup();
+    hub.take_lifecycle_events();
+    hub.focus_workspace("web");
+    hub.focus_workspace("0");
+    hub.focus_workspace("web");
+    assert_eq!(describe(hub.take_lifecycle_events()), ["focused web"]);
+    hub.focus_workspace("web");
+    assert!(hub.take_lifecycle_events().is_empty());
+}
+
+#[test]
+fn workspace_focus_round_trip_between_drains_is_silent() {
+    let mut hub = setup();
+    hub.take_lifecycle_events();
+    hub.focus_workspace("web");
+    hub.focus_workspace("0");
+    assert!(hub.take_lifecycle_events().is_empty());
+}
+
+#[test]
+fn monitor_connect_and_disconnect_reported_by_name() {
+    let mut hub = setup();
+    let primary = hub.focused_monitor();
+    hub.take_lifecycle_events();
+    let m1 = hub.add_monitor(
+        "monitor-1".to_string(),
+        PixelRect::new(150, 0, 100, 30),
+        1.0,
+    );
+    hub.remove_monitor(m1, primary);
+    assert_eq!(
+        describe(hub.take_lifecycle_events()),
+        ["connected monitor-1", "disconnected monitor-1"]
+    );
+}
@@ -0,0 +1,285 @@
+use insta::assert_snapshot;
+
+use crate::action::MonitorTarget;
+use crate::core::MonitorLayout;
+use crate::core::WindowEmphasis;
+use crate::core::dto::TreeWindowMode;
+use crate::core::node::{PixelRect, WindowRestrictions};
+use crate::core::tests::{
+    LayoutConfigBuilder, default_rect, setup, setup_with_layout, snapshot_text, titled,
+    titled_matcher,
+};
+
+#[test]
+fn focus_mark_switches_workspace() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("editor");
+    hub.focus_workspace("web");
+    hub.insert_window(titled("w1"), default_rect(), WindowRestrictions::None);
+
+    hub.focus_mark("editor");
+    assert_eq!(
+        hub.current_workspace(),
+        hub.access.windows.get(w0).workspace().unwrap()
+    );
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+}
+
+#[test]
+fn focus_mark_switches_monitor() {
+    let mut hub = setup();
+    let primary = hub.focused_monitor();
+    let m1 = hub.add_monitor(
+        "monitor-1".to_string(),
+        PixelRect::new(150, 0, 100, 30),
+        1.0,
+    );
+    hub.focus_monitor(&MonitorTarget::Name("monitor-1".to_string()));
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("chat");
+    hub.focus_monitor(&MonitorTarget::Name("primary".to_string()));
+    assert_eq!(hub.focused_monitor(), primary);
+
+    hub.focus_mark("chat");
+    assert_eq!(hub.focused_monitor(), m1);
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+}
+
+fn tiling_emphasis(hub: &crate::core::Hub) -> Vec<(crate::core::WindowId, WindowEmphasis)> {
+    hub.get_visible_placements()
+        .monitors
+        .iter()
+        .flat_map(|mp| match &mp.layout {
+            MonitorLayout::Normal { tiling_windows, .. } => tiling_windows
+                .iter()
+                .map(|wp| (wp.id, wp.emphasis))
+                .collect(),
+            MonitorLayout::Fullscreen(_) => Vec::new(),
+        })
+        .collect()
+}
+
+#[test]
+fn marked_window_placement_is_emphasized() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("editor");
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let emphasis = tiling_emphasis(&hub);
+    assert!(emphasis.contains(&(w0, WindowEmphasis::Marked)));
+    assert!(emphasis.contains(&(w1, WindowEmphasis::None)));
+}
+
+#[test]
+fn focused_window_on_unfocused_monitor_is_emphasized() {
+    let mut hub = setup();
+    hub.add_monitor(
+        "monitor-1".to_string(),
+        PixelRect::new(150, 0, 100, 30),
+        1.0,
+    );
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("editor");
+    hub.focus_monitor(&MonitorTarget::Name("monitor-1".to_string()));
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let emphasis = tiling_emphasis(&hub);
+    // Focus outranks the mark on the monitor that lost focus.
+    assert!(emphasis.contains(&(w0, WindowEmphasis::InactiveFocus)));
+    assert!(emphasis.contains(&(w1, WindowEmphasis::None)));
+}
+
+#[test]
+fn unknown_mark_is_ignored() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_mark("missing");
+    hub.move_focused_to_mark("missing");
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+}
+
+#[test]
+fn reusing_a_mark_moves_it() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("a");
+    hub.mark_focused("b");
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("a");
+    assert_eq!(hub.window_marks(w0), ["b"]);
+    assert_eq!(hub.window_marks(w1), ["a"]);
+}
+
+#[test]
+fn marks_survive_move_and_minimize() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("editor");
+    hub.move_focused_to_workspace("web");
+    assert_eq!(hub.window_marks(w0), ["editor"]);
+
+    hub.minimize_window(w0);
+    hub.focus_mark("editor");
+    assert_eq!(hub.focused_window(hub.current_workspace()), None);
+    assert_eq!(hub.query_tree().minimized[0].marks, ["editor"]);
+
+    hub.unminimize_window(w0);
+    hub.focus_workspace("web");
+    hub.focus_mark("editor");
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+}
+
+#[test]
+fn delete_window_clears_marks() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("editor");
+    hub.delete_window(w0);
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_mark("editor");
+    assert!(hub.window_marks(w1).is_empty());
+    assert!(hub.marks.is_empty());
+}
+
+#[test]
+fn move_to_mark_inserts_next_to_marked_window() {
+    let mut hub = setup();
+    hub.insert_window(titled("w0"), default_rect(), WindowRestrictions::None);
+    hub.mark_focused("anchor");
+    hub.insert_window(titled("w1"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(titled("w2"), default_rect(), WindowRestrictions::None);
+
+    hub.move_focused_to_mark("anchor");
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(2))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(1), x=100.00, y=0.00, w=50.00, h=30.00)
+        Window(id=WindowId(2), x=50.00, y=0.00, w=50.00, h=30.00, highlighted, spawn=right)
+        Window(id=WindowId(0), x=0.00, y=0.00, w=50.00, h=30.00)
+        Container(id=ContainerId(0), x=0.00, y=0.00, w=150.00, h=30.00, titles=[w0, w2, w1])
+      )
+    ");
+}
+
+#[test]
+fn move_to_mark_across_workspaces_keeps_current_workspace() {
+    let mut hub = setup();
+    hub.focus_workspace("web");
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.mark_focused("anchor");
+    hub.focus_workspace("0");
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let ws0 = hub.current_workspace();
+
+    hub.move_focused_to_mark("anchor");
+    assert_eq!(hub.current_workspace(), ws0);
+    assert_eq!(hub.focused_window(ws0), None);
+    let web = hub.access.windows.get(w0).workspace().unwrap();
+    assert_eq!(hub.access.windows.get(w1).workspace(), Some(web));
+    assert_eq!(hub.focused_window(web), Some(w1));
+}
+
+#[test]
+fn move_float_to_mark_keeps_float() {
+    let mut hub = setup_with_layout(
+        LayoutConfigBuilder::new()
+            .with_float(vec![titled_matcher("f0")])
+            .build(),
+    );
+    hub.focus_workspace("web");
+    hub.insert_window(titled("w0"), default_rect(), WindowRestrictions::None);
+    hub.mark_focused("anchor");
+    hub.focus_workspace("0");
+    let f0 = hub
+        .insert_window(
+            titled("f0"),
+            PixelRect::new(10, 5, 40, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+
+    hub.move_focused_to_mark("anchor");
+    let tree = hub.query_tree();
+    let web = &tree.monitors[0].workspaces[1];
+    assert_eq!(web.name, "web");
+    assert_eq!(web.windows[1].id, f0);
+    assert_eq!(web.windows[1].mode, TreeWindowMode::Float);
+}
+
+#[test]
+fn query_tree_lists_marks() {
+    let mut hub = setup();
+    hub.insert_window(titled("w0"), default_rect(), WindowRestrictions::None);
+    hub.mark_focused("b");
+    hub.mark_focused("a");
+    hub.insert_window(titled("w1"), default_rect(), WindowRestrictions::None);
+    assert_snapshot!(serde_json::to_string_pretty(&hub.query_tree()).unwrap(), @r#"
+    {
+      "monitors": [
+        {
+          "name": "primary",
+          "is_focused": true,
+          "workspaces": [
+            {
+              "name": "0",
+              "is_focused": true,
+              "is_visible": true,
+              "windows": [
+                {
+                  "id": 0,
+                  "title": "w0",
+                  "app_name": null,
+                  "mode": "tiling",
+                  "is_focused": false,
+                  "is_sticky": false,
+                  "marks": [
+                    "a",
+                    "b"
+                  ]
+                },
+                {
+                  "id": 1,
+                  "title": "w1",
+                  "app_name": null,
+                  "mode": "tiling",
+                  "is_focused": true,
+                  "is_sticky": false,
+                  "marks": []
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "minimized": []
+    }
+    "#);
+}
@@ -1,6 +1,7 @@
+use crate::action::{FloatAmount, ScrollDirection};
 use crate::config::{MasterConfig, SizeConstraint, Strategy};
 use crate::core::WindowRestrictions;
-use crate::core::node::{Length, LimitObservation, LimitUpdate, Pixels};
+use crate::core::node::{Length, LimitObservation, LimitUpdate, PixelRect, Pixels};
 use crate::core::strategy::TilingAction;
 use crate::core::tests::{LayoutConfigBuilder, TestHubBuilder, default_rect, snapshot, titled};
 use insta::assert_snapshot;
@@ -1276,3 +1277,94 @@ fn apply_config_relayouts_and_clamps_scroll() {
     +-------------------------------------------------------------------------+***************************************************************************
     ");
 }
+
+#[test]
+fn only_the_overflowing_pane_reports_a_scroll_extent() {
+    let mut hub = TestHubBuilder::new()
+        .with_layout(
+            LayoutConfigBuilder::new()
+                .with_strategy(Strategy::Master)
+                .with_master_config(MasterConfig {
+                    master_ratio: 0.5,
+                    master_count: 2,
+                })
+                .build(),
+        )
+        .build();
+    let ids: Vec<_> = (0..4)
+        .map(|i| {
+            hub.insert_window(
+                titled(&format!("w{i}")),
+                default_rect(),
+                WindowRestrictions::None,
+            )
+            .unwrap()
+        })
+        .collect();
+    for &w in &ids[2..] {
+        hub.set_window_constraint(
+            w,
+            LimitObservation {
+                min_height: LimitUpdate::Set(Length::new(20.0)),
+                ..Default::default()
+            },
+        );
+    }
+    hub.set_focus(ids[2]);
+
+    let extents = &hub.get_visible_placements().monitors[0].scroll_extents;
+    assert_eq!(extents.len(), 1);
+    assert_eq!(extents[0].viewport, PixelRect::new(75, 0, 75, 30));
+    assert_eq!(extents[0].content, PixelRect::new(75, 0, 75, 44));
+    assert_eq!(extents[0].windows.len(), 2);
+}
+
+#[test]
+fn scroll_pans_the_focused_pane_without_moving_focus() {
+    let mut hub = TestHubBuilder::new()
+        .with_layout(
+            LayoutConfigBuilder::new()
+                .with_strategy(Strategy::Master)
+                .with_master_config(MasterConfig {
+                    master_ratio: 0.5,
+                    master_count: 2,
+                })
+                .build(),
+        )
+        .build();
+    let ids: Vec<_> = (0..4)
+        .map(|i| {
+            hub.insert_window(
+                titled(&format!("w{i}")),
+                default_rect(),
+                WindowRestrictions::None,
+            )
+            .unwrap()
+        })
+        .collect();
+    for &w in &ids[2..] {
+        hub.set_window_constraint(
+            w,
+            LimitObservation {
+                min_height: LimitUpdate::Set(Length::new(20.0)),
+                ..Default::default()
+            },
+        );
+    }
+    hub.set_focus(ids[2]);
+    let content_y = |hub: &crate::core::Hub| {
+        hub.get_visible_placements().monitors[0].scroll_extents[0]
+            .content
+            .y()
+    };
+
+    // Master panes never scroll sideways
+    hub.scroll_viewport(ScrollDirection::Right, Some(FloatAmount::Pixels(5)));
+    assert_eq!(content_y(&hub), Pixels::new(0));
+    hub.scroll_viewport(ScrollDirection::Down, Some(FloatAmount::Percent(100.0)));
+    assert_eq!(content_y(&hub), Pixels::new(-14), "clamped to the content");
+    assert_eq!(hub.get_visible_placements().focused_window, Some(ids[2]));
+
+    hub.center_viewport();
+    assert_eq!(content_y(&hub), Pixels::new(0));
+}
@@ -1,29 +1,45 @@
+mod animation;
+mod border;
 mod export;
+mod float_geometry;
+mod float_placement;
 mod float_window;
 mod focus_workspace;
 mod fullscreen;
+mod hint;
+mod lifecycle;
+mod mark;
 mod master;
 mod minimize;
 mod monitor;
 mod move_to_workspace;
+mod overview;
 mod partition_tree;
 mod pixel_rect;
 mod preferred_layout;
 mod query;
+mod render;
 mod set_focus;
 mod smoke;
+mod sticky;
 mod strategy_switch;
+mod tab_title;
+mod urgent;
 
 use std::collections::HashSet;
 
 use crate::config::{
-    LayoutWorkspaceConfig, MasterConfig, PartitionTreeConfig, SizeConstraint, SizeConstraints,
-    Strategy, TreeLayoutNode, WindowMatcher,
+    AnimationConfig, BorderRule, FloatPlacement, LayoutWorkspaceConfig, MasterConfig,
+    PartitionTreeConfig, ScrollFocus, SizeConstraint, SizeConstraints, Strategy, TabBarPosition,
+    TitleRewrite, TreeLayoutNode, WindowMatcher,
 };
 use crate::core::GlobalLayoutConfig;
 use crate::core::allocator::NodeId;
 use crate::core::hub::{Hub, MonitorLayout, SpawnIndicator};
 use crate::core::node::{Direction, Logical, Pixels, WindowId};
+use crate::core::render::{
+    clear_interior, clip_edges, draw_focused_border, draw_rect, draw_tab_bar,
+};
 use crate::core::strategy::TilingAction;
 use crate::core::{
     ContainerPlacement, FloatWindowPlacement, PixelRect, TilingWindowPlacement, WindowMetadata,
@@ -32,6 +48,7 @@ use crate::core::{
 const ASCII_WIDTH: usize = 150;
 const ASCII_HEIGHT: usize = 30;
 const TAB_BAR_HEIGHT: i32 = 2;
+const TAB_SIDEBAR_WIDTH: i32 = 10;
 const BORDER_SIZE: i32 = 1;
 
 pub(super) fn snapshot(hub: &Hub) -> String {
@@ -76,7 +93,13 @@ pub(super) fn snapshot(hub: &Hub) -> String {
     for cp in containers {
         if cp.is_tabbed {
             let d = cp.visible_border_box;
-            draw_tab_bar(&mut grid, d, &cp.titles, cp.active_tab_index);
+            let band = PixelRect::new(
+                d.x().value(),
+                d.y().value(),
+                d.width().value(),
+                TAB_BAR_HEIGHT,
+            );
+            draw_tab_bar(&mut grid, band, &cp.titles, cp.active_tab_index);
         }
     }
 
@@ -96,17 +119,7 @@ pub(super) fn snapshot(hub: &Hub) -> String {
     for wp in float_windows {
         let d = wp.visible_border_box;
         let clip = clip_edges(wp.border_box, wp.visible_border_box);
-        let grid_w = grid[0].len() as isize;
-        let grid_h = grid.len() as isize;
-        let x1 = d.x().value() as isize;
-        let y1 = d.y().value() as isize;
-        let x2 = d.right().value() as isize - 1;
-        let y2 = d.bottom().value() as isize - 1;
-        for row in (y1 + 1).max(0)..y2.min(grid_h) {
-            for col in (x1 + 1).max(0)..x2.min(grid_w) {
-                grid[row as usize][col as usize] = ' ';
-            }
-        }
+        clear_interior(&mut grid, d);
         draw_rect(&mut grid, d, &format!("F{}", wp.id.get()), clip);
     }
 
@@ -259,191 +272,6 @@ fn fmt_container_placement(cp: &ContainerPlacement) -> String {
     parts
 }
 
-#[expect(
-    clippy::needless_range_loop,
-    reason = "grid indexing requires row/col indices"
-)]
-fn draw_tab_bar(grid: &mut [Vec<char>], rect: PixelRect, labels: &[String], active: usize) {
-    let (x, y, width) = (rect.x().value(), rect.y().value(), rect.width().value());
-    let x1 = x as usize;
-    let y1 = y as usize;
-    let y2 = y1 + TAB_BAR_HEIGHT as usize - 1;
-    let x2 = (x + width) as usize - 1;
-    let inner_width = x2 - x1 - 1;
-    let tab_count = labels.len();
-
-    for col in x1..=x2 {
-        grid[y1][col] = '-';
-    }
-    grid[y1][x1] = '+';
-    grid[y1][x2] = '+';
-
-    for row in (y1 + 1)..=y2 {
-        grid[row][x1] = '|';
-        grid[row][x2] = '|';
-    }
-
-    if tab_count == 0 {
-        return;
-    }
-
-    let label_row = y1 + 1 + (y2 - y1 - 1) / 2;
-    let tab_width = inner_width / tab_count;
-    for (i, label) in labels.iter().enumerate() {
-        let tab_start = x1 + 1 + i * tab_width;
-        let tab_end = if i == tab_count - 1 {
-            x2 - 1
-        } else {
-            tab_start + tab_width - 1
-        };
-        let display = if i == active {
-            format!("[{}]", label)
-        } else {
-            label.clone()
-        };
-        let mid = (tab_start + tab_end) / 2;
-        let label_start = mid.saturating_sub(display.len() / 2);
-        for (j, ch) in display.chars().enumerate() {
-            let col = label_start + j;
-            if col <= tab_end {
-                grid[label_row][col] = ch;
-            }
-        }
-        if i < tab_count - 1 {
-            for row in (y1 + 1)..=y2 {
-                grid[row][tab_end + 1] = '|';
-            }
-        }
-    }
-}
-
-/// Integral edges make these comparisons exact, where the `Dimension` form needed a
-/// half-unit tolerance to avoid reporting a clip that rounding had already removed.
-fn clip_edges(border_box: PixelRect, visible: PixelRect) -> [bool; 4] {
-    [
-        visible.x() > border_box.x(),
-        visible.right() < border_box.right(),
-        visible.y() > border_box.y(),
-        visible.bottom() < border_box.bottom(),
-    ]
-}
-
-fn draw_rect(grid: &mut [Vec<char>], rect: PixelRect, label: &str, clip: [bool; 4]) {
-    let (x, y, w, h) = (
-        rect.x().value(),
-        rect.y().value(),
-        rect.width().value(),
-        rect.height().value(),
-    );
-    let grid_w = grid[0].len() as isize;
-    let grid_h = grid.len() as isize;
-    let [clip_l, clip_r, clip_t, clip_b] = clip;
-
-    let x1 = x as isize;
-    let y1 = y as isize;
-    let x2 = (x + w) as isize - 1;
-    let y2 = (y + h) as isize - 1;
-
-    if !clip_t {
-        for col in x1.max(0)..=x2.min(grid_w - 1) {
-            if y1 >= 0 && y1 < grid_h {
-                grid[y1 as usize][col as usize] = '-';
-            }
-        }
-    }
-    if !clip_b {
-        for col in x1.max(0)..=x2.min(grid_w - 1) {
-            if y2 >= 0 && y2 < grid_h {
-                grid[y2 as usize][col as usize] = '-';
-            }
-        }
-    }
-    if !clip_l {
-        for row in y1.max(0)..=y2.min(grid_h - 1) {
-            if x1 >= 0 && x1 < grid_w {
-                grid[row as usize][x1 as usize] = '|';
-            }
-        }
-    }
-    if !clip_r {
-        for row in y1.max(0)..=y2.min(grid_h - 1) {
-            if x2 >= 0 && x2 < grid_w {
-                grid[row as usize][x2 as usize] = '|';
-            }
-        }
-    }
-    if !clip_l && !clip_t && x1 >= 0 && x1 < grid_w && y1 >= 0 && y1 < grid_h {
-        grid[y1 as usize][x1 as usize] = '+';
-    }
-    if !clip_r && !clip_t && x2 >= 0 && x2 < grid_w && y1 >= 0 && y1 < grid_h {
-        grid[y1 as usize][x2 as usize] = '+';
-    }
-    if !clip_l && !clip_b && x1 >= 0 && x1 < grid_w && y2 >= 0 && y2 < grid_h {
-        grid[y2 as usize][x1 as usize] = '+';
-    }
-    if !clip_r && !clip_b && x2 >= 0 && x2 < grid_w && y2 >= 0 && y2 < grid_h {
-        grid[y2 as usize][x2 as usize] = '+';
-    }
-
-    let mid_x = (x as f32 + w as f32 / 2.0).round() as isize;
-    let mid_y = (y as f32 + h as f32 / 2.0).round() as isize;
-    if mid_y >= 0 && mid_y < grid_h {
-        let start_x = mid_x - (label.len() / 2) as isize;
-        for (i, ch) in label.chars().enumerate() {
-            let col = start_x + i as isize;
-            if col > x1 && col < x2 && col >= 0 && col < grid_w {
-                grid[mid_y as usize][col as usize] = ch;
-            }
-        }
-    }
-}
-
-fn draw_focused_border(grid: &mut [Vec<char>], rect: PixelRect, clip: [bool; 4]) {
-    let (x, y, w, h) = (
-        rect.x().value(),
-        rect.y().value(),
-        rect.width().value(),
-        rect.height().value(),
-    );
-    let grid_w = grid[0].len() as isize;
-    let grid_h = grid.len() as isize;
-    let [clip_l, clip_r, clip_t, clip_b] = clip;
-
-    let x1 = x as isize;
-    let y1 = y as isize;
-    let x2 = (x + w) as isize - 1;
-    let y2 = (y + h) as isize - 1;
-
-    if !clip_t {
-        for col in x1.max(0)..=x2.min(grid_w - 1) {
-            if y1 >= 0 && y1 < grid_h {
-                grid[y1 as usize][col as usize] = '*';
-            }
-        }
-    }
-    if !clip_b {
-        for col in x1.max(0)..=x2.min(grid_w - 1) {
-            if y2 >= 0 && y2 < grid_h {
-                grid[y2 as usize][col as usize] = '*';
-            }
-        }
-    }
-    if !clip_l {
-        for row in y1.max(0)..=y2.min(grid_h - 1) {
-            if x1 >= 0 && x1 < grid_w {
-                grid[row as usize][x1 as usize] = '*';
-            }
-        }
-    }
-    if !clip_r {
-        for row in y1.max(0)..=y2.min(grid_h - 1) {
-            if x2 >= 0 && x2 < grid_w {
-                grid[row as usize][x2 as usize] = '*';
-            }
-        }
-    }
-}
-
 fn validate_hub(hub: &Hub) {
     hub.validate();
     validate_visible_placements(hub);
@@ -695,6 +523,12 @@ struct LayoutConfigBuilder {
     size_constraints: SizeConstraints,
     float: Vec<WindowMatcher>,
     fullscreen: Vec<WindowMatcher>,
+    sticky: Vec<WindowMatcher>,
+    float_placement: FloatPlacement,
+    scroll_focus: ScrollFocus,
+    animation: AnimationConfig,
+    border: Vec<BorderRule>,
+    tab_title: Vec<TitleRewrite>,
 }
 
 impl LayoutConfigBuilder {
@@ -706,10 +540,7 @@ impl LayoutConfigBuilder {
                 master_ratio: 0.5,
                 master_count: 1,
             },
-            partition_tree: PartitionTreeConfig {
-                tab_bar_height: Pixels::new(TAB_BAR_HEIGHT),
-                automatic_tiling: false,
-            },
+            partition_tree: PartitionTreeConfigBuilder::new().build(),
             size_constraints: SizeConstraints {
                 minimum_width: SizeConstraint::Pixels(Pixels::new(1)),
                 minimum_height: SizeConstraint::Pixels(Pixels::new(1)),
@@ -718,6 +549,12 @@ impl LayoutConfigBuilder {
             },
             float: vec![],
             fullscreen: vec![],
+            sticky: vec![],
+            float_placement: FloatPlacement::Keep,
+            scroll_focus: ScrollFocus::Minimal,
+            animation: AnimationConfig::default(),
+            border: vec![],
+            tab_title: vec![],
         }
     }
     fn with_strategy(self, strategy: Strategy) -> Self {
@@ -790,30 +627,70 @@ impl LayoutConfigBuilder {
         Self { fullscreen, ..self }
     }
 
+    fn with_sticky(self, sticky: Vec<WindowMatcher>) -> Self {
+        Self { sticky, ..self }
+    }
+
+    fn with_border(self, border: Vec<BorderRule>) -> Self {
+        Self { border, ..self }
+    }
+
+    fn with_tab_title(self, tab_title: Vec<TitleRewrite>) -> Self {
+        Self { tab_title, ..self }
+    }
+
+    fn with_float_placement(self, float_placement: FloatPlacement) -> Self {
+        Self {
+            float_placement,
+            ..self
+        }
+    }
+
+    fn with_scroll_focus(self, scroll_focus: ScrollFocus) -> Self {
+        Self {
+            scroll_focus,
+            ..self
+        }
+    }
+
+    fn with_animation(self, animation: AnimationConfig) -> Self {
+        Self { animation, ..self }
+    }
+
     fn build(self) -> GlobalLayoutConfig {
         GlobalLayoutConfig {
             strategy: self.strategy,
+            float_placement: self.float_placement,
+            scroll_focus: self.scroll_focus,
+            animation: self.animation,
             border_size: self.border_size,
             partition_tree: self.partition_tree,
             master: self.master,
             size_constraints: self.size_constraints,
             float: self.float,
             fullscreen: self.fullscreen,
+            sticky: self.sticky,
             ignore: Vec::new(),
+            border: self.border,
+            tab_title: self.tab_title,
         }
     }
 }
 
 struct PartitionTreeConfigBuilder {
     tab_bar_height: Pixels<Logical>,
+    tab_bar_position: TabBarPosition,
     automatic_tiling: bool,
+    tab_title_format: String,
 }
 
 impl PartitionTreeConfigBuilder {
     fn new() -> Self {
         Self {
             tab_bar_height: Pixels::new(TAB_BAR_HEIGHT),
+            tab_bar_position: TabBarPosition::Top,
             automatic_tiling: false,
+            tab_title_format: "{title}".to_string(),
         }
     }
 
@@ -824,6 +701,13 @@ impl PartitionTreeConfigBuilder {
         }
     }
 
+    fn with_tab_bar_position(self, tab_bar_position: TabBarPosition) -> Self {
+        Self {
+            tab_bar_position,
+            ..self
+        }
+    }
+
     fn with_automatic_tiling(self, automatic_tiling: bool) -> Self {
         Self {
             automatic_tiling,
@@ -831,10 +715,21 @@ impl PartitionTreeConfigBuilder {
         }
     }
 
+    fn with_tab_title_format(self, tab_title_format: &str) -> Self {
+        Self {
+            tab_title_format: tab_title_format.to_string(),
+            ..self
+        }
+    }
+
     fn build(self) -> PartitionTreeConfig {
         PartitionTreeConfig {
             tab_bar_height: self.tab_bar_height,
+            tab_bar_position: self.tab_bar_position,
+            tab_sidebar_width: Pixels::new(TAB_SIDEBAR_WIDTH),
             automatic_tiling: self.automatic_tiling,
+            tab_title_format: self.tab_title_format,
+            tab_icons: true,
         }
     }
 }
@@ -965,7 +860,7 @@ impl std::fmt::Display for TestMetadata {
 
 impl WindowMetadata for TestMetadata {
     fn app_name(&self) -> Option<String> {
-        None
+        self.process.clone()
     }
     fn title(&self) -> Option<&str> {
         self.title.as_deref()
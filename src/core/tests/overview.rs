@@ -0,0 +1,101 @@
+use crate::core::node::WindowRestrictions;
+use crate::core::tests::{default_rect, setup, titled};
+use crate::core::{HintPress, Hub, OverviewWorkspace, PixelRect};
+
+fn overview(hub: &Hub) -> Vec<OverviewWorkspace> {
+    hub.get_visible_placements().overview
+}
+
+#[test]
+fn overview_diagrams_every_workspace() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.insert_window(titled("w1"), default_rect(), WindowRestrictions::None);
+    hub.focus_workspace("web");
+    let w2 = hub
+        .insert_window(titled("w2"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.toggle_fullscreen();
+    hub.focus_workspace("0");
+
+    assert!(overview(&hub).is_empty());
+    let mut session = hub.show_overview().unwrap();
+    let workspaces = overview(&hub);
+    let summary: Vec<_> = workspaces
+        .iter()
+        .map(|ws| (ws.label.as_str(), ws.name.as_str(), ws.is_focused))
+        .collect();
+    assert_eq!(summary, [("a", "0", true), ("s", "web", false)]);
+
+    let home = &workspaces[0].windows;
+    assert_eq!(home.len(), 2);
+    let first = home.iter().find(|w| w.id == w0).unwrap();
+    assert_eq!(first.title, "w0");
+    assert_eq!(first.frame, PixelRect::new(0, 0, 75, 30));
+    assert!(!first.is_focused);
+    assert!(home.iter().any(|w| w.id != w0 && w.is_focused));
+
+    let web = &workspaces[1].windows;
+    assert_eq!(web.len(), 1);
+    assert_eq!(web[0].id, w2);
+    assert!(web[0].is_fullscreen);
+    assert_eq!(web[0].frame, workspaces[1].area);
+
+    assert_eq!(session.press('s'), HintPress::Chosen("web".to_string()));
+    assert!(hub.hide_overview());
+    assert!(overview(&hub).is_empty());
+}
+
+#[test]
+fn move_window_to_workspace_keeps_current_workspace() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_workspace("web");
+    hub.insert_window(titled("w2"), default_rect(), WindowRestrictions::None);
+    hub.focus_workspace("0");
+    hub.show_overview();
+
+    hub.move_window_to_workspace(w0, "web");
+    let workspaces = overview(&hub);
+    assert!(workspaces[0].is_focused);
+    let ids: Vec<_> = workspaces[0].windows.iter().map(|w| w.id).collect();
+    assert_eq!(ids, [w1]);
+    assert_eq!(workspaces[1].windows.len(), 2);
+    assert!(workspaces[1].windows.iter().any(|w| w.id == w0));
+}
+
+#[test]
+fn move_window_to_workspace_leaves_focus_alone() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_workspace("web");
+    let w2 = hub
+        .insert_window(titled("w2"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_workspace("0");
+    hub.take_lifecycle_events();
+
+    hub.move_window_to_workspace(w0, "web");
+    assert!(hub.take_lifecycle_events().is_empty());
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w1));
+
+    hub.move_window_to_workspace(w2, "0");
+    assert!(hub.take_lifecycle_events().is_empty());
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w1));
+    hub.show_overview();
+    let ids: Vec<_> = overview(&hub)[0].windows.iter().map(|w| w.id).collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&w2));
+}
@@ -1,10 +1,12 @@
 use insta::assert_snapshot;
 
 use crate::{
-    config::SizeConstraint,
+    action::{FloatAmount, ScrollDirection},
+    config::{ScrollFocus, SizeConstraint},
     core::{
-        Length, LimitObservation, LimitUpdate, Pixels, WindowRestrictions,
-        tests::{LayoutConfigBuilder, default_rect, setup, snapshot, titled},
+        Hub, Length, LimitObservation, LimitUpdate, PixelRect, Pixels, WindowId,
+        WindowRestrictions,
+        tests::{LayoutConfigBuilder, default_rect, setup, setup_with_layout, snapshot, titled},
     },
 };
 
@@ -599,3 +601,140 @@ fn laying_out_max_constrained_windows_leaves_no_hole() {
     +----------------------------++----------------------------++--------------------------------------------------+******************************+-------
     ");
 }
+
+#[test]
+fn overflowing_tree_reports_its_scroll_extent() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.toggle_spawn_mode();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    assert!(
+        hub.get_visible_placements().monitors[0]
+            .scroll_extents
+            .is_empty(),
+        "nothing overflows before the constraints"
+    );
+
+    for w in [w0, w1] {
+        hub.set_window_constraint(
+            w,
+            LimitObservation {
+                min_height: LimitUpdate::Set(Length::new(20.0)),
+                ..Default::default()
+            },
+        );
+    }
+    hub.set_focus(w1);
+
+    let extents = &hub.get_visible_placements().monitors[0].scroll_extents;
+    assert_eq!(extents.len(), 1);
+    assert_eq!(extents[0].viewport, PixelRect::new(0, 0, 150, 30));
+    assert_eq!(extents[0].content, PixelRect::new(0, -14, 150, 44));
+    assert_eq!(
+        extents[0].windows,
+        vec![
+            PixelRect::new(0, 8, 150, 22),
+            PixelRect::new(0, -14, 150, 22)
+        ]
+    );
+}
+
+/// Three windows stacked in a 30px tall monitor, each held at 22px including
+/// borders, so the content is 66px tall.
+fn three_stacked(hub: &mut Hub) -> [WindowId; 3] {
+    let windows = ["w0", "w1", "w2"].map(|title| {
+        let w = hub
+            .insert_window(titled(title), default_rect(), WindowRestrictions::None)
+            .unwrap();
+        if title == "w0" {
+            hub.toggle_spawn_mode();
+        }
+        w
+    });
+    for w in windows {
+        hub.set_window_constraint(
+            w,
+            LimitObservation {
+                min_height: LimitUpdate::Set(Length::new(20.0)),
+                ..Default::default()
+            },
+        );
+    }
+    windows
+}
+
+fn content_y(hub: &Hub) -> Pixels {
+    hub.get_visible_placements().monitors[0].scroll_extents[0]
+        .content
+        .y()
+}
+
+#[test]
+fn scroll_pans_without_moving_focus_and_clamps() {
+    let mut hub = setup();
+    let [w0, ..] = three_stacked(&mut hub);
+    hub.set_focus(w0);
+    assert_eq!(content_y(&hub), Pixels::new(0));
+
+    hub.scroll_viewport(ScrollDirection::Down, Some(FloatAmount::Pixels(5)));
+    assert_eq!(content_y(&hub), Pixels::new(-5));
+    // The default step is a tenth of the work area
+    hub.scroll_viewport(ScrollDirection::Down, None);
+    assert_eq!(content_y(&hub), Pixels::new(-8));
+    hub.scroll_viewport(ScrollDirection::Down, Some(FloatAmount::Percent(100.0)));
+    assert_eq!(
+        content_y(&hub),
+        Pixels::new(-36),
+        "stops at the end of the content"
+    );
+    hub.scroll_viewport(ScrollDirection::Up, Some(FloatAmount::Percent(100.0)));
+    hub.scroll_viewport(ScrollDirection::Up, Some(FloatAmount::Percent(100.0)));
+    assert_eq!(
+        content_y(&hub),
+        Pixels::new(0),
+        "stops at the start of the content"
+    );
+
+    // Nothing to pan along the axis that fits
+    hub.scroll_viewport(ScrollDirection::Right, Some(FloatAmount::Pixels(5)));
+    assert_eq!(content_y(&hub), Pixels::new(0));
+    assert_eq!(hub.get_visible_placements().focused_window, Some(w0));
+}
+
+#[test]
+fn scroll_center_brings_focused_window_to_the_middle() {
+    let mut hub = setup();
+    let [w0, w1, _] = three_stacked(&mut hub);
+    hub.set_focus(w0);
+    hub.set_focus(w1);
+    assert_eq!(
+        content_y(&hub),
+        Pixels::new(-14),
+        "focus scrolls just far enough"
+    );
+
+    hub.center_viewport();
+    assert_eq!(content_y(&hub), Pixels::new(-18));
+}
+
+#[test]
+fn scroll_focus_center_centers_on_focus_change() {
+    let mut hub = setup_with_layout(
+        LayoutConfigBuilder::new()
+            .with_scroll_focus(ScrollFocus::Center)
+            .build(),
+    );
+    let [w0, w1, _] = three_stacked(&mut hub);
+    hub.set_focus(w0);
+    assert_eq!(content_y(&hub), Pixels::new(0), "clamped at the start");
+    hub.set_focus(w1);
+    assert_eq!(content_y(&hub), Pixels::new(-18));
+    // Still fully in view after a small pan, so focusing it again stays put
+    hub.scroll_viewport(ScrollDirection::Up, Some(FloatAmount::Pixels(4)));
+    hub.set_focus(w0);
+    assert_eq!(content_y(&hub), Pixels::new(0));
+}
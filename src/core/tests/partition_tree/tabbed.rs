@@ -1,10 +1,13 @@
-use crate::core::ContainerId;
+use crate::config::TabBarPosition;
 use crate::core::GlobalLayoutConfig;
+use crate::core::MonitorLayout;
 use crate::core::allocator::NodeId;
 use crate::core::node::{Length, LimitObservation, LimitUpdate, PixelRect, WindowRestrictions};
 use crate::core::tests::{
-    LayoutConfigBuilder, default_rect, setup, setup_with_layout, snapshot, titled, titled_matcher,
+    LayoutConfigBuilder, PartitionTreeConfigBuilder, default_rect, setup, setup_with_layout,
+    snapshot, titled, titled_matcher, validate_hub,
 };
+use crate::core::{ContainerId, WindowId};
 use insta::assert_snapshot;
 
 /// Float matchers by exact title, since this file also inserts tiling windows named `wN`.
@@ -1440,6 +1443,170 @@ fn focus_tab_index() {
     assert_eq!(snapshot(&hub), pre);
 }
 
+#[test]
+fn move_tab_keeps_active_tab() {
+    let mut hub = setup();
+
+    hub.insert_window(titled("W0"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(titled("W1"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(titled("W2"), default_rect(), WindowRestrictions::None);
+    hub.toggle_container_layout();
+
+    hub.move_tab(ContainerId::new(0), 2, 0);
+    assert_snapshot!(snapshot(&hub), @"
+    Hub(focused=WindowId(2))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(2), x=0.00, y=2.00, w=150.00, h=28.00, highlighted, spawn=right)
+        Container(id=ContainerId(0), x=0.00, y=0.00, w=150.00, h=30.00, tabbed, active_tab=0, titles=[W2, W0, W1])
+      )
+
+    +----------------------------------------------------------------------------------------------------------------------------------------------------+
+    |                      [W2]                       |                      W0                        |                      W1                         |
+    ******************************************************************************************************************************************************
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                         W2                                                                         *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    *                                                                                                                                                    *
+    ******************************************************************************************************************************************************
+    ");
+
+    let before = snapshot(&hub);
+    hub.move_tab(ContainerId::new(0), 0, 3);
+    hub.move_tab(ContainerId::new(7), 0, 1);
+    assert_eq!(snapshot(&hub), before);
+}
+
+#[test]
+fn split_out_tab_places_it_after_container() {
+    let mut hub = setup();
+
+    hub.insert_window(titled("W0"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(titled("W1"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(titled("W2"), default_rect(), WindowRestrictions::None);
+    hub.toggle_container_layout();
+    hub.focus_tab_index(ContainerId::new(0), 0);
+
+    hub.split_out_tab(ContainerId::new(0), 1);
+    assert_snapshot!(snapshot(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(1), x=75.00, y=0.00, w=75.00, h=30.00, highlighted, spawn=right)
+        Window(id=WindowId(0), x=0.00, y=2.00, w=75.00, h=28.00)
+        Container(id=ContainerId(1), x=0.00, y=0.00, w=150.00, h=30.00, titles=[Container, W1])
+        Container(id=ContainerId(0), x=0.00, y=0.00, w=75.00, h=30.00, tabbed, active_tab=0, titles=[W0, W2])
+      )
+
+    +-------------------------------------------------------------------------+***************************************************************************
+    |               [W0]                 |                W2                  |*                                                                         *
+    +-------------------------------------------------------------------------+*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                    W1                                   *
+    |                                    W0                                   |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    |                                                                         |*                                                                         *
+    +-------------------------------------------------------------------------+***************************************************************************
+    ");
+}
+
+#[test]
+fn split_out_tab_dissolves_two_tab_container() {
+    let mut hub = setup();
+
+    hub.insert_window(titled("W0"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(titled("W1"), default_rect(), WindowRestrictions::None);
+    hub.toggle_spawn_mode();
+    hub.toggle_spawn_mode();
+    hub.insert_window(titled("W2"), default_rect(), WindowRestrictions::None);
+
+    let tabbed = ContainerId::new(1);
+    assert_eq!(hub.tab_window(tabbed, 1), Some(WindowId::new(2)));
+    hub.split_out_tab(tabbed, 1);
+    assert_snapshot!(snapshot(&hub), @"
+    Hub(focused=WindowId(2))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(2), x=100.00, y=0.00, w=50.00, h=30.00, highlighted, spawn=right)
+        Window(id=WindowId(1), x=50.00, y=0.00, w=50.00, h=30.00)
+        Window(id=WindowId(0), x=0.00, y=0.00, w=50.00, h=30.00)
+        Container(id=ContainerId(0), x=0.00, y=0.00, w=150.00, h=30.00, titles=[W0, W1, W2])
+      )
+
+    +------------------------------------------------++------------------------------------------------+**************************************************
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                       W0                       ||                       W1                       |*                       W2                       *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    |                                                ||                                                |*                                                *
+    +------------------------------------------------++------------------------------------------------+**************************************************
+    ");
+    assert_eq!(hub.tab_window(tabbed, 0), None);
+}
+
 #[test]
 fn focus_tab_noop() {
     let mut hub = setup();
@@ -1552,3 +1719,42 @@ fn tab_bar_visible_when_min_height_exceeds_screen() {
     *                                                                                                                                                    *
     ");
 }
+
+#[test]
+fn tab_band_reserves_the_configured_side() {
+    let cases = [
+        (TabBarPosition::Top, (0, 0, 150, 2), (0, 2, 150, 28)),
+        (TabBarPosition::Bottom, (0, 28, 150, 2), (0, 0, 150, 28)),
+        (TabBarPosition::Left, (0, 0, 10, 30), (10, 0, 140, 30)),
+        (TabBarPosition::Right, (140, 0, 10, 30), (0, 0, 140, 30)),
+    ];
+    for (position, band, window) in cases {
+        let mut hub = setup_with_layout(
+            LayoutConfigBuilder::new()
+                .with_partition_tree_config(
+                    PartitionTreeConfigBuilder::new()
+                        .with_tab_bar_position(position)
+                        .build(),
+                )
+                .build(),
+        );
+        hub.insert_window(titled("W0"), default_rect(), WindowRestrictions::None);
+        hub.insert_window(titled("W1"), default_rect(), WindowRestrictions::None);
+        hub.toggle_container_layout();
+        validate_hub(&hub);
+
+        let placements = hub.get_visible_placements();
+        let MonitorLayout::Normal {
+            tiling_windows,
+            containers,
+            ..
+        } = &placements.monitors[0].layout
+        else {
+            panic!("expected a normally tiled monitor");
+        };
+        let rect = |(x, y, w, h)| PixelRect::new(x, y, w, h);
+        assert_eq!(containers[0].tab_bar_band, rect(band), "{position:?}");
+        assert_eq!(containers[0].tab_bar_position, position);
+        assert_eq!(tiling_windows[0].border_box, rect(window), "{position:?}");
+    }
+}
@@ -38,3 +38,12 @@ fn adjacent_boxes_still_share_an_edge_after_rounding() {
         "rounding the extents instead of the edges opened a seam"
     );
 }
+
+#[test]
+fn union_covers_both_rects_including_the_gap_between() {
+    let a: PixelRect = PixelRect::new(0, 0, 10, 10);
+    let b = PixelRect::new(20, -5, 5, 5);
+
+    assert_eq!(a.union(b), PixelRect::new(0, -5, 25, 15));
+    assert_eq!(b.union(a), a.union(b));
+}
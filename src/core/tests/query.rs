@@ -123,12 +123,14 @@ fn workspace_info_json_shape() {
         is_focused: true,
         is_visible: false,
         window_count: 3,
+        urgent: true,
     };
     let v: serde_json::Value = serde_json::to_value(&info).unwrap();
     assert_eq!(v["name"], "main");
     assert_eq!(v["is_focused"], true);
     assert_eq!(v["is_visible"], false);
     assert_eq!(v["window_count"], 3);
+    assert_eq!(v["urgent"], true);
     let back: WorkspaceInfo = serde_json::from_value(v).unwrap();
     assert_eq!(back, info);
 }
@@ -0,0 +1,95 @@
+use insta::assert_snapshot;
+
+use crate::action::RenderFormat;
+use crate::core::node::WindowRestrictions;
+use crate::core::tests::{default_rect, setup, titled};
+
+#[test]
+fn ascii_shows_windows_tabs_and_floats() {
+    let mut hub = setup();
+    hub.insert_window(titled("editor"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.insert_window(titled("docs"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.insert_window(titled("terminal"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.toggle_container_layout();
+    hub.insert_window(titled("calc"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.toggle_float();
+
+    assert_snapshot!(hub.render(RenderFormat::Ascii, None).unwrap(), @r#"
+    workspace "0" on monitor "primary", 150x30
+    +----------------------------------------------------------------------------------------------------------------------+
+    |                editor                 |                doc************************************************************
+    |                                                           *                                                          *
+    |                                                           *                                                          *
+    |                                                           *                                                          *
+    |                                                           *                                                          *
+    |                                                           *                                                          *
+    |                                                      W2 te*                          F3 calc                         *
+    |                                                           *                                                          *
+    |                                                           *                                                          *
+    |                                                           *                                                          *
+    +-----------------------------------------------------------************************************************************
+    W2 0,2 150x28  terminal
+    F3 75,2 75x28  calc
+    "#);
+}
+
+#[test]
+fn ascii_fullscreen_fills_the_picture() {
+    let mut hub = setup();
+    hub.insert_window(titled("video"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.toggle_fullscreen();
+
+    assert_snapshot!(hub.render(RenderFormat::Ascii, None).unwrap(), @r#"
+    workspace "0" on monitor "primary", 150x30
+    +----------------------------------------------------------------------------------------------------------------------+
+    |                                                                                                                      |
+    |                                                                                                                      |
+    |                                                                                                                      |
+    |                                                                                                                      |
+    |                                                                                                                      |
+    |                                                       W0 video                                                       |
+    |                                                                                                                      |
+    |                                                                                                                      |
+    |                                                                                                                      |
+    |                                                                                                                      |
+    +----------------------------------------------------------------------------------------------------------------------+
+    W0 fullscreen  video
+    "#);
+}
+
+#[test]
+fn svg_draws_a_rect_per_window_and_escapes_titles() {
+    let mut hub = setup();
+    hub.insert_window(titled("<a&b>"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+
+    let svg = hub.render(RenderFormat::Svg, None).unwrap();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="150" height="30""#));
+    assert!(svg.ends_with("</svg>\n"));
+    // Background plus one per window.
+    assert_eq!(svg.matches("<rect").count(), 3, "{svg}");
+    assert!(svg.contains(">W0 &lt;a&amp;b&gt;</text>"));
+}
+
+#[test]
+fn render_named_workspace_or_none() {
+    let mut hub = setup();
+    hub.insert_window(titled("mail"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_workspace("web");
+
+    let picture = hub.render(RenderFormat::Ascii, Some("0")).unwrap();
+    assert!(picture.starts_with("workspace \"0\""));
+    assert!(picture.contains("W0 mail"));
+    let current = hub.render(RenderFormat::Ascii, None).unwrap();
+    assert!(current.starts_with("workspace \"web\""));
+    assert!(!current.contains("mail"));
+    assert!(hub.render(RenderFormat::Ascii, Some("missing")).is_none());
+}
@@ -573,3 +573,42 @@ fn detach_non_topmost_keeps_focus() {
     +----------------------------------------------------------------------------------------------------------------------------------------------------+
     ");
 }
+
+#[test]
+fn focus_for_hook_acts_on_the_window_and_restores_focus() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    assert!(hub.focus_for_hook(w1).is_none(), "already focused");
+
+    hub.set_focus(w0);
+    let restore = hub.focus_for_hook(w1).unwrap();
+    hub.move_focused_to_workspace("mail");
+    hub.restore_focus(restore);
+    let ws = hub.current_workspace();
+    assert_eq!(hub.focused_window(ws), Some(w0));
+    let workspaces = hub.query_workspaces();
+    let mail = workspaces.iter().find(|w| w.name == "mail").unwrap();
+    assert_eq!(mail.window_count, 1);
+}
+
+#[test]
+fn restore_focus_keeps_a_workspace_switch_made_by_the_hook() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.set_focus(w0);
+    let restore = hub.focus_for_hook(w1).unwrap();
+    hub.focus_workspace("web");
+    let web = hub.current_workspace();
+    hub.restore_focus(restore);
+    assert_eq!(hub.current_workspace(), web);
+}
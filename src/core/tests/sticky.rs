@@ -0,0 +1,241 @@
+use insta::assert_snapshot;
+
+use crate::action::MonitorTarget;
+use crate::core::GlobalLayoutConfig;
+use crate::core::node::{PixelRect, WindowRestrictions};
+use crate::core::strategy::WorkspaceExport;
+use crate::core::tests::{
+    LayoutConfigBuilder, default_rect, setup_with_layout, snapshot_text, titled, titled_matcher,
+};
+
+/// `pN` titles are sticky, `fN` titles plain floats, anything else tiles.
+fn layout_sticky() -> GlobalLayoutConfig {
+    LayoutConfigBuilder::new()
+        .with_float(vec![titled_matcher("/^f/")])
+        .with_sticky(vec![titled_matcher("/^p/")])
+        .build()
+}
+
+#[test]
+fn sticky_rule_floats_window() {
+    let mut hub = setup_with_layout(layout_sticky());
+    let p0 = hub
+        .insert_window(
+            titled("p0"),
+            PixelRect::new(100, 2, 40, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    let window = hub.access.windows.get(p0);
+    assert!(window.is_float());
+    assert!(window.sticky);
+}
+
+#[test]
+fn sticky_float_follows_workspace_switch() {
+    let mut hub = setup_with_layout(layout_sticky());
+    hub.insert_window(titled("w0"), default_rect(), WindowRestrictions::None);
+    hub.insert_window(
+        titled("p1"),
+        PixelRect::new(100, 2, 40, 10),
+        WindowRestrictions::None,
+    );
+    hub.focus_workspace("web");
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(1), x=100.00, y=2.00, w=40.00, h=10.00, float, highlighted)
+      )
+    ");
+
+    hub.focus_workspace("0");
+    assert_snapshot!(snapshot_text(&hub), @"
+    Hub(focused=WindowId(1))
+      Monitor(id=MonitorId(0), screen=(x=0.00 y=0.00 w=150.00 h=30.00),
+        Window(id=WindowId(0), x=0.00, y=0.00, w=150.00, h=30.00)
+        Window(id=WindowId(1), x=100.00, y=2.00, w=40.00, h=10.00, float, highlighted)
+      )
+    ");
+}
+
+#[test]
+fn sticky_floats_keep_stacking_order_and_leave_plain_floats() {
+    let mut hub = setup_with_layout(layout_sticky());
+    let p0 = hub
+        .insert_window(
+            titled("p0"),
+            PixelRect::new(0, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    let f1 = hub
+        .insert_window(
+            titled("f1"),
+            PixelRect::new(30, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    let p2 = hub
+        .insert_window(
+            titled("p2"),
+            PixelRect::new(60, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    let ws0 = hub.current_workspace();
+
+    hub.focus_workspace("web");
+    let web = hub.current_workspace();
+    assert_eq!(hub.access.workspaces.get(web).float_windows, [p0, p2]);
+    assert_eq!(hub.access.workspaces.get(ws0).float_windows, [f1]);
+    assert_eq!(hub.focused_window(web), Some(p2));
+}
+
+#[test]
+fn arriving_sticky_does_not_steal_float_focus() {
+    let mut hub = setup_with_layout(layout_sticky());
+    hub.insert_window(
+        titled("p0"),
+        PixelRect::new(0, 0, 20, 10),
+        WindowRestrictions::None,
+    );
+    hub.insert_window(titled("w1"), default_rect(), WindowRestrictions::None);
+    hub.focus_workspace("web");
+    let f2 = hub
+        .insert_window(
+            titled("f2"),
+            PixelRect::new(30, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    // Leave the sticky behind on "0", then bring it back to "web".
+    hub.focus_workspace("0");
+    hub.focus_workspace("web");
+    hub.focus_workspace("0");
+    hub.focus_workspace("web");
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(f2));
+}
+
+#[test]
+fn toggle_sticky_only_applies_to_floats() {
+    let mut hub = setup_with_layout(layout_sticky());
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.toggle_sticky();
+    assert!(!hub.access.windows.get(w0).sticky);
+
+    hub.toggle_float();
+    hub.toggle_sticky();
+    assert!(hub.access.windows.get(w0).sticky);
+    hub.focus_workspace("web");
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+
+    hub.toggle_float();
+    assert!(!hub.access.windows.get(w0).sticky);
+}
+
+#[test]
+fn sticky_survives_minimize() {
+    let mut hub = setup_with_layout(layout_sticky());
+    let p0 = hub
+        .insert_window(
+            titled("p0"),
+            PixelRect::new(0, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    hub.minimize_window(p0);
+    hub.focus_workspace("web");
+    hub.unminimize_window(p0);
+    hub.focus_workspace("mail");
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(p0));
+}
+
+#[test]
+fn sticky_follows_only_its_own_monitor() {
+    let mut hub = setup_with_layout(layout_sticky());
+    let p0 = hub
+        .insert_window(
+            titled("p0"),
+            PixelRect::new(0, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    let ws0 = hub.current_workspace();
+    hub.add_monitor(
+        "monitor-1".to_string(),
+        PixelRect::new(150, 0, 100, 30),
+        1.0,
+    );
+    hub.focus_monitor(&MonitorTarget::Name("monitor-1".to_string()));
+    hub.focus_workspace("web");
+    assert_eq!(hub.access.windows.get(p0).workspace(), Some(ws0));
+}
+
+#[test]
+fn sticky_moved_to_another_monitor_follows_that_monitor() {
+    let mut hub = setup_with_layout(layout_sticky());
+    let p0 = hub
+        .insert_window(
+            titled("p0"),
+            PixelRect::new(0, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    hub.add_monitor(
+        "monitor-1".to_string(),
+        PixelRect::new(150, 0, 100, 30),
+        1.0,
+    );
+    hub.move_focused_to_monitor(&MonitorTarget::Name("monitor-1".to_string()));
+    hub.focus_monitor(&MonitorTarget::Name("monitor-1".to_string()));
+    hub.focus_workspace("web");
+    assert!(hub.access.windows.get(p0).sticky);
+    assert_eq!(
+        hub.access.windows.get(p0).workspace(),
+        Some(hub.current_workspace())
+    );
+}
+
+#[test]
+fn removed_monitor_hands_sticky_to_fallback() {
+    let mut hub = setup_with_layout(layout_sticky());
+    let primary = hub.focused_monitor();
+    let m1 = hub.add_monitor(
+        "monitor-1".to_string(),
+        PixelRect::new(150, 0, 100, 30),
+        1.0,
+    );
+    hub.focus_monitor(&MonitorTarget::Name("monitor-1".to_string()));
+    let p0 = hub
+        .insert_window(
+            titled("p0"),
+            PixelRect::new(160, 0, 20, 10),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    hub.remove_monitor(m1, primary);
+    assert_eq!(
+        hub.access.windows.get(p0).workspace(),
+        Some(hub.current_workspace())
+    );
+}
+
+#[test]
+fn export_skips_sticky_floats() {
+    let mut hub = setup_with_layout(layout_sticky());
+    hub.insert_window(
+        titled("p0"),
+        PixelRect::new(0, 0, 20, 10),
+        WindowRestrictions::None,
+    );
+    let ws_id = hub.current_workspace();
+    assert_eq!(
+        hub.export_workspace(ws_id),
+        WorkspaceExport {
+            strategy: "partition_tree".into(),
+            ..WorkspaceExport::default()
+        }
+    );
+}
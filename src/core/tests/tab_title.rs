@@ -0,0 +1,67 @@
+use regex::Regex;
+
+use crate::config::{TitlePattern, TitleRewrite, WindowMatcher};
+use crate::core::hub::MonitorLayout;
+use crate::core::node::WindowRestrictions;
+use crate::core::tab_title::format_tab_title;
+use crate::core::tests::{
+    LayoutConfigBuilder, PartitionTreeConfigBuilder, default_rect, setup_with_layout,
+    titled_process,
+};
+
+#[test]
+fn format_fills_known_placeholders_once() {
+    assert_eq!(
+        format_tab_title("{index}. {app}: {title}", "Zed", "main.rs", 2),
+        "2. Zed: main.rs"
+    );
+    assert_eq!(format_tab_title("{title}", "Zed", "{index}", 1), "{index}");
+    assert_eq!(format_tab_title("{other} {", "Zed", "t", 1), "{other} {");
+}
+
+#[test]
+fn tabs_show_rewritten_and_formatted_titles() {
+    let rewrite = TitleRewrite {
+        matcher: WindowMatcher {
+            process: Some("firefox".into()),
+            ..Default::default()
+        },
+        pattern: TitlePattern(Regex::new(r" — Mozilla Firefox$").unwrap()),
+        replace: String::new(),
+    };
+    let mut hub = setup_with_layout(
+        LayoutConfigBuilder::new()
+            .with_partition_tree_config(
+                PartitionTreeConfigBuilder::new()
+                    .with_tab_title_format("{index} {app}: {title}")
+                    .build(),
+            )
+            .with_tab_title(vec![rewrite])
+            .build(),
+    );
+    let w0 = hub
+        .insert_window(
+            titled_process("Docs — Mozilla Firefox", "firefox"),
+            default_rect(),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    let w1 = hub
+        .insert_window(
+            titled_process("Notes — Mozilla Firefox", "zed"),
+            default_rect(),
+            WindowRestrictions::None,
+        )
+        .unwrap();
+    hub.toggle_container_layout();
+
+    let placements = hub.get_visible_placements();
+    let MonitorLayout::Normal { containers, .. } = &placements.monitors[0].layout else {
+        panic!("expected a normal layout");
+    };
+    assert_eq!(
+        containers[0].titles,
+        ["1 firefox: Docs", "2 zed: Notes — Mozilla Firefox"]
+    );
+    assert_eq!(containers[0].tab_windows, [Some(w0), Some(w1)]);
+}
@@ -0,0 +1,114 @@
+use crate::core::hub::{Hub, MonitorLayout, WindowEmphasis};
+use crate::core::node::{WindowId, WindowRestrictions};
+use crate::core::tests::{default_rect, setup, titled};
+
+fn emphasis(hub: &Hub, id: WindowId) -> WindowEmphasis {
+    hub.get_visible_placements()
+        .monitors
+        .iter()
+        .find_map(|mp| match &mp.layout {
+            MonitorLayout::Normal { tiling_windows, .. } => tiling_windows
+                .iter()
+                .find(|wp| wp.id == id)
+                .map(|wp| wp.emphasis),
+            MonitorLayout::Fullscreen(_) => None,
+        })
+        .unwrap()
+}
+
+fn urgent_workspaces(hub: &Hub) -> Vec<String> {
+    hub.query_workspaces()
+        .into_iter()
+        .filter(|ws| ws.urgent)
+        .map(|ws| ws.name)
+        .collect()
+}
+
+#[test]
+fn urgent_window_is_emphasized_until_focused() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.set_urgent(w0, true);
+    assert_eq!(emphasis(&hub, w0), WindowEmphasis::Urgent);
+    assert_eq!(urgent_workspaces(&hub), vec!["0"]);
+
+    // Tiling navigation commits focus without going through `set_focus`.
+    hub.focus_left();
+    assert!(!hub.is_urgent(w0));
+    assert!(urgent_workspaces(&hub).is_empty());
+
+    hub.set_urgent(w0, true);
+    assert!(!hub.is_urgent(w0), "the focused window is never urgent");
+    hub.set_urgent(w1, true);
+    hub.set_urgent(w1, false);
+    assert!(!hub.is_urgent(w1));
+}
+
+#[test]
+fn focus_urgent_picks_the_latest_and_switches_workspace() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_workspace("1");
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.focus_workspace("2");
+    hub.insert_window(titled("w2"), default_rect(), WindowRestrictions::None);
+
+    hub.set_urgent(w1, true);
+    hub.set_urgent(w0, true);
+    assert_eq!(urgent_workspaces(&hub), vec!["0", "1"]);
+
+    hub.focus_urgent();
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+    hub.focus_urgent();
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w1));
+}
+
+#[test]
+fn minimized_and_deleted_urgent_windows_are_skipped() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w2 = hub
+        .insert_window(titled("w2"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.set_urgent(w0, true);
+    hub.set_urgent(w1, true);
+    hub.minimize_window(w1);
+
+    hub.focus_urgent();
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+
+    hub.delete_window(w1);
+    hub.set_focus(w2);
+    hub.focus_urgent();
+    assert!(!hub.is_urgent(w1));
+}
+
+#[test]
+fn window_inheriting_focus_is_no_longer_urgent() {
+    let mut hub = setup();
+    let w0 = hub
+        .insert_window(titled("w0"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    let w1 = hub
+        .insert_window(titled("w1"), default_rect(), WindowRestrictions::None)
+        .unwrap();
+    hub.set_urgent(w0, true);
+
+    hub.delete_window(w1);
+    assert_eq!(hub.focused_window(hub.current_workspace()), Some(w0));
+    assert!(!hub.is_urgent(w0));
+}
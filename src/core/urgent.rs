@@ -0,0 +1,57 @@
+//! Urgent windows: windows the shells report as asking for attention, such as
+//! a flashing taskbar button.
+//!
+//! The flag is cleared where focus is committed: `set_focus`, workspace and
+//! monitor switches, tiling navigation, and the removals that hand focus to a
+//! neighbour. `delete_window` drops it with the window.
+
+use crate::core::{Hub, WindowId, hub::RestrictedAction};
+
+impl Hub {
+    /// Flag or unflag `window_id` as asking for attention. The focused window
+    /// already has it, so it is never flagged.
+    #[cfg_attr(
+        all(not(test), target_os = "macos"),
+        expect(
+            dead_code,
+            reason = "macOS has no way to observe another app asking for attention"
+        )
+    )]
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn set_urgent(&mut self, window_id: WindowId, urgent: bool) {
+        self.urgent.retain(|&w| w != window_id);
+        if urgent && self.focused_window(self.current_workspace()) != Some(window_id) {
+            self.urgent.push(window_id);
+        }
+    }
+
+    pub(crate) fn is_urgent(&self, window_id: WindowId) -> bool {
+        self.urgent.contains(&window_id)
+    }
+
+    /// Focus the window that most recently asked for attention, switching
+    /// workspace and monitor as needed. Minimized windows keep the flag but
+    /// are skipped until restored.
+    #[tracing::instrument(skip(self))]
+    pub(crate) fn focus_urgent(&mut self) {
+        if self.is_restricted(RestrictedAction::TilingNavigation) {
+            return;
+        }
+        let Some(&window_id) = self
+            .urgent
+            .iter()
+            .rev()
+            .find(|&&w| !self.access.windows.get(w).is_minimized())
+        else {
+            return;
+        };
+        self.set_focus(window_id);
+    }
+
+    /// Drop the flag from the focused window, once focus has settled on it.
+    pub(super) fn clear_focused_urgent(&mut self) {
+        if let Some(focused) = self.focused_window(self.current_workspace()) {
+            self.urgent.retain(|&w| w != focused);
+        }
+    }
+}
@@ -10,7 +10,12 @@ impl Hub {
         }
         let monitor_id = self.access.workspaces.get(workspace_id).monitor;
         self.access.focused_monitor = monitor_id;
-        self.access.monitors.get_mut(monitor_id).active_workspace = workspace_id;
+        let monitor = self.access.monitors.get_mut(monitor_id);
+        let previous = std::mem::replace(&mut monitor.active_workspace, workspace_id);
+        if previous != workspace_id {
+            self.carry_sticky_floats(previous, workspace_id);
+        }
+        self.clear_focused_urgent();
     }
 
     #[tracing::instrument(skip(self))]
@@ -32,7 +37,7 @@ impl Hub {
         if let Some(window_id) = self.focused_window(current_ws) {
             self.move_child_to_workspace_with_id(window_id, target_ws);
         } else {
-            self.move_focused_across_workspaces(current_ws, target_ws);
+            self.move_focused_across_workspaces(current_ws, target_ws, None);
         }
     }
 }
@@ -0,0 +1,686 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+use crate::config::{Config, IncludedFiles, LayoutConfig, layout_default_path, load_or_default};
+
+/// A problem found while loading a config file. Config loading warns and
+/// recovers field by field, so every diagnostic names the fallback Dome
+/// applies in `message` (e.g. "Invalid value, using default").
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Diagnostic {
+    pub(crate) file: String,
+    /// Set when the key path or parse error could be traced back to the
+    /// source.
+    pub(crate) location: Option<Location>,
+    /// Dotted key path of the offending entry, e.g. `keymaps.mode.resize.x`.
+    pub(crate) field: Option<String>,
+    pub(crate) message: String,
+    /// Extra event fields such as the underlying parse error.
+    pub(crate) detail: Option<String>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file)?;
+        if let Some(Location { line, column }) = self.location {
+            write!(f, ":{line}:{column}")?;
+        }
+        write!(f, ":")?;
+        if let Some(field) = &self.field {
+            write!(f, " {field}:")?;
+        }
+        write!(f, " {}", self.message)?;
+        if let Some(detail) = &self.detail {
+            write!(f, " ({detail})")?;
+        }
+        Ok(())
+    }
+}
+
+/// 1-based position in a config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub(crate) struct Location {
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl Location {
+    fn at(source: &str, offset: usize) -> Self {
+        let before = &source[..offset.min(source.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ConfigFile {
+    Config,
+    Layout,
+}
+
+/// Outcome of the latest load of one file, kept for `dome query
+/// config-status`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct LoadReport {
+    #[serde(skip)]
+    pub(crate) file: ConfigFile,
+    pub(crate) path: String,
+    /// Seconds since the Unix epoch.
+    pub(crate) loaded_at: u64,
+    pub(crate) reload: bool,
+    /// The file could not be loaded and Dome runs on built-in defaults.
+    pub(crate) used_defaults: bool,
+    /// A reload failed and the previous load stays in effect.
+    pub(crate) kept_previous: bool,
+    pub(crate) warnings: Vec<Diagnostic>,
+}
+
+impl LoadReport {
+    fn new(file: ConfigFile, path: &str, reload: bool, warnings: Vec<Diagnostic>) -> Self {
+        let loaded_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self {
+            file,
+            path: path.to_string(),
+            loaded_at,
+            reload,
+            used_defaults: false,
+            kept_previous: false,
+            warnings,
+        }
+    }
+
+    /// One-line notice for the tray, e.g. "config reloaded with 3 warnings".
+    /// `None` when the load was clean.
+    pub(crate) fn summary(&self) -> Option<String> {
+        if self.warnings.is_empty() {
+            return None;
+        }
+        let name = match self.file {
+            ConfigFile::Config => "config",
+            ConfigFile::Layout => "layout",
+        };
+        if self.kept_previous {
+            return Some(format!("{name} reload failed, keeping previous"));
+        }
+        let verb = if self.reload { "reloaded" } else { "loaded" };
+        let count = self.warnings.len();
+        let plural = if count == 1 { "" } else { "s" };
+        let fallback = if self.used_defaults {
+            ", using defaults"
+        } else {
+            ""
+        };
+        Some(format!(
+            "{name} {verb} with {count} warning{plural}{fallback}"
+        ))
+    }
+}
+
+/// Latest load of each file, as the hub last heard about it.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct ConfigStatus {
+    config: Option<LoadReport>,
+    layout: Option<LoadReport>,
+}
+
+impl ConfigStatus {
+    pub(crate) fn record(&mut self, report: LoadReport) {
+        match report.file {
+            ConfigFile::Config => self.config = Some(report),
+            ConfigFile::Layout => self.layout = Some(report),
+        }
+    }
+
+    /// JSON for `dome query config-status`.
+    pub(crate) fn query_json(&self) -> String {
+        serde_json::to_string(self).expect("ConfigStatus is infallibly serializable")
+    }
+}
+
+/// Entry point for `dome check-config`. Runs the same loaders as launch and
+/// reload, prints every problem, and fails when any were found so dotfile CI
+/// can gate on the exit code.
+pub(crate) fn check_config(config: Option<String>, layout: Option<String>) -> anyhow::Result<()> {
+    // Explicit paths must exist. Missing default files are fine since Dome
+    // runs on defaults without them.
+    let config_required = config.is_some();
+    let layout_required = layout.is_some();
+    let config_path = config.unwrap_or_else(Config::default_path);
+    let layout_path = layout.unwrap_or_else(|| {
+        layout_default_path(std::path::Path::new(&config_path))
+            .to_string_lossy()
+            .into_owned()
+    });
+
+    let mut problems = 0;
+    for (path, required, diagnostics) in [
+        (
+            &config_path,
+            config_required,
+            check_file(&config_path, Config::load),
+        ),
+        (
+            &layout_path,
+            layout_required,
+            check_file(&layout_path, LayoutConfig::load),
+        ),
+    ] {
+        match diagnostics {
+            None if required => {
+                println!("{path}: File not found");
+                problems += 1;
+            }
+            None => println!("{path}: not found, using defaults"),
+            Some(diagnostics) if diagnostics.is_empty() => println!("{path}: ok"),
+            Some(diagnostics) => {
+                for diagnostic in &diagnostics {
+                    println!("{diagnostic}");
+                }
+                problems += diagnostics.len();
+            }
+        }
+    }
+    if problems > 0 {
+        anyhow::bail!("found {problems} problem(s)");
+    }
+    Ok(())
+}
+
+/// Loads `path` with `load` and returns what went wrong, or `None` if the file
+/// does not exist.
+pub(crate) fn check_file<T: IncludedFiles>(
+    path: &str,
+    load: impl FnOnce(&str) -> anyhow::Result<T>,
+) -> Option<Vec<Diagnostic>> {
+    match diagnose(path, load, "Failed to load, using defaults") {
+        (Err(e), _) if is_not_found(&e) => None,
+        (_, diagnostics) => Some(diagnostics),
+    }
+}
+
+/// `load_or_default` for launch, also reporting what went wrong.
+pub(crate) fn load_reported<T: Default + IncludedFiles>(
+    file: ConfigFile,
+    path: &str,
+    load: impl Fn(&str) -> anyhow::Result<T>,
+) -> (T, LoadReport) {
+    let warnings = RefCell::new(Vec::new());
+    let loaded = Cell::new(false);
+    let value = load_or_default(path, |path| {
+        let (result, diagnostics) = diagnose(path, &load, "Failed to load, using defaults");
+        log_warnings(diagnostics.iter().filter(|d| d.field.is_some()));
+        *warnings.borrow_mut() = diagnostics;
+        loaded.set(result.is_ok());
+        result
+    });
+    let report = LoadReport::new(file, path, false, warnings.into_inner());
+    let report = LoadReport {
+        used_defaults: !loaded.get(),
+        ..report
+    };
+    (value, report)
+}
+
+/// One reload attempt for the config watcher. Returns the value only when it
+/// should replace the running one: a file that fails to load as a whole
+/// leaves the previous load in effect.
+pub(crate) fn reload_reported<T: IncludedFiles>(
+    file: ConfigFile,
+    path: &str,
+    load: impl FnOnce(&str) -> anyhow::Result<T>,
+) -> (Option<T>, LoadReport) {
+    let (result, diagnostics) = diagnose(path, load, "Failed to reload, keeping previous");
+    log_warnings(&diagnostics);
+    let report = LoadReport::new(file, path, true, diagnostics);
+    let report = LoadReport {
+        kept_previous: result.is_err(),
+        ..report
+    };
+    (result.ok(), report)
+}
+
+/// Runs `load` while capturing its warnings, and locates them in the file
+/// each offending key came from, which may be an include. A failed load adds
+/// a diagnostic carrying `failure`, except when the file is missing, which
+/// callers treat as "use defaults".
+fn diagnose<T: IncludedFiles>(
+    path: &str,
+    load: impl FnOnce(&str) -> anyhow::Result<T>,
+    failure: &str,
+) -> (anyhow::Result<T>, Vec<Diagnostic>) {
+    let source = match std::fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(e) => {
+            let e = anyhow::Error::from(e);
+            let diagnostics = if is_not_found(&e) {
+                Vec::new()
+            } else {
+                vec![Diagnostic {
+                    file: path.to_string(),
+                    location: None,
+                    field: None,
+                    message: failure.to_string(),
+                    detail: Some(e.to_string()),
+                }]
+            };
+            return (Err(e), diagnostics);
+        }
+    };
+    let (result, events) = capture_warnings(|| load(path));
+    let origins = result.as_ref().ok().and_then(IncludedFiles::key_origins);
+    // Spans come from a second, lossless parse of each file. If the main one
+    // fails, the loader failed too and reports the syntax error below.
+    let mut documents = HashMap::new();
+    documents.insert(
+        path.to_string(),
+        toml_edit::Document::parse(source.clone()).ok(),
+    );
+    let mut diagnostics: Vec<Diagnostic> = events
+        .into_iter()
+        .map(|event| {
+            // Include warnings name their file, merged keys are traced back
+            // through the origins, and anything else is from `path`.
+            let (file, field) = match (event.file, event.field) {
+                (Some(file), field) => (file, field),
+                (None, Some(field)) => match origins.and_then(|o| o.locate(&field)) {
+                    Some((file, local)) => (file.display().to_string(), Some(local)),
+                    None => (path.to_string(), Some(field)),
+                },
+                (None, None) => (path.to_string(), None),
+            };
+            let document = documents.entry(file.clone()).or_insert_with(|| {
+                std::fs::read_to_string(&file)
+                    .ok()
+                    .and_then(|source| toml_edit::Document::parse(source).ok())
+            });
+            let location = document
+                .as_ref()
+                .zip(field.as_deref())
+                .and_then(|(doc, field)| {
+                    let span = field_span(doc.as_item(), field)?;
+                    Some(Location::at(doc.raw(), span.start))
+                });
+            Diagnostic {
+                file,
+                location,
+                field,
+                message: event.message,
+                detail: (!event.detail.is_empty()).then(|| event.detail.join(", ")),
+            }
+        })
+        .collect();
+    // Walk order follows the loader's code, not the file. The loaded file
+    // comes first, then each include.
+    diagnostics.sort_by_key(|d| {
+        (
+            d.file != path,
+            d.file.clone(),
+            d.location.is_none(),
+            d.location,
+        )
+    });
+    if let Err(e) = &result {
+        let (location, detail) = match e.downcast_ref::<toml::de::Error>() {
+            Some(de) => (
+                de.span().map(|s| Location::at(&source, s.start)),
+                de.message().trim_end().to_string(),
+            ),
+            None => (None, format!("{e:#}")),
+        };
+        diagnostics.push(Diagnostic {
+            file: path.to_string(),
+            location,
+            field: None,
+            message: failure.to_string(),
+            detail: Some(detail),
+        });
+    }
+    (result, diagnostics)
+}
+
+fn is_not_found(e: &anyhow::Error) -> bool {
+    e.downcast_ref::<std::io::Error>()
+        .is_some_and(|io| io.kind() == std::io::ErrorKind::NotFound)
+}
+
+// Capturing replaces the logger for the duration of the load, so the warnings
+// are logged again here to keep dome.log complete.
+fn log_warnings<'a>(diagnostics: impl IntoIterator<Item = &'a Diagnostic>) {
+    for diagnostic in diagnostics {
+        tracing::warn!(
+            file = %diagnostic.file,
+            field = diagnostic.field.as_deref().unwrap_or(""),
+            detail = diagnostic.detail.as_deref().unwrap_or(""),
+            "{}",
+            diagnostic.message,
+        );
+    }
+}
+
+#[derive(Debug, Default)]
+struct CapturedEvent {
+    field: Option<String>,
+    file: Option<String>,
+    message: String,
+    detail: Vec<String>,
+}
+
+impl Visit for CapturedEvent {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message = value.to_string(),
+            "field" => self.field = Some(value.to_string()),
+            "file" => self.file = Some(value.to_string()),
+            name => self.detail.push(format!("{name}: {}", value.trim_end())),
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        // Display-captured (`%`) fields arrive here and print without quotes.
+        self.record_str(field, &format!("{value:?}"));
+    }
+}
+
+struct WarningCollector(Arc<Mutex<Vec<CapturedEvent>>>);
+
+impl<S: Subscriber> Layer<S> for WarningCollector {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() > Level::WARN {
+            return;
+        }
+        let mut captured = CapturedEvent::default();
+        event.record(&mut captured);
+        self.0.lock().unwrap().push(captured);
+    }
+}
+
+/// Runs `f` with a thread-local subscriber that records the warnings it
+/// emits instead of logging them.
+fn capture_warnings<T>(f: impl FnOnce() -> T) -> (T, Vec<CapturedEvent>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = tracing_subscriber::registry().with(WarningCollector(events.clone()));
+    let result = tracing::subscriber::with_default(subscriber, f);
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    (result, events)
+}
+
+/// Follows a walker key path such as `keymaps.mode.resize.h` or
+/// `ignore[2].title` through the document, returning the span of the deepest
+/// entry that exists. Keys may themselves contain dots, so each step takes
+/// the longest key that matches.
+fn field_span(root: &toml_edit::Item, field: &str) -> Option<Range<usize>> {
+    let mut item = root;
+    let mut span = None;
+    let mut rest = field;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('[') {
+            let Some((index, tail)) = after.split_once(']') else {
+                break;
+            };
+            let Some(next) = index.parse::<usize>().ok().and_then(|i| item.get(i)) else {
+                break;
+            };
+            span = next.span().or(span);
+            item = next;
+            rest = tail.strip_prefix('.').unwrap_or(tail);
+            continue;
+        }
+        let Some(table) = item.as_table_like() else {
+            break;
+        };
+        let Some((key, next)) = table
+            .iter()
+            .filter(|(key, _)| {
+                rest.strip_prefix(key)
+                    .is_some_and(|tail| tail.is_empty() || tail.starts_with(['.', '[']))
+            })
+            .max_by_key(|(key, _)| key.len())
+        else {
+            break;
+        };
+        span = table
+            .key(key)
+            .and_then(|k| k.span())
+            .or_else(|| next.span())
+            .or(span);
+        item = next;
+        rest = &rest[key.len()..];
+        rest = rest.strip_prefix('.').unwrap_or(rest);
+    }
+    span
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CleanupFile(std::path::PathBuf);
+    impl Drop for CleanupFile {
+        fn drop(&mut self) {
+            std::fs::remove_file(&self.0).ok();
+        }
+    }
+
+    fn write_temp(name: &str, source: &str) -> CleanupFile {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("dome_check_{name}_{nanos}.toml"));
+        std::fs::write(&path, source).unwrap();
+        CleanupFile(path)
+    }
+
+    fn check_source<T: IncludedFiles>(
+        name: &str,
+        source: &str,
+        load: impl FnOnce(&str) -> anyhow::Result<T>,
+    ) -> String {
+        let file = write_temp(name, source);
+        let path = file.0.to_str().unwrap();
+        check_file(path, load)
+            .unwrap()
+            .iter()
+            .map(|d| d.to_string().replace(path, "config.toml"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn check_file_locates_walker_warnings() {
+        let out = check_source(
+            "walker",
+            concat!(
+                "border_size = \"wide\"\n",
+                "colour = 1\n",
+                "\n",
+                "[keymaps]\n",
+                "\"meta+h\" = [\"focus sideways\"]\n",
+                "\n",
+                "[keymaps.mode.resize]\n",
+                "timeout_ms = 0\n",
+                "h = [\"master shrink\"]\n",
+                "\n",
+                "[[keymaps.app]]\n",
+                "match = { app = \"kitty\" }\n",
+                "passthrough = true\n",
+                "\"meta+t\" = [\"exec kitty\"]\n",
+                "\n",
+                "[[float]]\n",
+                "app = \"Finder\"\n",
+                "\n",
+                "[[float]]\n",
+                "size = 3\n",
+            ),
+            Config::load,
+        );
+        insta::assert_snapshot!(out, @r#"
+        config.toml:1:1: border_size: Invalid value, using default (error: invalid type: string "wide", expected f64)
+        config.toml:2:1: colour: Unknown config field, ignoring
+        config.toml:5:1: keymaps.meta+h: Invalid action, dropping binding (error: Unknown action: focus sideways)
+        config.toml:8:1: keymaps.mode.resize.timeout_ms: Timeout must be positive, ignoring
+        config.toml:20:1: float[1].size: Unknown config field, ignoring
+        "#);
+    }
+
+    #[test]
+    fn check_file_locates_warnings_in_included_files() {
+        let include = write_temp(
+            "included",
+            concat!(
+                "border_size = 2\n",
+                "\n",
+                "[[float]]\n",
+                "app = \"Finder\"\n",
+                "\n",
+                "[[float]]\n",
+                "size = 3\n",
+                "\n",
+                "[keymaps]\n",
+                "\"meta+h\" = [\"focus sideways\"]\n",
+            ),
+        );
+        // Includes are reported by their canonical path.
+        let include_path = include.0.canonicalize().unwrap();
+        let include_path = include_path.to_str().unwrap();
+        let main = write_temp(
+            "includer",
+            &format!("include = [{include_path:?}]\ntheme = 1\n\n[[float]]\napp = \"Notes\"\n"),
+        );
+        let path = main.0.to_str().unwrap();
+        let out = check_file(path, Config::load)
+            .unwrap()
+            .iter()
+            .map(|d| {
+                d.to_string()
+                    .replace(path, "config.toml")
+                    .replace(include_path, "common.toml")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        insta::assert_snapshot!(out, @"
+        config.toml:2:1: theme: Invalid value, using default (error: expected table, got integer)
+        common.toml:7:1: float[1].size: Unknown config field, ignoring
+        common.toml:10:1: keymaps.meta+h: Invalid action, dropping binding (error: Unknown action: focus sideways)
+        ");
+    }
+
+    #[test]
+    fn check_file_names_sequences_shadowed_by_a_shorter_binding() {
+        let out = check_source(
+            "shadowed",
+            concat!(
+                "[keymaps]\n",
+                "inherit_defaults = true\n",
+                "\"meta+w\" = [\"close\"]\n",
+                "\"meta+w v\" = [\"toggle direction\"]\n",
+                "\"meta+h x\" = [\"focus left\"]\n",
+                "\"meta+j\" = \"unbind\"\n",
+                "\"meta+j x\" = [\"focus down\"]\n",
+                "\n",
+                "[[keymaps.app]]\n",
+                "match = { app = \"Zed\" }\n",
+                "\"meta+l x\" = [\"focus right\"]\n",
+            ),
+            Config::load,
+        );
+        insta::assert_snapshot!(out, @"
+        config.toml:4:1: keymaps.meta+w v: Sequence never fires, a shorter binding starts it (shadowed_by: meta+w)
+        config.toml:5:1: keymaps.meta+h x: Sequence never fires, a shorter binding starts it (shadowed_by: meta+h)
+        config.toml:11:1: keymaps.app[0].meta+l x: Sequence never fires, a shorter binding starts it (shadowed_by: meta+l)
+        ");
+    }
+
+    #[test]
+    fn check_file_reports_syntax_error_position() {
+        let out = check_source("syntax", "border_size = 4\ntheme = \n", Config::load);
+        insta::assert_snapshot!(out, @"
+        config.toml:2:9: Failed to load, using defaults (string values must be quoted, expected literal string)
+        ");
+    }
+
+    #[test]
+    fn check_file_clean_config_has_no_diagnostics() {
+        let out = check_source("clean", "border_size = 2\n", Config::load);
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn check_file_missing_is_none() {
+        assert!(check_file("/nonexistent/dome/config.toml", Config::load).is_none());
+    }
+
+    #[test]
+    fn field_span_prefers_longest_key() {
+        let source = "[keymaps]\n\"meta+.\" = []\n\"meta+.x\" = []\n";
+        let doc = toml_edit::Document::parse(source).unwrap();
+        let span = field_span(doc.as_item(), "keymaps.meta+.x").unwrap();
+        assert_eq!(Location::at(source, span.start).line, 3);
+    }
+
+    #[test]
+    fn reload_reported_summarizes_warnings() {
+        let file = write_temp("reload_warn", "colour = 1\nborder_size = \"wide\"\n");
+        let (config, report) =
+            reload_reported(ConfigFile::Config, file.0.to_str().unwrap(), Config::load);
+        assert!(config.is_some());
+        assert!(!report.kept_previous);
+        assert_eq!(
+            report.summary().as_deref(),
+            Some("config reloaded with 2 warnings")
+        );
+    }
+
+    #[test]
+    fn reload_reported_keeps_previous_on_syntax_error() {
+        let file = write_temp("reload_syntax", "theme = \n");
+        let (config, report) =
+            reload_reported(ConfigFile::Config, file.0.to_str().unwrap(), Config::load);
+        assert!(config.is_none());
+        assert!(report.kept_previous);
+        assert_eq!(
+            report.summary().as_deref(),
+            Some("config reload failed, keeping previous")
+        );
+    }
+
+    #[test]
+    fn load_reported_missing_file_uses_defaults_quietly() {
+        let (_, report) = load_reported(
+            ConfigFile::Layout,
+            "/nonexistent/dome/layout.toml",
+            LayoutConfig::load,
+        );
+        assert!(report.used_defaults);
+        assert!(report.warnings.is_empty());
+        assert_eq!(report.summary(), None);
+    }
+
+    #[test]
+    fn config_status_keeps_latest_report_per_file() {
+        let file = write_temp("status", "colour = 1\n");
+        let path = file.0.to_str().unwrap();
+        let mut status = ConfigStatus::default();
+        status.record(load_reported(ConfigFile::Config, path, Config::load).1);
+        status.record(reload_reported(ConfigFile::Config, path, Config::load).1);
+        let json: serde_json::Value = serde_json::from_str(&status.query_json()).unwrap();
+        assert_eq!(json["config"]["reload"], true);
+        assert_eq!(json["config"]["warnings"][0]["field"], "colour");
+        assert_eq!(json["config"]["warnings"][0]["location"]["line"], 1);
+        assert!(json["layout"].is_null());
+    }
+}
@@ -0,0 +1,406 @@
+use serde::Deserialize;
+
+use crate::action::Actions;
+use crate::config::WindowMatcher;
+use crate::config::pattern_matches;
+use crate::core::{LifecycleEvent, WindowId, WindowMetadata};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum HookEvent {
+    WindowCreated,
+    WindowDestroyed,
+    WorkspaceFocused,
+    MonitorConnected,
+    MonitorDisconnected,
+    ModeEntered,
+}
+
+impl HookEvent {
+    fn as_str(self) -> &'static str {
+        match self {
+            HookEvent::WindowCreated => "window_created",
+            HookEvent::WindowDestroyed => "window_destroyed",
+            HookEvent::WorkspaceFocused => "workspace_focused",
+            HookEvent::MonitorConnected => "monitor_connected",
+            HookEvent::MonitorDisconnected => "monitor_disconnected",
+            HookEvent::ModeEntered => "mode_entered",
+        }
+    }
+
+    fn is_window_event(self) -> bool {
+        matches!(self, HookEvent::WindowCreated | HookEvent::WindowDestroyed)
+    }
+}
+
+/// One `[[hook]]` entry. `window` filters window events by the window's
+/// attributes. `name` filters by the name the event carries: the workspace for
+/// window and workspace events, the monitor for monitor events, the mode for
+/// `mode_entered`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "RawHook")]
+pub(crate) struct Hook {
+    pub(crate) event: HookEvent,
+    pub(crate) window: Option<WindowMatcher>,
+    pub(crate) name: Option<String>,
+    pub(crate) actions: Actions,
+}
+
+#[derive(Deserialize)]
+struct RawHook {
+    event: HookEvent,
+    #[serde(default, rename = "match")]
+    window: Option<WindowMatcher>,
+    #[serde(default)]
+    name: Option<String>,
+    actions: Actions,
+}
+
+impl TryFrom<RawHook> for Hook {
+    type Error = String;
+
+    fn try_from(raw: RawHook) -> Result<Self, Self::Error> {
+        if raw.window.is_some() && !raw.event.is_window_event() {
+            return Err(format!(
+                "`match` only applies to window events, not {}",
+                raw.event.as_str()
+            ));
+        }
+        if raw.actions.is_empty() {
+            return Err("hook has no actions".to_string());
+        }
+        Ok(Hook {
+            event: raw.event,
+            window: raw.window,
+            name: raw.name,
+            actions: raw.actions,
+        })
+    }
+}
+
+/// Actions a matched hook wants run, plus the `DOME_*` variables its `exec`
+/// actions see.
+#[derive(Debug, Clone)]
+pub(crate) struct HookRun {
+    pub(crate) actions: Actions,
+    pub(crate) env: Vec<(&'static str, String)>,
+    /// The created window for `window_created`. Actions act on the focused
+    /// window, so the shell focuses this one while they run.
+    pub(crate) window: Option<WindowId>,
+}
+
+/// What one event exposes to hooks.
+struct Fired<'a> {
+    event: HookEvent,
+    name: Option<&'a str>,
+    window: Option<&'a dyn WindowMetadata>,
+    target: Option<WindowId>,
+    env: Vec<(&'static str, String)>,
+}
+
+impl<'a> Fired<'a> {
+    fn window(
+        event: HookEvent,
+        id: WindowId,
+        workspace: Option<&'a str>,
+        metadata: &'a dyn WindowMetadata,
+    ) -> Self {
+        let mut env = vec![
+            ("DOME_EVENT", event.as_str().to_string()),
+            // Bare integer, same as `dome query minimized`, so scripts can
+            // hand it straight back to `dome unminimize-window`.
+            (
+                "DOME_WINDOW_ID",
+                serde_json::to_string(&id).expect("WindowId is infallibly serializable"),
+            ),
+        ];
+        if let Some(title) = metadata.title() {
+            env.push(("DOME_WINDOW_TITLE", title.to_string()));
+        }
+        if let Some(app) = metadata.app_name() {
+            env.push(("DOME_APP", app));
+        }
+        if let Some(workspace) = workspace {
+            env.push(("DOME_WORKSPACE", workspace.to_string()));
+        }
+        // A destroyed window is gone by the time its hooks run.
+        let target = (event == HookEvent::WindowCreated).then_some(id);
+        Self {
+            event,
+            name: workspace,
+            window: Some(metadata),
+            target,
+            env,
+        }
+    }
+
+    fn named(event: HookEvent, var: &'static str, name: &'a str) -> Self {
+        Self {
+            event,
+            name: Some(name),
+            window: None,
+            target: None,
+            env: vec![
+                ("DOME_EVENT", event.as_str().to_string()),
+                (var, name.to_string()),
+            ],
+        }
+    }
+
+    fn from_lifecycle(event: &'a LifecycleEvent) -> Self {
+        match event {
+            LifecycleEvent::WindowCreated {
+                id,
+                workspace,
+                metadata,
+            } => Self::window(HookEvent::WindowCreated, *id, Some(workspace), &**metadata),
+            LifecycleEvent::WindowDestroyed {
+                id,
+                workspace,
+                metadata,
+            } => Self::window(
+                HookEvent::WindowDestroyed,
+                *id,
+                workspace.as_deref(),
+                &**metadata,
+            ),
+            LifecycleEvent::WorkspaceFocused { name } => {
+                Self::named(HookEvent::WorkspaceFocused, "DOME_WORKSPACE", name)
+            }
+            LifecycleEvent::MonitorConnected { name } => {
+                Self::named(HookEvent::MonitorConnected, "DOME_MONITOR", name)
+            }
+            LifecycleEvent::MonitorDisconnected { name } => {
+                Self::named(HookEvent::MonitorDisconnected, "DOME_MONITOR", name)
+            }
+        }
+    }
+
+    fn matches(&self, hook: &Hook) -> bool {
+        if hook.event != self.event {
+            return false;
+        }
+        if let Some(matcher) = &hook.window
+            && !self
+                .window
+                .is_some_and(|m| m.matches_window_matcher(matcher))
+        {
+            return false;
+        }
+        if let Some(pattern) = &hook.name
+            && !self.name.is_some_and(|n| pattern_matches(pattern, n))
+        {
+            return false;
+        }
+        true
+    }
+
+    fn runs(&self, hooks: &[Hook]) -> Vec<HookRun> {
+        hooks
+            .iter()
+            .filter(|h| self.matches(h))
+            .map(|h| HookRun {
+                actions: h.actions.clone(),
+                env: self.env.clone(),
+                window: self.target,
+            })
+            .collect()
+    }
+}
+
+/// Every hook run triggered by `events`, in event order and then config order.
+pub(crate) fn lifecycle_runs(hooks: &[Hook], events: &[LifecycleEvent]) -> Vec<HookRun> {
+    if hooks.is_empty() {
+        return Vec::new();
+    }
+    events
+        .iter()
+        .flat_map(|e| Fired::from_lifecycle(e).runs(hooks))
+        .collect()
+}
+
+/// Mode switches live in the keymap state, not the hub, so the platform
+/// reports them here directly.
+pub(crate) fn mode_runs(hooks: &[Hook], mode: &str) -> Vec<HookRun> {
+    Fired::named(HookEvent::ModeEntered, "DOME_MODE", mode).runs(hooks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Meta {
+        title: &'static str,
+        app: &'static str,
+    }
+
+    impl std::fmt::Display for Meta {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} - {}", self.app, self.title)
+        }
+    }
+
+    impl WindowMetadata for Meta {
+        fn app_name(&self) -> Option<String> {
+            Some(self.app.to_string())
+        }
+        fn title(&self) -> Option<&str> {
+            Some(self.title)
+        }
+        fn set_title(&mut self, _title: String) {}
+        fn clone_box(&self) -> Box<dyn WindowMetadata> {
+            Box::new(self.clone())
+        }
+        fn matches_window_matcher(&self, matcher: &WindowMatcher) -> bool {
+            matcher
+                .app
+                .as_deref()
+                .is_some_and(|p| pattern_matches(p, self.app))
+        }
+        fn to_window_matcher(&self) -> WindowMatcher {
+            WindowMatcher::default()
+        }
+    }
+
+    fn hooks(src: &str) -> Vec<Hook> {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            hook: Vec<Hook>,
+        }
+        toml::from_str::<Wrapper>(src).unwrap().hook
+    }
+
+    fn created(app: &'static str, workspace: &str) -> LifecycleEvent {
+        LifecycleEvent::WindowCreated {
+            id: serde_json::from_str("7").unwrap(),
+            workspace: workspace.to_string(),
+            metadata: Box::new(Meta {
+                title: "inbox",
+                app,
+            }),
+        }
+    }
+
+    fn actions(runs: &[HookRun]) -> Vec<String> {
+        runs.iter().map(|r| r.actions.to_string()).collect()
+    }
+
+    #[test]
+    fn window_hook_filters_by_matcher() {
+        let hooks = hooks(
+            r#"
+            [[hook]]
+            event = "window_created"
+            match = { app = "Mail" }
+            actions = ["move workspace mail"]
+            "#,
+        );
+        let runs = lifecycle_runs(&hooks, &[created("Mail", "0"), created("Zed", "0")]);
+        assert_eq!(actions(&runs), ["[move workspace mail]"]);
+        assert_eq!(runs[0].window, serde_json::from_str("7").ok());
+    }
+
+    #[test]
+    fn window_hook_env_carries_window_fields() {
+        let hooks = hooks(
+            r#"
+            [[hook]]
+            event = "window_created"
+            actions = ["exec notify"]
+            "#,
+        );
+        let runs = lifecycle_runs(&hooks, &[created("Mail", "web")]);
+        assert_eq!(
+            runs[0].env,
+            [
+                ("DOME_EVENT", "window_created".to_string()),
+                ("DOME_WINDOW_ID", "7".to_string()),
+                ("DOME_WINDOW_TITLE", "inbox".to_string()),
+                ("DOME_APP", "Mail".to_string()),
+                ("DOME_WORKSPACE", "web".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn name_filters_workspace_and_mode() {
+        let hooks = hooks(
+            r#"
+            [[hook]]
+            event = "workspace_focused"
+            name = "/^web/"
+            actions = ["exec on-web"]
+
+            [[hook]]
+            event = "mode_entered"
+            name = "resize"
+            actions = ["exec on-resize"]
+            "#,
+        );
+        let focused = |name: &str| LifecycleEvent::WorkspaceFocused {
+            name: name.to_string(),
+        };
+        let runs = lifecycle_runs(&hooks, &[focused("0"), focused("web-2")]);
+        assert_eq!(actions(&runs), ["[exec on-web]"]);
+        assert_eq!(
+            runs[0].env,
+            [
+                ("DOME_EVENT", "workspace_focused".to_string()),
+                ("DOME_WORKSPACE", "web-2".to_string()),
+            ]
+        );
+        assert_eq!(actions(&mode_runs(&hooks, "resize")), ["[exec on-resize]"]);
+        assert!(mode_runs(&hooks, "default").is_empty());
+    }
+
+    #[test]
+    fn monitor_hooks_fire_per_direction() {
+        let hooks = hooks(
+            r#"
+            [[hook]]
+            event = "monitor_connected"
+            actions = ["exec docked"]
+
+            [[hook]]
+            event = "monitor_disconnected"
+            actions = ["exec undocked"]
+            "#,
+        );
+        let runs = lifecycle_runs(
+            &hooks,
+            &[LifecycleEvent::MonitorDisconnected {
+                name: "DELL".to_string(),
+            }],
+        );
+        assert_eq!(actions(&runs), ["[exec undocked]"]);
+        assert_eq!(runs[0].env[1], ("DOME_MONITOR", "DELL".to_string()));
+    }
+
+    #[test]
+    fn match_on_non_window_event_rejected() {
+        let err = toml::from_str::<Hook>(
+            r#"
+            event = "workspace_focused"
+            match = { app = "Mail" }
+            actions = ["exec x"]
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("only applies to window events"));
+    }
+
+    #[test]
+    fn empty_actions_rejected() {
+        assert!(toml::from_str::<Hook>(r#"event = "mode_entered""#).is_err());
+        assert!(
+            toml::from_str::<Hook>(
+                r#"
+                event = "mode_entered"
+                actions = []
+                "#
+            )
+            .is_err()
+        );
+    }
+}
@@ -1,5 +1,11 @@
-use crate::action::{Action, Actions};
-use crate::config::{Keymap, ModalKeymaps};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::action::{Action, Actions, FocusTarget};
+use crate::config::{AppKeymaps, KeySequence, Keymap, ModalKeymaps, ModeOptions, WindowMatcher};
+use crate::core::{HINT_MODE, HintPress, HintSession, OVERVIEW_MODE, WindowId, WindowMetadata};
 
 /// Runtime state for modal keybinding resolution. Both macOS and Windows
 /// keyboard handlers share a single `KeymapState` via `Arc<RwLock<KeymapState>>`.
@@ -8,66 +14,316 @@ use crate::config::{Keymap, ModalKeymaps};
 /// synchronously decide whether to suppress a keypress. Hub-owned mode state
 /// would require a round-trip and has a race where fast keypresses resolve
 /// against the stale mode before a hub push arrives.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub(crate) struct KeymapState {
     keymaps: ModalKeymaps,
     active_mode: String,
+    /// Keys typed so far of a multi-key sequence, and when the last one was
+    /// pressed. Empty when no sequence is in progress.
+    pending: Vec<Keymap>,
+    pending_since: Option<Instant>,
+    /// Swallowed keys of a sequence that was abandoned or timed out, for the
+    /// platform to type again via `take_replay`.
+    replay: Vec<Keymap>,
+    /// Last keypress in a named mode, or when it was entered. `None` in the
+    /// default mode.
+    mode_since: Option<Instant>,
+    /// The hub's focused window, pushed after every hub event so app
+    /// overrides resolve on the keyboard thread without a round-trip.
+    focused: Option<(WindowId, Box<dyn WindowMetadata>)>,
+    /// The default table with the focused window's `[[keymaps.app]]` blocks
+    /// applied. Rebuilt when focus or keymaps change, not per keypress.
+    default_bindings: HashMap<KeySequence, Actions>,
+    /// Labels of a `focus hint` in progress. While set, `active_mode` is
+    /// `HINT_MODE` and every key goes to the session instead of the bindings.
+    hints: Option<HintSession>,
+    /// Workspace labels of an `overview` in progress, handled like `hints`
+    /// with `OVERVIEW_MODE` active.
+    overview: Option<HintSession<String>>,
 }
 
 impl KeymapState {
     pub(crate) fn new(keymaps: ModalKeymaps) -> Self {
+        let default_bindings = keymaps.default.clone();
         Self {
             keymaps,
             active_mode: "default".to_string(),
+            pending: Vec::new(),
+            pending_since: None,
+            replay: Vec::new(),
+            mode_since: None,
+            focused: None,
+            default_bindings,
+            hints: None,
+            overview: None,
         }
     }
 
-    /// The single entry point for keymap resolution. Both platforms call this.
+    /// Records the focused window. Cheap when nothing changed, so platforms
+    /// call it after every hub event. A title change counts as a change since
+    /// app blocks may match on title.
+    pub(crate) fn set_focused_window(&mut self, focused: Option<(WindowId, &dyn WindowMetadata)>) {
+        let unchanged = match (&self.focused, focused) {
+            (None, None) => true,
+            (Some((old_id, old)), Some((id, new))) => *old_id == id && old.title() == new.title(),
+            _ => false,
+        };
+        if unchanged {
+            return;
+        }
+        self.focused = focused.map(|(id, metadata)| (id, metadata.clone_box()));
+        self.rebuild_default_bindings();
+    }
+
+    fn rebuild_default_bindings(&mut self) {
+        let matching: Vec<&AppKeymaps> = match &self.focused {
+            Some((_, metadata)) => self
+                .keymaps
+                .apps
+                .iter()
+                .filter(|app| metadata.matches_window_matcher(&app.window))
+                .collect(),
+            None => Vec::new(),
+        };
+        let mut bindings = if matching.iter().any(|app| app.passthrough) {
+            HashMap::new()
+        } else {
+            self.keymaps.default.clone()
+        };
+        for app in matching {
+            for seq in &app.unbound {
+                bindings.remove(seq);
+            }
+            bindings.extend(app.bindings.iter().map(|(k, a)| (k.clone(), a.clone())));
+        }
+        self.default_bindings = bindings;
+    }
+
+    /// Keymap resolution behind `resolve_key`, which both platforms call.
     ///
-    /// 1. Looks up `keymap` in the active mode's bindings. If the active mode
-    ///    has been removed (e.g. config reload dropped it), logs a warning and
-    ///    falls back to the `default` table so the keyboard keeps working.
-    /// 2. For any `Action::Mode` in the result, switches mode immediately.
-    /// 3. Returns only the non-Mode actions (to send to the hub).
-    /// 4. Returns `None` if no binding exists (after fallback) or all actions
-    ///    were Mode switches.
+    /// 1. Looks up the pending sequence plus `keymap` in the active mode's
+    ///    bindings. If the active mode has been removed (e.g. config reload
+    ///    dropped it), logs a warning and falls back to the `default` table so
+    ///    the keyboard keeps working.
+    /// 2. If that is only the start of a longer binding, remembers it and
+    ///    returns an empty `Actions` so the key is swallowed. If nothing
+    ///    matches mid-sequence, the sequence is abandoned and `keymap` is
+    ///    retried on its own. The abandoned keys are queued for `take_replay`,
+    ///    and `keymap` joins them if it is unbound, so the app receives them
+    ///    in the order they were typed.
+    /// 3. For any `Action::Mode` in the result, switches mode immediately.
+    /// 4. Returns only the non-Mode actions (to send to the hub).
+    /// 5. Returns `None` if no binding exists (after fallback) or all actions
+    ///    were Mode switches. A mode with `swallow_unbound` returns an empty
+    ///    `Actions` for unbound keys instead, and a `oneshot` mode returns to
+    ///    default once a binding fires.
     ///
     /// Multiple Mode actions in one binding are processed in order -- last one
     /// wins (each switch_mode call overwrites the previous). This matches how
     /// shells process trailing redirections.
-    pub(crate) fn resolve(&mut self, keymap: &Keymap) -> Option<Actions> {
-        let bindings = if self.active_mode == "default" {
-            &self.keymaps.default
-        } else {
-            match self.keymaps.modes.get(&self.active_mode) {
-                Some(m) => m,
-                None => {
-                    tracing::warn!(
-                        mode = %self.active_mode,
-                        "Active mode missing from keymaps, falling back to default table"
-                    );
-                    &self.keymaps.default
-                }
-            }
+    fn resolve_at(&mut self, keymap: &Keymap, now: Instant) -> Option<Actions> {
+        self.expire(now);
+        if self.hints.is_some() || self.overview.is_some() {
+            return Some(self.resolve_hint(keymap));
+        }
+        if self.mode_since.is_some() {
+            self.mode_since = Some(now);
+        }
+        let actions = self.resolve_typed(keymap, now);
+        // A key let through now would reach the app ahead of the replay.
+        if actions.is_none() && !self.replay.is_empty() {
+            self.replay.push(keymap.clone());
+            return Some(Actions::new(Vec::new()));
+        }
+        actions
+    }
+
+    /// Steps 1, 2 and 5 of `resolve_at` for the pending sequence plus
+    /// `keymap`.
+    fn resolve_typed(&mut self, keymap: &Keymap, now: Instant) -> Option<Actions> {
+        let mut typed = std::mem::take(&mut self.pending);
+        let restarted = !typed.is_empty();
+        typed.push(keymap.clone());
+        let typed = KeySequence(typed);
+        self.pending_since = None;
+
+        let bindings = self.active_bindings();
+        if let Some(actions) = bindings.get(&typed) {
+            return self.fire(actions.clone());
+        }
+        if bindings.keys().any(|seq| seq.extends(&typed.0)) {
+            self.pending = typed.0;
+            self.pending_since = Some(now);
+            return Some(Actions::new(Vec::new()));
+        }
+        if restarted {
+            let mut abandoned = typed.0;
+            abandoned.pop();
+            self.abandon(abandoned);
+            return self.resolve_typed(keymap, now);
+        }
+        if self.active_options().is_some_and(|o| o.swallow_unbound) {
+            return Some(Actions::new(Vec::new()));
+        }
+        None
+    }
+
+    /// Queues the swallowed keys of a dropped sequence for `take_replay`. A
+    /// `swallow_unbound` mode would have swallowed them anyway, so they are
+    /// dropped there.
+    fn abandon(&mut self, keys: Vec<Keymap>) {
+        if !self.active_options().is_some_and(|o| o.swallow_unbound) {
+            self.replay.extend(keys);
+        }
+    }
+
+    /// Keys the platform should type again, in order: the prefix of a
+    /// sequence that was abandoned or timed out, and the key that gave up on
+    /// it when that key is unbound. Platforms drain this after `resolve_key`,
+    /// `resolve_wheel` and `expire`, and mark the keys they synthesize so
+    /// their hooks let them through unresolved.
+    pub(crate) fn take_replay(&mut self) -> Vec<Keymap> {
+        std::mem::take(&mut self.replay)
+    }
+
+    /// `run_bound`, then back to default if the active mode is `oneshot` and
+    /// the binding did not switch modes itself.
+    fn fire(&mut self, actions: Actions) -> Option<Actions> {
+        let mode = self.active_mode.clone();
+        let result = self.run_bound(actions);
+        if self.active_mode == mode && self.active_options().is_some_and(|o| o.oneshot) {
+            self.switch_mode("default");
+        }
+        result
+    }
+
+    /// Like `resolve_key`, for the `wheelup`/`wheeldown`/`wheelleft`/
+    /// `wheelright` pseudo-keys of a mouse wheel tick. Only a single-key
+    /// binding with at least one modifier fires: a tick never starts, ends or
+    /// cancels a sequence, and an unbound tick reaches the app even in a
+    /// `swallow_unbound` mode, so plain scrolling is never taken.
+    pub(crate) fn resolve_wheel(&mut self, keymap: &Keymap) -> (Option<Actions>, Option<String>) {
+        let before = self.mode_label();
+        let actions = self.resolve_wheel_at(keymap, Instant::now());
+        let after = self.mode_label();
+        (actions, (after != before).then_some(after))
+    }
+
+    fn resolve_wheel_at(&mut self, keymap: &Keymap, now: Instant) -> Option<Actions> {
+        self.expire(now);
+        if keymap.modifiers.is_empty()
+            || !self.pending.is_empty()
+            || self.hints.is_some()
+            || self.overview.is_some()
+        {
+            return None;
+        }
+        let actions = self
+            .active_bindings()
+            .get(&KeySequence(vec![keymap.clone()]))?
+            .clone();
+        if self.mode_since.is_some() {
+            self.mode_since = Some(now);
+        }
+        self.fire(actions)
+    }
+
+    /// Feeds `keymap` to the hint or overview session. Every key is swallowed:
+    /// a label key narrows the session, and anything else (escape, a modified
+    /// key, a key no label continues with) cancels it. Either way the session
+    /// ends back in the default mode unless the label is still incomplete.
+    fn resolve_hint(&mut self, keymap: &Keymap) -> Actions {
+        let mut chars = keymap.key.chars();
+        let key = match (chars.next(), chars.next()) {
+            (Some(c), None) if keymap.modifiers.is_empty() => Some(c),
+            _ => None,
         };
+        let press = match (key, self.hints.as_mut(), self.overview.as_mut()) {
+            (Some(c), Some(session), _) => session.press(c).map(Action::FocusWindow),
+            (Some(c), None, Some(session)) => session
+                .press(c)
+                .map(|name| Action::Focus(FocusTarget::Workspace { name })),
+            _ => HintPress::Miss,
+        };
+        match press {
+            HintPress::Pending => Actions::new(Vec::new()),
+            HintPress::Chosen(action) => {
+                self.switch_mode("default");
+                Actions::new(vec![action])
+            }
+            HintPress::Miss => {
+                self.switch_mode("default");
+                Actions::new(Vec::new())
+            }
+        }
+    }
+
+    /// Enters `HINT_MODE` with `session`'s labels. Not a configured mode, so
+    /// this bypasses `switch_mode`'s check and has no timeout.
+    pub(crate) fn start_hints(&mut self, session: HintSession) {
+        self.hints = Some(session);
+        self.overview = None;
+        self.enter_label_mode(HINT_MODE);
+    }
 
-        let actions = bindings.get(keymap)?;
+    /// Enters `OVERVIEW_MODE` with `session`'s workspace labels, like
+    /// `start_hints`.
+    pub(crate) fn start_overview(&mut self, session: HintSession<String>) {
+        self.overview = Some(session);
+        self.hints = None;
+        self.enter_label_mode(OVERVIEW_MODE);
+    }
 
-        // Fast path: when no Mode actions present (the common case), return a
-        // single clone without the per-action filter loop.
-        let has_mode = actions
+    fn enter_label_mode(&mut self, mode: &str) {
+        self.active_mode = mode.to_string();
+        self.pending.clear();
+        self.pending_since = None;
+        self.mode_since = None;
+    }
+
+    /// Options of the active mode, or `None` in the default mode and in a
+    /// mode that a reload removed.
+    fn active_options(&self) -> Option<ModeOptions> {
+        if self.active_mode == "default" || !self.keymaps.modes.contains_key(&self.active_mode) {
+            return None;
+        }
+        Some(
+            self.keymaps
+                .mode_options
+                .get(&self.active_mode)
+                .copied()
+                .unwrap_or_default(),
+        )
+    }
+
+    fn active_bindings(&self) -> &HashMap<KeySequence, Actions> {
+        if self.active_mode == "default" {
+            return &self.default_bindings;
+        }
+        match self.keymaps.modes.get(&self.active_mode) {
+            Some(m) => m,
+            None => {
+                tracing::warn!(
+                    mode = %self.active_mode,
+                    "Active mode missing from keymaps, falling back to default table"
+                );
+                &self.default_bindings
+            }
+        }
+    }
+
+    fn run_bound(&mut self, actions: Actions) -> Option<Actions> {
+        // Fast path: when no Mode actions present (the common case), return
+        // the actions untouched without the per-action filter loop.
+        let has_mode = (&actions)
             .into_iter()
             .any(|a| matches!(a, Action::Mode { .. }));
         if !has_mode {
-            return Some(actions.clone());
+            return Some(actions);
         }
 
-        // Clone into an owned Vec to drop the borrow on self.keymaps before
-        // calling self.switch_mode() (which needs &mut self).
-        let owned: Vec<Action> = actions.into_iter().cloned().collect();
-
         let mut hub_actions = Vec::new();
-        for action in &owned {
+        for action in &actions {
             if let Action::Mode { name } = action {
                 self.switch_mode(name);
             } else {
@@ -81,43 +337,191 @@ impl KeymapState {
         Some(Actions::new(hub_actions))
     }
 
+    /// `resolve_at` plus the mode status bars should now show, if it changed.
+    /// Keyboard handlers forward that to the hub thread so `mode_entered`
+    /// hooks fire there, without changing which keys are swallowed. While a
+    /// sequence is in progress the reported mode is its pending keys (e.g.
+    /// `meta+w`), and the real mode is reported again once it completes or is
+    /// abandoned.
+    pub(crate) fn resolve_key(&mut self, keymap: &Keymap) -> (Option<Actions>, Option<String>) {
+        self.resolve_key_at(keymap, Instant::now())
+    }
+
+    fn resolve_key_at(
+        &mut self,
+        keymap: &Keymap,
+        now: Instant,
+    ) -> (Option<Actions>, Option<String>) {
+        let before = self.mode_label();
+        let actions = self.resolve_at(keymap, now);
+        let after = self.mode_label();
+        let entered = (after != before).then_some(after);
+        (actions, entered)
+    }
+
+    #[cfg(test)]
+    fn resolve(&mut self, keymap: &Keymap) -> Option<Actions> {
+        self.resolve_at(keymap, Instant::now())
+    }
+
+    /// The active mode, or the pending keys while a sequence is in progress.
+    pub(crate) fn mode_label(&self) -> String {
+        if self.pending.is_empty() {
+            self.active_mode.clone()
+        } else {
+            KeySequence(self.pending.clone()).to_string()
+        }
+    }
+
+    /// When the hub should next call `expire`: the earlier of the pending
+    /// sequence's timeout and the active mode's `timeout_ms`. `None` when
+    /// neither is running.
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        let sequence = self
+            .pending_since
+            .map(|since| since + self.keymaps.sequence_timeout);
+        let mode = self
+            .mode_since
+            .zip(self.active_options().and_then(|o| o.timeout))
+            .map(|(since, timeout)| since + timeout);
+        sequence.into_iter().chain(mode).min()
+    }
+
+    /// Abandons a pending sequence whose next key did not arrive in time, and
+    /// leaves a mode idle for longer than its `timeout_ms`. Returns whether
+    /// anything changed, so the caller can report the new mode. Checking
+    /// against the most recent keypress makes early or stale timers harmless.
+    pub(crate) fn expire(&mut self, now: Instant) -> bool {
+        let mut changed = false;
+        if let Some(since) = self.pending_since
+            && now.duration_since(since) >= self.keymaps.sequence_timeout
+        {
+            let abandoned = std::mem::take(&mut self.pending);
+            self.abandon(abandoned);
+            self.pending_since = None;
+            changed = true;
+        }
+        if let Some(since) = self.mode_since
+            && let Some(timeout) = self.active_options().and_then(|o| o.timeout)
+            && now.duration_since(since) >= timeout
+        {
+            self.switch_mode("default");
+            changed = true;
+        }
+        changed
+    }
+
     /// Switch to a named mode. Unknown mode names log a warning and leave
     /// `active_mode` unchanged so the user gets immediate log feedback rather
-    /// than a silent "nothing happens when I press keys" failure.
-    pub(crate) fn switch_mode(&mut self, name: &str) {
+    /// than a silent "nothing happens when I press keys" failure. Returns
+    /// whether `name` is now the active mode.
+    pub(crate) fn switch_mode(&mut self, name: &str) -> bool {
         if name == "default" || self.keymaps.modes.contains_key(name) {
+            self.hints = None;
+            self.overview = None;
             self.active_mode = name.to_string();
+            self.pending.clear();
+            self.pending_since = None;
+            self.mode_since = (name != "default").then(Instant::now);
+            true
         } else {
             tracing::warn!(mode = name, "Unknown mode, staying in current mode");
+            false
         }
     }
 
     /// Update keymaps on config reload. `active_mode` is preserved: if the new
-    /// config still defines it, the user stays in it; if not, `resolve` falls
-    /// back to the default table on the next keypress.
+    /// config still defines it, the user stays in it; if not, `resolve_key` falls
+    /// back to the default table on the next keypress. A half-typed sequence is
+    /// dropped since its bindings may be gone.
     pub(crate) fn update_keymaps(&mut self, keymaps: ModalKeymaps) {
         self.keymaps = keymaps;
+        self.pending.clear();
+        self.pending_since = None;
+        self.rebuild_default_bindings();
+    }
+
+    /// JSON for `dome query keymaps`: the effective bindings after config
+    /// merging, keyed by canonical keymap string. Sorted so the output diffs
+    /// cleanly between reloads. App blocks keep their config order, since a
+    /// later block wins over an earlier one.
+    pub(crate) fn query_keymaps_json(&self) -> String {
+        let info = KeymapsInfo {
+            default: bindings_info(&self.keymaps.default),
+            modes: self
+                .keymaps
+                .modes
+                .iter()
+                .map(|(name, bindings)| (name.clone(), bindings_info(bindings)))
+                .collect(),
+            apps: self
+                .keymaps
+                .apps
+                .iter()
+                .map(|app| AppKeymapsInfo {
+                    window: &app.window,
+                    passthrough: app.passthrough,
+                    bindings: bindings_info(&app.bindings),
+                    unbound: app.unbound.iter().map(|seq| seq.to_string()).collect(),
+                })
+                .collect(),
+        };
+        serde_json::to_string(&info).expect("KeymapsInfo is infallibly serializable")
     }
 
-    /// Reserved for planned `dome query mode` IPC command. Currently only
-    /// exercised by unit tests.
-    #[cfg_attr(
-        not(test),
-        expect(
-            dead_code,
-            reason = "reserved for planned `dome query mode` IPC command"
-        )
-    )]
     pub(crate) fn active_mode(&self) -> &str {
         &self.active_mode
     }
+
+    /// JSON for `dome query mode`.
+    pub(crate) fn query_mode_json(&self) -> String {
+        let info = ModeInfo {
+            mode: self.active_mode(),
+            pending: (!self.pending.is_empty())
+                .then(|| KeySequence(self.pending.clone()).to_string()),
+        };
+        serde_json::to_string(&info).expect("ModeInfo is infallibly serializable")
+    }
+}
+
+#[derive(Serialize)]
+struct ModeInfo<'a> {
+    mode: &'a str,
+    /// Keys typed so far of an unfinished key sequence.
+    pending: Option<String>,
+}
+
+#[derive(Serialize)]
+struct KeymapsInfo<'a> {
+    default: BTreeMap<String, Vec<String>>,
+    modes: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+    apps: Vec<AppKeymapsInfo<'a>>,
+}
+
+#[derive(Serialize)]
+struct AppKeymapsInfo<'a> {
+    window: &'a WindowMatcher,
+    passthrough: bool,
+    bindings: BTreeMap<String, Vec<String>>,
+    unbound: Vec<String>,
+}
+
+fn bindings_info(bindings: &HashMap<KeySequence, Actions>) -> BTreeMap<String, Vec<String>> {
+    bindings
+        .iter()
+        .map(|(keymap, actions)| {
+            let actions = actions.into_iter().map(|a| a.to_string()).collect();
+            (keymap.to_string(), actions)
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::action::{Action, Actions, FocusTarget};
-    use crate::config::{Keymap, Modifiers};
+    use crate::config::{Keymap, Modifiers, PASSTHROUGH_MODE, WindowMatcher, pattern_matches};
+    use std::time::Duration;
 
     fn km(key: &str, mods: Modifiers) -> Keymap {
         Keymap {
@@ -141,11 +545,17 @@ mod tests {
         modes: Vec<(&str, Vec<(Keymap, Actions)>)>,
     ) -> ModalKeymaps {
         ModalKeymaps {
-            default: default.into_iter().collect(),
+            default: default.into_iter().map(|(k, a)| (k.into(), a)).collect(),
             modes: modes
                 .into_iter()
-                .map(|(name, bindings)| (name.to_string(), bindings.into_iter().collect()))
+                .map(|(name, bindings)| {
+                    let bindings = bindings.into_iter().map(|(k, a)| (k.into(), a)).collect();
+                    (name.to_string(), bindings)
+                })
                 .collect(),
+            apps: Vec::new(),
+            sequence_timeout: Duration::from_millis(1000),
+            mode_options: HashMap::new(),
         }
     }
 
@@ -221,6 +631,35 @@ mod tests {
         assert_eq!(state.active_mode(), "resize");
     }
 
+    #[test]
+    fn keymap_state_resolve_key_reports_entered_mode() {
+        let cmd_r = km("r", Modifiers::META);
+        let cmd_h = km("h", Modifiers::META);
+        let keymaps = make_keymaps(
+            vec![
+                (cmd_r.clone(), Actions::new(vec![mode_action("resize")])),
+                (cmd_h.clone(), focus_left_actions()),
+            ],
+            vec![(
+                "resize",
+                vec![(cmd_r.clone(), Actions::new(vec![mode_action("resize")]))],
+            )],
+        );
+        let mut state = KeymapState::new(keymaps);
+
+        let (actions, entered) = state.resolve_key(&cmd_h);
+        assert_eq!(actions.unwrap().to_string(), "[focus left]");
+        assert_eq!(entered, None);
+
+        let (actions, entered) = state.resolve_key(&cmd_r);
+        assert!(actions.is_none());
+        assert_eq!(entered.as_deref(), Some("resize"));
+
+        // Re-entering the active mode is not a change
+        let (_, entered) = state.resolve_key(&cmd_r);
+        assert_eq!(entered, None);
+    }
+
     #[test]
     fn keymap_state_switch_to_unknown_mode_from_default() {
         let keymaps = make_keymaps(vec![], vec![]);
@@ -310,4 +749,463 @@ mod tests {
         // Falls back to default, but cmd+j is not bound there either
         assert!(state.resolve(&cmd_j).is_none());
     }
+
+    #[test]
+    fn query_keymaps_json_uses_canonical_keys() {
+        let mut keymaps = make_keymaps(
+            vec![(
+                km("h", Modifiers::SHIFT | Modifiers::META),
+                focus_left_actions(),
+            )],
+            vec![(
+                "resize",
+                vec![(
+                    km("escape", Modifiers::empty()),
+                    Actions::new(vec![mode_action("default")]),
+                )],
+            )],
+        );
+        let mut editor = app_block(
+            "Zed",
+            false,
+            vec![(km("j", Modifiers::META), focus_left_actions())],
+        );
+        editor
+            .unbound
+            .push(km("h", Modifiers::SHIFT | Modifiers::META).into());
+        keymaps.apps.push(editor);
+        let state = KeymapState::new(keymaps);
+        insta::assert_snapshot!(state.query_keymaps_json(), @r#"
+        {"default":{"meta+shift+h":["focus left"]},"modes":{"resize":{"escape":["mode default"]}},"apps":[{"window":{"app":"Zed"},"passthrough":false,"bindings":{"meta+j":["focus left"]},"unbound":["meta+shift+h"]}]}
+        "#);
+    }
+
+    fn sequence_keymaps(bindings: &[(&str, Actions)]) -> ModalKeymaps {
+        let mut keymaps = make_keymaps(vec![], vec![]);
+        for (seq, actions) in bindings {
+            keymaps
+                .default
+                .insert(seq.parse().unwrap(), actions.clone());
+        }
+        keymaps
+    }
+
+    #[test]
+    fn keymap_state_sequence_swallows_prefix_then_resolves() {
+        let keymaps = sequence_keymaps(&[("meta+w v", focus_left_actions())]);
+        let mut state = KeymapState::new(keymaps);
+        let now = Instant::now();
+
+        let (actions, entered) = state.resolve_key_at(&km("w", Modifiers::META), now);
+        assert!(actions.is_some_and(|a| a.is_empty()));
+        assert_eq!(entered.as_deref(), Some("meta+w"));
+
+        let (actions, entered) = state.resolve_key_at(&km("v", Modifiers::empty()), now);
+        assert_eq!(actions.unwrap().to_string(), "[focus left]");
+        assert_eq!(entered.as_deref(), Some("default"));
+        assert_eq!(state.next_deadline(), None);
+    }
+
+    #[test]
+    fn keymap_state_sequence_mismatch_retries_key_alone() {
+        let keymaps = sequence_keymaps(&[
+            ("meta+w v", focus_left_actions()),
+            ("meta+h", focus_left_actions()),
+        ]);
+        let mut state = KeymapState::new(keymaps);
+        let now = Instant::now();
+        state.resolve_at(&km("w", Modifiers::META), now);
+
+        // Unbound after the prefix: swallowed and replayed after it.
+        assert!(
+            state
+                .resolve_at(&km("x", Modifiers::empty()), now)
+                .is_some_and(|a| a.is_empty())
+        );
+        assert_eq!(state.mode_label(), "default");
+        assert_eq!(
+            state.take_replay(),
+            [km("w", Modifiers::META), km("x", Modifiers::empty())]
+        );
+
+        // Bound on its own: the prefix is replayed and the key still fires.
+        state.resolve_at(&km("w", Modifiers::META), now);
+        let result = state.resolve_at(&km("h", Modifiers::META), now);
+        assert_eq!(result.unwrap().to_string(), "[focus left]");
+        assert_eq!(state.take_replay(), [km("w", Modifiers::META)]);
+        assert!(state.take_replay().is_empty());
+    }
+
+    #[test]
+    fn keymap_state_sequence_times_out() {
+        let keymaps = sequence_keymaps(&[("meta+w v", focus_left_actions())]);
+        let mut state = KeymapState::new(keymaps);
+        let now = Instant::now();
+        state.resolve_at(&km("w", Modifiers::META), now);
+        assert_eq!(
+            state.next_deadline(),
+            Some(now + Duration::from_millis(1000))
+        );
+
+        assert!(!state.expire(now + Duration::from_millis(999)));
+        assert!(state.take_replay().is_empty());
+        assert!(state.expire(now + Duration::from_millis(1000)));
+        assert_eq!(state.mode_label(), "default");
+        assert_eq!(state.take_replay(), [km("w", Modifiers::META)]);
+
+        let late = state.resolve_at(&km("v", Modifiers::empty()), now + Duration::from_secs(2));
+        assert!(late.is_none());
+    }
+
+    #[test]
+    fn keymap_state_sequence_can_enter_mode() {
+        let mut keymaps =
+            sequence_keymaps(&[("meta+w r", Actions::new(vec![mode_action("resize")]))]);
+        keymaps.modes.insert("resize".to_string(), HashMap::new());
+        let mut state = KeymapState::new(keymaps);
+        let now = Instant::now();
+        state.resolve_key_at(&km("w", Modifiers::META), now);
+        let (_, entered) = state.resolve_key_at(&km("r", Modifiers::empty()), now);
+        assert_eq!(entered.as_deref(), Some("resize"));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Meta {
+        app: &'static str,
+        title: &'static str,
+    }
+
+    impl std::fmt::Display for Meta {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} - {}", self.app, self.title)
+        }
+    }
+
+    impl WindowMetadata for Meta {
+        fn app_name(&self) -> Option<String> {
+            Some(self.app.to_string())
+        }
+        fn title(&self) -> Option<&str> {
+            Some(self.title)
+        }
+        fn set_title(&mut self, _title: String) {}
+        fn clone_box(&self) -> Box<dyn WindowMetadata> {
+            Box::new(self.clone())
+        }
+        fn matches_window_matcher(&self, matcher: &WindowMatcher) -> bool {
+            matcher
+                .app
+                .as_deref()
+                .is_some_and(|p| pattern_matches(p, self.app))
+                && matcher
+                    .title
+                    .as_deref()
+                    .is_none_or(|p| pattern_matches(p, self.title))
+        }
+        fn to_window_matcher(&self) -> WindowMatcher {
+            WindowMatcher::default()
+        }
+    }
+
+    fn window_id(n: u32) -> WindowId {
+        serde_json::from_str(&n.to_string()).unwrap()
+    }
+
+    fn app_block(app: &str, passthrough: bool, bindings: Vec<(Keymap, Actions)>) -> AppKeymaps {
+        AppKeymaps {
+            window: WindowMatcher {
+                app: Some(app.to_string()),
+                ..Default::default()
+            },
+            passthrough,
+            bindings: bindings.into_iter().map(|(k, a)| (k.into(), a)).collect(),
+            unbound: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn keymap_state_app_block_overrides_and_unbinds_while_focused() {
+        let cmd_h = km("h", Modifiers::META);
+        let cmd_j = km("j", Modifiers::META);
+        let mut keymaps = make_keymaps(
+            vec![
+                (cmd_h.clone(), focus_left_actions()),
+                (cmd_j.clone(), focus_left_actions()),
+            ],
+            vec![],
+        );
+        let mut editor = app_block(
+            "Zed",
+            false,
+            vec![(cmd_j.clone(), Actions::new(vec![Action::Close]))],
+        );
+        editor.unbound.push(cmd_h.clone().into());
+        keymaps.apps.push(editor);
+        let mut state = KeymapState::new(keymaps);
+        let zed = Meta {
+            app: "Zed",
+            title: "main.rs",
+        };
+        let term = Meta {
+            app: "kitty",
+            title: "zsh",
+        };
+
+        state.set_focused_window(Some((window_id(1), &zed)));
+        assert!(state.resolve(&cmd_h).is_none());
+        assert_eq!(state.resolve(&cmd_j).unwrap().to_string(), "[close]");
+
+        state.set_focused_window(Some((window_id(2), &term)));
+        assert_eq!(state.resolve(&cmd_h).unwrap().to_string(), "[focus left]");
+        assert_eq!(state.resolve(&cmd_j).unwrap().to_string(), "[focus left]");
+
+        state.set_focused_window(None);
+        assert!(state.resolve(&cmd_h).is_some());
+    }
+
+    #[test]
+    fn keymap_state_passthrough_app_block_keeps_only_its_bindings() {
+        let cmd_h = km("h", Modifiers::META);
+        let cmd_g = km("g", Modifiers::META);
+        let mut keymaps = make_keymaps(vec![(cmd_h.clone(), focus_left_actions())], vec![]);
+        keymaps.apps.push(app_block(
+            "Game",
+            true,
+            vec![(cmd_g.clone(), focus_left_actions())],
+        ));
+        let mut state = KeymapState::new(keymaps);
+        let game = Meta {
+            app: "Game",
+            title: "fullscreen",
+        };
+        state.set_focused_window(Some((window_id(1), &game)));
+        assert!(state.resolve(&cmd_h).is_none());
+        assert!(state.resolve(&cmd_g).is_some());
+    }
+
+    #[test]
+    fn keymap_state_app_blocks_survive_reload_and_title_change() {
+        let cmd_h = km("h", Modifiers::META);
+        let block = || {
+            let mut app = app_block("Browser", false, vec![]);
+            app.window.title = Some("/Docs/".to_string());
+            app.unbound.push(km("h", Modifiers::META).into());
+            app
+        };
+        let mut keymaps = make_keymaps(vec![(cmd_h.clone(), focus_left_actions())], vec![]);
+        keymaps.apps.push(block());
+        let mut state = KeymapState::new(keymaps);
+
+        let news = Meta {
+            app: "Browser",
+            title: "News",
+        };
+        state.set_focused_window(Some((window_id(1), &news)));
+        assert!(state.resolve(&cmd_h).is_some());
+
+        let docs = Meta {
+            app: "Browser",
+            title: "Docs",
+        };
+        state.set_focused_window(Some((window_id(1), &docs)));
+        assert!(state.resolve(&cmd_h).is_none());
+
+        let mut reloaded = make_keymaps(vec![(cmd_h.clone(), focus_left_actions())], vec![]);
+        reloaded.apps.push(block());
+        state.update_keymaps(reloaded);
+        assert!(state.resolve(&cmd_h).is_none());
+    }
+
+    #[test]
+    fn keymap_state_passthrough_mode_forwards_all_but_escape() {
+        let cmd_h = km("h", Modifiers::META);
+        let escape = km("escape", Modifiers::META | Modifiers::SHIFT);
+        let mut keymaps = make_keymaps(vec![(cmd_h.clone(), focus_left_actions())], vec![]);
+        keymaps.modes.insert(
+            PASSTHROUGH_MODE.to_string(),
+            HashMap::from([(
+                escape.clone().into(),
+                Actions::new(vec![mode_action("default")]),
+            )]),
+        );
+        let mut state = KeymapState::new(keymaps);
+        assert!(state.switch_mode(PASSTHROUGH_MODE));
+        assert!(state.resolve(&cmd_h).is_none());
+        state.resolve(&escape);
+        assert_eq!(state.active_mode(), "default");
+        assert!(state.resolve(&cmd_h).is_some());
+    }
+
+    fn resize_keymaps(options: ModeOptions) -> ModalKeymaps {
+        let mut keymaps = make_keymaps(
+            vec![(km("h", Modifiers::META), focus_left_actions())],
+            vec![(
+                "resize",
+                vec![(km("h", Modifiers::empty()), focus_left_actions())],
+            )],
+        );
+        keymaps.mode_options.insert("resize".to_string(), options);
+        keymaps
+    }
+
+    #[test]
+    fn keymap_state_oneshot_mode_returns_to_default() {
+        let mut state = KeymapState::new(resize_keymaps(ModeOptions {
+            oneshot: true,
+            ..ModeOptions::default()
+        }));
+        state.switch_mode("resize");
+        // Unbound keys do not end a one-shot mode
+        state.resolve(&km("x", Modifiers::empty()));
+        assert_eq!(state.active_mode(), "resize");
+        let result = state.resolve(&km("h", Modifiers::empty()));
+        assert_eq!(result.unwrap().to_string(), "[focus left]");
+        assert_eq!(state.active_mode(), "default");
+    }
+
+    #[test]
+    fn wheel_fires_only_modified_single_key_bindings() {
+        let scroll = Actions::new(vec!["scroll down".parse().unwrap()]);
+        let mut keymaps = make_keymaps(
+            vec![
+                (km("wheeldown", Modifiers::ALT), scroll),
+                (km("wheelup", Modifiers::empty()), focus_left_actions()),
+            ],
+            vec![("resize", vec![])],
+        );
+        keymaps.mode_options.insert(
+            "resize".to_string(),
+            ModeOptions {
+                swallow_unbound: true,
+                ..ModeOptions::default()
+            },
+        );
+        let mut state = KeymapState::new(keymaps);
+        let (actions, entered) = state.resolve_wheel(&km("wheeldown", Modifiers::ALT));
+        assert_eq!(actions.unwrap().to_string(), "[scroll down]");
+        assert_eq!(entered, None);
+        // Plain scrolling always reaches the app
+        assert!(
+            state
+                .resolve_wheel(&km("wheelup", Modifiers::empty()))
+                .0
+                .is_none()
+        );
+        // Unbound ticks are not swallowed by a swallow_unbound mode
+        state.switch_mode("resize");
+        assert!(
+            state
+                .resolve_wheel(&km("wheelup", Modifiers::ALT))
+                .0
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn keymap_state_mode_passes_unbound_keys_unless_swallowing() {
+        let mut state = KeymapState::new(resize_keymaps(ModeOptions::default()));
+        state.switch_mode("resize");
+        assert!(state.resolve(&km("x", Modifiers::empty())).is_none());
+
+        let mut state = KeymapState::new(resize_keymaps(ModeOptions {
+            swallow_unbound: true,
+            ..ModeOptions::default()
+        }));
+        state.switch_mode("resize");
+        assert!(
+            state
+                .resolve(&km("x", Modifiers::empty()))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn keymap_state_mode_times_out_after_last_keypress() {
+        let timeout = Duration::from_millis(1000);
+        let mut state = KeymapState::new(resize_keymaps(ModeOptions {
+            timeout: Some(timeout),
+            ..ModeOptions::default()
+        }));
+        state.switch_mode("resize");
+        let start = state.next_deadline().unwrap() - timeout;
+
+        // A keypress halfway through restarts the countdown
+        let later = start + Duration::from_millis(500);
+        state.resolve_at(&km("h", Modifiers::empty()), later);
+        assert_eq!(state.next_deadline(), Some(later + timeout));
+        assert!(!state.expire(start + timeout));
+        assert_eq!(state.active_mode(), "resize");
+
+        assert!(state.expire(later + timeout));
+        assert_eq!(state.active_mode(), "default");
+        assert_eq!(state.next_deadline(), None);
+    }
+
+    fn hint_session() -> HintSession {
+        HintSession::new(vec![
+            ("a".to_string(), window_id(1)),
+            ("sa".to_string(), window_id(2)),
+            ("ss".to_string(), window_id(3)),
+        ])
+    }
+
+    #[test]
+    fn keymap_state_hint_label_focuses_window() {
+        let mut state = KeymapState::new(resize_keymaps(ModeOptions::default()));
+        state.start_hints(hint_session());
+        assert_eq!(state.active_mode(), HINT_MODE);
+
+        let (actions, entered) = state.resolve_key(&km("s", Modifiers::empty()));
+        assert!(actions.unwrap().is_empty());
+        assert_eq!(entered, None);
+        let (actions, entered) = state.resolve_key(&km("a", Modifiers::empty()));
+        assert_eq!(actions.unwrap().to_string(), "[focus window WindowId(2)]");
+        assert_eq!(entered.as_deref(), Some("default"));
+    }
+
+    #[test]
+    fn keymap_state_hint_cancels_on_other_keys() {
+        let mut state = KeymapState::new(resize_keymaps(ModeOptions::default()));
+        for key in [
+            km("escape", Modifiers::empty()),
+            km("a", Modifiers::META),
+            km("x", Modifiers::empty()),
+        ] {
+            state.start_hints(hint_session());
+            assert!(state.resolve(&key).unwrap().is_empty());
+            assert_eq!(state.active_mode(), "default");
+        }
+        let result = state.resolve(&km("h", Modifiers::META));
+        assert_eq!(result.unwrap().to_string(), "[focus left]");
+    }
+
+    #[test]
+    fn keymap_state_overview_label_focuses_workspace() {
+        let mut state = KeymapState::new(resize_keymaps(ModeOptions::default()));
+        state.start_overview(HintSession::new(vec![
+            ("a".to_string(), "main".to_string()),
+            ("s".to_string(), "web".to_string()),
+        ]));
+        assert_eq!(state.active_mode(), OVERVIEW_MODE);
+
+        let (actions, entered) = state.resolve_key(&km("s", Modifiers::empty()));
+        assert_eq!(actions.unwrap().to_string(), "[focus workspace web]");
+        assert_eq!(entered.as_deref(), Some("default"));
+        let result = state.resolve(&km("h", Modifiers::META));
+        assert_eq!(result.unwrap().to_string(), "[focus left]");
+    }
+
+    #[test]
+    fn query_mode_json_reports_pending_keys() {
+        let keymaps = sequence_keymaps(&[("meta+w v", focus_left_actions())]);
+        let mut state = KeymapState::new(keymaps);
+        insta::assert_snapshot!(state.query_mode_json(), @r#"
+        {"mode":"default","pending":null}
+        "#);
+        state.resolve(&km("w", Modifiers::META));
+        insta::assert_snapshot!(state.query_mode_json(), @r#"
+        {"mode":"default","pending":"meta+w"}
+        "#);
+    }
 }
@@ -2,7 +2,9 @@ mod action;
 pub mod cli;
 mod config;
 mod core;
+mod diagnostics;
 mod font;
+mod hook;
 mod ipc;
 mod keymap;
 mod log_dedup;
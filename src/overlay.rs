@@ -2,30 +2,111 @@
 // scale, so strokes, corner radii and rects are rescaled to physical pixels at tessellation
 // and must never be pre-multiplied here.
 
+use std::ops::Range;
+use std::sync::Arc;
+
 use egui::{
-    Align, Color32, CornerRadius, Id, LayerId, Layout, Order, Rect, RichText, Sense, Stroke,
-    StrokeKind, TextStyle, pos2, vec2,
+    Align, Align2, Color32, CornerRadius, FontId, Id, LayerId, Layout, Order, PointerButton, Pos2,
+    Rect, RichText, Sense, Stroke, StrokeKind, TextStyle, pos2, vec2,
 };
 
-use crate::core::{ContainerId, Dimension, Length, Logical, SpawnIndicator, WindowId};
+use crate::config::{Config, ScrollIndicator, TabBarPosition};
+use crate::core::{
+    ContainerId, Dimension, FloatWindowPlacement, Length, Logical, OverviewWindow,
+    OverviewWorkspace, PixelRect, SpawnIndicator, TilingWindowPlacement, WindowEmphasis, WindowId,
+};
 use crate::theme::Theme;
 
-/// Hardcoded corner radius for window borders and tabbed-container body
-/// borders. Kept private: rendering knobs should not leak into the config
-/// surface or into core, which has no view on pixels.
-const WINDOW_BORDER_RADIUS_LOGICAL: f32 = 12.0;
-
+/// Radius and painted widths come from config. Core only knows `thickness`, the
+/// space it reserves around each window's content.
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct BorderMetrics {
     pub thickness: Length<Logical>,
     pub radius: Length<Logical>,
+    pub focused_width: Length<Logical>,
+    pub unfocused_width: Length<Logical>,
 }
 
 impl BorderMetrics {
-    pub(crate) fn from_thickness(thickness: Length<Logical>) -> Self {
+    pub(crate) fn new(config: &Config, thickness: Length<Logical>) -> Self {
+        let width = |size: Option<_>| Length::from_pixels(size.unwrap_or(config.border_size));
         Self {
             thickness,
-            radius: Length::new(WINDOW_BORDER_RADIUS_LOGICAL),
+            radius: Length::from_pixels(config.border_radius),
+            focused_width: width(config.focused_border_size),
+            unfocused_width: width(config.unfocused_border_size),
+        }
+    }
+
+    /// Same metrics around a window that reserves `thickness` of its own.
+    pub(crate) fn with_thickness(self, thickness: Length<Logical>) -> Self {
+        Self { thickness, ..self }
+    }
+
+    /// Painted width for `state`. Never wider than the reserved thickness, so a
+    /// border cannot spill over the window's content. Core reserves room for
+    /// the widest state, so only a `[[border]]` rule's size cuts a width down.
+    fn stroke_width(&self, state: BorderState) -> f32 {
+        let width = match state {
+            BorderState::Focused | BorderState::FocusedFloat | BorderState::Flash => {
+                self.focused_width
+            }
+            BorderState::InactiveFocused
+            | BorderState::Urgent
+            | BorderState::Marked
+            | BorderState::Float
+            | BorderState::Unfocused => self.unfocused_width,
+        };
+        width.logical().min(self.thickness.logical()).max(0.0)
+    }
+}
+
+/// Which theme color a window border is painted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BorderState {
+    Focused,
+    FocusedFloat,
+    Flash,
+    InactiveFocused,
+    Urgent,
+    Marked,
+    /// A float without focus or emphasis.
+    Float,
+    Unfocused,
+}
+
+impl BorderState {
+    pub(crate) fn tiling(wp: &TilingWindowPlacement) -> Self {
+        Self::new(wp.is_highlighted, false, wp.emphasis)
+    }
+
+    pub(crate) fn float(wp: &FloatWindowPlacement) -> Self {
+        Self::new(wp.is_highlighted, true, wp.emphasis)
+    }
+
+    fn new(is_highlighted: bool, is_float: bool, emphasis: WindowEmphasis) -> Self {
+        match emphasis {
+            WindowEmphasis::Flash => BorderState::Flash,
+            _ if is_highlighted && is_float => BorderState::FocusedFloat,
+            _ if is_highlighted => BorderState::Focused,
+            WindowEmphasis::InactiveFocus => BorderState::InactiveFocused,
+            WindowEmphasis::Urgent => BorderState::Urgent,
+            WindowEmphasis::Marked => BorderState::Marked,
+            WindowEmphasis::None if is_float => BorderState::Float,
+            WindowEmphasis::None => BorderState::Unfocused,
+        }
+    }
+
+    fn color(self, theme: &Theme) -> Color32 {
+        match self {
+            BorderState::Focused => theme.focused_border,
+            BorderState::FocusedFloat => theme.float_border,
+            BorderState::Flash => theme.focus_flash,
+            BorderState::InactiveFocused => theme.inactive_focused_border,
+            BorderState::Urgent => theme.urgent_border,
+            BorderState::Marked => theme.marked_border,
+            BorderState::Float => theme.unfocused_float_border(),
+            BorderState::Unfocused => theme.unfocused_border,
         }
     }
 }
@@ -35,8 +116,10 @@ pub(crate) struct LogicalTiledWindow {
     pub id: WindowId,
     pub frame: Dimension<Logical>,
     pub visible_frame: Dimension<Logical>,
-    pub is_highlighted: bool,
+    pub state: BorderState,
     pub spawn_indicator: Option<SpawnIndicator>,
+    /// The window's own reserved border, see `TilingWindowPlacement::border`.
+    pub thickness: Length<Logical>,
 }
 
 #[derive(Clone, Debug)]
@@ -44,26 +127,114 @@ pub(crate) struct LogicalTiledContainer {
     pub id: ContainerId,
     pub frame: Dimension<Logical>,
     pub visible_frame: Dimension<Logical>,
-    /// Reserved by core at the top of `frame`. Reading config here instead reopens a sub-unit
-    /// seam against the painted bar.
-    pub tab_bar_height: Length<Logical>,
+    /// Reserved by core on the `tab_bar_position` side of `frame`. Reading config here
+    /// instead reopens a sub-unit seam against the painted bar.
+    pub tab_bar_thickness: Length<Logical>,
+    pub tab_bar_position: TabBarPosition,
     pub is_highlighted: bool,
     pub spawn_indicator: Option<SpawnIndicator>,
     pub is_tabbed: bool,
     pub titles: Vec<String>,
 }
 
+/// A `focus hint` label, centered over `frame`.
+#[derive(Clone, Debug)]
+pub(crate) struct LogicalHint {
+    pub label: String,
+    pub frame: Dimension<Logical>,
+}
+
+/// A region that scrolls, see `ScrollExtent`.
+#[derive(Clone, Debug)]
+pub(crate) struct LogicalScrollExtent {
+    pub viewport: Dimension<Logical>,
+    pub content: Dimension<Logical>,
+    pub windows: Vec<Dimension<Logical>>,
+}
+
+/// An app icon drawn beside a tab title, as unpremultiplied RGBA. `key` names the
+/// uploaded texture, so shells reuse one key per icon instead of re-uploading
+/// every frame.
+#[derive(Clone, Debug)]
+pub(crate) struct TabIcon {
+    pub key: Id,
+    pub size: [usize; 2],
+    pub rgba: Arc<[u8]>,
+}
+
+/// A pointer gesture on a tab bar, by tab index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TabBarEvent {
+    /// Primary click: switch to the tab.
+    Select(usize),
+    /// Middle click: close the tab's window.
+    Close(usize),
+    /// Dropped onto another tab: reorder within the bar.
+    Move { from: usize, to: usize },
+    /// Dropped away from the bar: split the tab off next to its container.
+    DragOut(usize),
+}
+
+/// A pointer gesture on the workspace overview.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum OverviewEvent {
+    /// Clicked a workspace's backdrop.
+    FocusWorkspace(String),
+    /// Clicked a window.
+    FocusWindow(WindowId),
+    /// Dropped a window onto another workspace.
+    MoveWindow { window: WindowId, workspace: String },
+    /// Clicked outside every workspace.
+    Dismiss,
+}
+
+/// Below this a title shows only a few glyphs, so the bar scrolls instead.
+const MIN_TAB_WIDTH: f32 = 80.0;
+/// Width of the `+N` chip standing in for tabs scrolled out of view.
+const OVERFLOW_CHIP_WIDTH: f32 = 36.0;
+/// Height of a sidebar row, in body text lines.
+const SIDEBAR_ROW_LINES: f32 = 2.0;
+/// How far past the bar's edge a dragged tab may drop and still reorder, so a
+/// sloppy release does not split it off.
+const DRAG_OUT_MARGIN: f32 = 24.0;
+
+/// Large enough to read at a glance from across the screen.
+const HINT_FONT_SIZE: f32 = 24.0;
+const HINT_PADDING: f32 = 8.0;
+const HINT_RADIUS: u8 = 6;
+
+/// Space around the overview grid and between its cells.
+const OVERVIEW_GAP: f32 = 32.0;
+/// How much of the desktop shows through the overview's backdrop.
+const OVERVIEW_DIM: f32 = 0.85;
+
+/// Thickness of the bars `scroll_indicator = "edges"` draws.
+const SCROLL_EDGE_WIDTH: f32 = 4.0;
+/// Length of an arrow pointing at an offscreen window.
+const SCROLL_ARROW_SIZE: f32 = 12.0;
+/// Longer side of the scroll minimap, unless the viewport is too small for it.
+const MINIMAP_SIZE: f32 = 120.0;
+/// Space between the minimap and the viewport's corner.
+const MINIMAP_MARGIN: f32 = 16.0;
+
 /// Paints the per-monitor tiling overlay: window borders, highlighted-container
 /// body border. Tab bars are owned by per-`ContainerId` windows the platform
 /// shell hosts separately and reach the painter via `paint_tab_bar`, so this
 /// entry point does not paint or hit-test tab bars. The returned click vector
 /// is always empty and exists only to keep the renderer API uniform with
 /// per-window overlays that do collect clicks.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "one paint pass worth of tiling overlay state"
+)]
 pub(crate) fn paint_tiling_overlay(
     ctx: &egui::Context,
     monitor: Dimension<Logical>,
     windows: &[LogicalTiledWindow],
     containers: &[LogicalTiledContainer],
+    hints: &[LogicalHint],
+    scroll_extents: &[LogicalScrollExtent],
+    scroll_indicator: ScrollIndicator,
     theme: &Theme,
     border: BorderMetrics,
 ) -> Vec<(ContainerId, usize)> {
@@ -85,10 +256,10 @@ pub(crate) fn paint_tiling_overlay(
             &painter.with_clip_rect(clip),
             wp.frame,
             wp.visible_frame,
-            wp.is_highlighted,
+            wp.state,
             wp.spawn_indicator,
             theme,
-            border,
+            border.with_thickness(wp.thickness),
             origin,
         );
     }
@@ -117,9 +288,205 @@ pub(crate) fn paint_tiling_overlay(
             });
     }
 
+    if scroll_indicator != ScrollIndicator::Off {
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("scroll")));
+        let canvas = |d: Dimension<Logical>| {
+            Rect::from_min_size(
+                pos2(
+                    d.x.logical() - monitor.x.logical(),
+                    d.y.logical() - monitor.y.logical(),
+                ),
+                vec2(d.width.logical(), d.height.logical()),
+            )
+        };
+        for extent in scroll_extents {
+            let viewport = canvas(extent.viewport);
+            let content = canvas(extent.content);
+            let windows: Vec<Rect> = extent.windows.iter().map(|&w| canvas(w)).collect();
+            match scroll_indicator {
+                ScrollIndicator::Off => {}
+                ScrollIndicator::Edges => {
+                    paint_scroll_edges(&painter, viewport, content, &windows, theme);
+                }
+                ScrollIndicator::Minimap => {
+                    paint_minimap(&painter, viewport, content, &windows, theme);
+                }
+            }
+        }
+    }
+
+    // Tooltip order keeps labels above borders and container tab bars.
+    let painter = ctx.layer_painter(LayerId::new(Order::Tooltip, Id::new("hints")));
+    for hint in hints {
+        let galley = painter.layout_no_wrap(
+            hint.label.clone(),
+            FontId::proportional(HINT_FONT_SIZE),
+            theme.tab_text,
+        );
+        let center = pos2(
+            hint.frame.x.logical() - monitor.x.logical() + hint.frame.width.logical() / 2.0,
+            hint.frame.y.logical() - monitor.y.logical() + hint.frame.height.logical() / 2.0,
+        );
+        let badge = Rect::from_center_size(
+            center,
+            galley.size() + vec2(HINT_PADDING, HINT_PADDING) * 2.0,
+        );
+        painter.rect(
+            badge,
+            CornerRadius::same(HINT_RADIUS),
+            theme.tab_bar_bg,
+            Stroke::new(2.0, theme.focused_border),
+            StrokeKind::Inside,
+        );
+        painter.galley(
+            badge.min + vec2(HINT_PADDING, HINT_PADDING),
+            galley,
+            theme.tab_text,
+        );
+    }
+
     Vec::new()
 }
 
+/// A bar along each viewport edge with content past it, and an arrow on the
+/// bars toward each window that is fully offscreen.
+fn paint_scroll_edges(
+    painter: &egui::Painter,
+    viewport: Rect,
+    content: Rect,
+    windows: &[Rect],
+    theme: &Theme,
+) {
+    let w = SCROLL_EDGE_WIDTH;
+    let (min, max) = (viewport.min, viewport.max);
+    let bars = [
+        (
+            content.min.x < min.x,
+            Rect::from_min_max(min, pos2(min.x + w, max.y)),
+        ),
+        (
+            content.max.x > max.x,
+            Rect::from_min_max(pos2(max.x - w, min.y), max),
+        ),
+        (
+            content.min.y < min.y,
+            Rect::from_min_max(min, pos2(max.x, min.y + w)),
+        ),
+        (
+            content.max.y > max.y,
+            Rect::from_min_max(pos2(min.x, max.y - w), max),
+        ),
+    ];
+    for (_, bar) in bars.into_iter().filter(|(overflows, _)| *overflows) {
+        painter.rect_filled(
+            bar,
+            CornerRadius::ZERO,
+            theme.focused_border.gamma_multiply(0.6),
+        );
+    }
+    for (tip, dir) in offscreen_arrows(viewport.shrink(w), windows) {
+        let base = tip - dir * SCROLL_ARROW_SIZE;
+        let side = dir.rot90() * (SCROLL_ARROW_SIZE / 2.0);
+        painter.add(egui::Shape::convex_polygon(
+            vec![tip, base + side, base - side],
+            theme.focused_border,
+            Stroke::NONE,
+        ));
+    }
+}
+
+/// Where to point at each window wholly outside `viewport`: the window's
+/// center pulled onto the viewport's edge, and the unit direction toward it.
+/// Windows past a corner get a diagonal arrow in that corner.
+fn offscreen_arrows(viewport: Rect, windows: &[Rect]) -> Vec<(Pos2, egui::Vec2)> {
+    windows
+        .iter()
+        .filter(|w| !viewport.intersect(**w).is_positive())
+        .map(|w| {
+            let side = |lo: f32, hi: f32, vlo: f32, vhi: f32| {
+                if hi <= vlo {
+                    -1.0
+                } else if lo >= vhi {
+                    1.0
+                } else {
+                    0.0
+                }
+            };
+            let dir = vec2(
+                side(w.min.x, w.max.x, viewport.min.x, viewport.max.x),
+                side(w.min.y, w.max.y, viewport.min.y, viewport.max.y),
+            );
+            (viewport.clamp(w.center()), dir.normalized())
+        })
+        .collect()
+}
+
+/// A scaled diagram of `content` in the viewport's bottom-right corner, with
+/// the windows in it and the part on screen outlined.
+fn paint_minimap(
+    painter: &egui::Painter,
+    viewport: Rect,
+    content: Rect,
+    windows: &[Rect],
+    theme: &Theme,
+) {
+    let (map, scale) = minimap_transform(viewport, content);
+    if !map.is_positive() {
+        return;
+    }
+    let project =
+        |r: Rect| Rect::from_min_size(map.min + (r.min - content.min) * scale, r.size() * scale);
+    painter.rect(
+        map,
+        CornerRadius::same(2),
+        theme.tab_bar_bg.gamma_multiply(OVERVIEW_DIM),
+        Stroke::new(1.0, theme.unfocused_border),
+        StrokeKind::Outside,
+    );
+    for &w in windows {
+        painter.rect(
+            project(w).shrink(1.0),
+            CornerRadius::ZERO,
+            theme.active_tab_bg,
+            Stroke::new(1.0, theme.unfocused_border),
+            StrokeKind::Inside,
+        );
+    }
+    // Along an axis that does not scroll the viewport can be larger than the
+    // content, so keep its outline on the map.
+    let shown = project(viewport).intersect(map);
+    if shown.is_positive() {
+        painter.rect_stroke(
+            shown,
+            CornerRadius::ZERO,
+            Stroke::new(2.0, theme.focused_border),
+            StrokeKind::Inside,
+        );
+    }
+}
+
+/// The minimap's rect and the scale from content to it: `MINIMAP_SIZE` along
+/// the content's longer side, shrunk further if that would not fit inside the
+/// viewport's margins.
+fn minimap_transform(viewport: Rect, content: Rect) -> (Rect, f32) {
+    if content.width() <= 0.0 || content.height() <= 0.0 {
+        return (Rect::NOTHING, 0.0);
+    }
+    let room = viewport.size() - vec2(MINIMAP_MARGIN, MINIMAP_MARGIN) * 2.0;
+    let scale = (MINIMAP_SIZE / content.width().max(content.height()))
+        .min(room.x / content.width())
+        .min(room.y / content.height())
+        .max(0.0);
+    let size = content.size() * scale;
+    (
+        Rect::from_min_size(
+            viewport.max - vec2(MINIMAP_MARGIN, MINIMAP_MARGIN) - size,
+            size,
+        ),
+        scale,
+    )
+}
+
 /// `origin` is the visible_frame's top-left in canvas coordinates.
 /// For per-window overlays (floats), pass `Vec2::ZERO`.
 /// For the tiling overlay, pass `vec2(vf.x - monitor.x, vf.y - monitor.y)`.
@@ -131,21 +498,35 @@ pub(crate) fn paint_window_border(
     painter: &egui::Painter,
     frame: Dimension<Logical>,
     visible_frame: Dimension<Logical>,
-    is_highlighted: bool,
+    state: BorderState,
     spawn_indicator: Option<SpawnIndicator>,
     theme: &Theme,
     border: BorderMetrics,
     origin: egui::Vec2,
 ) {
-    let colors = border_colors(is_highlighted, spawn_indicator, theme);
+    let b = border.stroke_width(state);
+    if b == 0.0 {
+        return;
+    }
+    // A stroke narrower than the reserved thickness hugs the content, so the
+    // leftover gap sits outside it.
+    let gap = border.thickness.logical() - b;
+    let g = Length::new(gap);
+    let frame = Dimension::new(
+        frame.x + g,
+        frame.y + g,
+        frame.width - g - g,
+        frame.height - g - g,
+    );
+    let colors = border_colors(state, spawn_indicator, theme);
     paint_border_edges(
         painter,
         frame,
         visible_frame,
-        border.thickness.logical(),
-        border.radius.logical(),
+        b,
+        (border.radius.logical() - gap).max(0.0),
         colors,
-        theme.focused_border,
+        state.color(theme),
         origin,
     );
 }
@@ -159,14 +540,16 @@ pub(crate) fn paint_tab_bar(
     ctx: &egui::Context,
     container_id: ContainerId,
     tab_bar_frame: Dimension<Logical>,
+    position: TabBarPosition,
     titles: &[String],
+    icons: &[Option<TabIcon>],
     active_index: usize,
     is_highlighted: bool,
     border: BorderMetrics,
     theme: &Theme,
-) -> Option<(ContainerId, usize)> {
+) -> Option<(ContainerId, TabBarEvent)> {
     let origin = vec2(tab_bar_frame.x.logical(), tab_bar_frame.y.logical());
-    let mut clicked = None;
+    let mut event = None;
     egui::Area::new(egui::Id::new(("tab_bar", container_id)))
         .order(egui::Order::Foreground)
         .fixed_pos(origin.to_pos2())
@@ -185,18 +568,283 @@ pub(crate) fn paint_tab_bar(
                 ),
             );
             ui.set_clip_rect(rect);
-            clicked = paint_tab_bar_into_ui(
+            event = paint_tab_bar_into_ui(
                 ui,
                 container_id,
                 rect,
+                position,
                 titles,
+                icons,
                 active_index,
                 is_highlighted,
                 border,
                 theme,
             );
         });
-    clicked.map(|tab_idx| (container_id, tab_idx))
+    event.map(|event| (container_id, event))
+}
+
+/// Paints the workspace overview over the whole `screen`: one cell per
+/// workspace in a grid, each a scaled diagram of its windows under its label.
+/// Frames are only compared with their own workspace's `area`, so any unit
+/// works as long as the two agree.
+pub(crate) fn paint_overview(
+    ctx: &egui::Context,
+    screen: Dimension<Logical>,
+    workspaces: &[OverviewWorkspace],
+    theme: &Theme,
+) -> Option<OverviewEvent> {
+    let canvas = Rect::from_min_size(
+        Pos2::ZERO,
+        vec2(screen.width.logical(), screen.height.logical()),
+    );
+    let mut event = None;
+    egui::Area::new(Id::new("overview"))
+        .order(Order::Foreground)
+        .fixed_pos(Pos2::ZERO)
+        .fade_in(false)
+        .show(ctx, |ui| {
+            // Without the discard, the first frame paints Shape::Noop and the overview shows up blank on Windows.
+            if ui.is_sizing_pass() {
+                ctx.request_discard("overview first frame");
+                return;
+            }
+            ui.set_clip_rect(canvas);
+            event = paint_overview_into_ui(ui, canvas, workspaces, theme);
+        });
+    event
+}
+
+fn paint_overview_into_ui(
+    ui: &mut egui::Ui,
+    canvas: Rect,
+    workspaces: &[OverviewWorkspace],
+    theme: &Theme,
+) -> Option<OverviewEvent> {
+    ui.painter().rect_filled(
+        canvas,
+        CornerRadius::ZERO,
+        theme.tab_bar_bg.gamma_multiply(OVERVIEW_DIM),
+    );
+    // Registered first so every cell and window sits above it for hit-testing.
+    let backdrop = ui.interact(canvas, Id::new("overview_backdrop"), Sense::click());
+    let mut event = backdrop.clicked().then_some(OverviewEvent::Dismiss);
+
+    let caption = HINT_FONT_SIZE + HINT_PADDING * 2.0;
+    let thumbs: Vec<Rect> = overview_cells(canvas, workspaces.len())
+        .into_iter()
+        .zip(workspaces)
+        .map(|(cell, ws)| {
+            let below = Rect::from_min_max(cell.min + vec2(0.0, caption), cell.max);
+            fit_aspect(unit_rect(ws.area).size(), below)
+        })
+        .collect();
+    let pointer = ui.input(|input| input.pointer.latest_pos());
+    let target = |pointer: Option<Pos2>| {
+        let p = pointer?;
+        thumbs.iter().position(|t| t.contains(p))
+    };
+    let mut dragging = None;
+
+    for (i, (ws, thumb)) in workspaces.iter().zip(&thumbs).enumerate() {
+        let thumb = *thumb;
+        let painter = ui.painter();
+        let chip = painter.layout_no_wrap(
+            ws.label.clone(),
+            FontId::proportional(HINT_FONT_SIZE),
+            theme.tab_text,
+        );
+        let chip_rect = Rect::from_min_size(
+            pos2(thumb.min.x, thumb.min.y - caption),
+            chip.size() + vec2(HINT_PADDING, HINT_PADDING) * 2.0,
+        );
+        painter.rect(
+            chip_rect,
+            CornerRadius::same(HINT_RADIUS),
+            theme.tab_bar_bg,
+            Stroke::new(2.0, theme.focused_border),
+            StrokeKind::Inside,
+        );
+        painter.galley(
+            chip_rect.min + vec2(HINT_PADDING, HINT_PADDING),
+            chip,
+            theme.tab_text,
+        );
+        let name = painter.layout_no_wrap(
+            ws.name.clone(),
+            FontId::proportional(HINT_FONT_SIZE * 0.75),
+            theme.tab_text,
+        );
+        painter.galley(
+            pos2(
+                chip_rect.max.x + HINT_PADDING,
+                chip_rect.center().y - name.size().y / 2.0,
+            ),
+            name,
+            theme.tab_text,
+        );
+
+        let border = if ws.is_focused {
+            theme.focused_border
+        } else {
+            theme.unfocused_border
+        };
+        painter.rect(
+            thumb,
+            CornerRadius::same(HINT_RADIUS),
+            theme.tab_bar_bg,
+            Stroke::new(2.0, border),
+            StrokeKind::Outside,
+        );
+        if ui
+            .interact(thumb, Id::new(("overview_workspace", i)), Sense::click())
+            .clicked()
+        {
+            event = Some(OverviewEvent::FocusWorkspace(ws.name.clone()));
+        }
+
+        let area = unit_rect(ws.area);
+        let scale = thumb.width() / area.width().max(1.0);
+        for window in &ws.windows {
+            let frame = unit_rect(window.frame);
+            let rect = Rect::from_min_size(
+                thumb.min + (frame.min - area.min) * scale,
+                frame.size() * scale,
+            )
+            .intersect(thumb);
+            let response = ui.interact(
+                rect,
+                Id::new(("overview_window", window.id)),
+                Sense::click_and_drag(),
+            );
+            if response.clicked() {
+                event = Some(OverviewEvent::FocusWindow(window.id));
+            } else if response.drag_stopped_by(PointerButton::Primary) {
+                event = target(pointer)
+                    .filter(|&to| to != i)
+                    .map(|to| OverviewEvent::MoveWindow {
+                        window: window.id,
+                        workspace: workspaces[to].name.clone(),
+                    });
+            } else if response.dragged_by(PointerButton::Primary) {
+                dragging = Some((rect.size(), &window.title));
+            }
+            paint_overview_window(ui, rect, window, theme);
+        }
+    }
+
+    // The dragged window follows the pointer over every cell, and the cell it
+    // would land in is outlined.
+    if let Some((size, title)) = dragging
+        && let Some(p) = pointer
+    {
+        if let Some(to) = target(Some(p)) {
+            ui.painter().rect_stroke(
+                thumbs[to],
+                CornerRadius::same(HINT_RADIUS),
+                (4.0, theme.spawn_indicator),
+                StrokeKind::Outside,
+            );
+        }
+        let ghost = Rect::from_center_size(p, size);
+        let painter = ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Tooltip, Id::new("overview_drag")));
+        painter.rect(
+            ghost,
+            CornerRadius::same(HINT_RADIUS),
+            theme.active_tab_bg.gamma_multiply(OVERVIEW_DIM),
+            Stroke::new(2.0, theme.focused_border),
+            StrokeKind::Inside,
+        );
+        painter.text(
+            ghost.center(),
+            Align2::CENTER_CENTER,
+            title,
+            FontId::proportional(12.0),
+            theme.tab_text,
+        );
+    }
+
+    event
+}
+
+fn paint_overview_window(ui: &egui::Ui, rect: Rect, window: &OverviewWindow, theme: &Theme) {
+    let border = if window.is_fullscreen {
+        theme.fullscreen_border
+    } else if window.is_focused {
+        theme.focused_border
+    } else if window.is_float {
+        theme.float_border
+    } else {
+        theme.unfocused_border
+    };
+    let painter = ui.painter().with_clip_rect(rect);
+    painter.rect(
+        rect,
+        CornerRadius::same(HINT_RADIUS),
+        theme.active_tab_bg,
+        Stroke::new(2.0, border),
+        StrokeKind::Inside,
+    );
+    let title = if window.title.is_empty() {
+        "Untitled"
+    } else {
+        window.title.as_str()
+    };
+    let galley = painter.layout(
+        title.to_string(),
+        FontId::proportional(12.0),
+        theme.tab_text,
+        (rect.width() - HINT_PADDING * 2.0).max(0.0),
+    );
+    painter.galley(rect.center() - galley.size() / 2.0, galley, theme.tab_text);
+}
+
+fn unit_rect(r: PixelRect) -> Rect {
+    Rect::from_min_size(
+        pos2(r.x().value() as f32, r.y().value() as f32),
+        vec2(r.width().value() as f32, r.height().value() as f32),
+    )
+}
+
+/// Splits `canvas` into a near-square grid of `count` cells, row by row, with
+/// `OVERVIEW_GAP` around and between them.
+fn overview_cells(canvas: Rect, count: usize) -> Vec<Rect> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let cols = (count as f32).sqrt().ceil() as usize;
+    let rows = count.div_ceil(cols);
+    let inner = canvas.shrink(OVERVIEW_GAP);
+    let size = vec2(
+        (inner.width() - OVERVIEW_GAP * (cols - 1) as f32) / cols as f32,
+        (inner.height() - OVERVIEW_GAP * (rows - 1) as f32) / rows as f32,
+    )
+    .max(egui::Vec2::ZERO);
+    (0..count)
+        .map(|i| {
+            let (row, col) = (i / cols, i % cols);
+            Rect::from_min_size(
+                inner.min
+                    + vec2(col as f32, row as f32) * (size + vec2(OVERVIEW_GAP, OVERVIEW_GAP)),
+                size,
+            )
+        })
+        .collect()
+}
+
+/// The largest rect with `aspect`'s proportions that fits `into`, centered
+/// horizontally and pinned to the top so captions sit right above it.
+fn fit_aspect(aspect: egui::Vec2, into: Rect) -> Rect {
+    if aspect.x <= 0.0 || aspect.y <= 0.0 {
+        return into;
+    }
+    let scale = (into.width() / aspect.x)
+        .min(into.height() / aspect.y)
+        .max(0.0);
+    let size = aspect * scale;
+    Rect::from_min_size(pos2(into.center().x - size.x / 2.0, into.min.y), size)
 }
 
 /// `origin` is the visible_frame's top-left in canvas coordinates (same as `paint_window_border`).
@@ -211,116 +859,43 @@ fn show_container(
     let f = placement.frame;
     let ox = origin.x + f.x.logical() - vf.x.logical();
     let oy = origin.y + f.y.logical() - vf.y.logical();
-    let b = border.thickness.logical();
+    let b = border.stroke_width(BorderState::Focused);
     let w = f.width.logical();
     let h = f.height.logical();
     let is_tabbed = placement.is_tabbed && !placement.titles.is_empty();
-    let th = placement.tab_bar_height.logical();
+    let th = placement.tab_bar_thickness.logical();
     let r = effective_radius(border.radius.logical(), w, h);
 
-    if placement.is_highlighted {
-        let colors = border_colors(true, placement.spawn_indicator, theme);
+    if placement.is_highlighted && b > 0.0 {
+        let colors = border_colors(BorderState::Focused, placement.spawn_indicator, theme);
         let focused = theme.focused_border;
         let painter = ui.painter();
 
         if is_tabbed {
-            let body_h = h - th;
-            let r_body = effective_radius(r, w, body_h);
-
-            // When r_body==0, clip rects collapse to zero dimensions and egui skips them entirely.
-            if r_body == 0.0 {
-                let corners = corner_colors(colors, focused);
-                // Left/right edges inset by b at bottom to avoid overlap with corner squares
-                painter.rect_filled(
-                    Rect::from_min_size(pos2(ox, oy + th), vec2(b, body_h - b)),
-                    CornerRadius::ZERO,
-                    colors[3],
-                );
-                painter.rect_filled(
-                    Rect::from_min_size(pos2(ox + w - b, oy + th), vec2(b, body_h - b)),
-                    CornerRadius::ZERO,
-                    colors[1],
-                );
-                painter.rect_filled(
-                    Rect::from_min_size(pos2(ox + b, oy + h - b), vec2(w - 2.0 * b, b)),
-                    CornerRadius::ZERO,
-                    colors[2],
-                );
-                painter.rect_filled(
-                    Rect::from_min_size(pos2(ox, oy + h - b), vec2(b, b)),
-                    CornerRadius::ZERO,
-                    corners[2],
-                );
-                painter.rect_filled(
-                    Rect::from_min_size(pos2(ox + w - b, oy + h - b), vec2(b, b)),
-                    CornerRadius::ZERO,
-                    corners[3],
-                );
-            } else {
-                let full_rect = Rect::from_min_size(pos2(ox, oy + th), vec2(w, body_h));
-                let cr = CornerRadius {
-                    nw: 0,
-                    ne: 0,
-                    sw: cr_u8(r_body),
-                    se: cr_u8(r_body),
-                };
-
-                stroke_clipped(
-                    painter,
-                    Rect::from_min_size(pos2(ox, oy + th), vec2(r_body, body_h - r_body)),
-                    full_rect,
-                    cr,
-                    (b, colors[3]),
-                );
-                stroke_clipped(
-                    painter,
-                    Rect::from_min_size(
-                        pos2(ox + w - r_body, oy + th),
-                        vec2(r_body, body_h - r_body),
-                    ),
-                    full_rect,
-                    cr,
-                    (b, colors[1]),
-                );
-                stroke_clipped(
-                    painter,
-                    Rect::from_min_size(
-                        pos2(ox + r_body, oy + h - r_body),
-                        vec2(w - 2.0 * r_body, r_body),
-                    ),
-                    full_rect,
-                    cr,
-                    (b, colors[2]),
-                );
-                paint_split_corner(
-                    painter,
-                    Rect::from_min_size(pos2(ox, oy + h - r_body), vec2(r_body, r_body)),
-                    full_rect,
-                    cr,
-                    b,
-                    colors[3],
-                    colors[2],
-                );
-                paint_split_corner(
-                    painter,
-                    Rect::from_min_size(
-                        pos2(ox + w - r_body, oy + h - r_body),
-                        vec2(r_body, r_body),
-                    ),
-                    full_rect,
-                    cr,
-                    b,
-                    colors[1],
-                    colors[2],
-                );
-            }
+            let (bx, by, bw, bh) = match placement.tab_bar_position {
+                TabBarPosition::Top => (ox, oy + th, w, h - th),
+                TabBarPosition::Bottom => (ox, oy, w, h - th),
+                TabBarPosition::Left => (ox + th, oy, w - th, h),
+                TabBarPosition::Right => (ox, oy, w - th, h),
+            };
+            let body = Rect::from_min_size(pos2(bx, by), vec2(bw, bh));
+            let r_body = effective_radius(r, bw, bh);
+            paint_tabbed_body(
+                painter,
+                body,
+                placement.tab_bar_position,
+                r_body,
+                b,
+                colors,
+                corner_colors(colors, focused),
+            );
         } else {
             paint_border_edges(
                 painter,
                 f,
                 vf,
                 b,
-                WINDOW_BORDER_RADIUS_LOGICAL,
+                border.radius.logical(),
                 colors,
                 focused,
                 origin,
@@ -337,26 +912,46 @@ fn paint_tab_bar_into_ui(
     ui: &mut egui::Ui,
     container_id: ContainerId,
     tab_bar_rect: Rect,
+    position: TabBarPosition,
     titles: &[String],
+    icons: &[Option<TabIcon>],
     active_index: usize,
     is_highlighted: bool,
     border: BorderMetrics,
     theme: &Theme,
-) -> Option<usize> {
-    let ox = tab_bar_rect.min.x;
-    let oy = tab_bar_rect.min.y;
-    let w = tab_bar_rect.width();
-    let th = tab_bar_rect.height();
-    let b = border.thickness.logical();
-    let border_c = if is_highlighted {
-        theme.focused_border
+) -> Option<TabBarEvent> {
+    let vertical = position.is_vertical();
+    let origin = tab_bar_rect.min;
+    // Tabs run along the main axis; `cross` is the bar's thickness.
+    let (length, cross) = if vertical {
+        (tab_bar_rect.height(), tab_bar_rect.width())
     } else {
-        theme.unfocused_border
+        (tab_bar_rect.width(), tab_bar_rect.height())
+    };
+    let span = |start: f32, len: f32| {
+        if vertical {
+            Rect::from_min_size(origin + vec2(0.0, start), vec2(cross, len))
+        } else {
+            Rect::from_min_size(origin + vec2(start, 0.0), vec2(len, cross))
+        }
     };
+    let state = if is_highlighted {
+        BorderState::Focused
+    } else {
+        BorderState::Unfocused
+    };
+    let b = border.stroke_width(state);
+    let border_c = state.color(theme);
 
+    // Sidebar rows keep a fixed height instead of splitting the whole list.
+    let row = if vertical {
+        (ui.text_style_height(&TextStyle::Body) * SIDEBAR_ROW_LINES).round()
+    } else {
+        cross
+    };
     let bg = theme.tab_bar_bg;
     let active_bg = theme.active_tab_bg;
-    let tab_cr = tab_bar_corner_radius(th);
+    let tab_cr = tab_bar_corner_radius(row);
     let tab_bar_cr = CornerRadius::same(cr_u8(tab_cr));
 
     ui.painter().rect_filled(tab_bar_rect, tab_bar_cr, bg);
@@ -364,17 +959,74 @@ fn paint_tab_bar_into_ui(
     ui.painter()
         .rect_stroke(tab_bar_rect, tab_bar_cr, (b, border_c), StrokeKind::Inside);
 
-    let tab_width = w / titles.len() as f32;
-    let mut clicked = None;
+    let (min_tab, chip_len) = if vertical {
+        (row, row)
+    } else {
+        (MIN_TAB_WIDTH, OVERFLOW_CHIP_WIDTH)
+    };
+    let shown = visible_tabs(titles.len(), active_index, length, min_tab, chip_len);
+    let hidden_before = shown.start;
+    let hidden_after = titles.len() - shown.end;
+    let lead_chip = if hidden_before > 0 { chip_len } else { 0.0 };
+    let trail_chip = if hidden_after > 0 { chip_len } else { 0.0 };
+    let segments = shown.len() + usize::from(hidden_before > 0) + usize::from(hidden_after > 0);
+    let tab_len = if vertical {
+        row
+    } else {
+        (length - lead_chip - trail_chip) / shown.len() as f32
+    };
+    let mut event = None;
+    let mut dragging = None;
     let focused_c = theme.focused_border;
+    let separator = |at: f32| {
+        if vertical {
+            Rect::from_min_size(origin + vec2(0.0, at - b / 2.0), vec2(cross, b))
+        } else {
+            Rect::from_min_size(origin + vec2(at - b / 2.0, 0.0), vec2(b, cross))
+        }
+    };
+
+    // A chip activates the nearest hidden tab, which scrolls the bar by one.
+    let chips = [
+        (hidden_before, 0.0, shown.start.checked_sub(1)),
+        (hidden_after, length - trail_chip, Some(shown.end)),
+    ];
+    for (hidden, chip_start, target) in chips {
+        if hidden == 0 {
+            continue;
+        }
+        let chip_rect = span(chip_start, chip_len);
+        let response = ui.interact(
+            chip_rect,
+            egui::Id::new(("tab_overflow", container_id, chip_start.to_bits())),
+            Sense::click(),
+        );
+        if response.clicked() {
+            event = target.map(TabBarEvent::Select);
+        }
+        ui.painter().text(
+            chip_rect.center(),
+            egui::Align2::CENTER_CENTER,
+            format!("+{hidden}"),
+            TextStyle::Body.resolve(ui.style()),
+            theme.tab_text,
+        );
+    }
 
-    for (i, title) in titles.iter().enumerate() {
-        let tab_x = ox + i as f32 * tab_width;
-        let tab_rect = Rect::from_min_size(pos2(tab_x, oy), vec2(tab_width, th));
+    for (i, title) in titles.iter().enumerate().take(shown.end).skip(shown.start) {
+        let slot = i - shown.start;
+        let tab_start = lead_chip + slot as f32 * tab_len;
+        let tab_rect = span(tab_start, tab_len);
         let is_active = i == active_index;
 
         if is_active {
-            let active_cr = active_tab_corner_radius(i, titles.len(), tab_cr);
+            let segment = slot + usize::from(hidden_before > 0);
+            let active_cr = if vertical {
+                let reaches_end = tab_start + tab_len + trail_chip >= length - 0.5;
+                active_row_corner_radius(segment, segments, reaches_end, tab_cr)
+            } else {
+                active_tab_corner_radius(segment, segments, tab_cr)
+            };
             ui.painter().rect_filled(tab_rect, active_cr, active_bg);
 
             if is_highlighted {
@@ -383,21 +1035,31 @@ fn paint_tab_bar_into_ui(
             }
         }
 
-        if i > 0 && !is_active && i != active_index + 1 {
-            ui.painter().rect_filled(
-                Rect::from_min_size(pos2(tab_rect.min.x - b / 2.0, oy), vec2(b, th)),
-                CornerRadius::ZERO,
-                border_c,
-            );
+        if (i > shown.start || hidden_before > 0) && !is_active && i != active_index + 1 {
+            ui.painter()
+                .rect_filled(separator(tab_start), CornerRadius::ZERO, border_c);
         }
 
         let response = ui.interact(
             tab_rect,
             egui::Id::new(("tab", container_id, i)),
-            Sense::click(),
+            Sense::click_and_drag(),
         );
+        let pointer = ui.input(|input| input.pointer.latest_pos());
+        let landing = pointer
+            .map(|p| tab_drop_target(tab_bar_rect, vertical, p, shown.clone(), lead_chip, tab_len));
         if response.clicked() {
-            clicked = Some(i);
+            event = Some(TabBarEvent::Select(i));
+        } else if response.middle_clicked() {
+            event = Some(TabBarEvent::Close(i));
+        } else if response.drag_stopped_by(PointerButton::Primary) {
+            event = match landing {
+                Some(Some(to)) if to != i => Some(TabBarEvent::Move { from: i, to }),
+                Some(None) => Some(TabBarEvent::DragOut(i)),
+                _ => None,
+            };
+        } else if response.dragged_by(PointerButton::Primary) {
+            dragging = landing.flatten().map(|to| (i, to));
         }
         let inner = tab_rect.shrink2(vec2(b * 2.0, 0.0));
         let mut tab_ui = ui.new_child(
@@ -405,6 +1067,11 @@ fn paint_tab_bar_into_ui(
                 .max_rect(inner)
                 .layout(Layout::left_to_right(Align::Center)),
         );
+        if let Some(Some(icon)) = icons.get(i) {
+            let side = row * 0.6;
+            let texture = tab_icon_texture(ui.ctx(), icon);
+            tab_ui.add(egui::Image::new((texture.id(), vec2(side, side))));
+        }
         tab_ui.add(
             egui::Label::new(
                 RichText::new(if title.is_empty() {
@@ -416,11 +1083,171 @@ fn paint_tab_bar_into_ui(
                 .text_style(TextStyle::Body),
             )
             .truncate()
-            .halign(Align::Center),
+            .halign(if vertical { Align::Min } else { Align::Center }),
+        );
+    }
+
+    // The separator after the last shown tab, when a trailing chip follows it.
+    if hidden_after > 0 && shown.end - 1 != active_index {
+        ui.painter()
+            .rect_filled(separator(length - trail_chip), CornerRadius::ZERO, border_c);
+    }
+
+    // Marks the edge the dragged tab will land on. Nothing is drawn once the
+    // pointer leaves the bar, since the drop then splits the tab off.
+    if let Some((from, to)) = dragging
+        && from != to
+    {
+        let slot = to - shown.start + usize::from(to > from);
+        let at = lead_chip + slot as f32 * tab_len;
+        let thickness = (b * 2.0).max(2.0);
+        let marker = span(
+            (at - thickness / 2.0).clamp(0.0, length - thickness),
+            thickness,
         );
+        ui.painter()
+            .rect_filled(marker, CornerRadius::ZERO, focused_c);
+    }
+
+    event
+}
+
+/// The shown tab under `pointer` for a tab dragged along the bar, clamped to the
+/// first or last shown tab when it sits over a chip or past an end. `None` once
+/// the pointer is more than `DRAG_OUT_MARGIN` outside the bar.
+fn tab_drop_target(
+    bar: Rect,
+    vertical: bool,
+    pointer: Pos2,
+    shown: Range<usize>,
+    lead_chip: f32,
+    tab_len: f32,
+) -> Option<usize> {
+    if !bar.expand(DRAG_OUT_MARGIN).contains(pointer) {
+        return None;
+    }
+    let along = if vertical {
+        pointer.y - bar.min.y
+    } else {
+        pointer.x - bar.min.x
+    };
+    let slot = ((along - lead_chip) / tab_len).floor().max(0.0) as usize;
+    Some((shown.start + slot).min(shown.end - 1))
+}
+
+/// The window of tabs that fit along `length` at `min_tab` each, kept roughly
+/// centered on the active tab. Once anything overflows, both chips are budgeted
+/// for so the window does not shift as one of them appears.
+fn visible_tabs(count: usize, active: usize, length: f32, min_tab: f32, chip: f32) -> Range<usize> {
+    if count as f32 * min_tab <= length {
+        return 0..count;
+    }
+    let fit = (((length - 2.0 * chip) / min_tab).floor() as usize).max(1);
+    let start = active
+        .min(count - 1)
+        .saturating_sub(fit / 2)
+        .min(count.saturating_sub(fit));
+    start..(start + fit).min(count)
+}
+
+/// Uploads each icon once and keeps the handle in egui's memory, so the texture
+/// lives as long as the context.
+fn tab_icon_texture(ctx: &egui::Context, icon: &TabIcon) -> egui::TextureHandle {
+    if let Some(texture) = ctx.data(|d| d.get_temp::<egui::TextureHandle>(icon.key)) {
+        return texture;
+    }
+    let image = egui::ColorImage::from_rgba_unmultiplied(icon.size, &icon.rgba);
+    let texture = ctx.load_texture("tab_icon", image, egui::TextureOptions::LINEAR);
+    ctx.data_mut(|d| d.insert_temp(icon.key, texture.clone()));
+    texture
+}
+
+/// Paints the three body edges of a tabbed container, leaving the band side open
+/// for the tab bar window. Edge and corner order follows `border_colors` and
+/// `corner_colors`.
+fn paint_tabbed_body(
+    painter: &egui::Painter,
+    body: Rect,
+    band: TabBarPosition,
+    r: f32,
+    b: f32,
+    colors: [Color32; 4],
+    corners: [Color32; 4],
+) {
+    let open = [
+        band == TabBarPosition::Top,
+        band == TabBarPosition::Right,
+        band == TabBarPosition::Bottom,
+        band == TabBarPosition::Left,
+    ];
+    // [nw, ne, sw, se]: a corner is drawn only where both of its edges are.
+    let corner_open = [
+        open[0] || open[3],
+        open[0] || open[1],
+        open[2] || open[3],
+        open[2] || open[1],
+    ];
+    // With r == 0 the clip rects below collapse to nothing and egui skips them, so
+    // edges are filled b thick and stop short of b-square corners instead.
+    let t = if r == 0.0 { b } else { r };
+    let inset = |i: usize| if corner_open[i] { 0.0 } else { t };
+    let (min, max) = (body.min, body.max);
+    let edges = [
+        Rect::from_min_max(
+            pos2(min.x + inset(0), min.y),
+            pos2(max.x - inset(1), min.y + t),
+        ),
+        Rect::from_min_max(
+            pos2(max.x - t, min.y + inset(1)),
+            pos2(max.x, max.y - inset(3)),
+        ),
+        Rect::from_min_max(
+            pos2(min.x + inset(2), max.y - t),
+            pos2(max.x - inset(3), max.y),
+        ),
+        Rect::from_min_max(
+            pos2(min.x, min.y + inset(0)),
+            pos2(min.x + t, max.y - inset(2)),
+        ),
+    ];
+    let corner_rects = [
+        Rect::from_min_size(min, vec2(t, t)),
+        Rect::from_min_size(pos2(max.x - t, min.y), vec2(t, t)),
+        Rect::from_min_size(pos2(min.x, max.y - t), vec2(t, t)),
+        Rect::from_min_size(pos2(max.x - t, max.y - t), vec2(t, t)),
+    ];
+
+    if r == 0.0 {
+        for i in (0..4).filter(|&i| !open[i]) {
+            painter.rect_filled(edges[i], CornerRadius::ZERO, colors[i]);
+        }
+        for i in (0..4).filter(|&i| !corner_open[i]) {
+            painter.rect_filled(corner_rects[i], CornerRadius::ZERO, corners[i]);
+        }
+        return;
     }
 
-    clicked
+    let rounded = |i: usize| if corner_open[i] { 0 } else { cr_u8(r) };
+    let cr = CornerRadius {
+        nw: rounded(0),
+        ne: rounded(1),
+        sw: rounded(2),
+        se: rounded(3),
+    };
+    for i in (0..4).filter(|&i| !open[i]) {
+        stroke_clipped(painter, edges[i], body, cr, (b, colors[i]));
+    }
+    // Split colors per corner, top half first: [nw, ne, sw, se].
+    let split = [
+        (colors[0], colors[3]),
+        (colors[0], colors[1]),
+        (colors[3], colors[2]),
+        (colors[1], colors[2]),
+    ];
+    for i in (0..4).filter(|&i| !corner_open[i]) {
+        let (top, bottom) = split[i];
+        paint_split_corner(painter, corner_rects[i], body, cr, b, top, bottom);
+    }
 }
 
 fn stroke_clipped(
@@ -467,9 +1294,9 @@ fn cr_u8(r: f32) -> u8 {
     r.clamp(0.0, 255.0) as u8
 }
 
-/// A quarter of the tab-bar thickness gives a visibly softer corner than
-/// `WINDOW_BORDER_RADIUS_LOGICAL` while still scaling with the
-/// user-configured bar thickness.
+/// A quarter of the tab-bar thickness gives a visibly softer corner than the
+/// default `border_radius` while still scaling with the user-configured bar
+/// thickness.
 fn tab_bar_corner_radius(tab_bar_height: f32) -> f32 {
     effective_radius(tab_bar_height * 0.25, tab_bar_height, tab_bar_height)
 }
@@ -490,6 +1317,25 @@ fn active_tab_corner_radius(index: usize, tab_count: usize, tab_cr: f32) -> Corn
     }
 }
 
+/// Sidebar counterpart of `active_tab_corner_radius`. Rows keep a fixed height,
+/// so the last one only meets the list's bottom corners when `reaches_end`.
+fn active_row_corner_radius(
+    index: usize,
+    row_count: usize,
+    reaches_end: bool,
+    tab_cr: f32,
+) -> CornerRadius {
+    let r = cr_u8(tab_cr);
+    let is_first = index == 0;
+    let is_last = reaches_end && index + 1 == row_count;
+    CornerRadius {
+        nw: if is_first { r } else { 0 },
+        ne: if is_first { r } else { 0 },
+        sw: if is_last { r } else { 0 },
+        se: if is_last { r } else { 0 },
+    }
+}
+
 #[expect(
     clippy::too_many_arguments,
     reason = "drawing params that must travel together; a struct would add indirection without clarity"
@@ -628,19 +1474,16 @@ fn paint_border_edges(
     );
 }
 
-/// [top, right, bottom, left] border colors based on highlight state and spawn indicator.
+/// [top, right, bottom, left] border colors based on border state and spawn indicator.
 fn border_colors(
-    is_highlighted: bool,
+    state: BorderState,
     spawn_indicator: Option<SpawnIndicator>,
     theme: &Theme,
 ) -> [Color32; 4] {
-    if !is_highlighted {
-        return [theme.unfocused_border; 4];
-    }
-    let Some(si) = spawn_indicator else {
-        return [theme.focused_border; 4];
+    let f = state.color(theme);
+    let Some(si) = spawn_indicator.filter(|_| state == BorderState::Focused) else {
+        return [f; 4];
     };
-    let f = theme.focused_border;
     let s = theme.spawn_indicator;
     [
         if si.top { s } else { f },
@@ -687,6 +1530,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn border_state_prefers_focus_over_emphasis() {
+        assert_eq!(
+            BorderState::new(true, false, WindowEmphasis::Marked),
+            BorderState::Focused
+        );
+        assert_eq!(
+            BorderState::new(true, true, WindowEmphasis::None),
+            BorderState::FocusedFloat
+        );
+        assert_eq!(
+            BorderState::new(false, true, WindowEmphasis::InactiveFocus),
+            BorderState::InactiveFocused
+        );
+        assert_eq!(
+            BorderState::new(false, false, WindowEmphasis::Marked),
+            BorderState::Marked
+        );
+        assert_eq!(
+            BorderState::new(false, false, WindowEmphasis::Urgent),
+            BorderState::Urgent
+        );
+        assert_eq!(
+            BorderState::new(false, true, WindowEmphasis::Urgent),
+            BorderState::Urgent
+        );
+        assert_eq!(
+            BorderState::new(false, true, WindowEmphasis::None),
+            BorderState::Float
+        );
+        assert_eq!(
+            BorderState::new(true, true, WindowEmphasis::Flash),
+            BorderState::Flash
+        );
+    }
+
+    #[test]
+    fn stroke_width_is_capped_by_reserved_thickness() {
+        let config = Config {
+            border_size: crate::core::Pixels::new(4),
+            focused_border_size: Some(crate::core::Pixels::new(6)),
+            unfocused_border_size: Some(crate::core::Pixels::new(1)),
+            ..Config::default()
+        };
+        let border = BorderMetrics::new(&config, Length::new(4.0));
+        assert_eq!(border.stroke_width(BorderState::Focused), 4.0);
+        assert_eq!(border.stroke_width(BorderState::Marked), 1.0);
+        // A window whose `[[border]]` rule reserves nothing paints nothing.
+        let none = border.with_thickness(Length::new(0.0));
+        assert_eq!(none.stroke_width(BorderState::FocusedFloat), 0.0);
+    }
+
+    #[test]
+    fn border_colors_spawn_indicator_only_on_focused_tiling() {
+        let theme = Theme::from_flavor(crate::theme::Flavor::Mocha);
+        let si = Some(SpawnIndicator {
+            top: false,
+            right: true,
+            bottom: false,
+            left: false,
+        });
+        assert_eq!(
+            border_colors(BorderState::Focused, si, &theme)[1],
+            theme.spawn_indicator
+        );
+        assert_eq!(
+            border_colors(BorderState::FocusedFloat, si, &theme),
+            [theme.float_border; 4]
+        );
+        assert_eq!(
+            border_colors(BorderState::Marked, None, &theme),
+            [theme.marked_border; 4]
+        );
+    }
+
     #[test]
     fn corner_colors_uniform() {
         assert_eq!(
@@ -784,6 +1702,131 @@ mod tests {
         assert_eq!(active_tab_corner_radius(0, 3, 0.0), CornerRadius::ZERO);
     }
 
+    #[test]
+    fn visible_tabs_all_fit() {
+        assert_eq!(visible_tabs(4, 3, 320.0, 80.0, 36.0), 0..4);
+        assert_eq!(visible_tabs(0, 0, 100.0, 80.0, 36.0), 0..0);
+    }
+
+    #[test]
+    fn visible_tabs_centers_on_active() {
+        // 400 - 2 * 36 leaves room for four tabs.
+        assert_eq!(visible_tabs(10, 5, 400.0, 80.0, 36.0), 3..7);
+    }
+
+    #[test]
+    fn visible_tabs_clamps_at_both_ends() {
+        assert_eq!(visible_tabs(10, 0, 400.0, 80.0, 36.0), 0..4);
+        assert_eq!(visible_tabs(10, 9, 400.0, 80.0, 36.0), 6..10);
+    }
+
+    #[test]
+    fn visible_tabs_keeps_one_tab_when_narrow() {
+        assert_eq!(visible_tabs(3, 2, 50.0, 80.0, 36.0), 2..3);
+    }
+
+    #[test]
+    fn visible_tabs_sidebar_rows() {
+        // Ten 28-point rows in 250 points: chips take two rows, leaving six.
+        assert_eq!(visible_tabs(10, 9, 250.0, 28.0, 28.0), 4..10);
+        assert_eq!(visible_tabs(10, 9, 280.0, 28.0, 28.0), 0..10);
+    }
+
+    #[test]
+    fn tab_drop_target_follows_pointer_along_bar() {
+        let bar = Rect::from_min_size(pos2(0.0, 0.0), vec2(300.0, 20.0));
+        let at = |x: f32, y: f32| tab_drop_target(bar, false, pos2(x, y), 0..3, 0.0, 100.0);
+        assert_eq!(at(10.0, 10.0), Some(0));
+        assert_eq!(at(150.0, 30.0), Some(1));
+        assert_eq!(at(310.0, 10.0), Some(2));
+        assert_eq!(at(150.0, 60.0), None);
+        assert_eq!(at(-30.0, 10.0), None);
+    }
+
+    #[test]
+    fn tab_drop_target_clamps_over_chips() {
+        // Tabs 4..7 shown between two 36-point chips.
+        let bar = Rect::from_min_size(pos2(0.0, 0.0), vec2(20.0, 400.0));
+        let at = |y: f32| tab_drop_target(bar, true, pos2(10.0, y), 4..7, 36.0, 80.0);
+        assert_eq!(at(10.0), Some(4));
+        assert_eq!(at(120.0), Some(5));
+        assert_eq!(at(390.0), Some(6));
+    }
+
+    #[test]
+    fn overview_cells_fill_a_near_square_grid() {
+        let canvas = Rect::from_min_size(pos2(0.0, 0.0), vec2(1064.0, 664.0));
+        let cells = overview_cells(canvas, 3);
+        assert_eq!(cells.len(), 3);
+        // Two columns: 1064 - 2 * 32 margin - 32 gap, halved.
+        assert_eq!(
+            cells[0],
+            Rect::from_min_size(pos2(32.0, 32.0), vec2(484.0, 284.0))
+        );
+        assert_eq!(cells[1].min, pos2(548.0, 32.0));
+        assert_eq!(cells[2].min, pos2(32.0, 348.0));
+        assert!(overview_cells(canvas, 0).is_empty());
+    }
+
+    #[test]
+    fn fit_aspect_keeps_proportions_and_pins_to_top() {
+        let into = Rect::from_min_size(pos2(0.0, 0.0), vec2(400.0, 400.0));
+        let fitted = fit_aspect(vec2(1600.0, 900.0), into);
+        assert_eq!(
+            fitted,
+            Rect::from_min_size(pos2(0.0, 0.0), vec2(400.0, 225.0))
+        );
+        let tall = fit_aspect(vec2(100.0, 200.0), into);
+        assert_eq!(
+            tall,
+            Rect::from_min_size(pos2(100.0, 0.0), vec2(200.0, 400.0))
+        );
+    }
+
+    #[test]
+    fn offscreen_arrows_point_at_hidden_windows_only() {
+        let viewport = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+        let windows = [
+            // Partly on screen, so no arrow.
+            Rect::from_min_size(pos2(50.0, 80.0), vec2(40.0, 40.0)),
+            Rect::from_min_size(pos2(20.0, 100.0), vec2(20.0, 50.0)),
+            Rect::from_min_size(pos2(-60.0, 40.0), vec2(40.0, 20.0)),
+            Rect::from_min_size(pos2(120.0, 120.0), vec2(10.0, 10.0)),
+        ];
+        let arrows = offscreen_arrows(viewport, &windows);
+        assert_eq!(arrows.len(), 3);
+        assert_eq!(arrows[0], (pos2(30.0, 100.0), vec2(0.0, 1.0)));
+        assert_eq!(arrows[1], (pos2(0.0, 50.0), vec2(-1.0, 0.0)));
+        assert_eq!(arrows[2].0, pos2(100.0, 100.0));
+        assert!((arrows[2].1 - vec2(1.0, 1.0).normalized()).length() < 1e-6);
+    }
+
+    #[test]
+    fn minimap_scales_the_longer_side_and_sits_in_the_corner() {
+        let viewport = Rect::from_min_size(pos2(0.0, 0.0), vec2(1000.0, 600.0));
+        let content = Rect::from_min_size(pos2(0.0, -600.0), vec2(1000.0, 1200.0));
+        let (map, scale) = minimap_transform(viewport, content);
+        assert_eq!(scale, 0.1);
+        assert_eq!(
+            map,
+            Rect::from_min_size(pos2(884.0, 464.0), vec2(100.0, 120.0))
+        );
+
+        let narrow = Rect::from_min_size(pos2(0.0, 0.0), vec2(82.0, 600.0));
+        let (map, _) = minimap_transform(narrow, content);
+        assert_eq!(map.width(), 50.0, "shrunk to fit between the margins");
+    }
+
+    #[test]
+    fn active_row_corner_radius_rounds_bottom_only_at_end() {
+        let first = active_row_corner_radius(0, 3, false, 6.0);
+        assert_eq!((first.nw, first.ne, first.sw, first.se), (6, 6, 0, 0));
+        let short_last = active_row_corner_radius(2, 3, false, 6.0);
+        assert_eq!(short_last, CornerRadius::ZERO);
+        let full_last = active_row_corner_radius(2, 3, true, 6.0);
+        assert_eq!((full_last.nw, full_last.sw, full_last.se), (0, 6, 6));
+    }
+
     #[test]
     fn tab_bar_corner_radius_default_height() {
         assert_eq!(tab_bar_corner_radius(24.0), 6.0);
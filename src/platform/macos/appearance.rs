@@ -0,0 +1,18 @@
+use objc2_foundation::{NSString, NSUserDefaults};
+
+use crate::theme::Appearance;
+
+/// Posted on the distributed center when the user flips Light/Dark in System
+/// Settings, including the Auto schedule.
+pub(super) const APPEARANCE_CHANGED_NOTIFICATION: &str = "AppleInterfaceThemeChangedNotification";
+
+/// `AppleInterfaceStyle` is only present, as "Dark", in dark mode. Reads the
+/// global domain so it works from any thread, unlike `NSApp.effectiveAppearance`.
+pub(super) fn system_appearance() -> Appearance {
+    let style = NSUserDefaults::standardUserDefaults()
+        .stringForKey(&NSString::from_str("AppleInterfaceStyle"));
+    match style {
+        Some(style) if style.to_string() == "Dark" => Appearance::Dark,
+        _ => Appearance::Light,
+    }
+}
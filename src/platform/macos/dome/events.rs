@@ -9,9 +9,13 @@ use crate::action::Actions;
 use crate::action::Query;
 use crate::config::{Config, LayoutConfig};
 use crate::core::{
-    ContainerId, ContainerPlacement, Dimension, FloatWindowPlacement, Length, Logical, MonitorId,
-    TilingWindowPlacement, WindowId, WorkspaceInfo,
+    ContainerId, ContainerPlacement, Dimension, FloatWindowPlacement, HintPlacement, Length,
+    Logical, MonitorId, OverviewWorkspace, ScrollExtent, TilingWindowPlacement, WindowId,
+    WorkspaceInfo,
 };
+use crate::diagnostics::LoadReport;
+use crate::overlay::{OverviewEvent, TabBarEvent};
+use crate::theme::Appearance;
 
 use super::super::MonitorInfo;
 
@@ -40,20 +44,29 @@ pub(in crate::platform::macos) enum HubEvent {
         observed_at: Instant,
     },
     Action(Actions),
+    /// The keyboard thread switched mode or moved through a key sequence.
+    /// Carries what status bars should show, so `mode_entered` hooks fire.
+    ModeEntered(String),
     Query {
         query: Query,
         sender: std::sync::mpsc::SyncSender<String>,
     },
     ConfigChanged(Box<Config>),
     LayoutConfigChanged(Box<LayoutConfig>),
+    /// Outcome of loading the config or layout file, at launch and after
+    /// every reload attempt.
+    ConfigReport(Box<LoadReport>),
     ExportLayout(String),
+    /// System Light/Dark setting flipped.
+    AppearanceChanged(Appearance),
     /// Periodic sync to catch missed AX notifications, as AX notifications are unreliable. Only
     /// syncs window state, not focus, as focus changes should come from user interactions. Beside
     /// we receive plenty of focus events, so missing them isn't a concern.
     Sync,
     MonitorsChanged(Vec<MonitorInfo>),
     MirrorClicked(CGWindowID),
-    TabClicked(ContainerId, usize),
+    TabBar(ContainerId, TabBarEvent),
+    Overview(OverviewEvent),
     /// Used to detect native fullscreen enter/exit, since native fullscreen
     /// moves windows to a separate Space.
     SpaceChanged,
@@ -77,18 +90,20 @@ impl fmt::Display for HubEvent {
                 write!(f, "WindowMovedOrResized(pid={pid})")
             }
             Self::Action(actions) => write!(f, "Action({actions})"),
+            Self::ModeEntered(name) => write!(f, "ModeEntered({name})"),
             Self::Query { query, .. } => write!(f, "Query({query:?})"),
             Self::ConfigChanged(_) => write!(f, "ConfigChanged"),
             Self::LayoutConfigChanged(_) => write!(f, "LayoutConfigChanged"),
+            Self::ConfigReport(report) => write!(f, "ConfigReport({})", report.path),
             Self::ExportLayout(_) => write!(f, "ExportLayout"),
+            Self::AppearanceChanged(appearance) => write!(f, "AppearanceChanged({appearance:?})"),
             Self::Sync => write!(f, "Sync"),
             Self::MonitorsChanged(monitors) => {
                 write!(f, "MonitorsChanged(count={})", monitors.len())
             }
             Self::MirrorClicked(cg_id) => write!(f, "MirrorClicked({cg_id})"),
-            Self::TabClicked(container_id, tab_idx) => {
-                write!(f, "TabClicked({container_id}, tab_idx={tab_idx})")
-            }
+            Self::TabBar(container_id, event) => write!(f, "TabBar({container_id}, {event:?})"),
+            Self::Overview(event) => write!(f, "Overview({event:?})"),
             Self::SpaceChanged => write!(f, "SpaceChanged"),
             Self::PidObserved { pid } => write!(f, "PidObserved(pid={pid})"),
             Self::ObservedPidsRefreshed(pids) => {
@@ -121,6 +136,21 @@ pub(in crate::platform::macos) struct RenderFrame {
     pub(in crate::platform::macos) focused_window: Option<WindowId>,
     pub(in crate::platform::macos) focused_monitor_id: MonitorId,
     pub(in crate::platform::macos) workspaces: Vec<WorkspaceInfo>,
+    /// Active keymap mode, `None` while in the default mode.
+    pub(in crate::platform::macos) mode: Option<String>,
+    /// Transient message such as "config reloaded with 3 warnings".
+    pub(in crate::platform::macos) notice: Option<String>,
+    /// Present while the overview shows. The main thread closes its window
+    /// on the first frame without one.
+    pub(in crate::platform::macos) overview: Option<Box<OverviewShow>>,
+}
+
+/// The overview, laid over the focused monitor.
+pub(in crate::platform::macos) struct OverviewShow {
+    pub(in crate::platform::macos) workspaces: Vec<OverviewWorkspace>,
+    pub(in crate::platform::macos) monitor_dim: Dimension,
+    pub(in crate::platform::macos) cocoa_frame: NSRect,
+    pub(in crate::platform::macos) scale: f64,
 }
 
 pub(in crate::platform::macos) struct MonitorTilingData {
@@ -131,6 +161,8 @@ pub(in crate::platform::macos) struct MonitorTilingData {
     pub(in crate::platform::macos) border_thickness: Length<Logical>,
     pub(in crate::platform::macos) windows: Vec<TilingWindowPlacement>,
     pub(in crate::platform::macos) containers: Vec<ContainerShow>,
+    pub(in crate::platform::macos) hints: Vec<HintPlacement>,
+    pub(in crate::platform::macos) scroll_extents: Vec<ScrollExtent>,
 }
 
 /// Per-container render bundle. Mirrors `FloatShow` for tabbed containers:
@@ -144,6 +176,9 @@ pub(in crate::platform::macos) struct ContainerShow {
     /// Pre-flipped Cocoa frame for `tab_bar_dim`. Always populated, even when
     /// `!placement.is_tabbed`.
     pub(in crate::platform::macos) tab_bar_cocoa_frame: NSRect,
+    /// Owning pid per tab, for the UI thread to look up app icons. Empty when
+    /// `tab_icons` is off.
+    pub(in crate::platform::macos) tab_pids: Vec<Option<i32>>,
 }
 
 pub(in crate::platform::macos) struct FloatShow {
@@ -1,10 +1,16 @@
 use std::collections::HashSet;
+use std::time::Instant;
 
-use crate::core::{Length, MonitorLayout, MonitorPlacements, WindowId};
+use crate::core::{
+    ContainerPlacement, FloatWindowPlacement, Length, MonitorId, MonitorLayout, MonitorPlacements,
+    OverviewWorkspace, TilingWindowPlacement, WindowId,
+};
 use crate::platform::macos::objc2_wrapper::dimension_to_ns_rect_cocoa;
 
 use super::Dome;
-use super::events::{ContainerShow, FloatShow, HubMessage, MonitorTilingData, RenderFrame};
+use super::events::{
+    ContainerShow, FloatShow, HubMessage, MonitorTilingData, OverviewShow, RenderFrame,
+};
 
 impl Dome {
     /// All fullscreen -> normal and normal -> fullscreen must be resolved before this step
@@ -12,7 +18,7 @@ impl Dome {
     pub(in crate::platform::macos) fn flush_layout(&mut self) {
         let mut tiling = Vec::new();
         let mut float_shows = Vec::new();
-        let result = self.hub.get_visible_placements();
+        let result = self.hub.get_animated_placements(Instant::now());
         let all_displayed_windows: HashSet<WindowId> = result
             .monitors
             .iter()
@@ -94,13 +100,175 @@ impl Dome {
             self.registry.remove(cg_id);
         }
 
+        let overview = self.overview_show(result.overview, focused_monitor);
+        self.workspaces = self.hub.query_workspaces();
         self.sender.send(HubMessage::Frame(RenderFrame {
             tiling,
             float_shows,
             focused_window,
             focused_monitor_id: focused_monitor,
-            workspaces: self.hub.query_workspaces(),
+            workspaces: self.workspaces.clone(),
+            mode: (self.mode != "default").then(|| self.mode.clone()),
+            notice: self.notice.clone(),
+            overview,
         }));
+
+        let events = self.hub.take_lifecycle_events();
+        self.pending_hooks
+            .extend(crate::hook::lifecycle_runs(&self.config.hooks, &events));
+    }
+
+    /// When `flush_animation_frame` should run to show the next animation
+    /// frame.
+    pub(in crate::platform::macos) fn next_animation_frame(&self) -> Option<Instant> {
+        self.hub.next_animation_frame()
+    }
+
+    /// Frames-only pass between layouts: moves tiling windows to their next
+    /// intermediate rect and sends a frame so borders follow. Nothing else
+    /// can have changed since the last `flush_layout`, so hiding, focus,
+    /// workspaces and hooks are left alone, and floats are reported where
+    /// they already are.
+    #[tracing::instrument(skip_all)]
+    pub(in crate::platform::macos) fn flush_animation_frame(&mut self) {
+        let result = self.hub.get_animated_placements(Instant::now());
+        let mut tiling = Vec::new();
+        let mut float_shows = Vec::new();
+        for mp in &result.monitors {
+            let MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                containers,
+            } = &mp.layout
+            else {
+                tiling.push(self.fullscreen_tiling_data(mp));
+                continue;
+            };
+            let mut placed_tiling = Vec::new();
+            for wp in tiling_windows {
+                if !wp.visible_content_box.is_empty() {
+                    self.show_tiling(wp.id, wp.visible_content_box);
+                    placed_tiling.push(*wp);
+                }
+            }
+            let scale = self.monitor_registry.monitor(mp.monitor_id).egui_scale();
+            float_shows.extend(
+                float_windows
+                    .iter()
+                    .filter(|wp| !wp.content_box.is_empty())
+                    .filter_map(|wp| self.float_show(wp, scale)),
+            );
+            tiling.push(self.normal_tiling_data(mp, placed_tiling, containers));
+        }
+        let overview = self.overview_show(result.overview, result.focused_monitor);
+        self.sender.send(HubMessage::Frame(RenderFrame {
+            tiling,
+            float_shows,
+            focused_window: result.focused_window,
+            focused_monitor_id: result.focused_monitor,
+            workspaces: self.workspaces.clone(),
+            mode: (self.mode != "default").then(|| self.mode.clone()),
+            notice: self.notice.clone(),
+            overview,
+        }));
+    }
+
+    fn overview_show(
+        &self,
+        workspaces: Vec<OverviewWorkspace>,
+        focused_monitor: MonitorId,
+    ) -> Option<Box<OverviewShow>> {
+        if workspaces.is_empty() {
+            return None;
+        }
+        let monitor = self.monitor_registry.monitor(focused_monitor);
+        let monitor_dim = monitor.work_area().to_dimension();
+        Some(Box::new(OverviewShow {
+            workspaces,
+            monitor_dim,
+            cocoa_frame: dimension_to_ns_rect_cocoa(
+                Length::new(self.primary_full_height),
+                monitor_dim,
+            ),
+            scale: monitor.egui_scale(),
+        }))
+    }
+
+    /// Overlay data for a monitor showing a fullscreen window, which draws
+    /// nothing.
+    fn fullscreen_tiling_data(&self, mp: &MonitorPlacements) -> MonitorTilingData {
+        let monitor = self.monitor_registry.monitor(mp.monitor_id);
+        let dim = monitor.work_area().to_dimension();
+        MonitorTilingData {
+            monitor_id: mp.monitor_id,
+            monitor_dim: dim,
+            cocoa_frame: dimension_to_ns_rect_cocoa(Length::new(self.primary_full_height), dim),
+            scale: monitor.egui_scale(),
+            border_thickness: Length::from_pixels(mp.border_thickness),
+            windows: Vec::new(),
+            containers: Vec::new(),
+            hints: Vec::new(),
+            scroll_extents: Vec::new(),
+        }
+    }
+
+    fn normal_tiling_data(
+        &self,
+        mp: &MonitorPlacements,
+        windows: Vec<TilingWindowPlacement>,
+        containers: &[ContainerPlacement],
+    ) -> MonitorTilingData {
+        let monitor = self.monitor_registry.monitor(mp.monitor_id);
+        let monitor_dim = monitor.work_area().to_dimension();
+        let mut container_data = Vec::with_capacity(containers.len());
+        for cp in containers {
+            let tab_bar_dim = cp.tab_bar_band.to_dimension();
+            let tab_bar_cocoa_frame =
+                dimension_to_ns_rect_cocoa(Length::new(self.primary_full_height), tab_bar_dim);
+            let tab_pids = if cp.is_tabbed && self.config.partition_tree.tab_icons {
+                cp.tab_windows
+                    .iter()
+                    .map(|id| Some(self.registry.by_id((*id)?)?.ext.pid()))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            container_data.push(ContainerShow {
+                placement: cp.clone(),
+                tab_bar_dim,
+                tab_bar_cocoa_frame,
+                tab_pids,
+            });
+        }
+        MonitorTilingData {
+            monitor_id: mp.monitor_id,
+            monitor_dim,
+            cocoa_frame: dimension_to_ns_rect_cocoa(
+                Length::new(self.primary_full_height),
+                monitor_dim,
+            ),
+            scale: monitor.egui_scale(),
+            border_thickness: Length::from_pixels(mp.border_thickness),
+            windows,
+            containers: container_data,
+            hints: mp.hints.clone(),
+            scroll_extents: mp.scroll_extents.clone(),
+        }
+    }
+
+    fn float_show(&self, wp: &FloatWindowPlacement, scale: f64) -> Option<FloatShow> {
+        let entry = self.registry.by_id(wp.id)?;
+        Some(FloatShow {
+            cg_id: entry.cg_id,
+            placement: *wp,
+            cocoa_frame: dimension_to_ns_rect_cocoa(
+                Length::new(self.primary_full_height),
+                wp.border_box.to_dimension(),
+            ),
+            scale,
+            border_thickness: Length::from_pixels(wp.border),
+            content_dim: wp.content_box.to_dimension(),
+        })
     }
 
     fn apply_monitor_placements(
@@ -111,33 +279,14 @@ impl Dome {
         match &mp.layout {
             MonitorLayout::Fullscreen(window_id) => {
                 self.place_fullscreen_window(*window_id, mp.monitor_id);
-                let monitor = self.monitor_registry.monitor(mp.monitor_id);
-                let dim = monitor.work_area().to_dimension();
-                (
-                    MonitorTilingData {
-                        monitor_id: mp.monitor_id,
-                        monitor_dim: dim,
-                        cocoa_frame: dimension_to_ns_rect_cocoa(
-                            Length::new(self.primary_full_height),
-                            dim,
-                        ),
-                        scale: monitor.egui_scale(),
-                        border_thickness: Length::from_pixels(mp.border_thickness),
-                        windows: Vec::new(),
-                        containers: Vec::new(),
-                    },
-                    Vec::new(),
-                )
+                (self.fullscreen_tiling_data(mp), Vec::new())
             }
             MonitorLayout::Normal {
                 tiling_windows,
                 float_windows,
                 containers,
             } => {
-                let monitor = self.monitor_registry.monitor(mp.monitor_id);
-                let monitor_dim = monitor.work_area().to_dimension();
-                let scale = monitor.egui_scale();
-
+                let scale = self.monitor_registry.monitor(mp.monitor_id).egui_scale();
                 let mut placed_tiling = Vec::new();
                 let mut float_shows = Vec::new();
 
@@ -172,49 +321,11 @@ impl Dome {
                     } else {
                         self.show_float(wp.id, wp.content_box);
                     }
-                    let Some(entry) = self.registry.by_id(wp.id) else {
-                        continue;
-                    };
-                    float_shows.push(FloatShow {
-                        cg_id: entry.cg_id,
-                        placement: *wp,
-                        cocoa_frame: dimension_to_ns_rect_cocoa(
-                            Length::new(self.primary_full_height),
-                            wp.border_box.to_dimension(),
-                        ),
-                        scale,
-                        border_thickness: Length::from_pixels(mp.border_thickness),
-                        content_dim: wp.content_box.to_dimension(),
-                    });
-                }
-
-                let mut container_data = Vec::with_capacity(containers.len());
-                for cp in containers {
-                    let tab_bar_dim = cp.tab_bar_band.to_dimension();
-                    let tab_bar_cocoa_frame = dimension_to_ns_rect_cocoa(
-                        Length::new(self.primary_full_height),
-                        tab_bar_dim,
-                    );
-                    container_data.push(ContainerShow {
-                        placement: cp.clone(),
-                        tab_bar_dim,
-                        tab_bar_cocoa_frame,
-                    });
+                    float_shows.extend(self.float_show(wp, scale));
                 }
 
                 (
-                    MonitorTilingData {
-                        monitor_id: mp.monitor_id,
-                        monitor_dim,
-                        cocoa_frame: dimension_to_ns_rect_cocoa(
-                            Length::new(self.primary_full_height),
-                            monitor_dim,
-                        ),
-                        scale,
-                        border_thickness: Length::from_pixels(mp.border_thickness),
-                        windows: placed_tiling,
-                        containers: container_data,
-                    },
+                    self.normal_tiling_data(mp, placed_tiling, containers),
                     float_shows,
                 )
             }
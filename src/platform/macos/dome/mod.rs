@@ -7,7 +7,7 @@ mod recovery;
 mod registry;
 mod window;
 
-pub(super) use events::{ContainerShow, HubEvent, HubMessage};
+pub(super) use events::{ContainerShow, HubEvent, HubMessage, OverviewShow};
 pub(in crate::platform::macos) use external_bar::{BarGeometry, ExternalBarProbe};
 pub(super) use inspect::{
     ExitNativeFullscreen, ExtRefresh, compute_reconcile_all, compute_reconciliation,
@@ -23,15 +23,21 @@ use std::time::Instant;
 use objc2_core_graphics::{CGDirectDisplayID, CGWindowID};
 
 use crate::action::{
-    FocusTarget, MasterTarget, MinimizedWindow, MoveTarget, TabDirection, ToggleTarget,
+    FloatTarget, FocusTarget, MasterTarget, MinimizedWindow, MoveTarget, ScrollTarget,
+    TabDirection, ToggleTarget,
 };
 use crate::config::{Config, LayoutConfig, LayoutWorkspaceConfig, WindowMatcher, pattern_matches};
 use crate::core::GlobalLayoutConfig;
 use crate::core::{
-    ContainerId, Dimension, Direction, Hub, Length, Logical, PixelRect, TilingAction, WindowId,
-    WindowMetadata, WindowRestrictions,
+    ContainerId, Dimension, Direction, HINT_MODE, HintSession, Hub, Length, Logical, OVERVIEW_MODE,
+    PixelRect, TilingAction, WindowId, WindowMetadata, WindowRestrictions,
 };
+use crate::core::{FocusRestore, WorkspaceInfo};
+use crate::diagnostics::{ConfigStatus, LoadReport};
+use crate::hook::HookRun;
+use crate::overlay::TabBarEvent;
 use crate::platform::macos::accessibility::ExternalWindow;
+use crate::theme::{Appearance, Flavor, ThemeSelection};
 
 use monitor::MonitorRegistry;
 use recovery::Recovery;
@@ -200,6 +206,16 @@ pub(in crate::platform::macos) struct Dome {
     pending_deleted: Vec<WindowId>,
     status_bars: StatusBarTracker,
     monitors: Vec<MonitorInfo>,
+    pending_hooks: Vec<HookRun>,
+    mode: String,
+    config_status: ConfigStatus,
+    /// Transient message for the status item, cleared by the runner's timer.
+    notice: Option<String>,
+    theme_selection: ThemeSelection,
+    /// Flavor the overlays currently draw with. `config` keeps the loaded one.
+    flavor: Flavor,
+    /// Workspaces as of the last `flush_layout`, resent by animation frames.
+    workspaces: Vec<WorkspaceInfo>,
 }
 
 impl Dome {
@@ -208,7 +224,10 @@ impl Dome {
         config: Config,
         workspace_overrides: Vec<LayoutWorkspaceConfig>,
         sender: Box<dyn FrameSender>,
+        appearance: Appearance,
     ) -> Self {
+        let theme_selection = ThemeSelection::new(appearance);
+        let flavor = theme_selection.select(&config.theme);
         let primary = monitors
             .iter()
             .find(|s| s.is_primary)
@@ -227,6 +246,8 @@ impl Dome {
                 monitor_registry.insert(monitor, id);
             }
         }
+        // Monitors present at launch were not connected, they were already there.
+        hub.take_lifecycle_events();
         Self {
             hub,
             registry: WindowRegistry::new(),
@@ -241,6 +262,13 @@ impl Dome {
             pending_deleted: Vec::new(),
             status_bars: StatusBarTracker::default(),
             monitors: monitors.to_vec(),
+            pending_hooks: Vec::new(),
+            mode: "default".to_string(),
+            config_status: ConfigStatus::default(),
+            notice: None,
+            theme_selection,
+            flavor,
+            workspaces: Vec::new(),
         }
     }
 
@@ -293,6 +321,7 @@ impl Dome {
                     } else {
                         WindowRestrictions::None
                     };
+                    self.hub.set_cursor_position(monitor::cursor_position());
                     let Some(id) =
                         self.hub
                             .insert_window(Box::new(new.metadata.clone()), rect, restrictions)
@@ -388,13 +417,39 @@ impl Dome {
     pub(in crate::platform::macos) fn config_changed(&mut self, new_config: Config) {
         self.hub
             .sync_configuration(GlobalLayoutConfig::from(&new_config));
-        self.sender
-            .send(HubMessage::ConfigChanged(Box::new(new_config.clone())));
+        self.theme_selection.unpin();
+        self.flavor = self.theme_selection.select(&new_config.theme);
         self.config = new_config;
+        self.sender.send(HubMessage::ConfigChanged(Box::new(
+            self.theme_selection.themed(&self.config),
+        )));
         tracing::info!("Config reloaded");
         self.flush_layout();
     }
 
+    pub(in crate::platform::macos) fn appearance_changed(&mut self, appearance: Appearance) {
+        self.theme_selection.set_appearance(appearance);
+        self.reselect_theme();
+    }
+
+    pub(in crate::platform::macos) fn set_theme(&mut self, flavor: Flavor) {
+        self.theme_selection.pin(flavor);
+        self.reselect_theme();
+    }
+
+    fn reselect_theme(&mut self) {
+        let flavor = self.theme_selection.select(&self.config.theme);
+        if flavor == self.flavor {
+            return;
+        }
+        tracing::info!(%flavor, "Theme changed");
+        self.flavor = flavor;
+        self.sender.send(HubMessage::ConfigChanged(Box::new(
+            self.theme_selection.themed(&self.config),
+        )));
+        self.flush_layout();
+    }
+
     pub(in crate::platform::macos) fn layout_changed(&mut self, new_layout: LayoutConfig) {
         self.hub.sync_preferred_layout(new_layout.workspace);
         tracing::info!("Layout reloaded");
@@ -466,13 +521,30 @@ impl Dome {
         self.flush_layout();
     }
 
-    #[tracing::instrument(skip(self), fields(container_id = %container_id, tab_idx))]
-    pub(in crate::platform::macos) fn tab_clicked(
+    #[tracing::instrument(skip(self), fields(container_id = %container_id))]
+    pub(in crate::platform::macos) fn tab_bar_event(
         &mut self,
         container_id: ContainerId,
-        tab_idx: usize,
+        event: TabBarEvent,
     ) {
-        self.hub.focus_tab_index(container_id, tab_idx);
+        match event {
+            TabBarEvent::Select(index) => self.hub.focus_tab_index(container_id, index),
+            TabBarEvent::Move { from, to } => self.hub.move_tab(container_id, from, to),
+            TabBarEvent::DragOut(index) => self.hub.split_out_tab(container_id, index),
+            // The window's destroy notification reshapes the container, so there
+            // is no layout to flush yet.
+            TabBarEvent::Close(index) => {
+                let Some(window_id) = self.hub.tab_window(container_id, index) else {
+                    return;
+                };
+                if let Some(window) = self.registry.by_id(window_id)
+                    && let Err(e) = window.ext.close()
+                {
+                    tracing::warn!(%window_id, "close failed: {e:#}");
+                }
+                return;
+            }
+        }
         self.flush_layout();
     }
 
@@ -563,6 +635,49 @@ impl Dome {
         self.remove_app_windows(pid);
     }
 
+    /// Records a config or layout load. Returns whether it raised a notice,
+    /// which the caller should clear after a while with `clear_notice`.
+    pub(in crate::platform::macos) fn config_reported(&mut self, report: LoadReport) -> bool {
+        let summary = report.summary();
+        self.config_status.record(report);
+        let Some(summary) = summary else {
+            return false;
+        };
+        self.notice = Some(summary);
+        self.flush_layout();
+        true
+    }
+
+    pub(in crate::platform::macos) fn clear_notice(&mut self) {
+        if self.notice.take().is_some() {
+            self.flush_layout();
+        }
+    }
+
+    pub(in crate::platform::macos) fn query_config_status(&self) -> String {
+        self.config_status.query_json()
+    }
+
+    pub(in crate::platform::macos) fn mode_entered(&mut self, name: &str) {
+        self.pending_hooks
+            .extend(crate::hook::mode_runs(&self.config.hooks, name));
+        if name != HINT_MODE {
+            self.hub.hide_hints();
+        }
+        if name != OVERVIEW_MODE {
+            self.hub.hide_overview();
+        }
+        if self.mode != name {
+            self.mode = name.to_string();
+            // Push a frame so the status item picks up the new mode label.
+            self.flush_layout();
+        }
+    }
+
+    pub(in crate::platform::macos) fn take_hook_runs(&mut self) -> Vec<HookRun> {
+        std::mem::take(&mut self.pending_hooks)
+    }
+
     pub(in crate::platform::macos) fn refresh_observers(&self) {
         self.sender.send(HubMessage::RefreshObservers);
     }
@@ -588,6 +703,71 @@ impl Dome {
             .expect("WorkspaceInfo is infallibly serializable")
     }
 
+    pub(in crate::platform::macos) fn focused_window_metadata(
+        &self,
+    ) -> Option<(WindowId, &dyn WindowMetadata)> {
+        self.hub.focused_window_metadata()
+    }
+
+    pub(in crate::platform::macos) fn query_tree_json(&self) -> String {
+        serde_json::to_string(&self.hub.query_tree()).expect("TreeInfo is infallibly serializable")
+    }
+
+    pub(in crate::platform::macos) fn query_render_json(
+        &self,
+        format: crate::action::RenderFormat,
+        workspace: Option<&str>,
+    ) -> String {
+        match self.hub.render(format, workspace) {
+            Some(picture) => {
+                serde_json::to_string(&picture).expect("String is infallibly serializable")
+            }
+            None => serde_json::json!({
+                "error": format!("no workspace named {}", workspace.unwrap_or_default())
+            })
+            .to_string(),
+        }
+    }
+
+    pub(in crate::platform::macos) fn mark_focused(&mut self, name: &str) {
+        self.hub.mark_focused(name);
+    }
+
+    /// Labels the visible windows for `focus hint`. The caller hands the
+    /// session to the keymap, which picks the window.
+    pub(in crate::platform::macos) fn show_hints(&mut self) -> Option<HintSession> {
+        self.hub.show_hints()
+    }
+
+    pub(in crate::platform::macos) fn focus_window(&mut self, window_id: WindowId) {
+        self.hub.focus_window(window_id);
+    }
+
+    pub(in crate::platform::macos) fn focus_for_hook(
+        &mut self,
+        id: WindowId,
+    ) -> Option<FocusRestore> {
+        self.hub.focus_for_hook(id)
+    }
+
+    pub(in crate::platform::macos) fn restore_focus(&mut self, restore: FocusRestore) {
+        self.hub.restore_focus(restore);
+    }
+
+    /// Labels every workspace for the overview. The caller hands the session
+    /// to the keymap, which picks the workspace.
+    pub(in crate::platform::macos) fn show_overview(&mut self) -> Option<HintSession<String>> {
+        self.hub.show_overview()
+    }
+
+    pub(in crate::platform::macos) fn move_window_to_workspace(
+        &mut self,
+        window_id: WindowId,
+        name: &str,
+    ) {
+        self.hub.move_window_to_workspace(window_id, name);
+    }
+
     pub(in crate::platform::macos) fn query_minimized_windows_json(&self) -> String {
         let entries: Vec<MinimizedWindow> = self
             .hub
@@ -659,6 +839,10 @@ impl Dome {
             }
             FocusTarget::Workspace { name } => self.hub.focus_workspace(name),
             FocusTarget::Monitor { target } => self.hub.focus_monitor(target),
+            FocusTarget::Mark { name } => self.hub.focus_mark(name),
+            FocusTarget::Urgent => self.hub.focus_urgent(),
+            // Needs the keymap, so the event loop starts it through `show_hints`.
+            FocusTarget::Hint => {}
         }
     }
 
@@ -683,6 +867,7 @@ impl Dome {
             }),
             MoveTarget::Workspace { name } => self.hub.move_focused_to_workspace(name),
             MoveTarget::Monitor { target } => self.hub.move_focused_to_monitor(target),
+            MoveTarget::Mark { name } => self.hub.move_focused_to_mark(name),
         }
     }
 
@@ -694,8 +879,12 @@ impl Dome {
             ToggleTarget::Layout => self
                 .hub
                 .handle_tiling_action(TilingAction::ToggleContainerLayout),
-            ToggleTarget::Float => self.hub.toggle_float(),
+            ToggleTarget::Float => {
+                self.hub.set_cursor_position(monitor::cursor_position());
+                self.hub.toggle_float();
+            }
             ToggleTarget::Fullscreen => self.hub.toggle_fullscreen(),
+            ToggleTarget::Sticky => self.hub.toggle_sticky(),
         }
     }
 
@@ -709,6 +898,24 @@ impl Dome {
         };
         self.hub.handle_tiling_action(action);
     }
+
+    #[tracing::instrument(skip(self), fields(target = ?target))]
+    pub(in crate::platform::macos) fn apply_float(&mut self, target: &FloatTarget) {
+        match *target {
+            FloatTarget::Move { direction, amount } => self.hub.move_float(direction, amount),
+            FloatTarget::Resize { change, amount } => self.hub.resize_float(change, amount),
+            FloatTarget::Center => self.hub.center_float(),
+            FloatTarget::Snap { preset } => self.hub.snap_float(preset),
+        }
+    }
+
+    #[tracing::instrument(skip(self), fields(target = ?target))]
+    pub(in crate::platform::macos) fn apply_scroll(&mut self, target: &ScrollTarget) {
+        match *target {
+            ScrollTarget::Pan { direction, amount } => self.hub.scroll_viewport(direction, amount),
+            ScrollTarget::Center => self.hub.center_viewport(),
+        }
+    }
 }
 
 impl Drop for Dome {
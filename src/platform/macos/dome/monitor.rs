@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use objc2::MainThreadMarker;
 use objc2_app_kit::NSScreen;
-use objc2_core_graphics::{CGDirectDisplayID, CGDisplayBounds, CGMainDisplayID};
+use objc2_core_graphics::{CGDirectDisplayID, CGDisplayBounds, CGEvent, CGMainDisplayID};
 use objc2_foundation::{NSNumber, NSString};
 
 use crate::core::{Dimension, Hub, Length, MonitorId, PixelRect, Pixels, WindowId};
@@ -10,6 +10,17 @@ use crate::platform::reserve_for_bar;
 
 use super::Dome;
 
+/// Pointer position in the global top-left coordinate space AX windows use,
+/// for cursor float placement. A null-source event reports the current location.
+pub(in crate::platform::macos) fn cursor_position() -> Option<(Pixels, Pixels)> {
+    let event = CGEvent::new(None)?;
+    let point = CGEvent::location(Some(&event));
+    Some((
+        Pixels::round(Length::new(point.x as f32)),
+        Pixels::round(Length::new(point.y as f32)),
+    ))
+}
+
 #[derive(Clone, Debug)]
 pub(in crate::platform::macos) struct MonitorInfo {
     pub(in crate::platform::macos) display_id: CGDirectDisplayID,
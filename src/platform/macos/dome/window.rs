@@ -492,6 +492,13 @@ impl Dome {
                     return;
                 }
 
+                // Intermediate animation frames move the target every few
+                // milliseconds, so only the settled placement is judged.
+                if self.hub.is_animating(window_id) {
+                    tracing::trace!("animation in flight, ignoring");
+                    return;
+                }
+
                 if is_borderless_fullscreen {
                     window.state = WindowState::BorderlessFullscreen;
                     self.hub
@@ -504,10 +511,13 @@ impl Dome {
                 // alongside a previous burst, which is essentially the same.
                 if observed_at.first <= p.placed_at + Duration::from_secs(1) {
                     if p.has_drifted(new_placement) {
-                        if let Some(target) = p.observe_drift(new_placement)
-                            && let Err(e) = window.ext.set_frame(target)
-                        {
-                            tracing::trace!("Window {} set_frame failed: {e}", window);
+                        match p.observe_drift(new_placement) {
+                            Some(target) => {
+                                if let Err(e) = window.ext.set_frame(target) {
+                                    tracing::trace!("Window {} set_frame failed: {e}", window);
+                                }
+                            }
+                            None => self.hub.stop_animating(window_id),
                         }
                         return;
                     }
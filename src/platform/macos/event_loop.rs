@@ -11,17 +11,25 @@ use objc2::rc::autoreleasepool;
 use objc2_app_kit::NSWorkspace;
 use objc2_core_graphics::CGWindowID;
 
-use crate::action::{Action, Actions};
+use crate::action::{Action, Actions, FocusTarget};
+use crate::core::{HINT_MODE, OVERVIEW_MODE, WindowId};
 use crate::keymap::KeymapState;
+use crate::overlay::OverviewEvent;
 use crate::platform::macos::accessibility::ExternalWindow;
 use crate::platform::macos::dispatcher::GcdDispatcher;
 use crate::platform::macos::dome::{
     DebounceBurst, Dome, ExternalBarProbe, HubEvent, MacOSMetadata, NewWindow, PendingAdd,
     WindowMove, compute_reconcile_all, compute_reconciliation, compute_window_positions,
 };
+use crate::platform::macos::keyboard;
 use crate::platform::macos::running_application::RunningApp;
 
 const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(100);
+/// How long a config reload notice stays in the status item.
+const NOTICE_DURATION: Duration = Duration::from_secs(5);
+/// Hook actions can fire further hooks, e.g. a `workspace_focused` hook that
+/// focuses another workspace. Rounds are capped so a cycle cannot spin.
+const MAX_HOOK_ROUNDS: usize = 8;
 
 pub(super) struct DomeRunner {
     dome: Dome,
@@ -33,6 +41,12 @@ pub(super) struct DomeRunner {
     handle: LoopHandle<'static, DomeRunner>,
     signal: LoopSignal,
     keymap_state: Arc<RwLock<KeymapState>>,
+    /// Timer behind `schedule_keymap_deadline`, if armed.
+    keymap_timer: Option<RegistrationToken>,
+    /// Timer behind `schedule_notice_clear`, if armed.
+    notice_timer: Option<RegistrationToken>,
+    /// Timer behind `schedule_animation_frame` and the frame it fires for.
+    animation_timer: Option<(RegistrationToken, Instant)>,
 }
 
 pub(super) fn run_dome(
@@ -56,6 +70,9 @@ pub(super) fn run_dome(
         handle: handle.clone(),
         signal,
         keymap_state,
+        keymap_timer: None,
+        notice_timer: None,
+        animation_timer: None,
     };
 
     handle
@@ -81,6 +98,9 @@ pub(super) fn run_dome(
     );
     event_loop
         .run(None, &mut runner, |runner| {
+            run_hooks(runner);
+            sync_keymap_focus(runner);
+            schedule_animation_frame(runner);
             if SIGNAL_RECEIVED.load(Ordering::Relaxed) {
                 runner.signal.stop();
             }
@@ -116,6 +136,14 @@ fn handle_event(runner: &mut DomeRunner, event: HubEvent) {
         HubEvent::LayoutConfigChanged(new_layout) => {
             runner.dome.layout_changed(*new_layout);
         }
+        HubEvent::AppearanceChanged(appearance) => {
+            runner.dome.appearance_changed(appearance);
+        }
+        HubEvent::ConfigReport(report) => {
+            if runner.dome.config_reported(*report) {
+                schedule_notice_clear(runner);
+            }
+        }
         HubEvent::SyncFocus { pid } => {
             dispatch_sync_focus(runner, pid);
         }
@@ -126,12 +154,25 @@ fn handle_event(runner: &mut DomeRunner, event: HubEvent) {
             tracing::debug!(%actions, "Executing actions");
             process_actions(runner, &actions);
         }
+        HubEvent::ModeEntered(name) => {
+            runner.dome.mode_entered(&name);
+            schedule_keymap_deadline(runner);
+        }
         HubEvent::Query { query, sender } => {
             let json = match query {
                 crate::action::Query::Workspaces => runner.dome.query_workspaces_json(),
                 crate::action::Query::MinimizedWindows => {
                     runner.dome.query_minimized_windows_json()
                 }
+                crate::action::Query::Tree => runner.dome.query_tree_json(),
+                crate::action::Query::Keymaps => {
+                    runner.keymap_state.read().unwrap().query_keymaps_json()
+                }
+                crate::action::Query::Mode => runner.keymap_state.read().unwrap().query_mode_json(),
+                crate::action::Query::ConfigStatus => runner.dome.query_config_status(),
+                crate::action::Query::Render { format, workspace } => {
+                    runner.dome.query_render_json(format, workspace.as_deref())
+                }
             };
             if sender.send(json).is_err() {
                 tracing::debug!("Query response dropped -- receiver gone");
@@ -148,8 +189,11 @@ fn handle_event(runner: &mut DomeRunner, event: HubEvent) {
         HubEvent::MirrorClicked(cg_id) => {
             runner.dome.mirror_clicked(cg_id);
         }
-        HubEvent::TabClicked(container_id, tab_idx) => {
-            runner.dome.tab_clicked(container_id, tab_idx);
+        HubEvent::TabBar(container_id, event) => {
+            runner.dome.tab_bar_event(container_id, event);
+        }
+        HubEvent::Overview(event) => {
+            overview_event(runner, event);
         }
         HubEvent::SpaceChanged => {
             dispatch_space_changed(runner);
@@ -167,12 +211,91 @@ fn handle_event(runner: &mut DomeRunner, event: HubEvent) {
 }
 
 fn process_actions(runner: &mut DomeRunner, actions: &Actions) {
+    run_actions(runner, actions, &[], None);
+}
+
+/// A click picks like a typed label and leaves overview mode. A drop moves
+/// the window and keeps the overview open for the next one.
+fn overview_event(runner: &mut DomeRunner, event: OverviewEvent) {
+    let picked = match event {
+        OverviewEvent::MoveWindow { window, workspace } => {
+            runner.dome.move_window_to_workspace(window, &workspace);
+            runner.dome.flush_layout();
+            return;
+        }
+        OverviewEvent::FocusWorkspace(name) => Some(Action::Focus(FocusTarget::Workspace { name })),
+        OverviewEvent::FocusWindow(id) => Some(Action::FocusWindow(id)),
+        OverviewEvent::Dismiss => None,
+    };
+    let mut actions = vec![Action::Mode {
+        name: "default".to_string(),
+    }];
+    actions.extend(picked);
+    run_actions(runner, &Actions::new(actions), &[], None);
+}
+
+/// Tells the keyboard tap which window is focused so `[[keymaps.app]]`
+/// overrides follow it.
+fn sync_keymap_focus(runner: &mut DomeRunner) {
+    runner
+        .keymap_state
+        .write()
+        .unwrap()
+        .set_focused_window(runner.dome.focused_window_metadata());
+}
+
+/// Runs hook actions queued since the last loop iteration, after the
+/// layout their event produced has been flushed.
+fn run_hooks(runner: &mut DomeRunner) {
+    for _ in 0..MAX_HOOK_ROUNDS {
+        let runs = runner.dome.take_hook_runs();
+        if runs.is_empty() {
+            return;
+        }
+        autoreleasepool(|_| {
+            for run in runs {
+                run_actions(runner, &run.actions, &run.env, run.window);
+            }
+        });
+    }
+    let dropped = runner.dome.take_hook_runs().len();
+    if dropped > 0 {
+        tracing::warn!(
+            dropped,
+            "Hooks kept triggering each other, dropping the rest"
+        );
+    }
+}
+
+/// `env` and `window` are only set for hook actions. `env` only reaches
+/// `exec`, and `window` has focus while the actions run so they act on it.
+fn run_actions(
+    runner: &mut DomeRunner,
+    actions: &Actions,
+    env: &[(&str, String)],
+    window: Option<WindowId>,
+) {
+    let restore = window.and_then(|id| runner.dome.focus_for_hook(id));
     for action in actions {
         match action {
+            Action::Focus(FocusTarget::Hint) => {
+                if let Some(session) = runner.dome.show_hints() {
+                    runner.keymap_state.write().unwrap().start_hints(session);
+                    runner.dome.mode_entered(HINT_MODE);
+                    runner.dome.flush_layout();
+                }
+            }
             Action::Focus(t) => {
                 runner.dome.apply_focus(t);
                 runner.dome.flush_layout();
             }
+            Action::Overview => {
+                if let Some(session) = runner.dome.show_overview() {
+                    runner.keymap_state.write().unwrap().start_overview(session);
+                    runner.dome.mode_entered(OVERVIEW_MODE);
+                    runner.dome.flush_layout();
+                }
+            }
             Action::Move(t) => {
                 runner.dome.apply_move(t);
                 runner.dome.flush_layout();
@@ -185,8 +308,16 @@ fn process_actions(runner: &mut DomeRunner, actions: &Actions) {
                 runner.dome.apply_master(t);
                 runner.dome.flush_layout();
             }
+            Action::Float(t) => {
+                runner.dome.apply_float(t);
+                runner.dome.flush_layout();
+            }
+            Action::Scroll(t) => {
+                runner.dome.apply_scroll(t);
+                runner.dome.flush_layout();
+            }
             Action::Exec { command } => {
-                if let Err(e) = crate::platform::macos::spawn::spawn_disclaimed_sh(command) {
+                if let Err(e) = crate::platform::macos::spawn::spawn_disclaimed_sh(command, env) {
                     tracing::warn!(%command, "Failed to exec: {e}");
                 }
             }
@@ -200,12 +331,29 @@ fn process_actions(runner: &mut DomeRunner, actions: &Actions) {
             Action::UnminimizeWindow(id) => {
                 runner.dome.unminimize_window(*id);
             }
+            Action::FocusWindow(id) => {
+                runner.dome.focus_window(*id);
+                runner.dome.flush_layout();
+            }
+            Action::Mark { name } => {
+                runner.dome.mark_focused(name);
+            }
+            Action::Theme { flavor } => {
+                runner.dome.set_theme(*flavor);
+            }
             Action::Mode { name } => {
-                runner.keymap_state.write().unwrap().switch_mode(name);
+                if runner.keymap_state.write().unwrap().switch_mode(name) {
+                    runner.dome.mode_entered(name);
+                    schedule_keymap_deadline(runner);
+                }
                 tracing::debug!(mode = %name, "Switching to mode");
             }
         }
     }
+    if let Some(restore) = restore {
+        runner.dome.restore_focus(restore);
+        runner.dome.flush_layout();
+    }
 }
 
 fn start_move_timer(runner: &mut DomeRunner, pid: i32, observed_at: Instant) {
@@ -239,6 +387,91 @@ fn start_move_timer(runner: &mut DomeRunner, pid: i32, observed_at: Instant) {
     runner.move_state.insert(pid, (token, burst));
 }
 
+/// Clears the status item notice after `NOTICE_DURATION`. A newer notice
+/// restarts the countdown.
+fn schedule_notice_clear(runner: &mut DomeRunner) {
+    if let Some(token) = runner.notice_timer.take() {
+        runner.handle.remove(token);
+    }
+    let token = runner
+        .handle
+        .insert_source(
+            Timer::from_duration(NOTICE_DURATION),
+            |_, _, runner: &mut DomeRunner| {
+                runner.notice_timer = None;
+                runner.dome.clear_notice();
+                TimeoutAction::Drop
+            },
+        )
+        .expect("Failed to insert timer");
+    runner.notice_timer = Some(token);
+}
+
+/// Arms the timer that drops a half-typed key sequence or leaves a mode with
+/// `timeout_ms`, replacing any earlier one. Keypresses push the deadline later
+/// without telling the hub, so the timer re-arms until nothing is pending.
+fn schedule_keymap_deadline(runner: &mut DomeRunner) {
+    if let Some(token) = runner.keymap_timer.take() {
+        runner.handle.remove(token);
+    }
+    let Some(deadline) = runner.keymap_state.read().unwrap().next_deadline() else {
+        return;
+    };
+    let token = runner
+        .handle
+        .insert_source(
+            Timer::from_deadline(deadline),
+            |_, _, runner: &mut DomeRunner| {
+                let (expired, next, replay) = {
+                    let mut ks = runner.keymap_state.write().unwrap();
+                    let expired = ks.expire(Instant::now()).then(|| ks.mode_label());
+                    (expired, ks.next_deadline(), ks.take_replay())
+                };
+                keyboard::replay_keys(&replay);
+                if let Some(mode) = expired {
+                    runner.dome.mode_entered(&mode);
+                }
+                match next {
+                    Some(deadline) => TimeoutAction::ToInstant(deadline),
+                    None => {
+                        runner.keymap_timer = None;
+                        TimeoutAction::Drop
+                    }
+                }
+            },
+        )
+        .expect("Failed to insert timer");
+    runner.keymap_timer = Some(token);
+}
+
+/// Arms the timer for the next animation frame, if any window is still on its
+/// way. Runs after every loop iteration, so a flush from any event that
+/// retargets a window moves the deadline along with it.
+fn schedule_animation_frame(runner: &mut DomeRunner) {
+    let next = runner.dome.next_animation_frame();
+    if runner.animation_timer.map(|(_, at)| at) == next {
+        return;
+    }
+    if let Some((token, _)) = runner.animation_timer.take() {
+        runner.handle.remove(token);
+    }
+    let Some(deadline) = next else {
+        return;
+    };
+    let token = runner
+        .handle
+        .insert_source(
+            Timer::from_deadline(deadline),
+            |_, _, runner: &mut DomeRunner| {
+                runner.animation_timer = None;
+                autoreleasepool(|_| runner.dome.flush_animation_frame());
+                TimeoutAction::Drop
+            },
+        )
+        .expect("Failed to insert timer");
+    runner.animation_timer = Some((token, deadline));
+}
+
 fn dispatch_refresh_windows(runner: &mut DomeRunner, pid: i32) {
     let tracked = runner.dome.tracked_for_pid(pid);
     runner.dispatcher.dispatch(
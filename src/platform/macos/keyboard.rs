@@ -1,7 +1,7 @@
 use std::cell::OnceCell;
 use std::ptr::NonNull;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, OnceLock, RwLock};
 
 use calloop::channel::Sender as CalloopSender;
 use objc2_core_foundation::{
@@ -9,21 +9,67 @@ use objc2_core_foundation::{
 };
 use objc2_core_graphics::{
     CGEvent, CGEventField, CGEventFlags, CGEventTapLocation, CGEventTapOptions,
-    CGEventTapPlacement, CGEventTapProxy, CGEventType,
+    CGEventTapPlacement, CGEventTapProxy, CGEventType, CGKeyCode,
 };
 
 use super::dome::HubEvent;
 use super::send_hub_event;
+use crate::action::Actions;
 use crate::config::{Keymap, Modifiers};
 use crate::keymap::KeymapState;
 
 pub(super) type SharedKeymapState = Arc<RwLock<KeymapState>>;
 
+/// `EventSourceUserData` of the keys `replay_keys` posts, so the tap lets them
+/// through instead of resolving them again.
+const REPLAY_MARKER: i64 = 0x444f_4d45;
+
 struct KeyboardCtx {
     keymap_state: SharedKeymapState,
     is_suspended: Arc<AtomicBool>,
     hub_sender: CalloopSender<HubEvent>,
     event_tap: OnceCell<CFRetained<CFMachPort>>,
+    wheel_tap: WheelTap,
+}
+
+/// Separate tap for wheel events, enabled only while a wheel pseudo-key is
+/// bound. A disabled tap is skipped by the window server, so without a wheel
+/// binding scrolling never reaches Dome. Cloned into the config watcher, which
+/// switches it on reload.
+#[derive(Clone)]
+pub(super) struct WheelTap {
+    wanted: Arc<AtomicBool>,
+    port: Arc<OnceLock<TapPort>>,
+}
+
+struct TapPort(CFRetained<CFMachPort>);
+
+// SAFETY: the port is only retained, released and passed to
+// `CGEventTapEnable`, all of which are safe from any thread. Its run loop
+// source is added on the tap thread.
+unsafe impl Send for TapPort {}
+unsafe impl Sync for TapPort {}
+
+impl WheelTap {
+    pub(super) fn new(enabled: bool) -> Self {
+        Self {
+            wanted: Arc::new(AtomicBool::new(enabled)),
+            port: Arc::new(OnceLock::new()),
+        }
+    }
+
+    pub(super) fn set_enabled(&self, enabled: bool) {
+        if self.wanted.swap(enabled, Ordering::Relaxed) != enabled {
+            tracing::debug!(enabled, "Wheel keymaps toggled");
+        }
+        if let Some(port) = self.port.get() {
+            CGEvent::tap_enable(&port.0, enabled);
+        }
+    }
+
+    fn enabled(&self) -> bool {
+        self.wanted.load(Ordering::Relaxed)
+    }
 }
 
 /// Runs until the process exits. Creation and registration happen here because
@@ -33,12 +79,14 @@ pub(super) fn run_event_tap(
     keymap_state: SharedKeymapState,
     is_suspended: Arc<AtomicBool>,
     hub_sender: CalloopSender<HubEvent>,
+    wheel_tap: WheelTap,
 ) {
     let ctx = KeyboardCtx {
         keymap_state,
         is_suspended,
         hub_sender,
         event_tap: OnceCell::new(),
+        wheel_tap,
     };
 
     let run_loop = CFRunLoop::current().unwrap();
@@ -69,6 +117,30 @@ pub(super) fn run_event_tap(
 
     ctx.event_tap.set(event_tap).ok();
 
+    let wheel_tap = unsafe {
+        CGEvent::tap_create(
+            CGEventTapLocation::SessionEventTap,
+            CGEventTapPlacement::HeadInsertEventTap,
+            CGEventTapOptions::Default,
+            1u64 << CGEventType::ScrollWheel.0,
+            Some(wheel_tap_callback),
+            ctx_ptr,
+        )
+    };
+    let wheel_source = wheel_tap.as_ref().and_then(|tap| {
+        CFMachPort::new_run_loop_source(unsafe { kCFAllocatorDefault }, Some(tap), 0)
+    });
+    match (wheel_tap, wheel_source) {
+        (Some(tap), Some(source)) => {
+            run_loop.add_source(Some(&source), unsafe { kCFRunLoopDefaultMode });
+            CGEvent::tap_enable(&tap, false);
+            ctx.wheel_tap.port.set(TapPort(tap)).ok();
+            // Apply whatever a reload asked for while the tap was being made.
+            ctx.wheel_tap.set_enabled(ctx.wheel_tap.enabled());
+        }
+        _ => tracing::warn!("Failed to create wheel event tap, wheel keymaps are unavailable"),
+    }
+
     CFRunLoop::run();
 
     tracing::error!("Event tap run loop ended, keymaps are dead");
@@ -93,7 +165,12 @@ unsafe extern "C-unwind" fn event_tap_callback(
         }
         false
     } else {
-        event_type == CGEventType::KeyDown && handle_keyboard(ctx, event_ptr)
+        event_type == CGEventType::KeyDown
+            && CGEvent::integer_value_field(
+                Some(unsafe { &*event_ptr }),
+                CGEventField::EventSourceUserData,
+            ) != REPLAY_MARKER
+            && handle_keyboard(ctx, event_ptr)
     };
 
     if handled {
@@ -103,10 +180,131 @@ unsafe extern "C-unwind" fn event_tap_callback(
     }
 }
 
+unsafe extern "C-unwind" fn wheel_tap_callback(
+    _proxy: CGEventTapProxy,
+    event_type: CGEventType,
+    event: NonNull<CGEvent>,
+    refcon: *mut std::ffi::c_void,
+) -> *mut CGEvent {
+    let event_ptr = event.as_ptr();
+
+    let ctx: &KeyboardCtx = unsafe { &*(refcon as *const KeyboardCtx) };
+
+    if event_type == CGEventType::TapDisabledByTimeout
+        || event_type == CGEventType::TapDisabledByUserInput
+    {
+        if ctx.wheel_tap.enabled() {
+            tracing::warn!(?event_type, "Wheel event tap disabled, re-enabling");
+            ctx.wheel_tap.set_enabled(true);
+        }
+        return event_ptr;
+    }
+    if event_type == CGEventType::ScrollWheel && handle_wheel(ctx, event_ptr) {
+        std::ptr::null_mut()
+    } else {
+        event_ptr
+    }
+}
+
 fn handle_keyboard(ctx: &KeyboardCtx, event: *mut CGEvent) -> bool {
-    let flags = CGEvent::flags(Some(unsafe { &*event }));
-    let key = get_key_from_event(event);
+    let keymap = Keymap {
+        key: get_key_from_event(event),
+        modifiers: event_modifiers(event),
+    };
+    let (resolved, replay) = {
+        let Ok(mut ks) = ctx.keymap_state.write() else {
+            return false;
+        };
+        (ks.resolve_key(&keymap), ks.take_replay())
+    };
+    replay_keys(&replay);
+    dispatch(ctx, &keymap, resolved)
+}
+
+/// A wheel tick as the `wheelup`/`wheeldown`/`wheelleft`/`wheelright`
+/// pseudo-key. Axis 1 is vertical and positive upward, axis 2 horizontal and
+/// positive leftward, both after the system's natural-scrolling setting.
+fn handle_wheel(ctx: &KeyboardCtx, event: *mut CGEvent) -> bool {
+    // Plain scrolling never matches, so skip the lock for it.
+    let modifiers = event_modifiers(event);
+    if modifiers.is_empty() {
+        return false;
+    }
+    let delta = |field| CGEvent::integer_value_field(Some(unsafe { &*event }), field);
+    let vertical = delta(CGEventField::ScrollWheelEventDeltaAxis1);
+    let horizontal = delta(CGEventField::ScrollWheelEventDeltaAxis2);
+    let key = if vertical > 0 {
+        "wheelup"
+    } else if vertical < 0 {
+        "wheeldown"
+    } else if horizontal > 0 {
+        "wheelleft"
+    } else if horizontal < 0 {
+        "wheelright"
+    } else {
+        return false;
+    };
+    let keymap = Keymap {
+        key: key.to_string(),
+        modifiers,
+    };
+    let (resolved, replay) = {
+        let Ok(mut ks) = ctx.keymap_state.write() else {
+            return false;
+        };
+        (ks.resolve_wheel(&keymap), ks.take_replay())
+    };
+    replay_keys(&replay);
+    dispatch(ctx, &keymap, resolved)
+}
+
+/// Type `keys` again for the focused app, after a key sequence that swallowed
+/// them was abandoned. Each key is posted with its own modifier flags, whatever
+/// is held now.
+pub(super) fn replay_keys(keys: &[Keymap]) {
+    for keymap in keys {
+        let Some(keycode) = keycode_for(&keymap.key) else {
+            tracing::debug!(?keymap, "No keycode to replay");
+            continue;
+        };
+        for key_down in [true, false] {
+            let Some(event) = CGEvent::new_keyboard_event(None, keycode, key_down) else {
+                tracing::warn!(?keymap, "Failed to create key event to replay");
+                break;
+            };
+            CGEvent::set_flags(Some(&event), event_flags(keymap.modifiers));
+            CGEvent::set_integer_value_field(
+                Some(&event),
+                CGEventField::EventSourceUserData,
+                REPLAY_MARKER,
+            );
+            CGEvent::post(CGEventTapLocation::SessionEventTap, Some(&event));
+        }
+    }
+    if !keys.is_empty() {
+        tracing::debug!(?keys, "Replayed abandoned keys");
+    }
+}
 
+fn event_flags(modifiers: Modifiers) -> CGEventFlags {
+    let mut flags = CGEventFlags::empty();
+    if modifiers.contains(Modifiers::META) {
+        flags |= CGEventFlags::MaskCommand;
+    }
+    if modifiers.contains(Modifiers::SHIFT) {
+        flags |= CGEventFlags::MaskShift;
+    }
+    if modifiers.contains(Modifiers::ALT) {
+        flags |= CGEventFlags::MaskAlternate;
+    }
+    if modifiers.contains(Modifiers::CTRL) {
+        flags |= CGEventFlags::MaskControl;
+    }
+    flags
+}
+
+fn event_modifiers(event: *mut CGEvent) -> Modifiers {
+    let flags = CGEvent::flags(Some(unsafe { &*event }));
     let mut modifiers = Modifiers::empty();
     if flags.contains(CGEventFlags::MaskCommand) {
         modifiers |= Modifiers::META;
@@ -120,17 +318,28 @@ fn handle_keyboard(ctx: &KeyboardCtx, event: *mut CGEvent) -> bool {
     if flags.contains(CGEventFlags::MaskControl) {
         modifiers |= Modifiers::CTRL;
     }
+    modifiers
+}
 
-    let keymap = Keymap { key, modifiers };
-    let actions = {
-        let Ok(mut ks) = ctx.keymap_state.write() else {
-            return false;
-        };
-        ks.resolve(&keymap)
-    };
+/// Send what the keymap resolved to the hub thread. Returns whether the event
+/// should be swallowed.
+fn dispatch(
+    ctx: &KeyboardCtx,
+    keymap: &Keymap,
+    (actions, entered_mode): (Option<Actions>, Option<String>),
+) -> bool {
+    if let Some(name) = entered_mode {
+        // Already switched in resolve_key. The hub thread is told only to
+        // fire `mode_entered` hooks and time out pending sequences.
+        send_hub_event(&ctx.hub_sender, HubEvent::ModeEntered(name));
+    }
     let Some(actions) = actions else {
         return false;
     };
+    if actions.is_empty() {
+        // Prefix of a key sequence: swallow it and wait for the next key.
+        return true;
+    }
 
     tracing::trace!(?keymap, %actions, "Keymap matched");
 
@@ -146,66 +355,80 @@ fn handle_keyboard(ctx: &KeyboardCtx, event: *mut CGEvent) -> bool {
 fn get_key_from_event(event: *mut CGEvent) -> String {
     let keycode =
         CGEvent::integer_value_field(Some(unsafe { &*event }), CGEventField::KeyboardEventKeycode);
+    match KEYCODES
+        .iter()
+        .find(|&&(code, _)| i64::from(code) == keycode)
+    {
+        Some(&(_, name)) => name.to_string(),
+        None => format!("keycode_{keycode}"),
+    }
+}
 
-    match keycode {
-        0x00 => "a",
-        0x01 => "s",
-        0x02 => "d",
-        0x03 => "f",
-        0x04 => "h",
-        0x05 => "g",
-        0x06 => "z",
-        0x07 => "x",
-        0x08 => "c",
-        0x09 => "v",
-        0x0B => "b",
-        0x0C => "q",
-        0x0D => "w",
-        0x0E => "e",
-        0x0F => "r",
-        0x10 => "y",
-        0x11 => "t",
-        0x12 => "1",
-        0x13 => "2",
-        0x14 => "3",
-        0x15 => "4",
-        0x16 => "6",
-        0x17 => "5",
-        0x18 => "=",
-        0x19 => "9",
-        0x1A => "7",
-        0x1B => "-",
-        0x1C => "8",
-        0x1D => "0",
-        0x1E => "]",
-        0x1F => "o",
-        0x20 => "u",
-        0x21 => "[",
-        0x22 => "i",
-        0x23 => "p",
-        0x25 => "l",
-        0x26 => "j",
-        0x27 => "'",
-        0x28 => "k",
-        0x29 => ";",
-        0x2A => "\\",
-        0x2B => ",",
-        0x2C => "/",
-        0x2D => "n",
-        0x2E => "m",
-        0x2F => ".",
-        0x32 => "`",
-        0x24 => "return",
-        0x4C => "enter",
-        0x33 => "backspace",
-        0x35 => "escape",
-        0x30 => "tab",
-        0x31 => "space",
-        0x7E => "up",
-        0x7D => "down",
-        0x7B => "left",
-        0x7C => "right",
-        _ => return format!("keycode_{keycode}"),
-    }
-    .to_string()
+/// The keycode `get_key_from_event` named `key`, for typing it again.
+fn keycode_for(key: &str) -> Option<CGKeyCode> {
+    if let Some(&(code, _)) = KEYCODES.iter().find(|&&(_, name)| name == key) {
+        return Some(code);
+    }
+    key.strip_prefix("keycode_")?.parse().ok()
 }
+
+/// Virtual keycodes of the ANSI layout and the names keymaps use for them.
+const KEYCODES: [(CGKeyCode, &str); 57] = [
+    (0x00, "a"),
+    (0x01, "s"),
+    (0x02, "d"),
+    (0x03, "f"),
+    (0x04, "h"),
+    (0x05, "g"),
+    (0x06, "z"),
+    (0x07, "x"),
+    (0x08, "c"),
+    (0x09, "v"),
+    (0x0B, "b"),
+    (0x0C, "q"),
+    (0x0D, "w"),
+    (0x0E, "e"),
+    (0x0F, "r"),
+    (0x10, "y"),
+    (0x11, "t"),
+    (0x12, "1"),
+    (0x13, "2"),
+    (0x14, "3"),
+    (0x15, "4"),
+    (0x16, "6"),
+    (0x17, "5"),
+    (0x18, "="),
+    (0x19, "9"),
+    (0x1A, "7"),
+    (0x1B, "-"),
+    (0x1C, "8"),
+    (0x1D, "0"),
+    (0x1E, "]"),
+    (0x1F, "o"),
+    (0x20, "u"),
+    (0x21, "["),
+    (0x22, "i"),
+    (0x23, "p"),
+    (0x25, "l"),
+    (0x26, "j"),
+    (0x27, "'"),
+    (0x28, "k"),
+    (0x29, ";"),
+    (0x2A, "\\"),
+    (0x2B, ","),
+    (0x2C, "/"),
+    (0x2D, "n"),
+    (0x2E, "m"),
+    (0x2F, "."),
+    (0x32, "`"),
+    (0x24, "return"),
+    (0x4C, "enter"),
+    (0x33, "backspace"),
+    (0x35, "escape"),
+    (0x30, "tab"),
+    (0x31, "space"),
+    (0x7E, "up"),
+    (0x7D, "down"),
+    (0x7B, "left"),
+    (0x7C, "right"),
+];
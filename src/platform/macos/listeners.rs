@@ -33,6 +33,7 @@ use objc2_foundation::{
 };
 
 use crate::platform::macos::accessibility::AXApp;
+use crate::platform::macos::appearance::{APPEARANCE_CHANGED_NOTIFICATION, system_appearance};
 use crate::platform::macos::dome::{HubEvent, get_all_monitors};
 use crate::platform::macos::objc2_wrapper::{
     add_observer_notification, create_observer, get_cg_window_id, get_pid,
@@ -262,6 +263,7 @@ fn setup_app_observers(ctx: &mut ListenerCtx) -> (WorkspaceObservers, Distribute
     let distributed_center = NSDistributedNotificationCenter::defaultCenter();
     let lock_name = NSString::from_str("com.apple.screenIsLocked");
     let unlock_name = NSString::from_str("com.apple.screenIsUnlocked");
+    let appearance_name = NSString::from_str(APPEARANCE_CHANGED_NOTIFICATION);
     let mut distributed_observers = Vec::new();
 
     distributed_observers.push(unsafe {
@@ -289,6 +291,22 @@ fn setup_app_observers(ctx: &mut ListenerCtx) -> (WorkspaceObservers, Distribute
         )
     });
 
+    distributed_observers.push(unsafe {
+        distributed_center.addObserverForName_object_queue_usingBlock(
+            Some(appearance_name.as_ref()),
+            None,
+            Some(&NSOperationQueue::mainQueue()),
+            &RcBlock::new(move |_: NonNull<NSNotification>| {
+                let appearance = system_appearance();
+                tracing::info!(?appearance, "System appearance changed");
+                send_hub_event(
+                    &(*ctx_ptr).hub_sender,
+                    HubEvent::AppearanceChanged(appearance),
+                );
+            }),
+        )
+    });
+
     (workspace_observers, distributed_observers)
 }
 
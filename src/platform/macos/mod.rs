@@ -1,4 +1,5 @@
 mod accessibility;
+mod appearance;
 mod dispatcher;
 mod dome;
 mod event_loop;
@@ -24,12 +25,12 @@ use objc2_application_services::{AXIsProcessTrustedWithOptions, kAXTrustedCheckO
 use objc2_core_foundation::{CFDictionary, kCFBooleanTrue};
 use objc2_core_graphics::{CGPreflightScreenCaptureAccess, CGRequestScreenCaptureAccess};
 
-use crate::config::{
-    Config, LayoutConfig, layout_default_path, load_or_default, start_config_watcher,
-};
+use crate::config::{Config, LayoutConfig, layout_default_path, start_config_watcher};
+use crate::diagnostics::{ConfigFile, load_reported};
 use crate::ipc;
 use crate::keymap::KeymapState;
 use crate::logging::Logger;
+use crate::theme::ThemeSelection;
 pub(in crate::platform::macos) use dome::MonitorInfo;
 use dome::{Dome, HubEvent, get_all_monitors};
 use listeners::EventListener;
@@ -39,7 +40,7 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> anyh
     let logger = Logger::init();
 
     let config_path = config_path.unwrap_or_else(Config::default_path);
-    let config = load_or_default(&config_path, Config::load);
+    let (config, config_report) = load_reported(ConfigFile::Config, &config_path, Config::load);
     logger.set_level(config.log_level);
     tracing::info!(%config_path, "Loaded config");
 
@@ -48,7 +49,8 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> anyh
             .to_string_lossy()
             .into_owned()
     });
-    let layout = load_or_default(&layout_path, LayoutConfig::load);
+    let (layout, layout_report) =
+        load_reported(ConfigFile::Layout, &layout_path, LayoutConfig::load);
     tracing::info!(path = %layout_path, "Loaded layout");
 
     let bundle_path = login_item::detect_bundle_path();
@@ -89,33 +91,59 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> anyh
 
     let (event_tx, event_rx) = calloop::channel::channel();
 
+    // The UI builds overlays before the hub thread starts, so hand it a copy
+    // with the flavor already picked. Dome::new repeats the same selection.
+    let appearance = appearance::system_appearance();
+    let ui_config = ThemeSelection::new(appearance).themed(&config);
     let hub_config = config.clone();
     let hub_layout = layout.workspace.clone();
     let keymap_state = Arc::new(RwLock::new(KeymapState::new(config.keymaps.clone())));
+    let wheel_tap = keyboard::WheelTap::new(config.keymaps.binds_wheel());
 
-    let _config_watcher = start_config_watcher(&config_path, Config::load, {
-        let keymap_state = keymap_state.clone();
-        let tx = event_tx.clone();
-        let bundle_path_for_watcher = bundle_path.clone();
-        move |cfg| {
-            logger.set_level(cfg.log_level);
-            keymap_state
-                .write()
-                .unwrap()
-                .update_keymaps(cfg.keymaps.clone());
-            let start_at_login = cfg.start_at_login;
-            tx.send(HubEvent::ConfigChanged(Box::new(cfg))).ok();
-            login_item::sync_login_item(start_at_login, bundle_path_for_watcher.as_deref());
-        }
-    })
+    event_tx
+        .send(HubEvent::ConfigReport(Box::new(config_report)))
+        .ok();
+    event_tx
+        .send(HubEvent::ConfigReport(Box::new(layout_report)))
+        .ok();
+
+    start_config_watcher(
+        &config_path,
+        ConfigFile::Config,
+        &config.includes,
+        Config::load,
+        {
+            let keymap_state = keymap_state.clone();
+            let wheel_tap = wheel_tap.clone();
+            let tx = event_tx.clone();
+            let bundle_path_for_watcher = bundle_path.clone();
+            move |cfg, report| {
+                if let Some(cfg) = cfg {
+                    logger.set_level(cfg.log_level);
+                    keymap_state
+                        .write()
+                        .unwrap()
+                        .update_keymaps(cfg.keymaps.clone());
+                    wheel_tap.set_enabled(cfg.keymaps.binds_wheel());
+                    let start_at_login = cfg.start_at_login;
+                    tx.send(HubEvent::ConfigChanged(Box::new(cfg))).ok();
+                    login_item::sync_login_item(start_at_login, bundle_path_for_watcher.as_deref());
+                }
+                tx.send(HubEvent::ConfigReport(Box::new(report))).ok();
+            }
+        },
+    )
     .inspect_err(|e| tracing::warn!("Failed to setup config watcher: {e:#}"))
     .ok();
 
-    let _layout_watcher = start_config_watcher(&layout_path, LayoutConfig::load, {
+    start_config_watcher(&layout_path, ConfigFile::Layout, &[], LayoutConfig::load, {
         let tx = event_tx.clone();
-        move |new_layout| {
-            tx.send(HubEvent::LayoutConfigChanged(Box::new(new_layout)))
-                .ok();
+        move |new_layout, report| {
+            if let Some(new_layout) = new_layout {
+                tx.send(HubEvent::LayoutConfigChanged(Box::new(new_layout)))
+                    .ok();
+            }
+            tx.send(HubEvent::ConfigReport(Box::new(report))).ok();
         }
     })
     .inspect_err(|e| tracing::warn!("Failed to setup layout watcher: {e:#}"))
@@ -152,14 +180,20 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> anyh
         .spawn({
             let keymap_state = keymap_state.clone();
             let hub_sender = event_tx.clone();
-            move || keyboard::run_event_tap(keymap_state, is_suspended, hub_sender)
+            move || keyboard::run_event_tap(keymap_state, is_suspended, hub_sender, wheel_tap)
         })?;
 
-    let (ui, sender) = Ui::new(mtm, event_tx, event_listener, config.clone());
+    let (ui, sender) = Ui::new(mtm, event_tx, event_listener, ui_config);
 
     let hub_thread = thread::spawn(move || {
         std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            let dome = Dome::new(&monitors, hub_config, hub_layout, Box::new(sender));
+            let dome = Dome::new(
+                &monitors,
+                hub_config,
+                hub_layout,
+                Box::new(sender),
+                appearance,
+            );
             event_loop::run_dome(dome, event_rx, keymap_state);
         }))
         .ok();
@@ -3,12 +3,18 @@ use std::sync::Arc;
 
 use objc2::rc::Retained;
 use objc2_app_kit::{
-    NSApplicationActivationPolicy, NSRunningApplication, NSWorkspace, NSWorkspaceApplicationKey,
+    NSApplicationActivationPolicy, NSBitmapFormat, NSBitmapImageRep, NSRunningApplication,
+    NSWorkspace, NSWorkspaceApplicationKey,
 };
 use objc2_foundation::NSNotification;
 
+use crate::overlay::TabIcon;
 use crate::platform::macos::accessibility::AXApp;
 
+/// Icon bitmaps are picked at the smallest size at least this wide, enough for a
+/// tab bar on a Retina display.
+const ICON_PIXELS: isize = 64;
+
 #[derive(Clone)]
 pub(super) struct RunningApp(Retained<NSRunningApplication>);
 
@@ -45,6 +51,29 @@ impl RunningApp {
         self.0.isActive()
     }
 
+    /// App icon for the tab bar. Serializes every representation, so callers cache
+    /// the result per pid.
+    pub(in crate::platform::macos) fn icon(&self) -> Option<TabIcon> {
+        let tiff = self.0.icon()?.TIFFRepresentation()?;
+        let rep = NSBitmapImageRep::imageRepsWithData(&tiff)
+            .into_iter()
+            .filter_map(|rep| rep.downcast::<NSBitmapImageRep>().ok())
+            .min_by_key(|rep| {
+                let w = rep.pixelsWide();
+                if w >= ICON_PIXELS {
+                    (false, w)
+                } else {
+                    (true, -w)
+                }
+            })?;
+        let (size, rgba) = bitmap_rgba(&rep)?;
+        Some(TabIcon {
+            key: egui::Id::new(("tab_icon", self.pid())),
+            size,
+            rgba: rgba.into(),
+        })
+    }
+
     pub(in crate::platform::macos) fn ax_app(&self) -> Arc<AXApp> {
         Arc::new(AXApp::new(&self.0))
     }
@@ -76,6 +105,47 @@ impl fmt::Display for RunningApp {
     }
 }
 
+/// Only meshed 8-bit RGBA is handled, which is what icon TIFFs decode to.
+fn bitmap_rgba(rep: &NSBitmapImageRep) -> Option<([usize; 2], Vec<u8>)> {
+    let format = rep.bitmapFormat();
+    if rep.isPlanar()
+        || rep.samplesPerPixel() != 4
+        || rep.bitsPerSample() != 8
+        || format.contains(NSBitmapFormat::FloatingPointSamples)
+    {
+        return None;
+    }
+    let (w, h) = (rep.pixelsWide() as usize, rep.pixelsHigh() as usize);
+    let stride = rep.bytesPerRow() as usize;
+    let data = rep.bitmapData();
+    if data.is_null() || w == 0 || stride < w * 4 {
+        return None;
+    }
+    // SAFETY: a meshed bitmap holds `bytesPerRow * pixelsHigh` bytes, alive as long as `rep`.
+    let bytes = unsafe { std::slice::from_raw_parts(data, stride * h) };
+    let alpha_first = format.contains(NSBitmapFormat::AlphaFirst);
+    let premultiplied = !format.contains(NSBitmapFormat::AlphaNonpremultiplied);
+    let mut out = Vec::with_capacity(w * h * 4);
+    for row in bytes.chunks_exact(stride) {
+        for px in row[..w * 4].chunks_exact(4) {
+            let [r, g, b, a] = if alpha_first {
+                [px[1], px[2], px[3], px[0]]
+            } else {
+                [px[0], px[1], px[2], px[3]]
+            };
+            let straight = |c: u8| {
+                if premultiplied && a > 0 {
+                    ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8
+                } else {
+                    c
+                }
+            };
+            out.extend_from_slice(&[straight(r), straight(g), straight(b), a]);
+        }
+    }
+    Some(([w, h], out))
+}
+
 fn is_valid_pid(pid: i32) -> bool {
     pid != -1 && pid != std::process::id() as i32
 }
@@ -15,7 +15,9 @@ use std::sync::OnceLock;
 
 use anyhow::{Context, Result, anyhow};
 
-pub(super) fn spawn_disclaimed_sh(command: &str) -> Result<libc::pid_t> {
+/// `env` entries are added to Dome's own environment, replacing any variable
+/// of the same name.
+pub(super) fn spawn_disclaimed_sh(command: &str, env: &[(&str, String)]) -> Result<libc::pid_t> {
     let path = CString::new("/bin/sh").context("CString /bin/sh")?;
     let arg0 = CString::new("sh").context("CString argv[0]")?;
     let arg1 = CString::new("-c").context("CString -c")?;
@@ -26,7 +28,17 @@ pub(super) fn spawn_disclaimed_sh(command: &str) -> Result<libc::pid_t> {
         arg2.as_ptr() as *mut c_char,
         ptr::null_mut(),
     ];
-    let envp = unsafe { *libc::_NSGetEnviron() };
+    let extra_env = merged_env(env)?;
+    let mut extra_envp: Vec<*mut c_char> = extra_env
+        .iter()
+        .map(|e| e.as_ptr() as *mut c_char)
+        .chain(std::iter::once(ptr::null_mut()))
+        .collect();
+    let envp = if env.is_empty() {
+        unsafe { *libc::_NSGetEnviron() }
+    } else {
+        extra_envp.as_mut_ptr()
+    };
 
     let mut attrs: libc::posix_spawnattr_t = ptr::null_mut();
     let rc = unsafe { libc::posix_spawnattr_init(&mut attrs) };
@@ -68,6 +80,27 @@ pub(super) fn spawn_disclaimed_sh(command: &str) -> Result<libc::pid_t> {
     Ok(pid)
 }
 
+fn merged_env(env: &[(&str, String)]) -> Result<Vec<CString>> {
+    use std::os::unix::ffi::OsStrExt;
+    if env.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut merged = Vec::new();
+    for (key, value) in std::env::vars_os() {
+        if env.iter().any(|(k, _)| key.as_bytes() == k.as_bytes()) {
+            continue;
+        }
+        let mut entry = key.as_bytes().to_vec();
+        entry.push(b'=');
+        entry.extend_from_slice(value.as_bytes());
+        merged.push(CString::new(entry).context("CString env entry")?);
+    }
+    for (key, value) in env {
+        merged.push(CString::new(format!("{key}={value}")).context("CString env entry")?);
+    }
+    Ok(merged)
+}
+
 type SetDisclaimFn = unsafe extern "C" fn(*mut libc::posix_spawnattr_t, bool) -> c_int;
 
 struct AttrsGuard(*mut libc::posix_spawnattr_t);
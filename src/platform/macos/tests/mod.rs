@@ -456,6 +456,7 @@ impl MacOS {
             config.clone(),
             Vec::new(),
             Box::new(sender),
+            crate::theme::Appearance::Dark,
         )
     }
 
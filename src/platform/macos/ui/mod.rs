@@ -24,10 +24,12 @@ use objc2_foundation::{NSNotification, NSObject, NSObjectProtocol};
 use super::dome::{FrameSender, HubEvent, HubMessage};
 use super::listeners::EventListener;
 use super::objc2_wrapper::{kAXFrontmostAttribute, set_attribute_value};
+use super::running_application::RunningApp;
 use crate::config::Config;
 use crate::core::{ContainerId, MonitorId, WindowId};
+use crate::overlay::TabIcon;
 use mirror::{WindowCapture, create_captures_async};
-use overlay::{FloatOverlay, TabBarOverlay, TilingOverlay};
+use overlay::{FloatOverlay, OverviewOverlay, TabBarOverlay, TilingOverlay};
 use renderer::WgpuFactory;
 use status_menu::StatusMenu;
 
@@ -158,7 +160,11 @@ struct AppDelegateIvars {
     capture_queue: DispatchRetained<DispatchQueue>,
     tiling_overlays: RefCell<HashMap<MonitorId, TilingOverlay>>,
     tab_bar_overlays: RefCell<HashMap<ContainerId, TabBarOverlay>>,
+    /// Keyed by pid and pruned to the pids in tab bars each frame, so a reused pid
+    /// does not show a stale icon for long.
+    app_icons: RefCell<HashMap<i32, Option<TabIcon>>>,
     float_overlays: RefCell<HashMap<CGWindowID, FloatOverlay>>,
+    overview_overlay: RefCell<Option<OverviewOverlay>>,
     captures: RefCell<HashMap<CGWindowID, WindowCapture>>,
     event_listener: EventListener,
     wgpu_factory: Rc<WgpuFactory>,
@@ -204,7 +210,9 @@ impl AppDelegate {
             capture_queue: DispatchQueue::new("dome.capture", None),
             tiling_overlays: RefCell::new(HashMap::new()),
             tab_bar_overlays: RefCell::new(HashMap::new()),
+            app_icons: RefCell::new(HashMap::new()),
             float_overlays: RefCell::new(HashMap::new()),
+            overview_overlay: RefCell::new(None),
             captures: RefCell::new(HashMap::new()),
             event_listener,
             wgpu_factory,
@@ -230,7 +238,12 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                     .status_menu
                     .borrow_mut()
                     .get_or_insert_with(|| StatusMenu::new(mtm, sender_clone))
-                    .update(mtm, &frame.workspaces);
+                    .update(
+                        mtm,
+                        &frame.workspaces,
+                        frame.mode.as_deref(),
+                        frame.notice.as_deref(),
+                    );
 
                 let mut tiling_overlays = delegate.ivars().tiling_overlays.borrow_mut();
                 let mut float_overlays = delegate.ivars().float_overlays.borrow_mut();
@@ -253,7 +266,10 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                         )
                     });
                     overlay.set_border_thickness(data.border_thickness);
-                    if data.windows.is_empty() && data.containers.is_empty() {
+                    if data.windows.is_empty()
+                        && data.containers.is_empty()
+                        && data.hints.is_empty()
+                    {
                         overlay.clear();
                     } else {
                         overlay.render(
@@ -262,6 +278,8 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                             data.monitor_dim,
                             &data.windows,
                             &data.containers,
+                            &data.hints,
+                            &data.scroll_extents,
                         );
                     }
                 }
@@ -272,7 +290,9 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                 // mouse-transparent, so tab clicks must land on a window
                 // that owns just the bar strip.
                 let mut tab_bar_overlays = delegate.ivars().tab_bar_overlays.borrow_mut();
+                let mut app_icons = delegate.ivars().app_icons.borrow_mut();
                 let mut active_tab_bars: HashSet<ContainerId> = HashSet::new();
+                let mut tab_pids: HashSet<i32> = HashSet::new();
                 for data in &frame.tiling {
                     for cs in &data.containers {
                         if !cs.placement.is_tabbed || cs.placement.titles.is_empty() {
@@ -289,12 +309,26 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                                 hub_sender.clone(),
                             )
                         });
-                        entry.render(cs, data.scale, data.border_thickness);
+                        let icons: Vec<Option<TabIcon>> = cs
+                            .tab_pids
+                            .iter()
+                            .map(|pid| {
+                                let pid = (*pid)?;
+                                tab_pids.insert(pid);
+                                app_icons
+                                    .entry(pid)
+                                    .or_insert_with(|| RunningApp::new(pid)?.icon())
+                                    .clone()
+                            })
+                            .collect();
+                        entry.render(cs, icons, data.scale, data.border_thickness);
                         active_tab_bars.insert(cs.placement.id);
                     }
                 }
                 tab_bar_overlays.retain(|id, _| active_tab_bars.contains(id));
+                app_icons.retain(|pid, _| tab_pids.contains(pid));
                 drop(tab_bar_overlays);
+                drop(app_icons);
 
                 // Float overlays: create on first show
                 let mut capture_pairs = Vec::new();
@@ -308,7 +342,7 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                             hub_sender.clone(),
                             wgpu_factory.clone(),
                             config.clone(),
-                            config.theme,
+                            config.theme.flavor,
                             &config.font,
                         )
                     });
@@ -349,6 +383,27 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                 drop(float_overlays);
                 drop(captures);
 
+                // Overview: one window over the focused monitor, alive only
+                // while the frame carries it.
+                {
+                    let mut overview = delegate.ivars().overview_overlay.borrow_mut();
+                    match &frame.overview {
+                        Some(show) => overview
+                            .get_or_insert_with(|| {
+                                OverviewOverlay::new(
+                                    mtm,
+                                    wgpu_factory.clone(),
+                                    config.clone(),
+                                    show.cocoa_frame,
+                                    show.scale,
+                                    hub_sender.clone(),
+                                )
+                            })
+                            .render(show),
+                        None => *overview = None,
+                    }
+                }
+
                 // Focus overlay when transitioning away from a window
                 {
                     let last = delegate.ivars().last_focused.get();
@@ -385,6 +440,9 @@ unsafe extern "C-unwind" fn frame_callback(info: *mut c_void) {
                 for overlay in delegate.ivars().tab_bar_overlays.borrow().values() {
                     overlay.set_config(&new_config);
                 }
+                if let Some(overlay) = &*delegate.ivars().overview_overlay.borrow() {
+                    overlay.set_config(&new_config);
+                }
             }
             HubMessage::Shutdown => {
                 NSApplication::sharedApplication(mtm).terminate(None);
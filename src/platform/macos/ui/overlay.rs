@@ -12,7 +12,8 @@ use objc2::runtime::AnyObject;
 use objc2::{DefinedClass, MainThreadMarker, MainThreadOnly, define_class, msg_send};
 use objc2_app_kit::{
     NSBackingStoreType, NSColor, NSEvent, NSFloatingWindowLevel, NSNormalWindowLevel, NSResponder,
-    NSView, NSWindow, NSWindowCollectionBehavior, NSWindowLevel, NSWindowStyleMask,
+    NSStatusWindowLevel, NSView, NSWindow, NSWindowCollectionBehavior, NSWindowLevel,
+    NSWindowStyleMask,
 };
 use objc2_core_graphics::CGWindowID;
 use objc2_foundation::{NSObject, NSObjectProtocol, NSPoint, NSRect, NSSize};
@@ -21,14 +22,18 @@ use objc2_quartz_core::{
     CAAutoresizingMask, CALayer, CAMetalLayer, CATransaction, kCAGravityResize,
 };
 
-use super::super::dome::{ContainerShow, HubEvent};
+use super::super::dome::{ContainerShow, HubEvent, OverviewShow};
 use super::renderer::{Renderer, WgpuFactory};
-use crate::config::Config;
+use crate::config::{Config, TabBarPosition};
 use crate::core::{
-    ContainerId, Dimension, FloatWindowPlacement, Length, Logical, TilingWindowPlacement,
+    ContainerId, Dimension, FloatWindowPlacement, HintPlacement, Length, Logical,
+    OverviewWorkspace, ScrollExtent, TilingWindowPlacement,
 };
 use crate::font::FontConfig;
-use crate::overlay::{self, BorderMetrics, LogicalTiledContainer, LogicalTiledWindow};
+use crate::overlay::{
+    self, BorderMetrics, LogicalHint, LogicalScrollExtent, LogicalTiledContainer,
+    LogicalTiledWindow, TabIcon,
+};
 use crate::theme::Flavor;
 
 define_class!(
@@ -62,6 +67,8 @@ impl KeyableWindow {
 }
 
 const FLOAT_OVERLAY_LEVEL: NSWindowLevel = NSFloatingWindowLevel;
+/// `NSEvent.buttonNumber` of the middle mouse button.
+const MIDDLE_BUTTON: isize = 2;
 
 pub(super) struct FloatOverlay {
     window: Retained<NSWindow>,
@@ -183,7 +190,7 @@ impl FloatOverlay {
         }
 
         let config = &self.config;
-        let border = BorderMetrics::from_thickness(self.border_thickness);
+        let border = BorderMetrics::new(config, self.border_thickness);
         let theme = config.theme();
         self.renderer.render(scale as f32, Vec::new(), |ui| {
             // layer_painter bypasses egui's Area sizing pass, avoiding
@@ -204,7 +211,7 @@ impl FloatOverlay {
                 &painter.with_clip_rect(clip),
                 placement.border_box.to_dimension(),
                 visible_border_box,
-                placement.is_highlighted,
+                overlay::BorderState::float(placement),
                 None,
                 &theme,
                 border,
@@ -215,8 +222,8 @@ impl FloatOverlay {
     }
 
     pub(super) fn set_config(&mut self, config: &Config) {
-        if self.config.theme != config.theme {
-            self.renderer.apply_theme(config.theme);
+        if self.config.theme.flavor != config.theme.flavor {
+            self.renderer.apply_theme(config.theme.flavor);
         }
         if self.config.font != config.font {
             if self.config.font.family != config.font.family {
@@ -229,7 +236,7 @@ impl FloatOverlay {
             let config = &self.config;
             // The stored thickness is one frame stale after a config change. The
             // following flush_layout carries the new one.
-            let border = BorderMetrics::from_thickness(self.border_thickness);
+            let border = BorderMetrics::new(config, self.border_thickness);
             let theme = config.theme();
             self.renderer.render(self.scale as f32, Vec::new(), |ui| {
                 let painter = ui.ctx().layer_painter(egui::LayerId::new(
@@ -248,7 +255,7 @@ impl FloatOverlay {
                     &painter.with_clip_rect(clip),
                     placement.border_box.to_dimension(),
                     visible_border_box,
-                    placement.is_highlighted,
+                    overlay::BorderState::float(&placement),
                     None,
                     &theme,
                     border,
@@ -295,7 +302,7 @@ impl TilingOverlay {
         cocoa_frame: NSRect,
         scale: f64,
     ) -> Self {
-        let flavor = config.theme;
+        let flavor = config.theme.flavor;
         let font = config.font.clone();
         let window = KeyableWindow::new(mtm, cocoa_frame, NSWindowStyleMask::Borderless);
         window.setBackgroundColor(Some(&NSColor::clearColor()));
@@ -321,6 +328,10 @@ impl TilingOverlay {
         Self { window, view }
     }
 
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "one paint pass worth of tiling overlay state"
+    )]
     pub(super) fn render(
         &self,
         cocoa_frame: NSRect,
@@ -328,9 +339,19 @@ impl TilingOverlay {
         monitor: Dimension,
         windows: &[TilingWindowPlacement],
         containers: &[ContainerShow],
+        hints: &[HintPlacement],
+        scroll_extents: &[ScrollExtent],
     ) {
         self.window.setFrame_display(cocoa_frame, false);
-        self.view.update(monitor, windows, containers, scale);
+        // Above app windows and float mirrors while hints show, so every label
+        // is readable; back below them otherwise.
+        self.window.setLevel(if hints.is_empty() {
+            NSNormalWindowLevel - 1
+        } else {
+            NSStatusWindowLevel
+        });
+        self.view
+            .update(monitor, windows, containers, hints, scroll_extents, scale);
     }
 
     pub(super) fn set_border_thickness(&self, t: Length<Logical>) {
@@ -338,6 +359,7 @@ impl TilingOverlay {
     }
 
     pub(super) fn clear(&self) {
+        self.window.setLevel(NSNormalWindowLevel - 1);
         self.view.clear();
         self.view.render_now();
     }
@@ -430,6 +452,8 @@ pub(super) struct TilingOverlayViewIvars {
     monitor: Cell<Dimension>,
     windows: RefCell<Vec<TilingWindowPlacement>>,
     containers: RefCell<Vec<ContainerShow>>,
+    hints: RefCell<Vec<HintPlacement>>,
+    scroll_extents: RefCell<Vec<ScrollExtent>>,
     config: RefCell<Config>,
     border_thickness: Cell<Length<Logical>>,
     scale: Cell<f64>,
@@ -468,6 +492,8 @@ impl TilingOverlayView {
             monitor: Cell::new(Dimension::default()),
             windows: RefCell::new(Vec::new()),
             containers: RefCell::new(Vec::new()),
+            hints: RefCell::new(Vec::new()),
+            scroll_extents: RefCell::new(Vec::new()),
             config: RefCell::new(config),
             border_thickness: Cell::new(Length::new(0.0)),
             scale: Cell::new(scale),
@@ -485,6 +511,8 @@ impl TilingOverlayView {
         monitor: Dimension,
         windows: &[TilingWindowPlacement],
         containers: &[ContainerShow],
+        hints: &[HintPlacement],
+        scroll_extents: &[ScrollExtent],
         scale: f64,
     ) {
         let ivars = self.ivars();
@@ -492,6 +520,8 @@ impl TilingOverlayView {
         ivars.scale.set(scale);
         *ivars.windows.borrow_mut() = windows.to_vec();
         *ivars.containers.borrow_mut() = containers.to_vec();
+        *ivars.hints.borrow_mut() = hints.to_vec();
+        *ivars.scroll_extents.borrow_mut() = scroll_extents.to_vec();
         ivars.renderer.borrow_mut().resize(
             scale,
             monitor.width.logical() as f64,
@@ -504,12 +534,17 @@ impl TilingOverlayView {
         let ivars = self.ivars();
         ivars.windows.borrow_mut().clear();
         ivars.containers.borrow_mut().clear();
+        ivars.hints.borrow_mut().clear();
+        ivars.scroll_extents.borrow_mut().clear();
     }
 
     fn set_config(&self, config: &Config) {
         let prev = self.ivars().config.borrow().clone();
-        if prev.theme != config.theme {
-            self.ivars().renderer.borrow_mut().apply_theme(config.theme);
+        if prev.theme.flavor != config.theme.flavor {
+            self.ivars()
+                .renderer
+                .borrow_mut()
+                .apply_theme(config.theme.flavor);
         }
         if prev.font != config.font {
             if prev.font.family != config.font.family {
@@ -539,7 +574,8 @@ impl TilingOverlayView {
                 id: wp.id,
                 frame: wp.border_box.to_dimension(),
                 visible_frame: wp.visible_border_box.to_dimension(),
-                is_highlighted: wp.is_highlighted,
+                state: overlay::BorderState::tiling(wp),
+                thickness: Length::from_pixels(wp.border),
                 spawn_indicator: wp.spawn_indicator,
             })
             .collect();
@@ -549,14 +585,34 @@ impl TilingOverlayView {
                 id: cs.placement.id,
                 frame: cs.placement.border_box.to_dimension(),
                 visible_frame: cs.placement.visible_border_box.to_dimension(),
-                tab_bar_height: Length::from_pixels(cs.placement.tab_bar_band.height()),
+                tab_bar_thickness: Length::from_pixels(cs.placement.tab_bar_thickness()),
+                tab_bar_position: cs.placement.tab_bar_position,
                 is_highlighted: cs.placement.is_highlighted,
                 spawn_indicator: cs.placement.spawn_indicator,
                 is_tabbed: cs.placement.is_tabbed,
                 titles: cs.placement.titles.clone(),
             })
             .collect();
-        let border = BorderMetrics::from_thickness(ivars.border_thickness.get());
+        let hints_logical: Vec<LogicalHint> = ivars
+            .hints
+            .borrow()
+            .iter()
+            .map(|h| LogicalHint {
+                label: h.label.clone(),
+                frame: h.visible_border_box.to_dimension(),
+            })
+            .collect();
+        let scroll_logical: Vec<LogicalScrollExtent> = ivars
+            .scroll_extents
+            .borrow()
+            .iter()
+            .map(|e| LogicalScrollExtent {
+                viewport: e.viewport.to_dimension(),
+                content: e.content.to_dimension(),
+                windows: e.windows.iter().map(|w| w.to_dimension()).collect(),
+            })
+            .collect();
+        let border = BorderMetrics::new(&config, ivars.border_thickness.get());
         let theme = config.theme();
 
         ivars
@@ -568,6 +624,9 @@ impl TilingOverlayView {
                     monitor_logical,
                     &windows_logical,
                     &containers_logical,
+                    &hints_logical,
+                    &scroll_logical,
+                    config.scroll_indicator,
                     &theme,
                     border,
                 )
@@ -595,7 +654,7 @@ impl TabBarOverlay {
         scale: f64,
         hub_sender: CalloopSender<HubEvent>,
     ) -> Self {
-        let flavor = config.theme;
+        let flavor = config.theme.flavor;
         let font = config.font.clone();
         let window = unsafe {
             NSWindow::initWithContentRect_styleMask_backing_defer(
@@ -641,13 +700,21 @@ impl TabBarOverlay {
         Self { window, view }
     }
 
-    pub(super) fn render(&self, cs: &ContainerShow, scale: f64, border_thickness: Length<Logical>) {
+    pub(super) fn render(
+        &self,
+        cs: &ContainerShow,
+        icons: Vec<Option<TabIcon>>,
+        scale: f64,
+        border_thickness: Length<Logical>,
+    ) {
         self.window.setFrame_display(cs.tab_bar_cocoa_frame, false);
         self.view.update(
             scale,
             cs.tab_bar_dim,
             border_thickness,
+            cs.placement.tab_bar_position,
             cs.placement.titles.clone(),
+            icons,
             cs.placement.active_tab_index,
             cs.placement.is_highlighted,
         );
@@ -672,7 +739,9 @@ pub(super) struct TabBarOverlayViewIvars {
     renderer: RefCell<Renderer>,
     bar: Cell<Dimension<Logical>>,
     border_thickness: Cell<Length<Logical>>,
+    position: Cell<TabBarPosition>,
     titles: RefCell<Vec<String>>,
+    icons: RefCell<Vec<Option<TabIcon>>>,
     active_tab_index: Cell<usize>,
     is_highlighted: Cell<bool>,
     scale: Cell<f64>,
@@ -696,32 +765,45 @@ define_class!(
         }
 
         // Both mouseDown: and mouseUp: are required: paint_tab_bar's
-        // Sense::click() fires response.clicked() only on press-then-release,
-        // so render_now must run on both edges to observe the second event.
+        // Sense::click_and_drag() fires response.clicked() only on
+        // press-then-release, so render_now must run on both edges to observe
+        // the second event.
         #[unsafe(method(mouseDown:))]
         fn mouse_down(&self, event: &NSEvent) {
-            let pos = self.event_pos(event);
-            self.ivars().events.borrow_mut().push(egui::Event::PointerButton {
-                pos,
-                button: egui::PointerButton::Primary,
-                pressed: true,
-                modifiers: egui::Modifiers::NONE,
-            });
-            self.render_now();
+            self.push_button(event, egui::PointerButton::Primary, true);
         }
 
         #[unsafe(method(mouseUp:))]
         fn mouse_up(&self, event: &NSEvent) {
+            self.push_button(event, egui::PointerButton::Primary, false);
+        }
+
+        // AppKit keeps delivering drags to the view that took the mouseDown:,
+        // even outside the bar, which is how a tab gets dragged out.
+        #[unsafe(method(mouseDragged:))]
+        fn mouse_dragged(&self, event: &NSEvent) {
             let pos = self.event_pos(event);
-            self.ivars().events.borrow_mut().push(egui::Event::PointerButton {
-                pos,
-                button: egui::PointerButton::Primary,
-                pressed: false,
-                modifiers: egui::Modifiers::NONE,
-            });
+            self.ivars()
+                .events
+                .borrow_mut()
+                .push(egui::Event::PointerMoved(pos));
             self.render_now();
         }
 
+        #[unsafe(method(otherMouseDown:))]
+        fn other_mouse_down(&self, event: &NSEvent) {
+            if event.buttonNumber() == MIDDLE_BUTTON {
+                self.push_button(event, egui::PointerButton::Middle, true);
+            }
+        }
+
+        #[unsafe(method(otherMouseUp:))]
+        fn other_mouse_up(&self, event: &NSEvent) {
+            if event.buttonNumber() == MIDDLE_BUTTON {
+                self.push_button(event, egui::PointerButton::Middle, false);
+            }
+        }
+
         #[unsafe(method(acceptsFirstMouse:))]
         fn accepts_first_mouse(&self, _event: Option<&NSEvent>) -> bool {
             true
@@ -752,7 +834,9 @@ impl TabBarOverlayView {
             renderer: RefCell::new(renderer),
             bar: Cell::new(Dimension::default()),
             border_thickness: Cell::new(Length::new(0.0)),
+            position: Cell::new(TabBarPosition::default()),
             titles: RefCell::new(Vec::new()),
+            icons: RefCell::new(Vec::new()),
             active_tab_index: Cell::new(0),
             is_highlighted: Cell::new(false),
             scale: Cell::new(scale),
@@ -768,20 +852,28 @@ impl TabBarOverlayView {
         view
     }
 
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "one paint pass worth of tab bar state"
+    )]
     fn update(
         &self,
         scale: f64,
         bar: Dimension<Logical>,
         border_thickness: Length<Logical>,
+        position: TabBarPosition,
         titles: Vec<String>,
+        icons: Vec<Option<TabIcon>>,
         active_tab_index: usize,
         is_highlighted: bool,
     ) {
         let ivars = self.ivars();
+        ivars.position.set(position);
         ivars.bar.set(bar);
         ivars.border_thickness.set(border_thickness);
         ivars.scale.set(scale);
         *ivars.titles.borrow_mut() = titles;
+        *ivars.icons.borrow_mut() = icons;
         ivars.active_tab_index.set(active_tab_index);
         ivars.is_highlighted.set(is_highlighted);
         ivars.renderer.borrow_mut().resize(
@@ -794,8 +886,11 @@ impl TabBarOverlayView {
 
     fn set_config(&self, config: &Config) {
         let prev = self.ivars().config.borrow().clone();
-        if prev.theme != config.theme {
-            self.ivars().renderer.borrow_mut().apply_theme(config.theme);
+        if prev.theme.flavor != config.theme.flavor {
+            self.ivars()
+                .renderer
+                .borrow_mut()
+                .apply_theme(config.theme.flavor);
         }
         if prev.font != config.font {
             if prev.font.family != config.font.family {
@@ -813,7 +908,9 @@ impl TabBarOverlayView {
     fn render_now(&self) {
         let ivars = self.ivars();
         let bar = ivars.bar.get();
+        let position = ivars.position.get();
         let titles = ivars.titles.borrow().clone();
+        let icons = ivars.icons.borrow().clone();
         let active_tab_index = ivars.active_tab_index.get();
         let is_highlighted = ivars.is_highlighted.get();
         let config = ivars.config.borrow();
@@ -821,7 +918,7 @@ impl TabBarOverlayView {
         let scale = ivars.scale.get();
         let container_id = ivars.container_id;
 
-        let border = BorderMetrics::from_thickness(ivars.border_thickness.get());
+        let border = BorderMetrics::new(&config, ivars.border_thickness.get());
         let theme = config.theme();
 
         // The tab-bar window's canvas is exactly the bar, so paint at the
@@ -830,7 +927,7 @@ impl TabBarOverlayView {
         let canvas_local =
             Dimension::<Logical>::new(Length::ZERO, Length::ZERO, bar.width, bar.height);
 
-        let clicked = ivars
+        let event = ivars
             .renderer
             .borrow_mut()
             .render(scale as f32, events, |ui| {
@@ -838,21 +935,266 @@ impl TabBarOverlayView {
                     ui.ctx(),
                     container_id,
                     canvas_local,
+                    position,
                     &titles,
+                    &icons,
                     active_tab_index,
                     is_highlighted,
                     border,
                     &theme,
                 )
             });
-        if let Some((cid, tab_idx)) = clicked {
-            ivars
-                .hub_sender
-                .send(HubEvent::TabClicked(cid, tab_idx))
-                .ok();
+        if let Some((cid, event)) = event {
+            ivars.hub_sender.send(HubEvent::TabBar(cid, event)).ok();
         }
     }
 
+    fn push_button(&self, event: &NSEvent, button: egui::PointerButton, pressed: bool) {
+        let pos = self.event_pos(event);
+        self.ivars()
+            .events
+            .borrow_mut()
+            .push(egui::Event::PointerButton {
+                pos,
+                button,
+                pressed,
+                modifiers: egui::Modifiers::NONE,
+            });
+        self.render_now();
+    }
+
+    fn event_pos(&self, event: &NSEvent) -> egui::Pos2 {
+        let loc = event.locationInWindow();
+        let view_loc = self.convertPoint_fromView(loc, None);
+        egui::pos2(view_loc.x as f32, view_loc.y as f32)
+    }
+}
+
+/// Click-receiving window covering the focused monitor while the overview
+/// shows. Created when a frame carries an overview and closed with the first
+/// frame that does not.
+pub(super) struct OverviewOverlay {
+    window: Retained<NSWindow>,
+    view: Retained<OverviewOverlayView>,
+}
+
+impl OverviewOverlay {
+    pub(super) fn new(
+        mtm: MainThreadMarker,
+        wgpu_factory: Rc<WgpuFactory>,
+        config: Config,
+        cocoa_frame: NSRect,
+        scale: f64,
+        hub_sender: CalloopSender<HubEvent>,
+    ) -> Self {
+        let window = unsafe {
+            NSWindow::initWithContentRect_styleMask_backing_defer(
+                NSWindow::alloc(mtm),
+                cocoa_frame,
+                NSWindowStyleMask::Borderless,
+                NSBackingStoreType::Buffered,
+                false,
+            )
+        };
+        window.setBackgroundColor(Some(&NSColor::clearColor()));
+        window.setOpaque(false);
+        // Same level hints raise the tiling overlay to, above app windows and
+        // float mirrors.
+        window.setLevel(NSStatusWindowLevel);
+        window.setCollectionBehavior(
+            NSWindowCollectionBehavior::Auxiliary
+                | NSWindowCollectionBehavior::Transient
+                | NSWindowCollectionBehavior::FullScreenNone
+                | NSWindowCollectionBehavior::IgnoresCycle,
+        );
+        unsafe { window.setReleasedWhenClosed(false) };
+        window.setIgnoresMouseEvents(false);
+
+        let view = OverviewOverlayView::new(mtm, wgpu_factory, config, scale, hub_sender);
+        window.setContentView(Some(&view));
+        window.setFrame_display(cocoa_frame, false);
+        window.orderFront(None);
+
+        Self { window, view }
+    }
+
+    pub(super) fn render(&self, show: &OverviewShow) {
+        self.window.setFrame_display(show.cocoa_frame, false);
+        self.view.update(show);
+    }
+
+    pub(super) fn set_config(&self, config: &Config) {
+        self.view.set_config(config);
+    }
+}
+
+impl Drop for OverviewOverlay {
+    fn drop(&mut self) {
+        self.window.close();
+    }
+}
+
+pub(super) struct OverviewOverlayViewIvars {
+    #[expect(dead_code, reason = "retains CAMetalLayer to prevent deallocation")]
+    layer: Retained<CAMetalLayer>,
+    events: RefCell<Vec<egui::Event>>,
+    renderer: RefCell<Renderer>,
+    screen: Cell<Dimension<Logical>>,
+    workspaces: RefCell<Vec<OverviewWorkspace>>,
+    scale: Cell<f64>,
+    hub_sender: CalloopSender<HubEvent>,
+    config: RefCell<Config>,
+}
+
+define_class!(
+    #[unsafe(super(NSView, NSResponder, NSObject))]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = OverviewOverlayViewIvars]
+    pub(super) struct OverviewOverlayView;
+
+    unsafe impl NSObjectProtocol for OverviewOverlayView {}
+
+    impl OverviewOverlayView {
+        #[unsafe(method(isFlipped))]
+        fn is_flipped(&self) -> bool {
+            true
+        }
+
+        #[unsafe(method(mouseDown:))]
+        fn mouse_down(&self, event: &NSEvent) {
+            self.push_button(event, true);
+        }
+
+        #[unsafe(method(mouseUp:))]
+        fn mouse_up(&self, event: &NSEvent) {
+            self.push_button(event, false);
+        }
+
+        #[unsafe(method(mouseDragged:))]
+        fn mouse_dragged(&self, event: &NSEvent) {
+            let pos = self.event_pos(event);
+            self.ivars()
+                .events
+                .borrow_mut()
+                .push(egui::Event::PointerMoved(pos));
+            self.render_now();
+        }
+
+        #[unsafe(method(acceptsFirstMouse:))]
+        fn accepts_first_mouse(&self, _event: Option<&NSEvent>) -> bool {
+            true
+        }
+    }
+);
+
+impl OverviewOverlayView {
+    fn new(
+        mtm: MainThreadMarker,
+        wgpu_factory: Rc<WgpuFactory>,
+        config: Config,
+        scale: f64,
+        hub_sender: CalloopSender<HubEvent>,
+    ) -> Retained<Self> {
+        let renderer = Renderer::new(
+            &wgpu_factory,
+            scale,
+            0.0,
+            0.0,
+            config.theme.flavor,
+            &config.font,
+            None,
+        );
+        let layer = renderer.layer();
+        let ivars = OverviewOverlayViewIvars {
+            layer: layer.clone(),
+            events: RefCell::new(Vec::new()),
+            renderer: RefCell::new(renderer),
+            screen: Cell::new(Dimension::default()),
+            workspaces: RefCell::new(Vec::new()),
+            scale: Cell::new(scale),
+            hub_sender,
+            config: RefCell::new(config),
+        };
+        let this = Self::alloc(mtm).set_ivars(ivars);
+        let frame = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(0.0, 0.0));
+        let view: Retained<Self> = unsafe { msg_send![super(this), initWithFrame: frame] };
+        view.setLayer(Some(&layer));
+        view.setWantsLayer(true);
+        view
+    }
+
+    fn update(&self, show: &OverviewShow) {
+        let ivars = self.ivars();
+        let screen = Dimension::<Logical>::new(
+            Length::ZERO,
+            Length::ZERO,
+            show.monitor_dim.width,
+            show.monitor_dim.height,
+        );
+        ivars.screen.set(screen);
+        ivars.scale.set(show.scale);
+        *ivars.workspaces.borrow_mut() = show.workspaces.clone();
+        ivars.renderer.borrow_mut().resize(
+            show.scale,
+            screen.width.logical() as f64,
+            screen.height.logical() as f64,
+        );
+        self.render_now();
+    }
+
+    fn set_config(&self, config: &Config) {
+        let prev = self.ivars().config.borrow().clone();
+        if prev.theme.flavor != config.theme.flavor {
+            self.ivars()
+                .renderer
+                .borrow_mut()
+                .apply_theme(config.theme.flavor);
+        }
+        if prev.font != config.font {
+            if prev.font.family != config.font.family {
+                self.ivars()
+                    .renderer
+                    .borrow_mut()
+                    .reinstall_fonts(config.font.family.as_deref());
+            }
+            self.ivars().renderer.borrow_mut().apply_font(&config.font);
+        }
+        *self.ivars().config.borrow_mut() = config.clone();
+        self.render_now();
+    }
+
+    fn render_now(&self) {
+        let ivars = self.ivars();
+        let screen = ivars.screen.get();
+        let workspaces = ivars.workspaces.borrow().clone();
+        let theme = ivars.config.borrow().theme();
+        let events = std::mem::take(&mut *ivars.events.borrow_mut());
+        let scale = ivars.scale.get();
+        let event = ivars
+            .renderer
+            .borrow_mut()
+            .render(scale as f32, events, |ui| {
+                overlay::paint_overview(ui.ctx(), screen, &workspaces, &theme)
+            });
+        if let Some(event) = event {
+            ivars.hub_sender.send(HubEvent::Overview(event)).ok();
+        }
+    }
+
+    fn push_button(&self, event: &NSEvent, pressed: bool) {
+        let pos = self.event_pos(event);
+        self.ivars()
+            .events
+            .borrow_mut()
+            .push(egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers: egui::Modifiers::NONE,
+            });
+        self.render_now();
+    }
+
     fn event_pos(&self, event: &NSEvent) -> egui::Pos2 {
         let loc = event.locationInWindow();
         let view_loc = self.convertPoint_fromView(loc, None);
@@ -7,6 +7,7 @@ use objc2::{DefinedClass, MainThreadMarker, MainThreadOnly, define_class, msg_se
 use objc2_app_kit::{
     NSControlStateValueOff, NSControlStateValueOn, NSImage, NSMenu, NSMenuItem,
     NSSquareStatusItemLength, NSStatusBar, NSStatusBarButton, NSStatusItem,
+    NSVariableStatusItemLength,
 };
 use objc2_foundation::{NSData, NSInteger, NSObject, NSObjectProtocol, NSSize, NSString};
 
@@ -22,6 +23,7 @@ pub(super) struct StatusMenu {
     menu: Retained<NSMenu>,
     target: Retained<StatusMenuTarget>,
     last_workspaces: RefCell<Vec<(String, bool)>>,
+    last_title: RefCell<Option<String>>,
 }
 
 impl StatusMenu {
@@ -59,18 +61,26 @@ impl StatusMenu {
             menu,
             target,
             last_workspaces: RefCell::new(Vec::new()),
+            last_title: RefCell::new(None),
         }
     }
 
-    pub(super) fn update(&self, mtm: MainThreadMarker, workspaces: &[WorkspaceInfo]) {
+    pub(super) fn update(
+        &self,
+        mtm: MainThreadMarker,
+        workspaces: &[WorkspaceInfo],
+        mode: Option<&str>,
+        notice: Option<&str>,
+    ) {
         let focused = workspaces
             .iter()
             .find(|w| w.is_focused)
             .map(|w| w.name.as_str())
             .unwrap_or("");
-        let tip = truncate_tooltip(focused);
+        let tip = status_tooltip(focused, mode);
         let ns_tip = NSString::from_str(&tip);
         self.button.setToolTip(Some(&ns_tip));
+        self.update_title(notice.or(mode));
 
         let changed = {
             let last = self.last_workspaces.borrow();
@@ -138,6 +148,27 @@ impl StatusMenu {
     }
 }
 
+impl StatusMenu {
+    // The item widens to fit a notice or the mode name next to the icon, and
+    // shrinks back to the square icon once neither is showing.
+    fn update_title(&self, title: Option<&str>) {
+        if self.last_title.borrow().as_deref() == title {
+            return;
+        }
+        match title {
+            Some(name) => {
+                self.status_item.setLength(NSVariableStatusItemLength);
+                self.button.setTitle(&NSString::from_str(name));
+            }
+            None => {
+                self.button.setTitle(&NSString::from_str(""));
+                self.status_item.setLength(NSSquareStatusItemLength);
+            }
+        }
+        *self.last_title.borrow_mut() = title.map(str::to_string);
+    }
+}
+
 impl Drop for StatusMenu {
     fn drop(&mut self) {
         NSStatusBar::systemStatusBar().removeStatusItem(&self.status_item);
@@ -196,6 +227,14 @@ impl StatusMenuTarget {
     }
 }
 
+fn status_tooltip(focused: &str, mode: Option<&str>) -> String {
+    let tip = truncate_tooltip(focused);
+    match mode {
+        Some(mode) => format!("{tip} [{mode}]"),
+        None => tip,
+    }
+}
+
 fn truncate_tooltip(name: &str) -> String {
     if name.chars().count() <= STATUS_TOOLTIP_MAX_CHARS {
         return name.to_string();
@@ -226,6 +265,7 @@ mod tests {
             is_focused: focused,
             is_visible: visible,
             window_count: 0,
+            urgent: false,
         }
     }
 
@@ -261,6 +301,12 @@ mod tests {
         assert_eq!(truncate_tooltip(""), "");
     }
 
+    #[test]
+    fn tooltip_appends_mode() {
+        assert_eq!(status_tooltip("2", None), "2");
+        assert_eq!(status_tooltip("2", Some("resize")), "2 [resize]");
+    }
+
     #[test]
     fn layout_same_seq_unchanged() {
         let old = vec![("1".into(), true), ("2".into(), false)];
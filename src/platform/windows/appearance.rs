@@ -0,0 +1,36 @@
+use std::ffi::c_void;
+
+use windows::Win32::Foundation::ERROR_SUCCESS;
+use windows::Win32::System::Registry::{HKEY_CURRENT_USER, RRF_RT_REG_DWORD, RegGetValueW};
+use windows::core::w;
+
+use crate::theme::Appearance;
+
+/// Reads the "app mode" half of Settings > Personalization > Colors. The
+/// taskbar half (`SystemUsesLightTheme`) is ignored because overlays sit next
+/// to app windows, not the taskbar.
+pub(super) fn system_appearance() -> Appearance {
+    let mut value: u32 = 0;
+    let mut size = size_of::<u32>() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!(r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize"),
+            w!("AppsUseLightTheme"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut value as *mut u32 as *mut c_void),
+            Some(&mut size),
+        )
+    };
+    if status != ERROR_SUCCESS {
+        // Missing on Windows versions without a dark mode, which are light.
+        tracing::debug!(?status, "AppsUseLightTheme unreadable, assuming light");
+        return Appearance::Light;
+    }
+    if value == 0 {
+        Appearance::Dark
+    } else {
+        Appearance::Light
+    }
+}
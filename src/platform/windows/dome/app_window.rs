@@ -1,21 +1,33 @@
 use windows::Win32::Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM};
 use windows::Win32::System::Threading::GetCurrentThreadId;
 use windows::Win32::UI::WindowsAndMessaging::{
-    ChangeWindowMessageFilterEx, DefWindowProcW, GWLP_USERDATA, GetWindowLongPtrW, MSGFLT_ALLOW,
-    PostThreadMessageW, RegisterWindowMessageW, SPI_SETWORKAREA, SetWindowLongPtrW, WM_DESTROY,
-    WM_DISPLAYCHANGE, WM_SETTINGCHANGE,
+    ChangeWindowMessageFilterEx, DefWindowProcW, DeregisterShellHookWindow, GWLP_USERDATA,
+    GetWindowLongPtrW, HSHELL_HIGHBIT, HSHELL_REDRAW, MSGFLT_ALLOW, PostThreadMessageW,
+    RegisterShellHookWindow, RegisterWindowMessageW, SPI_SETWORKAREA, SetWindowLongPtrW,
+    WM_DESTROY, WM_DISPLAYCHANGE, WM_SETTINGCHANGE,
 };
 use windows::core::{PCWSTR, w};
 
 use crate::core::WorkspaceInfo;
+use crate::platform::windows::dome::HubEvent;
 use crate::platform::windows::dome::overlay::OwnedHwnd;
 use crate::platform::windows::dome::tray::{TRAY_CALLBACK_MSG, TrayIndicator};
-use crate::platform::windows::{HubSender, WM_APP_DISPLAY_CHANGE, WM_APP_WORKAREA_CHANGE};
+use crate::platform::windows::external::HwndId;
+use crate::platform::windows::{
+    HubSender, WM_APP_APPEARANCE_CHANGE, WM_APP_DISPLAY_CHANGE, WM_APP_WORKAREA_CHANGE,
+};
 
 pub(in crate::platform::windows) const APP_WINDOW_CLASS: PCWSTR = w!("DomeAppWindow");
 
+/// Shell hook code for a window flashing its taskbar button. Not exported by
+/// the `windows` crate. Defined in WinUser.h.
+const HSHELL_FLASH: u32 = HSHELL_REDRAW | HSHELL_HIGHBIT;
+
 pub(in crate::platform::windows) trait AppWindowApi {
-    fn update_tray(&self, workspaces: &[WorkspaceInfo]);
+    /// `mode` is the keymap mode to show, `None` in the default mode.
+    fn update_tray(&self, workspaces: &[WorkspaceInfo], mode: Option<&str>);
+    /// Transient notice, e.g. after a config reload with warnings.
+    fn notify(&self, message: &str);
 }
 
 pub(in crate::platform::windows) struct AppWindow {
@@ -24,6 +36,9 @@ pub(in crate::platform::windows) struct AppWindow {
     tray: Option<Box<TrayIndicator>>,
     hwnd: OwnedHwnd,
     taskbar_created_msg: u32,
+    /// Registered `SHELLHOOK` message, 0 when the shell hook is not installed.
+    shell_hook_msg: u32,
+    hub_sender: HubSender,
 }
 
 impl AppWindow {
@@ -37,6 +52,8 @@ impl AppWindow {
             tray: None,
             hwnd,
             taskbar_created_msg: 0,
+            shell_hook_msg: 0,
+            hub_sender: hub_sender.clone(),
         });
 
         // Install GWLP_USERDATA before TrayIndicator::new so shell callbacks after NIM_ADD see an initialized AppWindow.
@@ -62,16 +79,39 @@ impl AppWindow {
             }
         }
 
+        // Reports windows that flash their taskbar button, so they can be
+        // marked urgent. Without it nothing is ever urgent, so failure is not fatal.
+        let msg = unsafe { RegisterWindowMessageW(w!("SHELLHOOK")) };
+        if msg == 0 || !unsafe { RegisterShellHookWindow(app.hwnd.hwnd()) }.as_bool() {
+            tracing::warn!("Failed to register shell hook, urgent windows will not be reported");
+        } else {
+            app.shell_hook_msg = msg;
+        }
+
         let tray = TrayIndicator::new(hub_sender, app.hwnd.hwnd())?;
         app.tray = Some(tray);
         Ok(app)
     }
 }
 
+impl Drop for AppWindow {
+    fn drop(&mut self) {
+        if self.shell_hook_msg != 0 {
+            unsafe { DeregisterShellHookWindow(self.hwnd.hwnd()).ok().ok() };
+        }
+    }
+}
+
 impl AppWindowApi for AppWindow {
-    fn update_tray(&self, workspaces: &[WorkspaceInfo]) {
+    fn update_tray(&self, workspaces: &[WorkspaceInfo], mode: Option<&str>) {
         if let Some(tray) = self.tray.as_ref() {
-            tray.update(workspaces);
+            tray.update(workspaces, mode);
+        }
+    }
+
+    fn notify(&self, message: &str) {
+        if let Some(tray) = self.tray.as_ref() {
+            tray.notify(message);
         }
     }
 }
@@ -98,6 +138,19 @@ pub(in crate::platform::windows) unsafe extern "system" fn app_wnd_proc(
         };
         return LRESULT(0);
     }
+    // Light/dark app mode toggles broadcast this with lParam naming the area.
+    if msg == WM_SETTINGCHANGE && is_immersive_color_set(lparam) {
+        unsafe {
+            PostThreadMessageW(
+                GetCurrentThreadId(),
+                WM_APP_APPEARANCE_CHANGE,
+                WPARAM(0),
+                LPARAM(0),
+            )
+            .ok()
+        };
+        return LRESULT(0);
+    }
     if msg == WM_DISPLAYCHANGE {
         unsafe {
             PostThreadMessageW(
@@ -122,6 +175,14 @@ pub(in crate::platform::windows) unsafe extern "system" fn app_wnd_proc(
             tray.show_menu(hwnd);
             return LRESULT(0);
         }
+        if app.shell_hook_msg != 0 && msg == app.shell_hook_msg {
+            if wparam.0 as u32 == HSHELL_FLASH {
+                let flashing = HWND(lparam.0 as *mut core::ffi::c_void);
+                app.hub_sender
+                    .send(HubEvent::WindowUrgent(HwndId::from(flashing)));
+            }
+            return LRESULT(0);
+        }
         if app.taskbar_created_msg != 0 && msg == app.taskbar_created_msg {
             if let Err(e) = tray.add_icon() {
                 tracing::warn!(?e, "failed to re-add tray icon after taskbar restart");
@@ -137,6 +198,14 @@ pub(in crate::platform::windows) unsafe extern "system" fn app_wnd_proc(
     unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) }
 }
 
+fn is_immersive_color_set(lparam: LPARAM) -> bool {
+    if lparam.0 == 0 {
+        return false;
+    }
+    let area = PCWSTR(lparam.0 as *const u16);
+    unsafe { area.to_string() }.is_ok_and(|s| s == "ImmersiveColorSet")
+}
+
 unsafe fn app_from_hwnd<'a>(hwnd: HWND) -> Option<&'a AppWindow> {
     let ptr = unsafe { GetWindowLongPtrW(hwnd, GWLP_USERDATA) };
     if ptr == 0 {
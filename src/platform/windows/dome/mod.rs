@@ -16,18 +16,24 @@ use std::time::Instant;
 
 use crate::action::Query;
 use crate::action::{
-    Actions, FocusTarget, MasterTarget, MinimizedWindow, MoveTarget, TabDirection, ToggleTarget,
+    Actions, FloatTarget, FocusTarget, MasterTarget, MinimizedWindow, MoveTarget, ScrollTarget,
+    TabDirection, ToggleTarget,
 };
 use crate::config::{Config, LayoutConfig, LayoutWorkspaceConfig};
 use crate::core::GlobalLayoutConfig;
 use crate::core::{
-    ContainerId, ContainerPlacement, Direction, FloatWindowPlacement, Hub, LimitObservation,
-    MonitorId, MonitorLayout, Physical, PixelRect, Pixels, TilingAction, TilingWindowPlacement,
-    WindowId, WindowRestrictions, WorkspaceInfo,
+    ContainerId, ContainerPlacement, Direction, FloatWindowPlacement, FocusRestore, HINT_MODE,
+    HintPlacement, HintSession, Hub, LimitObservation, MonitorId, MonitorLayout, OVERVIEW_MODE,
+    OverviewWorkspace, Physical, PixelRect, Pixels, ScrollExtent, TilingAction,
+    TilingWindowPlacement, WindowId, WindowMetadata, WindowRestrictions, WorkspaceInfo,
 };
+use crate::diagnostics::{ConfigStatus, LoadReport};
+use crate::hook::HookRun;
+use crate::overlay::{OverviewEvent, TabBarEvent, TabIcon};
+use crate::theme::{Appearance, Flavor, ThemeSelection};
 
 use self::app_window::AppWindowApi;
-use self::overlay::{FloatOverlayApi, TabBarOverlayApi, TilingOverlayApi};
+use self::overlay::{FloatOverlayApi, OverviewOverlayApi, TabBarOverlayApi, TilingOverlayApi};
 use self::placement_tracker::PlacementTracker;
 use self::recovery::Recovery;
 use self::registry::{ManagedWindow, WindowRegistry};
@@ -51,6 +57,8 @@ pub(super) enum HubEvent {
         observed_at: Instant,
     },
     WindowFocused(HwndId),
+    /// The window flashed its taskbar button to ask for attention.
+    WindowUrgent(HwndId),
     WindowTitleChanged(HwndId),
     MoveSizeStart(HwndId),
     MoveSizeEnd {
@@ -62,14 +70,21 @@ pub(super) enum HubEvent {
         observed_at: Instant,
     },
     Action(Actions),
+    /// The keyboard hook switched mode or moved through a key sequence.
+    /// Carries what status bars should show, so `mode_entered` hooks fire.
+    ModeEntered(String),
     Query {
         query: Query,
         sender: std::sync::mpsc::SyncSender<String>,
     },
     ConfigChanged(Box<Config>),
     LayoutConfigChanged(Box<LayoutConfig>),
+    /// Outcome of loading the config or layout file, at launch and after
+    /// every reload attempt.
+    ConfigReport(Box<LoadReport>),
     ExportLayout(String),
-    TabClicked(ContainerId, usize),
+    TabBar(ContainerId, TabBarEvent),
+    Overview(OverviewEvent),
     Shutdown,
 }
 
@@ -80,6 +95,8 @@ struct MonitorPositionData {
     tiling_windows: Vec<TilingWindowPlacement>,
     float_windows: Vec<FloatWindowPlacement>,
     containers: Vec<(ContainerPlacement, Vec<String>)>,
+    hints: Vec<HintPlacement>,
+    scroll_extents: Vec<ScrollExtent>,
 }
 
 pub(super) trait CreateOverlay {
@@ -102,6 +119,12 @@ pub(super) trait CreateOverlay {
         rect: PixelRect,
         scale: f32,
     ) -> anyhow::Result<Box<dyn TabBarOverlayApi>>;
+    fn create_overview(
+        &self,
+        config: Config,
+        monitor: PixelRect,
+        scale: f32,
+    ) -> anyhow::Result<Box<dyn OverviewOverlayApi>>;
 }
 
 /// Platform-specific state machine that bridges Win32 window events with the core tree
@@ -119,7 +142,12 @@ pub(super) struct Dome {
     display: Box<dyn QueryDisplay>,
     tiling_overlays: HashMap<MonitorId, Box<dyn TilingOverlayApi>>,
     tab_bars: HashMap<ContainerId, Box<dyn TabBarOverlayApi>>,
+    /// Read once per window while it sits in a tab bar. `None` records a window
+    /// class without an icon so it is not queried again.
+    tab_icons: HashMap<WindowId, Option<TabIcon>>,
     float_overlays: HashMap<WindowId, Box<dyn FloatOverlayApi>>,
+    /// Present only while the overview shows, on the focused monitor.
+    overview: Option<Box<dyn OverviewOverlayApi>>,
     last_focused: Option<WindowId>,
     last_focused_monitor: Option<MonitorId>,
     pending_created: Vec<WindowId>,
@@ -127,6 +155,14 @@ pub(super) struct Dome {
     recovery: Recovery,
     app_window: Box<dyn AppWindowApi>,
     status_bars: StatusBars,
+    pending_hooks: Vec<HookRun>,
+    /// Last mode reported by `mode_entered`, shown in the tray tooltip.
+    mode: String,
+    config_status: ConfigStatus,
+    theme_selection: ThemeSelection,
+    /// `config` with the flavor `theme_selection` picked, which is what the
+    /// overlays draw with. `config` itself stays as loaded.
+    overlay_config: Config,
 }
 
 impl Drop for Dome {
@@ -143,7 +179,10 @@ impl Dome {
         overlay_factory: Box<dyn CreateOverlay>,
         display: Box<dyn QueryDisplay>,
         app_window: Box<dyn AppWindowApi>,
+        appearance: Appearance,
     ) -> anyhow::Result<Self> {
+        let theme_selection = ThemeSelection::new(appearance);
+        let overlay_config = theme_selection.themed(&config);
         let monitors = display.get_all_monitors()?;
         anyhow::ensure!(!monitors.is_empty(), "No monitors detected");
         let primary = monitors
@@ -165,9 +204,11 @@ impl Dome {
             primary.work_area,
             primary.scale,
         );
-        if let Ok(overlay) =
-            overlay_factory.create_tiling_overlay(config.clone(), primary.work_area, primary.scale)
-        {
+        if let Ok(overlay) = overlay_factory.create_tiling_overlay(
+            overlay_config.clone(),
+            primary.work_area,
+            primary.scale,
+        ) {
             tiling_overlays.insert(primary_monitor_id, overlay);
         }
         tracing::info!(
@@ -182,7 +223,7 @@ impl Dome {
                 let id = hub.add_monitor(monitor.name.clone(), monitor.work_area, monitor.scale);
                 monitors_reg.insert(monitor.handle, id, monitor.work_area, monitor.scale);
                 if let Ok(overlay) = overlay_factory.create_tiling_overlay(
-                    config.clone(),
+                    overlay_config.clone(),
                     monitor.work_area,
                     monitor.scale,
                 ) {
@@ -197,6 +238,9 @@ impl Dome {
             }
         }
 
+        // Monitors present at launch were not connected, they were already there.
+        hub.take_lifecycle_events();
+
         Ok(Self {
             hub,
             registry: WindowRegistry::new(),
@@ -207,7 +251,9 @@ impl Dome {
             display,
             tiling_overlays,
             tab_bars: HashMap::new(),
+            tab_icons: HashMap::new(),
             float_overlays: HashMap::new(),
+            overview: None,
             last_focused: None,
             last_focused_monitor: None,
             pending_created: Vec::new(),
@@ -215,28 +261,63 @@ impl Dome {
             recovery: Recovery::new(taskbar),
             app_window,
             status_bars: StatusBars::default(),
+            pending_hooks: Vec::new(),
+            mode: "default".to_string(),
+            config_status: ConfigStatus::default(),
+            theme_selection,
+            overlay_config,
         })
     }
 
     fn refresh_tray(&self) {
-        self.app_window.update_tray(&self.query_workspaces());
+        let mode = (self.mode != "default").then_some(self.mode.as_str());
+        self.app_window.update_tray(&self.query_workspaces(), mode);
     }
 
     pub(super) fn config_changed(&mut self, new_config: Config) {
         self.hub
             .sync_configuration(GlobalLayoutConfig::from(&new_config));
+        self.theme_selection.unpin();
+        self.overlay_config = self.theme_selection.themed(&new_config);
         self.config = new_config;
+        self.push_config_to_overlays();
+        tracing::info!("Config reloaded");
+        self.apply_layout();
+    }
+
+    pub(super) fn appearance_changed(&mut self, appearance: Appearance) {
+        self.theme_selection.set_appearance(appearance);
+        self.reselect_theme();
+    }
+
+    pub(super) fn set_theme(&mut self, flavor: Flavor) {
+        self.theme_selection.pin(flavor);
+        self.reselect_theme();
+    }
+
+    fn reselect_theme(&mut self) {
+        let flavor = self.theme_selection.select(&self.config.theme);
+        if flavor == self.overlay_config.theme.flavor {
+            return;
+        }
+        tracing::info!(%flavor, "Theme changed");
+        self.overlay_config.theme.flavor = flavor;
+        self.push_config_to_overlays();
+    }
+
+    fn push_config_to_overlays(&mut self) {
         for overlay in self.tiling_overlays.values_mut() {
-            overlay.set_config(&self.config);
+            overlay.set_config(&self.overlay_config);
         }
         for overlay in self.float_overlays.values_mut() {
-            overlay.set_config(&self.config);
+            overlay.set_config(&self.overlay_config);
         }
         for overlay in self.tab_bars.values_mut() {
-            overlay.set_config(&self.config);
+            overlay.set_config(&self.overlay_config);
+        }
+        if let Some(overlay) = &mut self.overview {
+            overlay.set_config(&self.overlay_config);
         }
-        tracing::info!("Config reloaded");
-        self.apply_layout();
     }
 
     pub(super) fn layout_changed(&mut self, new_layout: LayoutConfig) {
@@ -262,6 +343,18 @@ impl Dome {
         }
     }
 
+    #[tracing::instrument(
+        skip(self, id_key),
+        fields(hwnd = %id_key),
+    )]
+    pub(super) fn window_urgent(&mut self, id_key: HwndId) {
+        let Some(id) = self.registry.get_id(id_key) else {
+            return;
+        };
+        self.hub.set_urgent(id, true);
+        self.apply_layout();
+    }
+
     #[tracing::instrument(
         skip(self, id_key),
         fields(hwnd = %id_key),
@@ -301,8 +394,24 @@ impl Dome {
         self.update_monitors(monitors)
     }
 
-    pub(super) fn tab_clicked(&mut self, container_id: ContainerId, tab_idx: usize) {
-        self.hub.focus_tab_index(container_id, tab_idx);
+    pub(super) fn tab_bar_event(&mut self, container_id: ContainerId, event: TabBarEvent) {
+        match event {
+            TabBarEvent::Select(index) => self.hub.focus_tab_index(container_id, index),
+            TabBarEvent::Move { from, to } => self.hub.move_tab(container_id, from, to),
+            TabBarEvent::DragOut(index) => self.hub.split_out_tab(container_id, index),
+            // The window's destroy event reshapes the container, so there is no
+            // layout to apply yet.
+            TabBarEvent::Close(index) => {
+                if let Some(entry) = self
+                    .hub
+                    .tab_window(container_id, index)
+                    .and_then(|id| self.registry.get(id))
+                {
+                    entry.ext.close();
+                }
+                return;
+            }
+        }
         self.apply_layout();
     }
 
@@ -362,6 +471,7 @@ impl Dome {
         } else {
             WindowRestrictions::None
         };
+        self.hub.set_cursor_position(self.display.cursor_position());
         let Some(id) = self
             .hub
             .insert_window(Box::new(metadata.clone()), rect, restrictions)
@@ -434,6 +544,62 @@ impl Dome {
             .expect("WorkspaceInfo is infallibly serializable")
     }
 
+    pub(super) fn focused_window_metadata(&self) -> Option<(WindowId, &dyn WindowMetadata)> {
+        self.hub.focused_window_metadata()
+    }
+
+    pub(super) fn query_tree_json(&self) -> String {
+        serde_json::to_string(&self.hub.query_tree()).expect("TreeInfo is infallibly serializable")
+    }
+
+    pub(super) fn query_render_json(
+        &self,
+        format: crate::action::RenderFormat,
+        workspace: Option<&str>,
+    ) -> String {
+        match self.hub.render(format, workspace) {
+            Some(picture) => {
+                serde_json::to_string(&picture).expect("String is infallibly serializable")
+            }
+            None => serde_json::json!({
+                "error": format!("no workspace named {}", workspace.unwrap_or_default())
+            })
+            .to_string(),
+        }
+    }
+
+    pub(super) fn mark_focused(&mut self, name: &str) {
+        self.hub.mark_focused(name);
+    }
+
+    /// Labels the visible windows for `focus hint`. The caller hands the
+    /// session to the keymap, which picks the window.
+    pub(super) fn show_hints(&mut self) -> Option<HintSession> {
+        self.hub.show_hints()
+    }
+
+    pub(super) fn focus_window(&mut self, id: WindowId) {
+        self.hub.focus_window(id);
+    }
+
+    pub(super) fn focus_for_hook(&mut self, id: WindowId) -> Option<FocusRestore> {
+        self.hub.focus_for_hook(id)
+    }
+
+    pub(super) fn restore_focus(&mut self, restore: FocusRestore) {
+        self.hub.restore_focus(restore);
+    }
+
+    /// Labels every workspace for the overview. The caller hands the session
+    /// to the keymap, which picks the workspace.
+    pub(super) fn show_overview(&mut self) -> Option<HintSession<String>> {
+        self.hub.show_overview()
+    }
+
+    pub(super) fn move_window_to_workspace(&mut self, id: WindowId, name: &str) {
+        self.hub.move_window_to_workspace(id, name);
+    }
+
     pub(super) fn query_minimized_windows_json(&self) -> String {
         let entries: Vec<MinimizedWindow> = self
             .hub
@@ -476,6 +642,10 @@ impl Dome {
             }
             FocusTarget::Workspace { name } => self.hub.focus_workspace(name),
             FocusTarget::Monitor { target } => self.hub.focus_monitor(target),
+            FocusTarget::Mark { name } => self.hub.focus_mark(name),
+            FocusTarget::Urgent => self.hub.focus_urgent(),
+            // Needs the keymap, so the runner starts it through `show_hints`.
+            FocusTarget::Hint => {}
         }
     }
 
@@ -499,6 +669,7 @@ impl Dome {
             }),
             MoveTarget::Workspace { name } => self.hub.move_focused_to_workspace(name),
             MoveTarget::Monitor { target } => self.hub.move_focused_to_monitor(target),
+            MoveTarget::Mark { name } => self.hub.move_focused_to_mark(name),
         }
     }
 
@@ -509,8 +680,12 @@ impl Dome {
             ToggleTarget::Layout => self
                 .hub
                 .handle_tiling_action(TilingAction::ToggleContainerLayout),
-            ToggleTarget::Float => self.hub.toggle_float(),
+            ToggleTarget::Float => {
+                self.hub.set_cursor_position(self.display.cursor_position());
+                self.hub.toggle_float();
+            }
             ToggleTarget::Fullscreen => self.hub.toggle_fullscreen(),
+            ToggleTarget::Sticky => self.hub.toggle_sticky(),
         }
     }
 
@@ -524,6 +699,22 @@ impl Dome {
         self.hub.handle_tiling_action(action);
     }
 
+    pub(super) fn apply_float(&mut self, target: &FloatTarget) {
+        match *target {
+            FloatTarget::Move { direction, amount } => self.hub.move_float(direction, amount),
+            FloatTarget::Resize { change, amount } => self.hub.resize_float(change, amount),
+            FloatTarget::Center => self.hub.center_float(),
+            FloatTarget::Snap { preset } => self.hub.snap_float(preset),
+        }
+    }
+
+    pub(super) fn apply_scroll(&mut self, target: &ScrollTarget) {
+        match *target {
+            ScrollTarget::Pan { direction, amount } => self.hub.scroll_viewport(direction, amount),
+            ScrollTarget::Center => self.hub.center_viewport(),
+        }
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     pub(super) fn unminimize_window(&mut self, id: WindowId) {
         self.hub.unminimize_window(id);
@@ -550,11 +741,85 @@ impl Dome {
         entry.ext.close();
     }
 
+    /// When `apply_animation_frame` should run to show the next animation
+    /// frame.
+    pub(super) fn next_animation_frame(&self) -> Option<Instant> {
+        self.hub.next_animation_frame()
+    }
+
+    /// Frames-only pass between layouts: moves tiling windows to their next
+    /// intermediate rect and repaints borders. Nothing else can have changed
+    /// since the last `apply_layout`, so hiding, taskbar tabs, tab bars, the
+    /// tray and hooks are left alone.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(super) fn apply_animation_frame(&mut self) {
+        let result = self.hub.get_animated_placements(Instant::now());
+        let focused = result.focused_window;
+        for mp in &result.monitors {
+            let MonitorLayout::Normal {
+                tiling_windows,
+                float_windows,
+                containers,
+            } = &mp.layout
+            else {
+                continue;
+            };
+            // Floats don't move, but the focus flash may have ended.
+            for wp in float_windows {
+                if !wp.content_box.is_empty() && self.float_overlays.contains_key(&wp.id) {
+                    self.show_float(
+                        wp.id,
+                        wp,
+                        false,
+                        focused == Some(wp.id),
+                        mp.monitor_id,
+                        wp.border,
+                    );
+                }
+            }
+            if !self.tiling_overlays.contains_key(&mp.monitor_id) {
+                continue;
+            }
+            let tiling_windows: Vec<_> = tiling_windows
+                .iter()
+                .filter(|wp| !wp.content_box.is_empty() && self.registry.get(wp.id).is_some())
+                .copied()
+                .collect();
+            for wp in &tiling_windows {
+                let moving = self
+                    .registry
+                    .get(wp.id)
+                    .is_some_and(|entry| self.placement_tracker.is_moving(entry.ext.id()));
+                if !moving {
+                    self.show_tiling(wp.id, wp, mp.monitor_id);
+                }
+            }
+            let containers: Vec<_> = containers
+                .iter()
+                .filter(|cp| cp.is_tabbed || cp.is_highlighted)
+                .map(|cp| (cp.clone(), cp.titles.clone()))
+                .collect();
+            let work_area = self.monitors.monitor(mp.monitor_id).work_area();
+            let scale = self.monitors.monitor(mp.monitor_id).scale();
+            if let Some(overlay) = self.tiling_overlays.get_mut(&mp.monitor_id) {
+                overlay.update(
+                    work_area,
+                    &tiling_windows,
+                    &containers,
+                    &mp.hints,
+                    &mp.scroll_extents,
+                    scale,
+                    mp.border_thickness,
+                );
+            }
+        }
+    }
+
     #[tracing::instrument(level = "trace", skip_all)]
     pub(super) fn apply_layout(&mut self) {
         let created = std::mem::take(&mut self.pending_created);
 
-        let result = self.hub.get_visible_placements();
+        let result = self.hub.get_animated_placements(Instant::now());
         let focused_window = result.focused_window;
         let focused_monitor = result.focused_monitor;
         let focused = focused_window;
@@ -630,6 +895,8 @@ impl Dome {
                         tiling_windows: placed_tiling,
                         float_windows: placed_floats,
                         containers: container_data,
+                        hints: mp.hints.clone(),
+                        scroll_extents: mp.scroll_extents.clone(),
                     });
                 }
             }
@@ -676,6 +943,7 @@ impl Dome {
         }
 
         self.position_windows(&per_monitor, focused);
+        self.update_overview(&result.overview, focused_monitor);
 
         let current_float_ids: HashSet<WindowId> = per_monitor
             .iter()
@@ -709,6 +977,37 @@ impl Dome {
         }
         self.last_focused_monitor = Some(current_monitor);
         self.refresh_tray();
+
+        let events = self.hub.take_lifecycle_events();
+        self.pending_hooks
+            .extend(crate::hook::lifecycle_runs(&self.config.hooks, &events));
+    }
+
+    pub(super) fn mode_entered(&mut self, name: &str) {
+        self.pending_hooks
+            .extend(crate::hook::mode_runs(&self.config.hooks, name));
+        self.mode = name.to_string();
+        self.refresh_tray();
+        let hid_hints = name != HINT_MODE && self.hub.hide_hints();
+        let hid_overview = name != OVERVIEW_MODE && self.hub.hide_overview();
+        if hid_hints || hid_overview {
+            self.apply_layout();
+        }
+    }
+
+    pub(super) fn config_reported(&mut self, report: LoadReport) {
+        if let Some(summary) = report.summary() {
+            self.app_window.notify(&summary);
+        }
+        self.config_status.record(report);
+    }
+
+    pub(super) fn query_config_status(&self) -> String {
+        self.config_status.query_json()
+    }
+
+    pub(super) fn take_hook_runs(&mut self) -> Vec<HookRun> {
+        std::mem::take(&mut self.pending_hooks)
     }
 
     #[tracing::instrument(level = "trace", skip_all)]
@@ -727,7 +1026,7 @@ impl Dome {
                 }
                 if !self.float_overlays.contains_key(&wp.id) {
                     match self.overlay_factory.create_float_overlay(
-                        self.config.clone(),
+                        self.overlay_config.clone(),
                         self.monitors.monitor(data.monitor_id).scale(),
                         wp.visible_border_box,
                     ) {
@@ -746,14 +1045,15 @@ impl Dome {
                     focus_changed,
                     focused == Some(wp.id),
                     data.monitor_id,
-                    data.border_thickness,
+                    wp.border,
                 );
             }
 
             if !self.tiling_overlays.contains_key(&data.monitor_id) {
                 continue;
             }
-            if data.tiling_windows.is_empty() && data.containers.is_empty() {
+            if data.tiling_windows.is_empty() && data.containers.is_empty() && data.hints.is_empty()
+            {
                 self.tiling_overlays
                     .get_mut(&data.monitor_id)
                     .unwrap()
@@ -780,6 +1080,8 @@ impl Dome {
                     data.work_area,
                     &data.tiling_windows,
                     &data.containers,
+                    &data.hints,
+                    &data.scroll_extents,
                     scale,
                     data.border_thickness,
                 );
@@ -789,7 +1091,7 @@ impl Dome {
                     std::collections::hash_map::Entry::Occupied(e) => e.into_mut(),
                     std::collections::hash_map::Entry::Vacant(e) => {
                         match self.overlay_factory.create_tab_bar(
-                            self.config.clone(),
+                            self.overlay_config.clone(),
                             placement.id,
                             rect,
                             scale,
@@ -802,9 +1104,27 @@ impl Dome {
                         }
                     }
                 };
+                let icons = if self.config.partition_tree.tab_icons {
+                    placement
+                        .tab_windows
+                        .iter()
+                        .map(|id| {
+                            let id = (*id)?;
+                            let registry = &self.registry;
+                            self.tab_icons
+                                .entry(id)
+                                .or_insert_with(|| registry.get(id)?.ext.app_icon())
+                                .clone()
+                        })
+                        .collect()
+                } else {
+                    Vec::new()
+                };
                 tab_bar.update(
                     rect,
+                    placement.tab_bar_position,
                     titles.clone(),
+                    icons,
                     placement.active_tab_index,
                     placement.is_highlighted,
                     scale,
@@ -822,6 +1142,38 @@ impl Dome {
             })
             .collect();
         self.tab_bars.retain(|id, _| active.contains(id));
+        let tabbed: HashSet<WindowId> = per_monitor
+            .iter()
+            .flat_map(|d| d.containers.iter().filter(|(p, _)| p.is_tabbed))
+            .flat_map(|(p, _)| p.tab_windows.iter().flatten().copied())
+            .collect();
+        self.tab_icons.retain(|id, _| tabbed.contains(id));
+    }
+
+    /// Covers the focused monitor with the overview while core reports one,
+    /// and destroys it once core stops.
+    fn update_overview(&mut self, workspaces: &[OverviewWorkspace], monitor_id: MonitorId) {
+        if workspaces.is_empty() {
+            self.overview = None;
+            return;
+        }
+        let monitor = self.monitors.monitor(monitor_id);
+        let (area, scale) = (monitor.work_area(), monitor.scale());
+        if self.overview.is_none() {
+            match self
+                .overlay_factory
+                .create_overview(self.overlay_config.clone(), area, scale)
+            {
+                Ok(o) => self.overview = Some(o),
+                Err(err) => {
+                    tracing::warn!(?err, "failed to create overview");
+                    return;
+                }
+            }
+        }
+        if let Some(overview) = &mut self.overview {
+            overview.update(area, workspaces, scale);
+        }
     }
 
     pub(super) fn handle_window_moved(
@@ -861,7 +1213,7 @@ impl Dome {
         for id in change.added {
             let m = self.monitors.monitor(id);
             if let Ok(overlay) = self.overlay_factory.create_tiling_overlay(
-                self.config.clone(),
+                self.overlay_config.clone(),
                 m.work_area(),
                 m.scale(),
             ) {
@@ -1,15 +1,17 @@
 use std::collections::{HashMap, HashSet};
 
-use windows::Win32::Foundation::{LPARAM, RECT};
+use windows::Win32::Foundation::{LPARAM, POINT, RECT};
 use windows::Win32::Graphics::Gdi::{
     EnumDisplayMonitors, GetMonitorInfoW, HDC, HMONITOR, MONITORINFOEXW,
 };
 use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
 use windows::Win32::UI::Shell::{QUNS_RUNNING_D3D_FULL_SCREEN, SHQueryUserNotificationState};
-use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, MONITORINFOF_PRIMARY};
+use windows::Win32::UI::WindowsAndMessaging::{
+    GetCursorPos, GetForegroundWindow, MONITORINFOF_PRIMARY,
+};
 use windows::core::BOOL;
 
-use crate::core::{Dimension, Hub, MonitorId, Physical, PixelRect, WindowId};
+use crate::core::{Dimension, Hub, MonitorId, Physical, PixelRect, Pixels, WindowId};
 use crate::platform::windows::external::HwndId;
 use crate::platform::windows::handle;
 
@@ -30,6 +32,8 @@ pub(in crate::platform::windows) trait QueryDisplay {
     fn get_all_monitors(&self) -> anyhow::Result<Vec<MonitorInfo>>;
     /// Returns the hwnd of the foreground window if D3D exclusive fullscreen is active.
     fn get_exclusive_fullscreen_hwnd(&self) -> Option<HwndId>;
+    /// Pointer position in physical screen coordinates, for cursor float placement.
+    fn cursor_position(&self) -> Option<(Pixels<Physical>, Pixels<Physical>)>;
 }
 
 pub(in crate::platform::windows) struct Win32Display;
@@ -46,6 +50,15 @@ impl QueryDisplay for Win32Display {
             None
         }
     }
+
+    fn cursor_position(&self) -> Option<(Pixels<Physical>, Pixels<Physical>)> {
+        let mut pt = POINT::default();
+        if let Err(e) = unsafe { GetCursorPos(&mut pt) } {
+            tracing::debug!(?e, "GetCursorPos failed");
+            return None;
+        }
+        Some((Pixels::new(pt.x), Pixels::new(pt.y)))
+    }
 }
 
 /// Per-monitor state. `displayed` is rebuilt each `apply_layout` pass.
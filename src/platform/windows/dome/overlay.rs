@@ -5,7 +5,7 @@
 
 use std::sync::Arc;
 
-use crate::config::Config;
+use crate::config::{Config, TabBarPosition};
 use crate::font::FontConfig;
 use crate::platform::windows::{HubEvent, HubSender};
 use crate::theme::{Flavor, apply_catppuccin};
@@ -16,19 +16,21 @@ use windows::Win32::Graphics::DirectComposition::{
 };
 use windows::Win32::Graphics::Gdi::{BeginPaint, EndPaint, PAINTSTRUCT};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::UI::Input::KeyboardAndMouse::{GetCapture, ReleaseCapture, SetCapture};
 use windows::Win32::UI::WindowsAndMessaging::{
     CreateWindowExW, DefWindowProcW, DestroyWindow, GW_HWNDPREV, GWLP_USERDATA, GetWindow,
-    GetWindowLongPtrW, HWND_BOTTOM, HWND_TOP, MA_NOACTIVATE, SW_HIDE, SW_SHOWNA, SWP_NOACTIVATE,
-    SWP_NOMOVE, SWP_NOREDRAW, SWP_NOSIZE, SWP_NOZORDER, SWP_SHOWWINDOW, SetWindowLongPtrW,
-    SetWindowPos, ShowWindow, WINDOW_EX_STYLE, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MOUSEACTIVATE,
-    WM_MOUSEMOVE, WM_PAINT, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_NOREDIRECTIONBITMAP,
-    WS_EX_TOOLWINDOW, WS_EX_TRANSPARENT, WS_OVERLAPPED, WS_POPUP,
+    GetWindowLongPtrW, HWND_BOTTOM, HWND_NOTOPMOST, HWND_TOP, HWND_TOPMOST, MA_NOACTIVATE, SW_HIDE,
+    SW_SHOWNA, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOREDRAW, SWP_NOSIZE, SWP_NOZORDER, SWP_SHOWWINDOW,
+    SetWindowLongPtrW, SetWindowPos, ShowWindow, WINDOW_EX_STYLE, WM_LBUTTONDOWN, WM_LBUTTONUP,
+    WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEACTIVATE, WM_MOUSEMOVE, WM_PAINT, WS_EX_LAYERED,
+    WS_EX_NOACTIVATE, WS_EX_NOREDIRECTIONBITMAP, WS_EX_TOOLWINDOW, WS_EX_TRANSPARENT,
+    WS_OVERLAPPED, WS_POPUP,
 };
 use windows::core::{Interface, PCWSTR};
 
 use crate::core::{
-    ContainerId, ContainerPlacement, Dimension, FloatWindowPlacement, Length, Logical, Physical,
-    PixelRect, Pixels, TilingWindowPlacement,
+    ContainerId, ContainerPlacement, Dimension, FloatWindowPlacement, HintPlacement, Length,
+    Logical, OverviewWorkspace, Physical, PixelRect, Pixels, ScrollExtent, TilingWindowPlacement,
 };
 use crate::overlay;
 use crate::platform::windows::dome::CreateOverlay;
@@ -382,6 +384,10 @@ pub(in crate::platform::windows) struct TilingOverlay {
     height_phys: u32,
     windows: Vec<TilingWindowPlacement>,
     containers: Vec<(ContainerPlacement, Vec<String>)>,
+    hints: Vec<HintPlacement>,
+    scroll_extents: Vec<ScrollExtent>,
+    /// Topmost while hints are showing, so labels paint over every window.
+    raised: bool,
     config: Config,
     border_thickness: Pixels<Physical>,
     window: OwnedHwnd,
@@ -398,7 +404,7 @@ impl TilingOverlay {
         monitor: PixelRect,
         scale: f32,
     ) -> anyhow::Result<Box<Self>> {
-        let flavor = config.theme;
+        let flavor = config.theme.flavor;
         let font = &config.font;
         // Initialize the wgpu surface at the monitor's physical size so the
         // overlay is ready to render without a preceding update() call.
@@ -441,6 +447,9 @@ impl TilingOverlay {
             height_phys: init_h,
             windows: Vec::new(),
             containers: Vec::new(),
+            hints: Vec::new(),
+            scroll_extents: Vec::new(),
+            raised: false,
             border_thickness: Pixels::ZERO,
             config,
             window,
@@ -460,7 +469,8 @@ impl TilingOverlay {
                 id: wp.id,
                 frame: wp.border_box.to_logical(scale),
                 visible_frame: wp.visible_border_box.to_logical(scale),
-                is_highlighted: wp.is_highlighted,
+                state: overlay::BorderState::tiling(wp),
+                thickness: Length::from_pixels(wp.border).to_logical(scale),
                 spawn_indicator: wp.spawn_indicator,
             })
             .collect();
@@ -471,16 +481,35 @@ impl TilingOverlay {
                 id: cp.id,
                 frame: cp.border_box.to_logical(scale),
                 visible_frame: cp.visible_border_box.to_logical(scale),
-                tab_bar_height: Length::from_pixels(cp.tab_bar_band.height()).to_logical(scale),
+                tab_bar_thickness: Length::from_pixels(cp.tab_bar_thickness()).to_logical(scale),
+                tab_bar_position: cp.tab_bar_position,
                 is_highlighted: cp.is_highlighted,
                 spawn_indicator: cp.spawn_indicator,
                 is_tabbed: cp.is_tabbed,
                 titles: titles.clone(),
             })
             .collect();
+        let hints_logical: Vec<overlay::LogicalHint> = self
+            .hints
+            .iter()
+            .map(|h| overlay::LogicalHint {
+                label: h.label.clone(),
+                frame: h.visible_border_box.to_logical(scale),
+            })
+            .collect();
+        let scroll_logical: Vec<overlay::LogicalScrollExtent> = self
+            .scroll_extents
+            .iter()
+            .map(|e| overlay::LogicalScrollExtent {
+                viewport: e.viewport.to_logical(scale),
+                content: e.content.to_logical(scale),
+                windows: e.windows.iter().map(|w| w.to_logical(scale)).collect(),
+            })
+            .collect();
         let config = &self.config;
         let theme = config.theme();
-        let border = overlay::BorderMetrics::from_thickness(
+        let border = overlay::BorderMetrics::new(
+            config,
             Length::from_pixels(self.border_thickness).to_logical(scale),
         );
         let w_phys = self.width_phys;
@@ -494,11 +523,34 @@ impl TilingOverlay {
                 monitor_logical,
                 &windows_logical,
                 &containers_logical,
+                &hints_logical,
+                &scroll_logical,
+                config.scroll_indicator,
                 &theme,
                 border,
             )
         });
     }
+
+    /// Lifts the overlay above every window while hints show and parks it back
+    /// at `HWND_BOTTOM`, where it was created, once they are gone. Leaving the
+    /// topmost band takes `HWND_NOTOPMOST` first; `HWND_BOTTOM` alone would not.
+    fn set_raised(&mut self, raised: bool) {
+        if self.raised == raised {
+            return;
+        }
+        self.raised = raised;
+        let hwnd = self.window.hwnd();
+        let flags = SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE;
+        unsafe {
+            if raised {
+                SetWindowPos(hwnd, Some(HWND_TOPMOST), 0, 0, 0, 0, flags).ok();
+            } else {
+                SetWindowPos(hwnd, Some(HWND_NOTOPMOST), 0, 0, 0, 0, flags).ok();
+                SetWindowPos(hwnd, Some(HWND_BOTTOM), 0, 0, 0, 0, flags).ok();
+            }
+        }
+    }
 }
 
 impl TilingOverlayApi for TilingOverlay {
@@ -507,6 +559,8 @@ impl TilingOverlayApi for TilingOverlay {
         monitor: PixelRect,
         windows: &[TilingWindowPlacement],
         containers: &[(ContainerPlacement, Vec<String>)],
+        hints: &[HintPlacement],
+        scroll_extents: &[ScrollExtent],
         scale: f32,
         border_thickness: Pixels<Physical>,
     ) {
@@ -539,22 +593,28 @@ impl TilingOverlayApi for TilingOverlay {
         self.height_phys = h_phys;
         self.windows = windows.to_vec();
         self.containers = containers.to_vec();
+        self.hints = hints.to_vec();
+        self.scroll_extents = scroll_extents.to_vec();
         self.scale = scale;
         self.border_thickness = border_thickness;
+        self.set_raised(!hints.is_empty());
         self.rerender();
     }
 
     fn clear(&mut self) {
         self.windows.clear();
         self.containers.clear();
+        self.hints.clear();
+        self.scroll_extents.clear();
+        self.set_raised(false);
         // Render a transparent frame so the overlay becomes invisible.
         // No region clipping needed: the overlay sits behind managed windows.
         self.rerender();
     }
 
     fn set_config(&mut self, config: &Config) {
-        if self.config.theme != config.theme {
-            self.renderer.apply_theme(config.theme);
+        if self.config.theme.flavor != config.theme.flavor {
+            self.renderer.apply_theme(config.theme.flavor);
         }
         if self.config.font != config.font {
             if self.config.font.family != config.font.family {
@@ -657,11 +717,17 @@ pub(in crate::platform::windows) trait FloatOverlayApi {
 }
 
 pub(in crate::platform::windows) trait TilingOverlayApi {
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "one paint pass worth of tiling overlay state"
+    )]
     fn update(
         &mut self,
         monitor: PixelRect,
         windows: &[TilingWindowPlacement],
         containers: &[(ContainerPlacement, Vec<String>)],
+        hints: &[HintPlacement],
+        scroll_extents: &[ScrollExtent],
         scale: f32,
         border_thickness: Pixels<Physical>,
     );
@@ -725,7 +791,7 @@ impl FloatOverlay {
             hwnd,
             width_phys,
             height_phys,
-            config.theme,
+            config.theme.flavor,
             &config.font,
         )?;
         let boxed = Box::new(Self {
@@ -782,10 +848,11 @@ impl FloatOverlayApi for FloatOverlay {
         let vf_logical = vf.to_logical(scale);
         let frame_logical = wp.border_box.to_logical(scale);
         let theme = config.theme();
-        let border = overlay::BorderMetrics::from_thickness(
+        let border = overlay::BorderMetrics::new(
+            config,
             Length::from_pixels(border_thickness).to_logical(scale),
         );
-        let is_highlighted = wp.is_highlighted;
+        let state = overlay::BorderState::float(wp);
 
         self.renderer.render(w_phys, h_phys, scale, vec![], |ui| {
             // layer_painter bypasses egui's Area sizing pass, avoiding
@@ -802,7 +869,7 @@ impl FloatOverlayApi for FloatOverlay {
                 &painter.with_clip_rect(clip),
                 frame_logical,
                 vf_logical,
-                is_highlighted,
+                state,
                 None,
                 &theme,
                 border,
@@ -816,8 +883,8 @@ impl FloatOverlayApi for FloatOverlay {
     }
 
     fn set_config(&mut self, config: &Config) {
-        if self.config.theme != config.theme {
-            self.renderer.apply_theme(config.theme);
+        if self.config.theme.flavor != config.theme.flavor {
+            self.renderer.apply_theme(config.theme.flavor);
         }
         if self.config.font != config.font {
             if self.config.font.family != config.font.family {
@@ -908,6 +975,23 @@ impl CreateOverlay for WgpuOverlayFactory {
             self.hub_sender.clone(),
         )?)
     }
+    fn create_overview(
+        &self,
+        config: Config,
+        monitor: PixelRect,
+        scale: f32,
+    ) -> anyhow::Result<Box<dyn OverviewOverlayApi>> {
+        Ok(OverviewOverlay::new(
+            &self.instance,
+            &self.adapter,
+            Arc::clone(&self.device),
+            Arc::clone(&self.queue),
+            config,
+            monitor,
+            scale,
+            self.hub_sender.clone(),
+        )?)
+    }
 }
 
 trait PhysicalRectExt {
@@ -958,10 +1042,16 @@ impl PhysicalRectExt for PixelRect<Physical> {
 }
 
 pub(in crate::platform::windows) trait TabBarOverlayApi {
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "one paint pass worth of tab bar state"
+    )]
     fn update(
         &mut self,
         rect: PixelRect,
+        position: TabBarPosition,
         titles: Vec<String>,
+        icons: Vec<Option<overlay::TabIcon>>,
         active_index: usize,
         is_highlighted: bool,
         scale: f32,
@@ -984,7 +1074,9 @@ pub(in crate::platform::windows) struct TabBarOverlay {
     container_id: ContainerId,
     width_phys: u32,
     height_phys: u32,
+    position: TabBarPosition,
     titles: Vec<String>,
+    icons: Vec<Option<overlay::TabIcon>>,
     active_index: usize,
     is_highlighted: bool,
     config: Config,
@@ -1014,8 +1106,8 @@ impl TabBarOverlay {
         hub_sender: HubSender,
     ) -> anyhow::Result<Box<Self>> {
         let (x_phys, y_phys, w_phys, h_phys) = rect.to_surface_size();
-        // WS_EX_NOACTIVATE prevents foreground theft on click. Tab clicks are
-        // dispatched as `HubEvent::TabClicked`, not by raising the window.
+        // WS_EX_NOACTIVATE prevents foreground theft on click. Tab gestures are
+        // dispatched as `HubEvent::TabBar`, not by raising the window.
         let window = OwnedHwnd::new(
             TAB_BAR_OVERLAY_CLASS,
             WS_EX_TOOLWINDOW | WS_EX_NOACTIVATE,
@@ -1033,7 +1125,7 @@ impl TabBarOverlay {
             hwnd,
             w_phys,
             h_phys,
-            config.theme,
+            config.theme.flavor,
             &config.font,
         )?;
         let mut boxed = Box::new(Self {
@@ -1043,7 +1135,9 @@ impl TabBarOverlay {
             width_phys: w_phys,
             height_phys: h_phys,
             border_thickness: Pixels::ZERO,
+            position: TabBarPosition::default(),
             titles: Vec::new(),
+            icons: Vec::new(),
             active_index: 0,
             is_highlighted: false,
             config,
@@ -1056,14 +1150,44 @@ impl TabBarOverlay {
         Ok(boxed)
     }
 
-    /// Renders one frame and returns `Some((container_id, tab_index))` when a
-    /// click landed on a tab.
-    fn rerender(&mut self) -> Option<(ContainerId, usize)> {
+    /// Bar pixels are the canvas. Coords in window-local physical pixels map
+    /// directly to egui logical points after Renderer::render's
+    /// pixels_per_point = scale rescale. Captured coords may be negative.
+    fn pointer_pos(&self, lparam: LPARAM) -> egui::Pos2 {
+        let x = (lparam.0 & 0xFFFF) as i16 as f32;
+        let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as f32;
+        egui::pos2(x / self.scale, y / self.scale)
+    }
+
+    fn push_button(&mut self, lparam: LPARAM, middle: bool, pressed: bool) {
+        let pos = self.pointer_pos(lparam);
+        self.events.push(egui::Event::PointerButton {
+            pos,
+            button: if middle {
+                egui::PointerButton::Middle
+            } else {
+                egui::PointerButton::Primary
+            },
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        });
+    }
+
+    fn rerender_and_dispatch(&mut self) {
+        if let Some((cid, event)) = self.rerender() {
+            self.hub_sender.send(HubEvent::TabBar(cid, event));
+        }
+    }
+
+    /// Renders one frame and returns the tab gesture it completed, if any.
+    fn rerender(&mut self) -> Option<(ContainerId, overlay::TabBarEvent)> {
         let events = std::mem::take(&mut self.events);
         let scale = self.scale;
         let w_phys = self.width_phys;
         let h_phys = self.height_phys;
+        let position = self.position;
         let titles = self.titles.clone();
+        let icons = self.icons.clone();
         let active_index = self.active_index;
         let is_highlighted = self.is_highlighted;
         let container_id = self.container_id;
@@ -1071,7 +1195,8 @@ impl TabBarOverlay {
         let theme = config.theme();
         let bar_h_logical = Length::<Logical>::new(h_phys as f32 / scale);
         let bar_w_logical = Length::<Logical>::new(w_phys as f32 / scale);
-        let border = overlay::BorderMetrics::from_thickness(
+        let border = overlay::BorderMetrics::new(
+            config,
             Length::from_pixels(self.border_thickness).to_logical(scale),
         );
         let canvas_local =
@@ -1081,7 +1206,9 @@ impl TabBarOverlay {
                 ui.ctx(),
                 container_id,
                 canvas_local,
+                position,
                 &titles,
+                &icons,
                 active_index,
                 is_highlighted,
                 border,
@@ -1095,13 +1222,17 @@ impl TabBarOverlayApi for TabBarOverlay {
     fn update(
         &mut self,
         rect: PixelRect,
+        position: TabBarPosition,
         titles: Vec<String>,
+        icons: Vec<Option<overlay::TabIcon>>,
         active_index: usize,
         is_highlighted: bool,
         scale: f32,
         border_thickness: Pixels<Physical>,
     ) {
+        self.position = position;
         self.titles = titles;
+        self.icons = icons;
         self.active_index = active_index;
         self.is_highlighted = is_highlighted;
         self.scale = scale;
@@ -1139,8 +1270,8 @@ impl TabBarOverlayApi for TabBarOverlay {
     }
 
     fn set_config(&mut self, config: &Config) {
-        if self.config.theme != config.theme {
-            self.renderer.apply_theme(config.theme);
+        if self.config.theme.flavor != config.theme.flavor {
+            self.renderer.apply_theme(config.theme.flavor);
         }
         if self.config.font != config.font {
             self.renderer.apply_font(&config.font);
@@ -1176,44 +1307,255 @@ pub(in crate::platform::windows) unsafe extern "system" fn tab_bar_overlay_wnd_p
     let overlay = unsafe { &mut *ptr };
     match msg {
         WM_MOUSEMOVE => {
-            let x = (lparam.0 & 0xFFFF) as i16 as f32;
-            let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as f32;
-            // Bar pixels are the canvas. Coords in window-local physical pixels
-            // map directly to egui logical points after Renderer::render's
-            // pixels_per_point = scale rescale.
-            let scale = overlay.scale;
-            overlay
-                .events
-                .push(egui::Event::PointerMoved(egui::pos2(x / scale, y / scale)));
+            let pos = overlay.pointer_pos(lparam);
+            overlay.events.push(egui::Event::PointerMoved(pos));
+            // Only a held button captures the pointer. Rendering each move
+            // then lets egui track the drag and draw the drop marker.
+            if unsafe { GetCapture() } == hwnd {
+                overlay.rerender_and_dispatch();
+            }
+            LRESULT(0)
+        }
+        WM_LBUTTONDOWN | WM_MBUTTONDOWN => {
+            overlay.push_button(lparam, msg == WM_MBUTTONDOWN, true);
+            // Capture keeps moves and the release flowing here after the
+            // pointer leaves the bar, which is how a tab gets dragged out.
+            unsafe { SetCapture(hwnd) };
+            overlay.rerender_and_dispatch();
+            LRESULT(0)
+        }
+        WM_LBUTTONUP | WM_MBUTTONUP => {
+            overlay.push_button(lparam, msg == WM_MBUTTONUP, false);
+            unsafe { ReleaseCapture() }.ok();
+            // Button-up is the edge paint_tab_bar's click and drag-stop observe.
+            overlay.rerender_and_dispatch();
+            LRESULT(0)
+        }
+        WM_PAINT => {
+            unsafe {
+                let mut ps = PAINTSTRUCT::default();
+                BeginPaint(hwnd, &mut ps);
+                EndPaint(hwnd, &ps).ok().ok();
+            }
+            LRESULT(0)
+        }
+        _ => unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) },
+    }
+}
+
+pub(in crate::platform::windows) const OVERVIEW_OVERLAY_CLASS: PCWSTR =
+    windows::core::w!("DomeOverviewOverlay");
+
+pub(in crate::platform::windows) trait OverviewOverlayApi {
+    fn update(&mut self, monitor: PixelRect, workspaces: &[OverviewWorkspace], scale: f32);
+    fn set_config(&mut self, config: &Config);
+}
+
+/// Topmost window covering the focused monitor while the overview shows. It
+/// takes pointer events, unlike the tiling overlay, and lives only as long as
+/// the overview does.
+pub(in crate::platform::windows) struct OverviewOverlay {
+    renderer: Renderer,
+    events: Vec<egui::Event>,
+    monitor: PixelRect,
+    width_phys: u32,
+    height_phys: u32,
+    workspaces: Vec<OverviewWorkspace>,
+    config: Config,
+    hub_sender: HubSender,
+    window: OwnedHwnd,
+    scale: f32,
+}
+
+impl OverviewOverlay {
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "wgpu handles, geometry, and the hub sender all travel together at construction"
+    )]
+    pub(in crate::platform::windows) fn new(
+        instance: &wgpu::Instance,
+        adapter: &wgpu::Adapter,
+        device: Arc<wgpu::Device>,
+        queue: Arc<wgpu::Queue>,
+        config: Config,
+        monitor: PixelRect,
+        scale: f32,
+        hub_sender: HubSender,
+    ) -> anyhow::Result<Box<Self>> {
+        let (x_phys, y_phys, w_phys, h_phys) = monitor.to_surface_size();
+        // WS_EX_NOACTIVATE keeps the keyboard with the focused window, where
+        // the hook reads typed labels from anyway.
+        let mut window = OwnedHwnd::new(
+            OVERVIEW_OVERLAY_CLASS,
+            WS_EX_TOOLWINDOW | WS_EX_NOACTIVATE,
+            x_phys,
+            y_phys,
+            w_phys,
+            h_phys,
+        )?;
+        let hwnd = window.hwnd();
+        let renderer = Renderer::new(
+            instance,
+            adapter,
+            device,
+            queue,
+            hwnd,
+            w_phys,
+            h_phys,
+            config.theme.flavor,
+            &config.font,
+        )?;
+        window.show();
+        unsafe {
+            SetWindowPos(
+                hwnd,
+                Some(HWND_TOPMOST),
+                0,
+                0,
+                0,
+                0,
+                SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+            )
+            .ok();
+        }
+        let mut boxed = Box::new(Self {
+            renderer,
+            events: Vec::new(),
+            monitor,
+            width_phys: w_phys,
+            height_phys: h_phys,
+            workspaces: Vec::new(),
+            config,
+            hub_sender,
+            window,
+            scale,
+        });
+        unsafe { SetWindowLongPtrW(hwnd, GWLP_USERDATA, &mut *boxed as *mut Self as isize) };
+        Ok(boxed)
+    }
+
+    /// Same mapping as `TabBarOverlay::pointer_pos`: the monitor is the canvas.
+    fn pointer_pos(&self, lparam: LPARAM) -> egui::Pos2 {
+        let x = (lparam.0 & 0xFFFF) as i16 as f32;
+        let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as f32;
+        egui::pos2(x / self.scale, y / self.scale)
+    }
+
+    fn push_button(&mut self, lparam: LPARAM, pressed: bool) {
+        let pos = self.pointer_pos(lparam);
+        self.events.push(egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        });
+    }
+
+    fn rerender_and_dispatch(&mut self) {
+        if let Some(event) = self.rerender() {
+            self.hub_sender.send(HubEvent::Overview(event));
+        }
+    }
+
+    fn rerender(&mut self) -> Option<overlay::OverviewEvent> {
+        let events = std::mem::take(&mut self.events);
+        let scale = self.scale;
+        let w_phys = self.width_phys;
+        let h_phys = self.height_phys;
+        let screen = Dimension::<Logical>::new(
+            Length::ZERO,
+            Length::ZERO,
+            Length::new(w_phys as f32 / scale),
+            Length::new(h_phys as f32 / scale),
+        );
+        let workspaces = &self.workspaces;
+        let theme = self.config.theme();
+        self.renderer.render(w_phys, h_phys, scale, events, |ui| {
+            overlay::paint_overview(ui.ctx(), screen, workspaces, &theme)
+        })
+    }
+}
+
+impl OverviewOverlayApi for OverviewOverlay {
+    fn update(&mut self, monitor: PixelRect, workspaces: &[OverviewWorkspace], scale: f32) {
+        if self.monitor != monitor {
+            let (x_phys, y_phys, w_phys, h_phys) = monitor.to_surface_size();
+            self.renderer.resize(w_phys, h_phys);
+            unsafe {
+                SetWindowPos(
+                    self.window.hwnd(),
+                    Some(HWND_TOPMOST),
+                    x_phys,
+                    y_phys,
+                    w_phys as i32,
+                    h_phys as i32,
+                    SWP_NOACTIVATE,
+                )
+                .ok();
+            }
+            self.monitor = monitor;
+            self.width_phys = w_phys;
+            self.height_phys = h_phys;
+        }
+        self.workspaces = workspaces.to_vec();
+        self.scale = scale;
+        let _ = self.rerender();
+    }
+
+    fn set_config(&mut self, config: &Config) {
+        if self.config.theme.flavor != config.theme.flavor {
+            self.renderer.apply_theme(config.theme.flavor);
+        }
+        if self.config.font != config.font {
+            self.renderer.apply_font(&config.font);
+        }
+        self.config = config.clone();
+    }
+}
+
+impl Drop for OverviewOverlay {
+    fn drop(&mut self) {
+        unsafe { SetWindowLongPtrW(self.window.hwnd(), GWLP_USERDATA, 0) };
+    }
+}
+
+pub(in crate::platform::windows) unsafe extern "system" fn overview_overlay_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    if let Some(lr) = crate::platform::windows::dome_wnd_proc_common(hwnd, msg, wparam, lparam) {
+        return lr;
+    }
+    if msg == WM_MOUSEACTIVATE {
+        return LRESULT(MA_NOACTIVATE as isize);
+    }
+    let ptr = unsafe { GetWindowLongPtrW(hwnd, GWLP_USERDATA) } as *mut OverviewOverlay;
+    if ptr.is_null() {
+        return unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) };
+    }
+    let overlay = unsafe { &mut *ptr };
+    match msg {
+        WM_MOUSEMOVE => {
+            let pos = overlay.pointer_pos(lparam);
+            overlay.events.push(egui::Event::PointerMoved(pos));
+            // Only a drag needs a frame per move, to carry the window along.
+            if unsafe { GetCapture() } == hwnd {
+                overlay.rerender_and_dispatch();
+            }
             LRESULT(0)
         }
         WM_LBUTTONDOWN => {
-            let x = (lparam.0 & 0xFFFF) as i16 as f32;
-            let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as f32;
-            let scale = overlay.scale;
-            overlay.events.push(egui::Event::PointerButton {
-                pos: egui::pos2(x / scale, y / scale),
-                button: egui::PointerButton::Primary,
-                pressed: true,
-                modifiers: egui::Modifiers::NONE,
-            });
+            overlay.push_button(lparam, true);
+            unsafe { SetCapture(hwnd) };
+            overlay.rerender_and_dispatch();
             LRESULT(0)
         }
         WM_LBUTTONUP => {
-            let x = (lparam.0 & 0xFFFF) as i16 as f32;
-            let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as f32;
-            let scale = overlay.scale;
-            overlay.events.push(egui::Event::PointerButton {
-                pos: egui::pos2(x / scale, y / scale),
-                button: egui::PointerButton::Primary,
-                pressed: false,
-                modifiers: egui::Modifiers::NONE,
-            });
-            // Button-up is the edge paint_tab_bar's Sense::click() observes.
-            // Both Down and Up must be present in the same render pass.
-            if let Some((cid, idx)) = overlay.rerender() {
-                overlay.hub_sender.send(HubEvent::TabClicked(cid, idx));
-            }
+            overlay.push_button(lparam, false);
+            unsafe { ReleaseCapture() }.ok();
+            overlay.rerender_and_dispatch();
             LRESULT(0)
         }
         WM_PAINT => {
@@ -5,8 +5,8 @@ use anyhow::{Result, anyhow};
 use windows::Win32::Foundation::{HINSTANCE, HWND, LPARAM, POINT, WPARAM};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::UI::Shell::{
-    NIF_ICON, NIF_MESSAGE, NIF_TIP, NIM_ADD, NIM_DELETE, NIM_MODIFY, NOTIFYICONDATAW,
-    Shell_NotifyIconW,
+    NIF_ICON, NIF_INFO, NIF_MESSAGE, NIF_TIP, NIIF_WARNING, NIM_ADD, NIM_DELETE, NIM_MODIFY,
+    NOTIFYICONDATAW, Shell_NotifyIconW,
 };
 use windows::Win32::UI::WindowsAndMessaging::{
     AppendMenuW, CreatePopupMenu, DestroyMenu, GetCursorPos, HICON, IMAGE_ICON, LR_DEFAULTSIZE,
@@ -88,9 +88,13 @@ impl TrayIndicator {
         Ok(())
     }
 
-    pub(in crate::platform::windows) fn update(&self, workspaces: &[WorkspaceInfo]) {
+    pub(in crate::platform::windows) fn update(
+        &self,
+        workspaces: &[WorkspaceInfo],
+        mode: Option<&str>,
+    ) {
         *self.workspaces.borrow_mut() = workspaces.to_vec();
-        let tip = focused_tooltip(workspaces);
+        let tip = focused_tooltip(workspaces, mode);
         let mut data = self.data.borrow_mut();
         write_wide_into(&mut data.szTip, &tip);
         let ok = unsafe { Shell_NotifyIconW(NIM_MODIFY, &*data) };
@@ -99,6 +103,21 @@ impl TrayIndicator {
         }
     }
 
+    /// Shows a balloon next to the tray icon. The shell hides it on its own.
+    pub(in crate::platform::windows) fn notify(&self, message: &str) {
+        // NIF_INFO goes on a copy so later tooltip updates do not replay the
+        // balloon.
+        let mut data = *self.data.borrow();
+        data.uFlags |= NIF_INFO;
+        data.dwInfoFlags = NIIF_WARNING;
+        write_wide_into(&mut data.szInfoTitle, "Dome");
+        write_wide_into(&mut data.szInfo, message);
+        let ok = unsafe { Shell_NotifyIconW(NIM_MODIFY, &data) };
+        if !ok.as_bool() {
+            tracing::warn!("Shell_NotifyIconW(NIM_MODIFY) failed for balloon");
+        }
+    }
+
     pub(super) fn show_menu(&self, hwnd: HWND) {
         // TrackPopupMenu docs require the owner window to be foreground first,
         // otherwise the menu can fail to dismiss on click-outside.
@@ -211,12 +230,18 @@ pub(super) fn truncate_tooltip(name: &str) -> String {
     format!("{cutoff}\u{2026}")
 }
 
-pub(super) fn focused_tooltip(workspaces: &[WorkspaceInfo]) -> String {
-    workspaces
+/// Focused workspace name, followed by the keymap mode when it is not the
+/// default one so a sticky mode is visible from the tray.
+pub(super) fn focused_tooltip(workspaces: &[WorkspaceInfo], mode: Option<&str>) -> String {
+    let workspace = workspaces
         .iter()
         .find(|w| w.is_focused)
         .map(|w| truncate_tooltip(&w.name))
-        .unwrap_or_default()
+        .unwrap_or_default();
+    match mode {
+        Some(mode) => format!("{workspace} [{}]", truncate_tooltip(mode)),
+        None => workspace,
+    }
 }
 
 pub(super) fn write_wide_into(dst: &mut [u16], s: &str) {
@@ -264,6 +289,7 @@ mod tests {
             is_focused: focused,
             is_visible: visible,
             window_count: 0,
+            urgent: false,
         }
     }
 
@@ -289,13 +315,19 @@ mod tests {
     #[test]
     fn focused_tooltip_none() {
         let list = vec![ws("1", false, true)];
-        assert_eq!(focused_tooltip(&list), "");
+        assert_eq!(focused_tooltip(&list, None), "");
     }
 
     #[test]
     fn focused_tooltip_picks() {
         let list = vec![ws("1", false, true), ws("2", true, true)];
-        assert_eq!(focused_tooltip(&list), "2");
+        assert_eq!(focused_tooltip(&list, None), "2");
+    }
+
+    #[test]
+    fn focused_tooltip_shows_mode() {
+        let list = vec![ws("1", false, true), ws("2", true, true)];
+        assert_eq!(focused_tooltip(&list, Some("resize")), "2 [resize]");
     }
 
     #[test]
@@ -299,7 +299,7 @@ impl Dome {
                 entry.ext.set_position(ZOrder::Topmost, new_target);
                 overlay.update(
                     wp,
-                    &self.config,
+                    &self.overlay_config,
                     ZOrder::After(entry.ext.id()),
                     scale,
                     border_thickness,
@@ -310,13 +310,19 @@ impl Dome {
                 entry.ext.set_position(ZOrder::Unchanged, new_target);
                 overlay.update(
                     wp,
-                    &self.config,
+                    &self.overlay_config,
                     ZOrder::After(entry.ext.id()),
                     scale,
                     border_thickness,
                 );
             } else {
-                overlay.update(wp, &self.config, ZOrder::Unchanged, scale, border_thickness);
+                overlay.update(
+                    wp,
+                    &self.overlay_config,
+                    ZOrder::Unchanged,
+                    scale,
+                    border_thickness,
+                );
             }
         }
 
@@ -634,10 +640,15 @@ impl Dome {
                     return;
                 }
                 drift.actual = new_placement;
-                if drift.actual != drift.target {
+                // Intermediate animation frames move the target every few
+                // milliseconds, so only the settled placement is judged.
+                if drift.actual != drift.target && self.hub.is_animating(id) {
+                    tracing::trace!(%id, "animation in flight, ignoring drift");
+                } else if drift.actual != drift.target {
                     drift.retries = drift.retries.saturating_add(1);
                     if drift.retries > MAX_DRIFT_RETRIES {
                         tracing::debug!("Drift retries exhausted, giving up");
+                        self.hub.stop_animating(id);
                     } else {
                         tracing::trace!(%id, target = ?drift.target, actual = ?drift.actual, retries = drift.retries, "window drifted, correcting");
                         entry.ext.set_position(ZOrder::Unchanged, drift.target);
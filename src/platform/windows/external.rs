@@ -2,6 +2,7 @@ use windows::Win32::Foundation::HWND;
 use windows::Win32::UI::WindowsAndMessaging::{HWND_NOTOPMOST, HWND_TOPMOST};
 
 use crate::core::{LimitObservation, Physical, PixelRect};
+use crate::overlay::TabIcon;
 
 /// Opaque window identity.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -79,6 +80,8 @@ pub(crate) trait ManageExternalWindow: Send + Sync {
     fn close(&self);
     fn set_foreground_window(&self);
     fn is_maximized(&self) -> bool;
+    /// The class icon for the tab bar, if the window class registered one.
+    fn app_icon(&self) -> Option<TabIcon>;
     fn recover(&self, was_maximized: bool);
 }
 
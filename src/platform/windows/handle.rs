@@ -6,7 +6,9 @@ use windows::Win32::Graphics::Dwm::{
     DWMWA_CLOAKED, DWMWA_EXTENDED_FRAME_BOUNDS, DwmGetWindowAttribute,
 };
 use windows::Win32::Graphics::Gdi::{
-    GetMonitorInfoW, MONITOR_DEFAULTTONEAREST, MONITORINFO, MonitorFromWindow,
+    BI_RGB, BITMAP, BITMAPINFO, BITMAPINFOHEADER, DIB_RGB_COLORS, DeleteObject, GetDC, GetDIBits,
+    GetMonitorInfoW, GetObjectW, HBITMAP, MONITOR_DEFAULTTONEAREST, MONITORINFO, MonitorFromWindow,
+    ReleaseDC,
 };
 use windows::Win32::Storage::FileSystem::{
     GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW,
@@ -19,18 +21,20 @@ use windows::Win32::UI::Input::KeyboardAndMouse::{
     INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, SendInput, VK_MENU,
 };
 use windows::Win32::UI::WindowsAndMessaging::{
-    EnumThreadWindows, EnumWindows, GA_ROOT, GA_ROOTOWNER, GW_OWNER, GWL_EXSTYLE, GWL_STYLE,
-    GetAncestor, GetClassNameW, GetForegroundWindow, GetWindow, GetWindowLongW, GetWindowRect,
-    GetWindowThreadProcessId, HWND_BOTTOM, IsIconic, IsWindowVisible, IsZoomed, MINMAXINFO,
-    PostMessageW, SMTO_ABORTIFHUNG, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, SWP_ASYNCWINDOWPOS,
-    SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER, SendMessageTimeoutW, SetForegroundWindow,
-    SetWindowPos, ShowWindow, ShowWindowAsync, WM_CLOSE, WM_GETMINMAXINFO, WM_GETTEXT,
-    WM_GETTEXTLENGTH, WS_CHILD, WS_EX_APPWINDOW, WS_EX_DLGMODALFRAME, WS_EX_NOACTIVATE,
-    WS_EX_TOOLWINDOW, WS_EX_TRANSPARENT, WS_MAXIMIZEBOX, WS_MINIMIZEBOX, WS_POPUP, WS_THICKFRAME,
+    EnumThreadWindows, EnumWindows, GA_ROOT, GA_ROOTOWNER, GCLP_HICON, GCLP_HICONSM, GW_OWNER,
+    GWL_EXSTYLE, GWL_STYLE, GetAncestor, GetClassLongPtrW, GetClassNameW, GetForegroundWindow,
+    GetIconInfo, GetWindow, GetWindowLongW, GetWindowRect, GetWindowThreadProcessId, HICON,
+    HWND_BOTTOM, ICONINFO, IsIconic, IsWindowVisible, IsZoomed, MINMAXINFO, PostMessageW,
+    SMTO_ABORTIFHUNG, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, SWP_ASYNCWINDOWPOS, SWP_NOACTIVATE,
+    SWP_NOSIZE, SWP_NOZORDER, SendMessageTimeoutW, SetForegroundWindow, SetWindowPos, ShowWindow,
+    ShowWindowAsync, WM_CLOSE, WM_GETMINMAXINFO, WM_GETTEXT, WM_GETTEXTLENGTH, WS_CHILD,
+    WS_EX_APPWINDOW, WS_EX_DLGMODALFRAME, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TRANSPARENT,
+    WS_MAXIMIZEBOX, WS_MINIMIZEBOX, WS_POPUP, WS_THICKFRAME,
 };
 use windows::core::{BOOL, PCWSTR, w};
 
 use crate::core::{Dimension, Length, LimitObservation, LimitUpdate, PixelRect, Pixels};
+use crate::overlay::TabIcon;
 use crate::platform::windows::external::{
     HwndId, InspectExternalWindow, ManageExternalWindow, ShowCmd, ZOrder,
 };
@@ -183,6 +187,88 @@ where
     }
 }
 
+/// Reads the class icon (small first) as RGBA. Only class data is touched, so this never
+/// waits on the owning app the way `WM_GETICON` would.
+fn class_icon_rgba(hwnd: HWND) -> Option<([usize; 2], Vec<u8>)> {
+    let handle = match unsafe { GetClassLongPtrW(hwnd, GCLP_HICONSM) } {
+        0 => unsafe { GetClassLongPtrW(hwnd, GCLP_HICON) },
+        h => h,
+    };
+    if handle == 0 {
+        return None;
+    }
+    let mut info = ICONINFO::default();
+    if let Err(e) = unsafe { GetIconInfo(HICON(handle as *mut _), &mut info) } {
+        tracing::trace!(?hwnd, "GetIconInfo failed: {e}");
+        return None;
+    }
+    let pixels = icon_bitmap_rgba(info.hbmColor);
+    unsafe {
+        let _ = DeleteObject(info.hbmColor.into());
+        let _ = DeleteObject(info.hbmMask.into());
+    }
+    pixels
+}
+
+fn icon_bitmap_rgba(bitmap: HBITMAP) -> Option<([usize; 2], Vec<u8>)> {
+    // Monochrome icons carry no color bitmap; they are rare enough to skip.
+    if bitmap.is_invalid() {
+        return None;
+    }
+    let mut bm = BITMAP::default();
+    let read = unsafe {
+        GetObjectW(
+            bitmap.into(),
+            size_of::<BITMAP>() as i32,
+            Some(&mut bm as *mut BITMAP as *mut _),
+        )
+    };
+    if read == 0 || bm.bmWidth <= 0 || bm.bmHeight <= 0 {
+        return None;
+    }
+    let (w, h) = (bm.bmWidth, bm.bmHeight);
+    let mut bmi = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: w,
+            // Negative height asks for top-down rows.
+            biHeight: -h,
+            biPlanes: 1,
+            biBitCount: 32,
+            biCompression: BI_RGB.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut pixels = vec![0u8; w as usize * h as usize * 4];
+    let lines = unsafe {
+        let hdc = GetDC(None);
+        let lines = GetDIBits(
+            hdc,
+            bitmap,
+            0,
+            h as u32,
+            Some(pixels.as_mut_ptr().cast()),
+            &mut bmi,
+            DIB_RGB_COLORS,
+        );
+        ReleaseDC(None, hdc);
+        lines
+    };
+    if lines == 0 {
+        return None;
+    }
+    // Pre-Vista icons leave alpha at zero and rely on the mask instead.
+    let has_alpha = pixels.chunks_exact(4).any(|p| p[3] != 0);
+    for p in pixels.chunks_exact_mut(4) {
+        p.swap(0, 2);
+        if !has_alpha {
+            p[3] = 255;
+        }
+    }
+    Some(([w as usize, h as usize], pixels))
+}
+
 fn is_cloaked(hwnd: HWND) -> bool {
     let mut cloaked = 0u32;
     let result = unsafe {
@@ -366,6 +452,15 @@ impl ManageExternalWindow for ExternalHwnd {
         unsafe { IsZoomed(self.0) }.as_bool()
     }
 
+    fn app_icon(&self) -> Option<TabIcon> {
+        let rgba = class_icon_rgba(self.0)?;
+        Some(TabIcon {
+            key: egui::Id::new(("tab_icon", HwndId::from(self.0))),
+            size: rgba.0,
+            rgba: rgba.1.into(),
+        })
+    }
+
     fn recover(&self, was_maximized: bool) {
         let hwnd = self.0;
         unsafe {
@@ -5,14 +5,15 @@ use std::thread::{self, JoinHandle};
 use windows::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
 use windows::Win32::System::Threading::GetCurrentThreadId;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
-    GetAsyncKeyState, VIRTUAL_KEY, VK_BACK, VK_CONTROL, VK_DOWN, VK_ESCAPE, VK_LEFT, VK_LMENU,
-    VK_LWIN, VK_MENU, VK_OEM_4, VK_OEM_6, VK_RETURN, VK_RIGHT, VK_RMENU, VK_RWIN, VK_SHIFT,
-    VK_SPACE, VK_TAB, VK_UP,
+    GetAsyncKeyState, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBD_EVENT_FLAGS, KEYBDINPUT,
+    KEYEVENTF_EXTENDEDKEY, KEYEVENTF_KEYUP, SendInput, VIRTUAL_KEY, VK_BACK, VK_CONTROL, VK_DOWN,
+    VK_ESCAPE, VK_LEFT, VK_LMENU, VK_LWIN, VK_MENU, VK_OEM_4, VK_OEM_6, VK_RETURN, VK_RIGHT,
+    VK_RMENU, VK_RWIN, VK_SHIFT, VK_SPACE, VK_TAB, VK_UP,
 };
 use windows::Win32::UI::WindowsAndMessaging::{
-    CallNextHookEx, DispatchMessageW, GetMessageW, KBDLLHOOKSTRUCT, MSG, PostThreadMessageW,
-    SetWindowsHookExW, TranslateMessage, UnhookWindowsHookEx, WH_KEYBOARD_LL, WM_KEYDOWN, WM_QUIT,
-    WM_SYSKEYDOWN,
+    CallNextHookEx, DispatchMessageW, GetMessageW, HHOOK, KBDLLHOOKSTRUCT, MSG, MSLLHOOKSTRUCT,
+    PostThreadMessageW, SetWindowsHookExW, TranslateMessage, UnhookWindowsHookEx, WH_KEYBOARD_LL,
+    WH_MOUSE_LL, WM_APP, WM_KEYDOWN, WM_MOUSEHWHEEL, WM_MOUSEWHEEL, WM_QUIT, WM_SYSKEYDOWN,
 };
 
 use super::HubSender;
@@ -21,11 +22,76 @@ use crate::action::Actions;
 use crate::config::{Keymap, Modifiers};
 use crate::keymap::KeymapState;
 
+/// Thread message asking the hook thread to install (`wParam` 1) or remove
+/// (`wParam` 0) the mouse hook.
+const WM_SET_MOUSE_HOOK: u32 = WM_APP + 1;
+
+/// `dwExtraInfo` of the keys `replay_keys` types, so the hook lets them
+/// through instead of resolving them again.
+const REPLAY_MARKER: usize = 0x444f_4d45;
+
+/// Keys named in keymaps other than digits and letters, which share their
+/// virtual-key codes with their uppercase ASCII.
+const NAMED_KEYS: [(VIRTUAL_KEY, &str); 11] = [
+    (VK_RETURN, "return"),
+    (VK_BACK, "backspace"),
+    (VK_ESCAPE, "escape"),
+    (VK_TAB, "tab"),
+    (VK_SPACE, "space"),
+    (VK_UP, "up"),
+    (VK_DOWN, "down"),
+    (VK_LEFT, "left"),
+    (VK_RIGHT, "right"),
+    (VK_OEM_4, "["),
+    (VK_OEM_6, "]"),
+];
+
 pub(super) struct KeyboardHookHandle {
-    thread_id: u32,
+    thread: HookThread,
     join_handle: Option<JoinHandle<()>>,
 }
 
+impl KeyboardHookHandle {
+    pub(super) fn thread(&self) -> HookThread {
+        self.thread
+    }
+}
+
+/// The hook thread, for switching the mouse hook from other threads.
+#[derive(Clone, Copy)]
+pub(super) struct HookThread(u32);
+
+impl HookThread {
+    /// The mouse hook only serves wheel bindings. Without one it stays
+    /// uninstalled, so wheel events never pass through Dome.
+    pub(super) fn set_mouse_hook(self, enabled: bool) {
+        let wparam = WPARAM(usize::from(enabled));
+        if let Err(e) = unsafe { PostThreadMessageW(self.0, WM_SET_MOUSE_HOOK, wparam, LPARAM(0)) }
+        {
+            tracing::warn!("Failed to post mouse hook toggle: {e}");
+        }
+    }
+}
+
+/// Install or remove the mouse hook. Must run on the hook thread, whose
+/// message loop services it.
+fn set_mouse_hook(hook: &mut Option<HHOOK>, enabled: bool) {
+    match (hook.take(), enabled) {
+        (None, true) => {
+            match unsafe { SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook_proc), None, 0) } {
+                Ok(mouse_hook) => *hook = Some(mouse_hook),
+                Err(e) => tracing::warn!("Mouse hook unavailable, wheel keymaps disabled: {e}"),
+            }
+        }
+        (Some(mouse_hook), false) => {
+            if let Err(e) = unsafe { UnhookWindowsHookEx(mouse_hook) } {
+                tracing::warn!("UnhookWindowsHookEx failed: {e}");
+            }
+        }
+        (current, _) => *hook = current,
+    }
+}
+
 struct KeyboardState {
     sender: HubSender,
     keymap_state: Arc<RwLock<KeymapState>>,
@@ -36,6 +102,7 @@ static STATE: OnceLock<KeyboardState> = OnceLock::new();
 pub(super) fn install_keyboard_hook(
     sender: HubSender,
     keymap_state: Arc<RwLock<KeymapState>>,
+    wheel: bool,
 ) -> anyhow::Result<KeyboardHookHandle> {
     STATE
         .set(KeyboardState {
@@ -50,14 +117,21 @@ pub(super) fn install_keyboard_hook(
         let thread_id = unsafe { GetCurrentThreadId() };
         match unsafe { SetWindowsHookExW(WH_KEYBOARD_LL, Some(keyboard_hook_proc), None, 0) } {
             Ok(hook) => {
+                let mut mouse_hook = None;
+                set_mouse_hook(&mut mouse_hook, wheel);
                 tx.send(Ok(thread_id)).ok();
                 let mut msg = MSG::default();
                 unsafe {
                     while GetMessageW(&mut msg, None, 0, 0).into() {
+                        if msg.hwnd.is_invalid() && msg.message == WM_SET_MOUSE_HOOK {
+                            set_mouse_hook(&mut mouse_hook, msg.wParam.0 != 0);
+                            continue;
+                        }
                         let _ = TranslateMessage(&msg);
                         DispatchMessageW(&msg);
                     }
                 }
+                set_mouse_hook(&mut mouse_hook, false);
                 if let Err(e) = unsafe { UnhookWindowsHookEx(hook) } {
                     tracing::warn!("UnhookWindowsHookEx failed: {e}");
                 }
@@ -73,13 +147,13 @@ pub(super) fn install_keyboard_hook(
         .map_err(|_| anyhow::anyhow!("keyboard hook thread died"))??;
 
     Ok(KeyboardHookHandle {
-        thread_id,
+        thread: HookThread(thread_id),
         join_handle: Some(join_handle),
     })
 }
 
 pub(super) fn uninstall_keyboard_hook(mut handle: KeyboardHookHandle) {
-    unsafe { PostThreadMessageW(handle.thread_id, WM_QUIT, WPARAM(0), LPARAM(0)).ok() };
+    unsafe { PostThreadMessageW(handle.thread.0, WM_QUIT, WPARAM(0), LPARAM(0)).ok() };
     if let Some(jh) = handle.join_handle.take() {
         jh.join().ok();
     }
@@ -92,10 +166,7 @@ unsafe extern "system" fn keyboard_hook_proc(code: i32, wparam: WPARAM, lparam:
             let kb_struct = unsafe { &*(lparam.0 as *const KBDLLHOOKSTRUCT) };
             let vk = VIRTUAL_KEY(kb_struct.vkCode as u16);
 
-            if let Some(actions) = get_actions(vk) {
-                if let Some(state) = STATE.get() {
-                    state.sender.send(HubEvent::Action(actions));
-                }
+            if kb_struct.dwExtraInfo != REPLAY_MARKER && dispatch(get_actions(vk)) {
                 return LRESULT(1);
             }
         }
@@ -103,14 +174,108 @@ unsafe extern "system" fn keyboard_hook_proc(code: i32, wparam: WPARAM, lparam:
     unsafe { CallNextHookEx(None, code, wparam, lparam) }
 }
 
-fn get_actions(vk: VIRTUAL_KEY) -> Option<Actions> {
+unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code >= 0 {
+        let msg = wparam.0 as u32;
+        // Plain scrolling never matches, so it skips the keymap lock.
+        let modifiers = (msg == WM_MOUSEWHEEL || msg == WM_MOUSEHWHEEL)
+            .then(current_modifiers)
+            .filter(|m| !m.is_empty());
+        if let Some(modifiers) = modifiers {
+            let ms_struct = unsafe { &*(lparam.0 as *const MSLLHOOKSTRUCT) };
+            // The high word is the signed wheel delta: positive away from the
+            // user for the vertical wheel, to the right for the horizontal.
+            let delta = (ms_struct.mouseData >> 16) as u16 as i16;
+            let key = match (msg == WM_MOUSEWHEEL, delta > 0) {
+                (true, true) => "wheelup",
+                (true, false) => "wheeldown",
+                (false, true) => "wheelright",
+                (false, false) => "wheelleft",
+            };
+            if delta != 0 && dispatch(get_wheel_actions(key, modifiers)) {
+                return LRESULT(1);
+            }
+        }
+    }
+    unsafe { CallNextHookEx(None, code, wparam, lparam) }
+}
+
+/// Send what a hook resolved to the hub thread. Returns whether the input
+/// should be swallowed.
+fn dispatch((actions, entered_mode): (Option<Actions>, Option<String>)) -> bool {
+    if let Some(name) = entered_mode
+        && let Some(state) = STATE.get()
+    {
+        // Already switched in resolve_key. The hub thread is told only to
+        // fire `mode_entered` hooks and time out pending sequences.
+        state.sender.send(HubEvent::ModeEntered(name));
+    }
+    let Some(actions) = actions else {
+        return false;
+    };
+    // Empty for the prefix of a key sequence: swallowed, nothing to run yet.
+    if !actions.is_empty()
+        && let Some(state) = STATE.get()
+    {
+        state.sender.send(HubEvent::Action(actions));
+    }
+    true
+}
+
+fn get_actions(vk: VIRTUAL_KEY) -> (Option<Actions>, Option<String>) {
     if matches!(
         vk,
         VK_SHIFT | VK_CONTROL | VK_MENU | VK_LWIN | VK_RWIN | VK_LMENU | VK_RMENU
     ) {
-        return None;
+        return (None, None);
+    }
+
+    let Some(key) = vk_to_string(vk) else {
+        return (None, None);
+    };
+    let keymap = Keymap {
+        key,
+        modifiers: current_modifiers(),
+    };
+
+    let Some(state) = STATE.get() else {
+        return (None, None);
+    };
+    let Ok(mut ks) = state.keymap_state.write() else {
+        return (None, None);
+    };
+    let (actions, entered_mode) = ks.resolve_key(&keymap);
+    let replay = ks.take_replay();
+    drop(ks);
+    replay_keys(&replay);
+    if let Some(actions) = &actions {
+        tracing::trace!(?keymap, %actions, "Keymap matched");
     }
+    (actions, entered_mode)
+}
 
+fn get_wheel_actions(key: &str, modifiers: Modifiers) -> (Option<Actions>, Option<String>) {
+    let keymap = Keymap {
+        key: key.to_string(),
+        modifiers,
+    };
+    let Some(state) = STATE.get() else {
+        return (None, None);
+    };
+    let Ok(mut ks) = state.keymap_state.write() else {
+        return (None, None);
+    };
+    let (actions, entered_mode) = ks.resolve_wheel(&keymap);
+    let replay = ks.take_replay();
+    drop(ks);
+    replay_keys(&replay);
+    if let Some(actions) = &actions {
+        tracing::trace!(?keymap, %actions, "Keymap matched");
+    }
+    (actions, entered_mode)
+}
+
+fn current_modifiers() -> Modifiers {
     let mut modifiers = Modifiers::empty();
     if is_key_pressed(VK_LWIN) || is_key_pressed(VK_RWIN) {
         modifiers |= Modifiers::META;
@@ -124,16 +289,69 @@ fn get_actions(vk: VIRTUAL_KEY) -> Option<Actions> {
     if is_key_pressed(VK_CONTROL) {
         modifiers |= Modifiers::CTRL;
     }
+    modifiers
+}
+
+/// Type `keys` again for the focused app, after a key sequence that swallowed
+/// them was abandoned. Each key is pressed with its own modifiers: held ones
+/// it lacks are released around it, missing ones pressed around it.
+pub(super) fn replay_keys(keys: &[Keymap]) {
+    if keys.is_empty() {
+        return;
+    }
+    let held = current_modifiers();
+    let mut inputs = Vec::new();
+    for keymap in keys {
+        let Some(vk) = string_to_vk(&keymap.key) else {
+            tracing::debug!(?keymap, "No virtual key to replay");
+            continue;
+        };
+        let extra = modifier_keys(held.difference(keymap.modifiers));
+        let missing = modifier_keys(keymap.modifiers.difference(held));
+        inputs.extend(extra.iter().map(|&m| key_input(m, true)));
+        inputs.extend(missing.iter().map(|&m| key_input(m, false)));
+        inputs.push(key_input(vk, false));
+        inputs.push(key_input(vk, true));
+        inputs.extend(missing.iter().rev().map(|&m| key_input(m, true)));
+        inputs.extend(extra.iter().rev().map(|&m| key_input(m, false)));
+    }
+    tracing::debug!(?keys, "Replaying abandoned keys");
+    unsafe { SendInput(&inputs, size_of::<INPUT>() as i32) };
+}
 
-    let key = vk_to_string(vk)?;
-    let keymap = Keymap { key, modifiers };
+fn modifier_keys(modifiers: Modifiers) -> Vec<VIRTUAL_KEY> {
+    [
+        (Modifiers::META, VK_LWIN),
+        (Modifiers::SHIFT, VK_SHIFT),
+        (Modifiers::ALT, VK_MENU),
+        (Modifiers::CTRL, VK_CONTROL),
+    ]
+    .into_iter()
+    .filter(|&(m, _)| modifiers.contains(m))
+    .map(|(_, vk)| vk)
+    .collect()
+}
 
-    let state = STATE.get()?;
-    let mut ks = state.keymap_state.write().ok()?;
-    let actions = ks.resolve(&keymap)?;
-    drop(ks);
-    tracing::trace!(?keymap, %actions, "Keymap matched");
-    Some(actions)
+fn key_input(vk: VIRTUAL_KEY, up: bool) -> INPUT {
+    let mut flags = KEYBD_EVENT_FLAGS(0);
+    if up {
+        flags |= KEYEVENTF_KEYUP;
+    }
+    if matches!(vk, VK_LWIN | VK_UP | VK_DOWN | VK_LEFT | VK_RIGHT) {
+        flags |= KEYEVENTF_EXTENDEDKEY;
+    }
+    INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: vk,
+                dwFlags: flags,
+                dwExtraInfo: REPLAY_MARKER,
+                // wScan and time zeroed: the system fills them in.
+                ..Default::default()
+            },
+        },
+    }
 }
 
 fn is_key_pressed(vk: VIRTUAL_KEY) -> bool {
@@ -141,25 +359,22 @@ fn is_key_pressed(vk: VIRTUAL_KEY) -> bool {
 }
 
 fn vk_to_string(vk: VIRTUAL_KEY) -> Option<String> {
-    let s = match vk {
-        VK_RETURN => "return",
-        VK_BACK => "backspace",
-        VK_ESCAPE => "escape",
-        VK_TAB => "tab",
-        VK_SPACE => "space",
-        VK_UP => "up",
-        VK_DOWN => "down",
-        VK_LEFT => "left",
-        VK_RIGHT => "right",
-        VK_OEM_4 => "[",
-        VK_OEM_6 => "]",
-        _ => {
-            let code = vk.0 as u8;
-            if matches!(code, b'0'..=b'9' | b'A'..=b'Z') {
-                return Some((code.to_ascii_lowercase() as char).to_string());
-            }
-            return None;
-        }
-    };
-    Some(s.to_string())
+    if let Some(&(_, name)) = NAMED_KEYS.iter().find(|&&(named, _)| named == vk) {
+        return Some(name.to_string());
+    }
+    let code = vk.0 as u8;
+    if matches!(code, b'0'..=b'9' | b'A'..=b'Z') {
+        return Some((code.to_ascii_lowercase() as char).to_string());
+    }
+    None
+}
+
+fn string_to_vk(key: &str) -> Option<VIRTUAL_KEY> {
+    if let Some(&(vk, _)) = NAMED_KEYS.iter().find(|&&(_, name)| name == key) {
+        return Some(vk);
+    }
+    match key.as_bytes() {
+        &[c @ (b'0'..=b'9' | b'a'..=b'z')] => Some(VIRTUAL_KEY(c.to_ascii_uppercase().into())),
+        _ => None,
+    }
 }
@@ -1,3 +1,4 @@
+mod appearance;
 mod dome;
 mod event_listener;
 mod external;
@@ -44,15 +45,16 @@ use windows::Win32::UI::WindowsAndMessaging::{
 use windows::core::BOOL;
 
 use crate::config::{
-    Config, LayoutConfig, LayoutWorkspaceConfig, layout_default_path, load_or_default,
-    start_config_watcher,
+    Config, LayoutConfig, LayoutWorkspaceConfig, layout_default_path, start_config_watcher,
 };
+use crate::diagnostics::{ConfigFile, load_reported};
 use crate::ipc;
 use crate::keymap::KeymapState;
 use dome::app_window::{APP_WINDOW_CLASS, AppWindow, app_wnd_proc};
 use dome::overlay::{
-    FLOAT_OVERLAY_CLASS, TAB_BAR_OVERLAY_CLASS, TILING_OVERLAY_CLASS, WgpuOverlayFactory,
-    tab_bar_overlay_wnd_proc, tiling_overlay_wnd_proc,
+    FLOAT_OVERLAY_CLASS, OVERVIEW_OVERLAY_CLASS, TAB_BAR_OVERLAY_CLASS, TILING_OVERLAY_CLASS,
+    WgpuOverlayFactory, overview_overlay_wnd_proc, tab_bar_overlay_wnd_proc,
+    tiling_overlay_wnd_proc,
 };
 use dome::{Dome, HubEvent};
 use event_listener::install_event_hooks;
@@ -113,6 +115,8 @@ pub(super) const WM_APP_DISPATCH_RESULT: u32 = WM_APP + 2;
 /// on WM_DPICHANGED; decoded by the dome-thread message loop.
 pub(super) const WM_APP_DPI_CHANGE: u32 = WM_APP + 3;
 pub(super) const WM_APP_WORKAREA_CHANGE: u32 = WM_APP + 4;
+/// Posted when the light/dark app mode may have changed.
+pub(super) const WM_APP_APPEARANCE_CHANGE: u32 = WM_APP + 6;
 /// Not exported by the `windows` crate as of v0.62. Defined in WinUser.h.
 /// Sent before WM_DPICHANGED; the handler writes the desired scaled window
 /// size into the SIZE* at lparam and returns TRUE.
@@ -169,7 +173,7 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> Resu
     let logger = Logger::init();
 
     let config_path = config_path.unwrap_or_else(Config::default_path);
-    let config = load_or_default(&config_path, Config::load);
+    let (config, config_report) = load_reported(ConfigFile::Config, &config_path, Config::load);
     logger.set_level(config.log_level);
     tracing::info!(%config_path, "Loaded config");
 
@@ -178,7 +182,8 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> Resu
             .to_string_lossy()
             .into_owned()
     });
-    let layout = load_or_default(&layout_path, LayoutConfig::load);
+    let (layout, layout_report) =
+        load_reported(ConfigFile::Layout, &layout_path, LayoutConfig::load);
     tracing::info!(path = %layout_path, "Loaded layout");
 
     login_item::sync_login_item(config.start_at_login);
@@ -232,7 +237,11 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> Resu
         thread_id: dome_thread_id.load(std::sync::atomic::Ordering::Acquire),
     };
 
-    let keyboard_hook = install_keyboard_hook(hub_sender.clone(), Arc::clone(&keymap_state))?;
+    let keyboard_hook = install_keyboard_hook(
+        hub_sender.clone(),
+        Arc::clone(&keymap_state),
+        config.keymaps.binds_wheel(),
+    )?;
     let _event_hooks = install_event_hooks(hub_sender.clone())?;
 
     ipc::start_server(layout_path.clone(), {
@@ -250,27 +259,44 @@ pub fn run_app(config_path: Option<String>, layout_path: Option<String>) -> Resu
         }
     })?;
 
-    let _config_watcher = start_config_watcher(&config_path, Config::load, {
-        let sender = hub_sender.clone();
-        let keymap_state = Arc::clone(&keymap_state);
-        move |cfg| {
-            logger.set_level(cfg.log_level);
-            keymap_state
-                .write()
-                .unwrap()
-                .update_keymaps(cfg.keymaps.clone());
-            let start_at_login = cfg.start_at_login;
-            sender.send(HubEvent::ConfigChanged(Box::new(cfg)));
-            login_item::sync_login_item(start_at_login);
-        }
-    })
+    hub_sender.send(HubEvent::ConfigReport(Box::new(config_report)));
+    hub_sender.send(HubEvent::ConfigReport(Box::new(layout_report)));
+
+    start_config_watcher(
+        &config_path,
+        ConfigFile::Config,
+        &config.includes,
+        Config::load,
+        {
+            let sender = hub_sender.clone();
+            let keymap_state = Arc::clone(&keymap_state);
+            let hook_thread = keyboard_hook.thread();
+            move |cfg, report| {
+                if let Some(cfg) = cfg {
+                    logger.set_level(cfg.log_level);
+                    keymap_state
+                        .write()
+                        .unwrap()
+                        .update_keymaps(cfg.keymaps.clone());
+                    hook_thread.set_mouse_hook(cfg.keymaps.binds_wheel());
+                    let start_at_login = cfg.start_at_login;
+                    sender.send(HubEvent::ConfigChanged(Box::new(cfg)));
+                    login_item::sync_login_item(start_at_login);
+                }
+                sender.send(HubEvent::ConfigReport(Box::new(report)));
+            }
+        },
+    )
     .inspect_err(|e| tracing::warn!("Failed to setup config watcher: {e:#}"))
     .ok();
 
-    let _layout_watcher = start_config_watcher(&layout_path, LayoutConfig::load, {
+    start_config_watcher(&layout_path, ConfigFile::Layout, &[], LayoutConfig::load, {
         let sender = hub_sender.clone();
-        move |new_layout| {
-            sender.send(HubEvent::LayoutConfigChanged(Box::new(new_layout)));
+        move |new_layout, report| {
+            if let Some(new_layout) = new_layout {
+                sender.send(HubEvent::LayoutConfigChanged(Box::new(new_layout)));
+            }
+            sender.send(HubEvent::ConfigReport(Box::new(report)));
         }
     })
     .inspect_err(|e| tracing::warn!("Failed to setup layout watcher: {e:#}"))
@@ -415,6 +441,15 @@ fn run_dome(
     };
     unsafe { RegisterClassW(&wc_tab_bar) };
 
+    let wc_overview = WNDCLASSW {
+        lpfnWndProc: Some(overview_overlay_wnd_proc),
+        hInstance: hinstance.into(),
+        lpszClassName: OVERVIEW_OVERLAY_CLASS,
+        hCursor: arrow,
+        ..Default::default()
+    };
+    unsafe { RegisterClassW(&wc_overview) };
+
     let wc_app = WNDCLASSW {
         lpfnWndProc: Some(app_wnd_proc),
         hInstance: hinstance.into(),
@@ -471,6 +506,7 @@ fn run_dome(
         Box::new(overlays),
         Box::new(dome::Win32Display),
         app_window,
+        appearance::system_appearance(),
     )
     .expect("Failed to initialize Dome");
 
@@ -511,6 +547,9 @@ fn run_dome(
                 WM_APP_WORKAREA_CHANGE => {
                     runner.handle_work_area_change();
                 }
+                WM_APP_APPEARANCE_CHANGE => {
+                    runner.handle_appearance_change();
+                }
                 WM_APP_DISPATCH_RESULT => {
                     let apply = *Box::from_raw(msg.wParam.0 as *mut runner::ApplyFn);
                     apply(&mut runner);
@@ -523,6 +562,9 @@ fn run_dome(
                     DispatchMessageW(&msg);
                 }
             }
+            runner.run_hooks();
+            runner.sync_keymap_focus();
+            runner.schedule_animation_frame();
         }
     }
 }
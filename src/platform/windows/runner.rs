@@ -4,13 +4,16 @@ use std::time::{Duration, Instant};
 use windows::Win32::Foundation::{LPARAM, WPARAM};
 use windows::Win32::UI::WindowsAndMessaging::{PostQuitMessage, PostThreadMessageW, WM_QUIT};
 
-use crate::action::{Action, Actions};
-use crate::core::{Physical, PixelRect};
+use crate::action::{Action, Actions, FocusTarget};
+use crate::core::{HINT_MODE, OVERVIEW_MODE, Physical, PixelRect, WindowId};
 use crate::keymap::KeymapState;
+use crate::overlay::OverviewEvent;
 use crate::platform::windows::WM_APP_DISPATCH_RESULT;
+use crate::platform::windows::appearance;
 use crate::platform::windows::dome::{Dome, HubEvent, NewWindow, WindowsMetadata};
 use crate::platform::windows::external::{HwndId, InspectExternalWindow, ManageExternalWindow};
 use crate::platform::windows::handle::ExternalHwnd;
+use crate::platform::windows::keyboard;
 use crate::platform::windows::throttle::{Throttle, ThrottleResult};
 use crate::platform::windows::timer_registry::{TimerKind, TimerRegistry, Win32Timer};
 
@@ -18,6 +21,9 @@ const FOCUS_THROTTLE_INTERVAL: Duration = Duration::from_millis(500);
 const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(100);
 const DRAG_SAFETY_TIMEOUT: Duration = Duration::from_secs(60);
 const DRIFT_RETRY_INTERVAL: Duration = Duration::from_secs(2);
+/// Hook actions can fire further hooks, e.g. a `workspace_focused` hook that
+/// focuses another workspace. Rounds are capped so a cycle cannot spin.
+const MAX_HOOK_ROUNDS: usize = 8;
 
 pub(super) struct Runner {
     dome: Dome,
@@ -29,6 +35,8 @@ pub(super) struct Runner {
     timers: TimerRegistry,
     main_thread_id: u32,
     keymap_state: Arc<RwLock<KeymapState>>,
+    /// The frame `TimerKind::AnimationFrame` is armed for, if any.
+    animation_frame: Option<Instant>,
 }
 
 impl Runner {
@@ -47,6 +55,7 @@ impl Runner {
             timers,
             main_thread_id,
             keymap_state,
+            animation_frame: None,
         }
     }
 
@@ -68,6 +77,22 @@ impl Runner {
             TimerKind::DriftRetry => {
                 self.dome.retry_drifted_windows();
             }
+            TimerKind::KeymapDeadline => {
+                let (expired, replay) = {
+                    let mut ks = self.keymap_state.write().unwrap();
+                    let expired = ks.expire(Instant::now()).then(|| ks.mode_label());
+                    (expired, ks.take_replay())
+                };
+                keyboard::replay_keys(&replay);
+                if let Some(mode) = expired {
+                    self.dome.mode_entered(&mode);
+                }
+                self.schedule_keymap_deadline();
+            }
+            TimerKind::AnimationFrame => {
+                self.animation_frame = None;
+                self.dome.apply_animation_frame();
+            }
         }
     }
 
@@ -83,6 +108,9 @@ impl Runner {
             HubEvent::LayoutConfigChanged(c) => {
                 self.dome.layout_changed(*c);
             }
+            HubEvent::ConfigReport(report) => {
+                self.dome.config_reported(*report);
+            }
             HubEvent::WindowCreated(hwnd_id) => {
                 self.dispatch_window_created(hwnd_id);
             }
@@ -94,6 +122,9 @@ impl Runner {
             HubEvent::WindowMinimized(hwnd_id) => {
                 self.dome.window_minimized(hwnd_id);
             }
+            HubEvent::WindowUrgent(hwnd_id) => {
+                self.dome.window_urgent(hwnd_id);
+            }
             HubEvent::WindowRestored {
                 hwnd_id,
                 observed_at,
@@ -163,19 +194,37 @@ impl Runner {
             HubEvent::Action(a) => {
                 self.handle_actions(&a);
             }
+            HubEvent::ModeEntered(name) => {
+                self.dome.mode_entered(&name);
+                self.schedule_keymap_deadline();
+            }
             HubEvent::Query { query, sender } => {
                 let json = match query {
                     crate::action::Query::Workspaces => self.dome.query_workspaces_json(),
                     crate::action::Query::MinimizedWindows => {
                         self.dome.query_minimized_windows_json()
                     }
+                    crate::action::Query::Tree => self.dome.query_tree_json(),
+                    crate::action::Query::Keymaps => {
+                        self.keymap_state.read().unwrap().query_keymaps_json()
+                    }
+                    crate::action::Query::Mode => {
+                        self.keymap_state.read().unwrap().query_mode_json()
+                    }
+                    crate::action::Query::ConfigStatus => self.dome.query_config_status(),
+                    crate::action::Query::Render { format, workspace } => {
+                        self.dome.query_render_json(format, workspace.as_deref())
+                    }
                 };
                 if sender.send(json).is_err() {
                     tracing::debug!("Query response dropped -- receiver gone");
                 }
             }
-            HubEvent::TabClicked(id, idx) => {
-                self.dome.tab_clicked(id, idx);
+            HubEvent::TabBar(id, event) => {
+                self.dome.tab_bar_event(id, event);
+            }
+            HubEvent::Overview(event) => {
+                self.overview_event(event);
             }
             HubEvent::ExportLayout(path) => {
                 self.dome.export_layout(std::path::Path::new(&path));
@@ -183,13 +232,112 @@ impl Runner {
         }
     }
 
-    #[tracing::instrument(skip(self))]
     fn handle_actions(&mut self, actions: &Actions) {
+        self.run_actions(actions, &[], None);
+    }
+
+    /// A click picks like a typed label and leaves overview mode. A drop
+    /// moves the window and keeps the overview open for the next one.
+    fn overview_event(&mut self, event: OverviewEvent) {
+        let picked = match event {
+            OverviewEvent::MoveWindow { window, workspace } => {
+                self.dome.move_window_to_workspace(window, &workspace);
+                self.dome.apply_layout();
+                return;
+            }
+            OverviewEvent::FocusWorkspace(name) => {
+                Some(Action::Focus(FocusTarget::Workspace { name }))
+            }
+            OverviewEvent::FocusWindow(id) => Some(Action::FocusWindow(id)),
+            OverviewEvent::Dismiss => None,
+        };
+        let mut actions = vec![Action::Mode {
+            name: "default".to_string(),
+        }];
+        actions.extend(picked);
+        self.run_actions(&Actions::new(actions), &[], None);
+    }
+
+    /// Arms the timer that drops a half-typed key sequence or leaves a mode
+    /// with `timeout_ms`. Keypresses push the deadline later without telling
+    /// the hub, so an early firing just re-arms.
+    fn schedule_keymap_deadline(&mut self) {
+        let deadline = self.keymap_state.read().unwrap().next_deadline();
+        if let Some(deadline) = deadline {
+            self.timers
+                .schedule_keymap_deadline(deadline.saturating_duration_since(Instant::now()));
+        }
+    }
+
+    /// Arms the timer for the next animation frame, if any window is still on
+    /// its way. Called once per message, so any `apply_layout` that retargets
+    /// a window moves the deadline along with it.
+    pub(super) fn schedule_animation_frame(&mut self) {
+        let next = self.dome.next_animation_frame();
+        if self.animation_frame == next {
+            return;
+        }
+        self.animation_frame = next;
+        match next {
+            Some(frame) => self
+                .timers
+                .schedule_animation_frame(frame.saturating_duration_since(Instant::now())),
+            None => self.timers.cancel_animation_frame(),
+        }
+    }
+
+    /// Tells the keyboard hook which window is focused so `[[keymaps.app]]`
+    /// overrides follow it. Called once per message after hooks ran.
+    pub(super) fn sync_keymap_focus(&mut self) {
+        self.keymap_state
+            .write()
+            .unwrap()
+            .set_focused_window(self.dome.focused_window_metadata());
+    }
+
+    /// Runs hook actions queued by the last message. Called once per message
+    /// from the loop so hooks see the layout their event produced.
+    pub(super) fn run_hooks(&mut self) {
+        for _ in 0..MAX_HOOK_ROUNDS {
+            let runs = self.dome.take_hook_runs();
+            if runs.is_empty() {
+                return;
+            }
+            for run in runs {
+                self.run_actions(&run.actions, &run.env, run.window);
+            }
+        }
+        let dropped = self.dome.take_hook_runs().len();
+        if dropped > 0 {
+            tracing::warn!(
+                dropped,
+                "Hooks kept triggering each other, dropping the rest"
+            );
+        }
+    }
+
+    /// `env` and `window` are only set for hook actions. `env` only reaches
+    /// `exec`, and `window` has focus while the actions run so they act on it.
+    #[tracing::instrument(skip(self, env))]
+    fn run_actions(&mut self, actions: &Actions, env: &[(&str, String)], window: Option<WindowId>) {
+        let restore = window.and_then(|id| self.dome.focus_for_hook(id));
         for action in actions {
             match action {
+                Action::Focus(FocusTarget::Hint) => {
+                    if let Some(session) = self.dome.show_hints() {
+                        self.keymap_state.write().unwrap().start_hints(session);
+                        self.dome.mode_entered(HINT_MODE);
+                    }
+                }
                 Action::Focus(t) => {
                     self.dome.apply_focus(t);
                 }
+                Action::Overview => {
+                    if let Some(session) = self.dome.show_overview() {
+                        self.keymap_state.write().unwrap().start_overview(session);
+                        self.dome.mode_entered(OVERVIEW_MODE);
+                    }
+                }
                 Action::Move(t) => {
                     self.dome.apply_move(t);
                 }
@@ -199,8 +347,14 @@ impl Runner {
                 Action::Master(t) => {
                     self.dome.apply_master(t);
                 }
+                Action::Float(t) => {
+                    self.dome.apply_float(t);
+                }
+                Action::Scroll(t) => {
+                    self.dome.apply_scroll(t);
+                }
                 Action::Exec { command } => {
-                    if let Err(e) = crate::platform::windows::spawn::spawn(command) {
+                    if let Err(e) = crate::platform::windows::spawn::spawn(command, env) {
                         tracing::warn!(%command, "Failed to exec: {e:#}");
                     }
                 }
@@ -216,12 +370,27 @@ impl Runner {
                 Action::UnminimizeWindow(id) => {
                     self.dome.unminimize_window(*id);
                 }
+                Action::FocusWindow(id) => {
+                    self.dome.focus_window(*id);
+                }
+                Action::Mark { name } => {
+                    self.dome.mark_focused(name);
+                }
+                Action::Theme { flavor } => {
+                    self.dome.set_theme(*flavor);
+                }
                 Action::Mode { name } => {
-                    self.keymap_state.write().unwrap().switch_mode(name);
+                    if self.keymap_state.write().unwrap().switch_mode(name) {
+                        self.dome.mode_entered(name);
+                        self.schedule_keymap_deadline();
+                    }
                     tracing::debug!(mode = %name, "Switching to mode");
                 }
             }
         }
+        if let Some(restore) = restore {
+            self.dome.restore_focus(restore);
+        }
         self.dome.apply_layout();
     }
 
@@ -318,6 +487,12 @@ impl Runner {
         self.dome.apply_layout();
     }
 
+    pub(super) fn handle_appearance_change(&mut self) {
+        self.dome
+            .appearance_changed(appearance::system_appearance());
+        self.dome.apply_layout();
+    }
+
     pub(super) fn handle_dpi_change(&mut self, handle: isize, dpi: u32) {
         self.dome.monitor_dpi_changed(handle, dpi);
         // apply_layout is idempotent: runs even when monitor_dpi_changed
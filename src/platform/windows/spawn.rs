@@ -13,11 +13,18 @@ use windows::core::PCWSTR;
 /// Returns `Ok(())` when `ShellExecuteW` reports success (>32). Returns
 /// `Err` on failure, including when the user cancels a UAC prompt or
 /// the association is missing.
-pub(super) fn spawn(command: &str) -> Result<(), anyhow::Error> {
+///
+/// `ShellExecuteW` cannot pass an environment, so a non-empty `env` runs the
+/// command through `cmd.exe /C` instead, with no console window. Hook
+/// commands take this path to see their `DOME_*` variables.
+pub(super) fn spawn(command: &str, env: &[(&str, String)]) -> Result<(), anyhow::Error> {
     let command = command.trim();
     if command.is_empty() {
         return Ok(());
     }
+    if !env.is_empty() {
+        return spawn_with_env(command, env);
+    }
 
     let (program, args) = split_first_arg(command);
 
@@ -63,6 +70,20 @@ pub(super) fn spawn(command: &str) -> Result<(), anyhow::Error> {
     }
 }
 
+fn spawn_with_env(command: &str, env: &[(&str, String)]) -> Result<(), anyhow::Error> {
+    use std::os::windows::process::CommandExt;
+    use windows::Win32::System::Threading::CREATE_NO_WINDOW;
+
+    std::process::Command::new("cmd.exe")
+        .arg("/C")
+        .raw_arg(command)
+        .envs(env.iter().map(|(k, v)| (*k, v.as_str())))
+        .creation_flags(CREATE_NO_WINDOW.0)
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("cmd.exe /C failed: {e}"))?;
+    Ok(())
+}
+
 /// Splits `input` into `(first_token, rest)` on the first whitespace run.
 ///
 /// Handles quoted tokens: `"foo bar" baz` → `(foo bar, baz)`.
@@ -45,7 +45,7 @@ fn config_reload_dispatches_apply_theme_on_flavor_change() {
     }
 
     let mut new_config = env.config.clone();
-    new_config.theme = crate::theme::Flavor::Latte;
+    new_config.theme.flavor = crate::theme::Flavor::Latte;
     env.dome.config_changed(new_config);
 
     // After a flavor change, overlays must end up holding Latte.
@@ -56,6 +56,43 @@ fn config_reload_dispatches_apply_theme_on_flavor_change() {
     }
 }
 
+#[test]
+fn appearance_change_switches_light_dark_flavor() {
+    let mut config = Config::default();
+    config.theme.light_dark = Some(crate::theme::LightDark::default());
+    let mut env = TestEnv::new_with_config(config);
+    let _w1 = env.open(1, "App1", "app1.exe", SPAWN_DIM);
+    env.run_actions("toggle float");
+    assert_eq!(env.tiling_overlays()[0].flavor, crate::theme::Flavor::Mocha);
+
+    env.dome.appearance_changed(crate::theme::Appearance::Light);
+    assert_eq!(env.tiling_overlays()[0].flavor, crate::theme::Flavor::Latte);
+    for f in &env.float_overlays() {
+        assert_eq!(f.flavor, crate::theme::Flavor::Latte);
+    }
+}
+
+#[test]
+fn theme_set_holds_until_config_reload() {
+    let mut env = TestEnv::new();
+    let _w1 = env.open(1, "App1", "app1.exe", SPAWN_DIM);
+    env.run_actions("theme set frappe");
+    assert_eq!(
+        env.tiling_overlays()[0].flavor,
+        crate::theme::Flavor::Frappe
+    );
+
+    // An appearance change does not undo an explicit choice.
+    env.dome.appearance_changed(crate::theme::Appearance::Light);
+    assert_eq!(
+        env.tiling_overlays()[0].flavor,
+        crate::theme::Flavor::Frappe
+    );
+
+    env.dome.config_changed(env.config.clone());
+    assert_eq!(env.tiling_overlays()[0].flavor, crate::theme::Flavor::Mocha);
+}
+
 #[test]
 fn config_reload_dispatches_apply_font_on_font_change() {
     let mut env = TestEnv::new();
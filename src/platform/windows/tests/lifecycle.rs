@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
 use super::*;
-use crate::config::{Config, LayoutConfig, PartitionTreeConfig, WindowMatcher};
+use crate::config::{
+    Config, LayoutConfig, PartitionTreeConfig, WindowMatcher, default_partition_tree_config,
+};
 use crate::core::GlobalLayoutConfig;
+use crate::overlay::TabBarEvent;
 
 /// Count minimized windows tracked by the daemon by parsing the same JSON
 /// blob external launchers consume via `Query::MinimizedWindows`.
@@ -274,6 +277,7 @@ fn focus_child_after_parent_does_not_focus_overlay() {
             partition_tree: PartitionTreeConfig {
                 automatic_tiling: false,
                 tab_bar_height: Pixels::new(24),
+                ..default_partition_tree_config()
             },
             ..GlobalLayoutConfig::default()
         },
@@ -560,6 +564,30 @@ fn tab_bar_lifecycle_per_container() {
     assert!(env.tab_bars.borrow().is_empty());
 }
 
+#[test]
+fn tab_bar_icons_follow_config() {
+    let mut env = TestEnv::new();
+    env.open(1, "App1", "app1.exe", SPAWN_DIM);
+    env.open(2, "App2", "app2.exe", SPAWN_DIM);
+    env.run_actions("toggle layout");
+    let icon_count = |env: &TestEnv| {
+        let tab_bars = env.tab_bars.borrow();
+        tab_bars
+            .values()
+            .next()
+            .unwrap()
+            .last_update()
+            .unwrap()
+            .icon_count
+    };
+    assert_eq!(icon_count(&env), 2);
+
+    let mut config = env.config.clone();
+    config.partition_tree.tab_icons = false;
+    env.dome.config_changed(config);
+    assert_eq!(icon_count(&env), 0);
+}
+
 #[test]
 fn tab_click_focuses_tab_index() {
     let mut env = TestEnv::new();
@@ -579,7 +607,7 @@ fn tab_click_focuses_tab_index() {
         .active_index;
     assert_eq!(initial_active, 1);
 
-    env.dome.tab_clicked(cid, 0);
+    env.dome.tab_bar_event(cid, TabBarEvent::Select(0));
 
     let after_active = env
         .tab_bars
@@ -591,3 +619,77 @@ fn tab_click_focuses_tab_index() {
         .active_index;
     assert_eq!(after_active, 0);
 }
+
+#[test]
+fn tab_drag_reorders_and_splits_out() {
+    let mut env = TestEnv::new();
+    env.open(1, "App1", "app1.exe", SPAWN_DIM);
+    env.open(2, "App2", "app2.exe", SPAWN_DIM);
+    env.open(3, "App3", "app3.exe", SPAWN_DIM);
+
+    env.run_actions("toggle layout");
+    let cid = *env.tab_bars.borrow().keys().next().unwrap();
+    let titles = |env: &TestEnv| env.tab_bars.borrow()[&cid].last_update().unwrap().titles;
+
+    env.dome
+        .tab_bar_event(cid, TabBarEvent::Move { from: 2, to: 0 });
+    assert_eq!(titles(&env), ["App3", "App1", "App2"]);
+
+    env.dome.tab_bar_event(cid, TabBarEvent::DragOut(0));
+    assert_eq!(titles(&env), ["App1", "App2"]);
+}
+
+#[test]
+fn overview_window_lives_until_mode_changes() {
+    let mut env = TestEnv::new();
+    let w1 = env.open(1, "App1", "app1.exe", SPAWN_DIM);
+    env.open(2, "App2", "app2.exe", SPAWN_DIM);
+    env.run_actions("focus workspace web");
+    env.open(3, "App3", "app3.exe", SPAWN_DIM);
+    env.run_actions("focus workspace 0");
+
+    assert!(env.dome.show_overview().is_some());
+    env.dome.apply_layout();
+    let shown = env.overview.shown().expect("overview window should exist");
+    let names: Vec<_> = shown.iter().map(|ws| ws.name.as_str()).collect();
+    assert_eq!(names, ["0", "web"]);
+
+    // A drop keeps the overview open on the updated diagram.
+    let id = shown[0]
+        .windows
+        .iter()
+        .find(|w| w.title == "App1")
+        .unwrap()
+        .id;
+    env.dome.move_window_to_workspace(id, "web");
+    env.dome.apply_layout();
+    let shown = env.overview.shown().unwrap();
+    assert_eq!(shown[0].windows.len(), 1);
+    assert_eq!(shown[1].windows.len(), 2);
+    assert!(env.is_offscreen(w1));
+
+    env.dome.mode_entered("default");
+    assert!(env.overview.shown().is_none());
+}
+
+#[test]
+fn hints_show_on_tiling_overlay_until_mode_changes() {
+    let mut env = TestEnv::new();
+    env.open(1, "App1", "app1.exe", SPAWN_DIM);
+    env.open(2, "App2", "app2.exe", SPAWN_DIM);
+
+    assert!(env.dome.show_hints().is_some());
+    env.dome.apply_layout();
+    let TilingOverlayState::Visible { hints, .. } = env.tiling_overlays()[0].state.clone() else {
+        panic!("tiling overlay should be visible with hints");
+    };
+    let labels: Vec<_> = hints.iter().map(|h| h.label.as_str()).collect();
+    assert_eq!(labels, ["a", "s"]);
+
+    // Leaving the hint mode (label typed or cancelled) takes the labels down.
+    env.dome.mode_entered("default");
+    let TilingOverlayState::Visible { hints, .. } = env.tiling_overlays()[0].state.clone() else {
+        panic!("tiling overlay should stay visible for borders");
+    };
+    assert!(hints.is_empty());
+}
@@ -13,17 +13,19 @@ use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use crate::action::{Action, Actions};
-use crate::config::{Config, LayoutConfig, LayoutWorkspaceConfig};
+use crate::config::{Config, LayoutConfig, LayoutWorkspaceConfig, TabBarPosition};
 use crate::core::GlobalLayoutConfig;
 use crate::core::{
-    ContainerId, ContainerPlacement, Dimension, Length, LimitObservation, LimitUpdate, Logical,
-    Physical, PixelRect, Pixels, TilingWindowPlacement, WindowId, WorkspaceInfo,
+    ContainerId, ContainerPlacement, Dimension, HintPlacement, Length, LimitObservation,
+    LimitUpdate, Logical, OverviewWorkspace, Physical, PixelRect, Pixels, ScrollExtent,
+    TilingWindowPlacement, WindowId, WorkspaceInfo,
 };
 use crate::font::FontConfig;
+use crate::overlay::TabIcon;
 use crate::platform::windows::dome::MonitorInfo;
 use crate::platform::windows::dome::app_window::AppWindowApi;
 use crate::platform::windows::dome::overlay::{
-    FloatOverlayApi, TabBarOverlayApi, TilingOverlayApi,
+    FloatOverlayApi, OverviewOverlayApi, TabBarOverlayApi, TilingOverlayApi,
 };
 use crate::platform::windows::dome::{
     CreateOverlay, Dome, NewWindow, QueryDisplay, WindowsMetadata,
@@ -39,6 +41,7 @@ enum TilingOverlayState {
     Hidden,
     Visible {
         windows: Vec<TilingWindowPlacement>,
+        hints: Vec<HintPlacement>,
         border_thickness: Pixels<Physical>,
     },
 }
@@ -178,6 +181,10 @@ impl QueryDisplay for MockDisplay {
     fn get_exclusive_fullscreen_hwnd(&self) -> Option<HwndId> {
         *self.exclusive_fullscreen_hwnd.lock().unwrap()
     }
+
+    fn cursor_position(&self) -> Option<(Pixels<Physical>, Pixels<Physical>)> {
+        None
+    }
 }
 
 struct TestEnv {
@@ -191,6 +198,7 @@ struct TestEnv {
     config: Config,
     overlays: Rc<RefCell<MockOverlays>>,
     tab_bars: Rc<RefCell<HashMap<ContainerId, MockTabBarOverlay>>>,
+    overview: MockOverview,
     z_stack: ZOrderStack,
     focus_target: Arc<Mutex<FocusTarget>>,
 }
@@ -242,6 +250,7 @@ impl TestEnv {
         let tiling_overlay_ids: Rc<RefCell<HashSet<HwndId>>> =
             Rc::new(RefCell::new(HashSet::new()));
         let tab_bars = Rc::new(RefCell::new(HashMap::new()));
+        let overview = MockOverview::default();
         let overlays = Rc::new(RefCell::new(MockOverlays {
             tiling_overlays: HashMap::new(),
             next_tiling_overlay_id: next_tiling_overlay_id.clone(),
@@ -251,6 +260,7 @@ impl TestEnv {
             z_stack: z_stack.clone(),
             next_float_overlay_id: next_float_overlay_id.clone(),
             tab_bars: tab_bars.clone(),
+            overview: overview.clone(),
         }));
 
         let dome = Dome::new(
@@ -260,6 +270,7 @@ impl TestEnv {
             Box::new(overlays.clone()),
             Box::new(display),
             Box::new(NoopAppWindow),
+            crate::theme::Appearance::Dark,
         )
         .unwrap();
         Self {
@@ -271,6 +282,7 @@ impl TestEnv {
             config,
             overlays,
             tab_bars,
+            overview,
             z_stack,
             focus_target,
         }
@@ -518,6 +530,7 @@ impl TestEnv {
             Action::Move(t) => self.dome.apply_move(t),
             Action::Toggle(t) => self.dome.apply_toggle(t),
             Action::Master(t) => self.dome.apply_master(t),
+            Action::Theme { flavor } => self.dome.set_theme(*flavor),
             _ => {}
         }
         self.dome.apply_layout();
@@ -898,6 +911,14 @@ impl ManageExternalWindow for MockExternalHwnd {
         false
     }
 
+    fn app_icon(&self) -> Option<TabIcon> {
+        Some(TabIcon {
+            key: egui::Id::new(("tab_icon", self.hwnd_id)),
+            size: [1, 1],
+            rgba: Arc::from([0u8, 0, 0, 255]),
+        })
+    }
+
     fn recover(&self, _was_maximized: bool) {
         let mut dim = self.dimension.lock().unwrap();
         dim.x = Length::new(100.0);
@@ -954,7 +975,8 @@ impl ManageTaskbar for NoopTaskbar {
 
 struct NoopAppWindow;
 impl AppWindowApi for NoopAppWindow {
-    fn update_tray(&self, _: &[WorkspaceInfo]) {}
+    fn update_tray(&self, _: &[WorkspaceInfo], _: Option<&str>) {}
+    fn notify(&self, _: &str) {}
 }
 
 #[derive(Clone)]
@@ -980,7 +1002,7 @@ impl MockFloatOverlay {
                 overlay_id,
                 stale: Cell::new(false),
                 state: Cell::new(FloatOverlayState::Hidden),
-                flavor: Cell::new(config.theme),
+                flavor: Cell::new(config.theme.flavor),
                 font: RefCell::new(config.font.clone()),
             }),
             overlays,
@@ -1014,7 +1036,7 @@ impl FloatOverlayApi for MockFloatOverlay {
         self.z_stack.remove(self.overlay_id);
     }
     fn set_config(&mut self, config: &Config) {
-        self.shared.flavor.set(config.theme);
+        self.shared.flavor.set(config.theme.flavor);
         *self.shared.font.borrow_mut() = config.font.clone();
         *self.config.borrow_mut() = config.clone();
     }
@@ -1052,7 +1074,7 @@ impl MockTilingOverlay {
             overlay_id,
             z_stack,
             state: Rc::new(RefCell::new(TilingOverlayState::Hidden)),
-            flavor: Rc::new(Cell::new(config.theme)),
+            flavor: Rc::new(Cell::new(config.theme.flavor)),
             font: Rc::new(RefCell::new(config.font.clone())),
             monitor: Rc::new(Cell::new(PixelRect::ZERO)),
             config: Rc::new(RefCell::new(config)),
@@ -1079,6 +1101,8 @@ impl TilingOverlayApi for MockTilingOverlay {
         monitor: PixelRect,
         windows: &[TilingWindowPlacement],
         _containers: &[(ContainerPlacement, Vec<String>)],
+        hints: &[HintPlacement],
+        _scroll_extents: &[ScrollExtent],
         _scale: f32,
         border_thickness: Pixels<Physical>,
     ) {
@@ -1091,6 +1115,7 @@ impl TilingOverlayApi for MockTilingOverlay {
         // where show_tiling's per-window lift maintains the invariant.
         *self.state.borrow_mut() = TilingOverlayState::Visible {
             windows: windows.to_vec(),
+            hints: hints.to_vec(),
             border_thickness,
         };
     }
@@ -1098,7 +1123,7 @@ impl TilingOverlayApi for MockTilingOverlay {
         *self.state.borrow_mut() = TilingOverlayState::Hidden;
     }
     fn set_config(&mut self, config: &Config) {
-        self.flavor.set(config.theme);
+        self.flavor.set(config.theme.flavor);
         *self.font.borrow_mut() = config.font.clone();
         *self.config.borrow_mut() = config.clone();
     }
@@ -1116,6 +1141,7 @@ impl TilingOverlayApi for MockTilingOverlay {
 #[derive(Clone, Debug)]
 struct TabBarUpdate {
     titles: Vec<String>,
+    icon_count: usize,
     active_index: usize,
 }
 
@@ -1156,7 +1182,9 @@ impl TabBarOverlayApi for MockTabBarHandle {
     fn update(
         &mut self,
         _rect: PixelRect,
+        _position: TabBarPosition,
         titles: Vec<String>,
+        icons: Vec<Option<TabIcon>>,
         active_index: usize,
         _is_highlighted: bool,
         _scale: f32,
@@ -1164,6 +1192,7 @@ impl TabBarOverlayApi for MockTabBarHandle {
     ) {
         *self.inner.last_update.borrow_mut() = Some(TabBarUpdate {
             titles,
+            icon_count: icons.iter().flatten().count(),
             active_index,
         });
     }
@@ -1177,6 +1206,34 @@ impl Drop for MockTabBarHandle {
     }
 }
 
+/// What the overview window last painted, or `None` while no overview window
+/// exists.
+#[derive(Clone, Default)]
+struct MockOverview(Rc<RefCell<Option<Vec<OverviewWorkspace>>>>);
+
+impl MockOverview {
+    fn shown(&self) -> Option<Vec<OverviewWorkspace>> {
+        self.0.borrow().clone()
+    }
+}
+
+/// Boxed into `Dome` in place of the overview window. Dropping it mirrors
+/// production destroying the window once the overview closes.
+struct MockOverviewHandle(MockOverview);
+
+impl OverviewOverlayApi for MockOverviewHandle {
+    fn update(&mut self, _monitor: PixelRect, workspaces: &[OverviewWorkspace], _scale: f32) {
+        *self.0.0.borrow_mut() = Some(workspaces.to_vec());
+    }
+    fn set_config(&mut self, _config: &Config) {}
+}
+
+impl Drop for MockOverviewHandle {
+    fn drop(&mut self) {
+        self.0.0.borrow_mut().take();
+    }
+}
+
 struct MockOverlays {
     tiling_overlays: HashMap<HwndId, MockTilingOverlay>,
     next_tiling_overlay_id: Rc<Cell<isize>>,
@@ -1186,6 +1243,7 @@ struct MockOverlays {
     z_stack: ZOrderStack,
     next_float_overlay_id: Rc<Cell<isize>>,
     tab_bars: Rc<RefCell<HashMap<ContainerId, MockTabBarOverlay>>>,
+    overview: MockOverview,
 }
 
 impl CreateOverlay for Rc<RefCell<MockOverlays>> {
@@ -1258,6 +1316,14 @@ impl CreateOverlay for Rc<RefCell<MockOverlays>> {
             map: this.tab_bars.clone(),
         }))
     }
+    fn create_overview(
+        &self,
+        _config: Config,
+        _monitor: PixelRect,
+        _scale: f32,
+    ) -> anyhow::Result<Box<dyn OverviewOverlayApi>> {
+        Ok(Box::new(MockOverviewHandle(self.borrow().overview.clone())))
+    }
 }
 
 fn setup_logger() {
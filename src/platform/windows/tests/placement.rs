@@ -268,6 +268,7 @@ fn painted_thickness_matches_core_inset() {
     let TilingOverlayState::Visible {
         windows,
         border_thickness,
+        ..
     } = env.tiling_overlays()[0].state.clone()
     else {
         panic!(
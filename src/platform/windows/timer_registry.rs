@@ -9,6 +9,8 @@ pub(super) enum TimerKind {
     Focus,
     MoveSettle { hwnd: HwndId, observed_at: Instant },
     DriftRetry,
+    KeymapDeadline,
+    AnimationFrame,
 }
 
 pub(super) trait OsTimer {
@@ -66,6 +68,27 @@ impl TimerRegistry {
         }
     }
 
+    /// One timer for keymap timeouts (pending sequence or idle mode). A new
+    /// deadline replaces the old one.
+    pub(super) fn schedule_keymap_deadline(&mut self, delay: Duration) {
+        let hint = self.find_keymap_deadline_id().unwrap_or(0);
+        self.schedule(TimerKind::KeymapDeadline, hint, delay);
+    }
+
+    /// One timer for the next animation frame. A new frame replaces the old
+    /// one.
+    pub(super) fn schedule_animation_frame(&mut self, delay: Duration) {
+        let hint = self.find_animation_frame_id().unwrap_or(0);
+        self.schedule(TimerKind::AnimationFrame, hint, delay);
+    }
+
+    pub(super) fn cancel_animation_frame(&mut self) {
+        if let Some(id) = self.find_animation_frame_id() {
+            self.os.kill_timer(id);
+            self.by_id.remove(&id);
+        }
+    }
+
     pub(super) fn schedule_drift_retry(&mut self, period: Duration) {
         self.schedule(TimerKind::DriftRetry, 0, period);
     }
@@ -73,7 +96,10 @@ impl TimerRegistry {
     pub(super) fn dispatch(&mut self, timer_id: usize) -> Option<TimerKind> {
         let kind = self.by_id.get(&timer_id).copied()?;
         match kind {
-            TimerKind::Focus | TimerKind::MoveSettle { .. } => {
+            TimerKind::Focus
+            | TimerKind::MoveSettle { .. }
+            | TimerKind::KeymapDeadline
+            | TimerKind::AnimationFrame => {
                 self.by_id.remove(&timer_id);
                 self.os.kill_timer(timer_id);
             }
@@ -102,6 +128,18 @@ impl TimerRegistry {
             .find_map(|(&id, k)| matches!(k, TimerKind::Focus).then_some(id))
     }
 
+    fn find_keymap_deadline_id(&self) -> Option<usize> {
+        self.by_id
+            .iter()
+            .find_map(|(&id, k)| matches!(k, TimerKind::KeymapDeadline).then_some(id))
+    }
+
+    fn find_animation_frame_id(&self) -> Option<usize> {
+        self.by_id
+            .iter()
+            .find_map(|(&id, k)| matches!(k, TimerKind::AnimationFrame).then_some(id))
+    }
+
     fn find_move_settle_id(&self, target: HwndId) -> Option<usize> {
         self.by_id.iter().find_map(|(&id, k)| match k {
             TimerKind::MoveSettle { hwnd, .. } if *hwnd == target => Some(id),
@@ -231,6 +269,20 @@ mod tests {
         assert!(kill_calls.borrow().is_empty());
     }
 
+    #[test]
+    fn cancel_animation_frame_kills_the_pending_frame() {
+        let mock = MockOs::new(300);
+        let kill_calls = mock.kill_calls.clone();
+        let mut reg = TimerRegistry::new(Box::new(mock));
+
+        reg.schedule_animation_frame(Duration::from_millis(16));
+        reg.schedule_animation_frame(Duration::from_millis(16));
+        reg.cancel_animation_frame();
+
+        assert_eq!(*kill_calls.borrow(), vec![300]);
+        assert!(reg.dispatch(300).is_none());
+    }
+
     #[test]
     fn schedule_focus_uses_previous_id_as_hint_when_live() {
         let mock = MockOs::new(100);
@@ -2,12 +2,15 @@ use egui::Color32;
 use egui::epaint::Shadow;
 use egui::style::{Selection, WidgetVisuals, Widgets};
 use egui::{Stroke, Visuals};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::config::Config;
 
 // Mocha is the darkest flavour and matches Dome's pre-theme default palette.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+// pub because `Action::Theme` carries it over IPC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
-pub(crate) enum Flavor {
+pub enum Flavor {
     Latte,
     Frappe,
     Macchiato,
@@ -15,6 +18,45 @@ pub(crate) enum Flavor {
     Mocha,
 }
 
+impl std::fmt::Display for Flavor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Flavor::Latte => "latte",
+            Flavor::Frappe => "frappe",
+            Flavor::Macchiato => "macchiato",
+            Flavor::Mocha => "mocha",
+        };
+        f.write_str(name)
+    }
+}
+
+impl std::str::FromStr for Flavor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "latte" => Ok(Flavor::Latte),
+            "frappe" => Ok(Flavor::Frappe),
+            "macchiato" => Ok(Flavor::Macchiato),
+            "mocha" => Ok(Flavor::Mocha),
+            _ => Err(anyhow::anyhow!(
+                "Unknown flavor: {s} (expected latte, frappe, macchiato or mocha)"
+            )),
+        }
+    }
+}
+
+/// System light/dark setting as reported by the platform shell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum Appearance {
+    Light,
+    #[default]
+    Dark,
+}
+
+/// Opacity of `float_border` on floats that are not focused.
+const UNFOCUSED_FLOAT_FADE: f32 = 0.5;
+
 // DTO: a resolved palette with no invariants. pub(crate) fields are intentional.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Theme {
@@ -24,6 +66,14 @@ pub(crate) struct Theme {
     pub(crate) tab_bar_bg: Color32,
     pub(crate) active_tab_bg: Color32,
     pub(crate) tab_text: Color32,
+    pub(crate) float_border: Color32,
+    pub(crate) fullscreen_border: Color32,
+    pub(crate) urgent_border: Color32,
+    pub(crate) marked_border: Color32,
+    /// Focused window of a monitor that does not hold keyboard focus.
+    pub(crate) inactive_focused_border: Color32,
+    /// Newly focused window, for `animation.focus_flash_ms`.
+    pub(crate) focus_flash: Color32,
 }
 
 impl Theme {
@@ -36,7 +86,218 @@ impl Theme {
             tab_bar_bg: p.mantle,
             active_tab_bg: p.surface1,
             tab_text: p.text,
+            float_border: p.mauve,
+            fullscreen_border: p.green,
+            urgent_border: p.red,
+            marked_border: p.yellow,
+            inactive_focused_border: p.overlay1,
+            focus_flash: p.text,
+        }
+    }
+
+    /// Border of a float without focus or emphasis: `float_border`, faded so
+    /// the focused float still stands out.
+    pub(crate) fn unfocused_float_border(&self) -> Color32 {
+        self.float_border.gamma_multiply(UNFOCUSED_FLOAT_FADE)
+    }
+
+    fn slot_mut(&mut self, slot: ThemeColor) -> &mut Color32 {
+        match slot {
+            ThemeColor::FocusedBorder => &mut self.focused_border,
+            ThemeColor::UnfocusedBorder => &mut self.unfocused_border,
+            ThemeColor::SpawnIndicator => &mut self.spawn_indicator,
+            ThemeColor::TabBarBg => &mut self.tab_bar_bg,
+            ThemeColor::ActiveTabBg => &mut self.active_tab_bg,
+            ThemeColor::TabText => &mut self.tab_text,
+            ThemeColor::FloatBorder => &mut self.float_border,
+            ThemeColor::FullscreenBorder => &mut self.fullscreen_border,
+            ThemeColor::UrgentBorder => &mut self.urgent_border,
+            ThemeColor::MarkedBorder => &mut self.marked_border,
+            ThemeColor::InactiveFocusedBorder => &mut self.inactive_focused_border,
+            ThemeColor::FocusFlash => &mut self.focus_flash,
+        }
+    }
+}
+
+/// A `Theme` color the `[theme]` table can override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ThemeColor {
+    FocusedBorder,
+    UnfocusedBorder,
+    SpawnIndicator,
+    TabBarBg,
+    ActiveTabBg,
+    TabText,
+    FloatBorder,
+    FullscreenBorder,
+    UrgentBorder,
+    MarkedBorder,
+    InactiveFocusedBorder,
+    FocusFlash,
+}
+
+impl ThemeColor {
+    pub(crate) const ALL: [ThemeColor; 12] = [
+        ThemeColor::FocusedBorder,
+        ThemeColor::UnfocusedBorder,
+        ThemeColor::SpawnIndicator,
+        ThemeColor::TabBarBg,
+        ThemeColor::ActiveTabBg,
+        ThemeColor::TabText,
+        ThemeColor::FloatBorder,
+        ThemeColor::FullscreenBorder,
+        ThemeColor::UrgentBorder,
+        ThemeColor::MarkedBorder,
+        ThemeColor::InactiveFocusedBorder,
+        ThemeColor::FocusFlash,
+    ];
+
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            ThemeColor::FocusedBorder => "focused_border",
+            ThemeColor::UnfocusedBorder => "unfocused_border",
+            ThemeColor::SpawnIndicator => "spawn_indicator",
+            ThemeColor::TabBarBg => "tab_bar_bg",
+            ThemeColor::ActiveTabBg => "active_tab_bg",
+            ThemeColor::TabText => "tab_text",
+            ThemeColor::FloatBorder => "float_border",
+            ThemeColor::FullscreenBorder => "fullscreen_border",
+            ThemeColor::UrgentBorder => "urgent_border",
+            ThemeColor::MarkedBorder => "marked_border",
+            ThemeColor::InactiveFocusedBorder => "inactive_focused_border",
+            ThemeColor::FocusFlash => "focus_flash",
+        }
+    }
+}
+
+/// The `[theme]` table: a base flavor plus hex overrides for single colors.
+/// A bare `theme = "latte"` string is the same as a table with only `flavor`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(from = "Flavor")]
+pub(crate) struct ThemeConfig {
+    /// The flavor in use. Platforms overwrite it with `ThemeSelection::select`
+    /// so overlays only ever read this field.
+    pub(crate) flavor: Flavor,
+    /// Set when the table has `light` or `dark`. Follows the system appearance
+    /// instead of `flavor`.
+    pub(crate) light_dark: Option<LightDark>,
+    pub(crate) overrides: Vec<(ThemeColor, Color32)>,
+}
+
+impl From<Flavor> for ThemeConfig {
+    fn from(flavor: Flavor) -> Self {
+        Self {
+            flavor,
+            light_dark: None,
+            overrides: Vec::new(),
+        }
+    }
+}
+
+/// Flavors for `theme = { light = "latte", dark = "mocha" }`. A missing side
+/// keeps the stock Catppuccin pairing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LightDark {
+    pub(crate) light: Flavor,
+    pub(crate) dark: Flavor,
+}
+
+impl Default for LightDark {
+    fn default() -> Self {
+        Self {
+            light: Flavor::Latte,
+            dark: Flavor::Mocha,
+        }
+    }
+}
+
+/// Runtime input to the live flavor. A `theme set` pin wins until the next
+/// config reload, then the light/dark pair for the current appearance, then
+/// the configured `flavor`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ThemeSelection {
+    appearance: Appearance,
+    pinned: Option<Flavor>,
+}
+
+impl ThemeSelection {
+    pub(crate) fn new(appearance: Appearance) -> Self {
+        Self {
+            appearance,
+            pinned: None,
+        }
+    }
+
+    pub(crate) fn set_appearance(&mut self, appearance: Appearance) {
+        self.appearance = appearance;
+    }
+
+    pub(crate) fn pin(&mut self, flavor: Flavor) {
+        self.pinned = Some(flavor);
+    }
+
+    pub(crate) fn unpin(&mut self) {
+        self.pinned = None;
+    }
+
+    pub(crate) fn select(&self, theme: &ThemeConfig) -> Flavor {
+        if let Some(flavor) = self.pinned {
+            return flavor;
+        }
+        match (theme.light_dark, self.appearance) {
+            (Some(pair), Appearance::Light) => pair.light,
+            (Some(pair), Appearance::Dark) => pair.dark,
+            (None, _) => theme.flavor,
+        }
+    }
+
+    /// A copy of `config` with the selected flavor, for the overlays to draw
+    /// with. The loaded config itself keeps the flavor it was written with.
+    pub(crate) fn themed(&self, config: &Config) -> Config {
+        let mut themed = config.clone();
+        themed.theme.flavor = self.select(&config.theme);
+        themed
+    }
+}
+
+impl ThemeConfig {
+    pub(crate) fn resolve(&self) -> Theme {
+        let mut theme = Theme::from_flavor(self.flavor);
+        for &(slot, color) in &self.overrides {
+            *theme.slot_mut(slot) = color;
+        }
+        theme
+    }
+}
+
+/// `#rrggbb` or `#rrggbbaa`. Alpha is straight, not premultiplied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HexColor(pub(crate) Color32);
+
+impl std::str::FromStr for HexColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("expected #rrggbb or #rrggbbaa, got {s:?}");
+        let hex = s.strip_prefix('#').ok_or_else(err)?;
+        if !matches!(hex.len(), 6 | 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(err());
         }
+        let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| err());
+        let alpha = if hex.len() == 8 { byte(6)? } else { u8::MAX };
+        Ok(HexColor(Color32::from_rgba_unmultiplied(
+            byte(0)?,
+            byte(2)?,
+            byte(4)?,
+            alpha,
+        )))
+    }
+}
+
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -198,7 +459,7 @@ pub(crate) fn palette(flavor: Flavor) -> Palette {
 
 /// Sets egui's built-in widget chrome to the Catppuccin palette for `flavor`.
 /// Dome-specific painted colours (borders, tab bars) come from
-/// `Theme::from_flavor` instead.
+/// `ThemeConfig::resolve` instead.
 pub(crate) fn apply_catppuccin(ctx: &egui::Context, flavor: Flavor) {
     let p = palette(flavor);
     let old = ctx.global_style().visuals.clone();
@@ -332,6 +593,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn hex_color_parses_rgb_and_rgba() {
+        assert_eq!(
+            "#ff8000".parse::<HexColor>().unwrap().0,
+            Color32::from_rgb(255, 128, 0)
+        );
+        assert_eq!(
+            "#FF800080".parse::<HexColor>().unwrap().0,
+            Color32::from_rgba_unmultiplied(255, 128, 0, 128)
+        );
+        for bad in ["ff8000", "#ff80", "#ff800", "#gg8000", "#ff8000800"] {
+            assert!(bad.parse::<HexColor>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn theme_config_overrides_only_named_colors() {
+        let red = Color32::from_rgb(255, 0, 0);
+        let theme = ThemeConfig {
+            flavor: Flavor::Latte,
+            light_dark: None,
+            overrides: vec![(ThemeColor::MarkedBorder, red)],
+        }
+        .resolve();
+        let base = Theme::from_flavor(Flavor::Latte);
+        assert_eq!(theme.marked_border, red);
+        assert_eq!(theme.focused_border, base.focused_border);
+        assert_eq!(theme.float_border, base.float_border);
+    }
+
+    #[test]
+    fn flavor_display_round_trips() {
+        for flavor in [
+            Flavor::Latte,
+            Flavor::Frappe,
+            Flavor::Macchiato,
+            Flavor::Mocha,
+        ] {
+            assert_eq!(flavor.to_string().parse::<Flavor>().unwrap(), flavor);
+        }
+        assert!("dracula".parse::<Flavor>().is_err());
+    }
+
+    #[test]
+    fn selection_without_pair_uses_flavor() {
+        let theme = ThemeConfig::from(Flavor::Frappe);
+        for appearance in [Appearance::Light, Appearance::Dark] {
+            assert_eq!(
+                ThemeSelection::new(appearance).select(&theme),
+                Flavor::Frappe
+            );
+        }
+    }
+
+    #[test]
+    fn selection_follows_appearance() {
+        let theme = ThemeConfig {
+            light_dark: Some(LightDark {
+                light: Flavor::Latte,
+                dark: Flavor::Macchiato,
+            }),
+            ..ThemeConfig::from(Flavor::Frappe)
+        };
+        let mut selection = ThemeSelection::new(Appearance::Light);
+        assert_eq!(selection.select(&theme), Flavor::Latte);
+        selection.set_appearance(Appearance::Dark);
+        assert_eq!(selection.select(&theme), Flavor::Macchiato);
+    }
+
+    #[test]
+    fn pinned_flavor_wins_until_unpinned() {
+        let theme = ThemeConfig {
+            light_dark: Some(LightDark::default()),
+            ..ThemeConfig::default()
+        };
+        let mut selection = ThemeSelection::new(Appearance::Light);
+        selection.pin(Flavor::Frappe);
+        selection.set_appearance(Appearance::Dark);
+        assert_eq!(selection.select(&theme), Flavor::Frappe);
+        selection.unpin();
+        assert_eq!(selection.select(&theme), Flavor::Mocha);
+    }
+
+    #[test]
+    fn theme_color_keys_are_unique() {
+        let keys: std::collections::HashSet<_> = ThemeColor::ALL.iter().map(|c| c.key()).collect();
+        assert_eq!(keys.len(), ThemeColor::ALL.len());
+    }
+
     #[test]
     fn palette_latte_differs_from_mocha() {
         assert_ne!(palette(Flavor::Latte).base, palette(Flavor::Mocha).base);